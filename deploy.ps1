@@ -80,9 +80,7 @@ soroban contract invoke `
   --network $Network `
   -- `
   initialize `
-  --admin $Address `
-  --usdc_token $UsdcId `
-  --fee_bps 250
+  --config "{`"admin`":`"$Address`",`"tokens`":[`"$UsdcId`"],`"fee_bps`":250,`"rate_limit_cooldown`":0,`"min_transfer_amount`":`"0`",`"max_transfer_amount`":null,`"treasury`":null,`"paused`":false,`"paused_ops`":[]}"
 
 Write-Host ""
 Write-Host "🎉 Deployment Complete!" -ForegroundColor Cyan
@@ -70,9 +70,7 @@ soroban contract invoke \
   --network $NETWORK \
   -- \
   initialize \
-  --admin $ADDRESS \
-  --usdc_token $USDC_ID \
-  --fee_bps 250
+  --config "{\"admin\":\"$ADDRESS\",\"tokens\":[\"$USDC_ID\"],\"fee_bps\":250,\"rate_limit_cooldown\":0,\"min_transfer_amount\":\"0\",\"max_transfer_amount\":null,\"treasury\":null,\"paused\":false,\"paused_ops\":[]}"
 
 echo ""
 echo "🎉 Deployment Complete!"
@@ -269,12 +269,14 @@ async function createRemittance(senderKeypair, agentAddress, amount) {
   // - agent: Address
   // - amount: i128
   // - expiry: Option<u64>
+  // - idempotency_key: Option<BytesN<32>> (reuse the same key when retrying)
   
   const args = [
     new StellarSdk.Address(sender).toScVal(),
     new StellarSdk.Address(agentAddress).toScVal(),
     StellarSdk.xdr.ScVal.scvI128(amountStroops),
     StellarSdk.xdr.ScVal.scvVoid(), // No expiry
+    StellarSdk.xdr.ScVal.scvVoid(), // No idempotency key
   ];
   
   const response = await invokeContract(
@@ -349,6 +351,7 @@ async function withdrawFees(adminKeypair, recipientAddress) {
   logger.info({ recipientAddress }, '=== Withdrawing Fees ===');
   
   const args = [
+    new StellarSdk.Address(CONFIG.usdcTokenId).toScVal(),
     new StellarSdk.Address(recipientAddress).toScVal(),
   ];
   
@@ -0,0 +1,173 @@
+//! Settlement accounting.
+//!
+//! Every USDC unit held by the contract for remittances is booked either as
+//! escrow (the net amount owed to the agent) or as accumulated fees:
+//!
+//! - Creating a remittance accrues its fee to the platform immediately and
+//!   holds only the net amount (`amount - fee`) in escrow.
+//! - Paying out, in one go or in tranches, releases the net amount from
+//!   escrow. The fee is already accrued, so accumulated fees do not change.
+//! - Cancelling or expiring a remittance refunds the unreleased amount in
+//!   full: the unreleased net leaves escrow and the unreleased fee is reversed
+//!   out of accumulated fees.
+//! - Quarantining a remittance books it like a refund, but the funds are held
+//!   in the quarantine vault instead of leaving the contract.
+//! - A direct remittance pays the agent without passing through the contract;
+//!   only its fee is received, and it accrues as platform property at once.
+//!
+//! Because a fee can still be refunded until its remittance is paid out, the
+//! fees of open remittances are tracked as refundable and cannot be withdrawn.
+//! Fees become platform property, and withdrawable, when the payout is made.
+//! Throughout, the contract's USDC balance equals escrow plus accumulated fees
+//! (plus insurance, queued settlements and quarantine), which `reconcile` checks.
+//! Escrow deposited in a yield adapter counts towards the balance, and is
+//! withdrawn as soon as escrow is released (see the `yield_adapter` module).
+//!
+//! The balances themselves are accounts of the double-entry ledger (see the
+//! `ledger` module), so every booking here is also a journal entry.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::{
+    decrease_escrow, get_accumulated_fees, get_accumulated_fees_for, get_total_escrow,
+    get_usdc_token, increase_escrow, post_entry, quarantine_funds, rebalance_yield,
+    set_accumulated_fees, AccountClass, ContractError,
+};
+
+#[contracttype]
+#[derive(Clone)]
+enum AccountingKey {
+    /// Accrued fees of open remittances, which a refund may still reverse
+    RefundableFees,
+}
+
+/// Returns the accrued fees of open remittances that may still be refunded.
+pub fn get_refundable_fees(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&AccountingKey::RefundableFees)
+        .unwrap_or(0)
+}
+
+fn adjust_refundable_fees(env: &Env, delta: i128) -> Result<(), ContractError> {
+    let refundable = get_refundable_fees(env)
+        .checked_add(delta)
+        .ok_or(ContractError::Overflow)?;
+    if refundable < 0 {
+        return Err(ContractError::InsufficientEscrow);
+    }
+    env.storage()
+        .instance()
+        .set(&AccountingKey::RefundableFees, &refundable);
+    Ok(())
+}
+
+fn adjust_accumulated_fees(env: &Env, delta: i128) -> Result<(), ContractError> {
+    let fees = get_accumulated_fees(env)?
+        .checked_add(delta)
+        .ok_or(ContractError::Overflow)?;
+    if fees < 0 {
+        return Err(ContractError::InsufficientEscrow);
+    }
+    set_accumulated_fees(env, fees)
+}
+
+/// Returns the amount owed to the agent for `amount` after `fee`.
+pub fn net_amount(amount: i128, fee: i128) -> Result<i128, ContractError> {
+    amount.checked_sub(fee).ok_or(ContractError::Overflow)
+}
+
+/// Books funds received for a new remittance: the fee accrues, the net is escrowed.
+pub fn book_remittance(env: &Env, amount: i128, fee: i128) -> Result<(), ContractError> {
+    increase_escrow(env, net_amount(amount, fee)?)?;
+    adjust_accumulated_fees(env, fee)?;
+    adjust_refundable_fees(env, fee)
+}
+
+/// Books the release of `amount` (carrying `fee`) to the agent.
+///
+/// # Returns
+///
+/// * `Ok(i128)` - Net amount to transfer to the agent
+pub fn book_payout(env: &Env, amount: i128, fee: i128) -> Result<i128, ContractError> {
+    let payout = net_amount(amount, fee)?;
+    decrease_escrow(env, payout)?;
+    rebalance_yield(env)?;
+    adjust_refundable_fees(env, -fee)?;
+    Ok(payout)
+}
+
+/// Books the release of a hop fee from escrow to the intermediary that earned it.
+///
+/// The fee moves from escrow to commissions, and out of commissions as it is
+/// paid, so hop fees show up in the ledger separately from agent payouts.
+pub fn book_commission(env: &Env, hop_fee: i128) -> Result<(), ContractError> {
+    if hop_fee > get_total_escrow(env) {
+        return Err(ContractError::InsufficientEscrow);
+    }
+    post_entry(env, AccountClass::Escrow, AccountClass::Commissions, hop_fee)?;
+    rebalance_yield(env)?;
+    post_entry(env, AccountClass::Commissions, AccountClass::Holdings, hop_fee)
+}
+
+/// Books the refund of `amount` (carrying `fee`) to the sender.
+///
+/// # Returns
+///
+/// * `Ok(i128)` - Amount to transfer back to the sender, fee included
+pub fn book_refund(env: &Env, amount: i128, fee: i128) -> Result<i128, ContractError> {
+    decrease_escrow(env, net_amount(amount, fee)?)?;
+    rebalance_yield(env)?;
+    adjust_accumulated_fees(env, -fee)?;
+    adjust_refundable_fees(env, -fee)?;
+    Ok(amount)
+}
+
+/// Books `amount` (carrying `fee`) of a remittance into quarantine.
+///
+/// # Returns
+///
+/// * `Ok(i128)` - Amount now held in quarantine, fee included
+pub fn book_quarantine(
+    env: &Env,
+    remittance_id: u64,
+    amount: i128,
+    fee: i128,
+) -> Result<i128, ContractError> {
+    let held = book_refund(env, amount, fee)?;
+    quarantine_funds(env, remittance_id, held)?;
+    Ok(held)
+}
+
+/// Returns the fees in `token` that are platform property and may be withdrawn.
+pub fn get_withdrawable_fees_for(env: &Env, token: &Address) -> i128 {
+    let fees = get_accumulated_fees_for(env, token);
+    match get_usdc_token(env) {
+        Ok(usdc) if usdc == *token => fees.saturating_sub(get_refundable_fees(env)),
+        _ => fees,
+    }
+}
+
+/// Returns the USDC fees that are platform property and may be withdrawn.
+pub fn get_withdrawable_fees(env: &Env) -> Result<i128, ContractError> {
+    let usdc = get_usdc_token(env)?;
+    Ok(get_withdrawable_fees_for(env, &usdc))
+}
+
+/// Books the fee of a direct remittance, which is never refundable.
+pub fn book_direct_fee(env: &Env, fee: i128) -> Result<(), ContractError> {
+    adjust_accumulated_fees(env, fee)
+}
+
+/// Books `amount` of a paid-out remittance's fee rebated to its sender.
+pub fn book_fee_rebate(env: &Env, amount: i128) -> Result<(), ContractError> {
+    adjust_accumulated_fees(env, -amount)
+}
+
+/// Books `amount` of withdrawable USDC fees leaving the contract.
+pub fn book_fee_withdrawal(env: &Env, amount: i128) -> Result<(), ContractError> {
+    if amount > get_withdrawable_fees(env)? {
+        return Err(ContractError::InsufficientEscrow);
+    }
+    adjust_accumulated_fees(env, -amount)
+}
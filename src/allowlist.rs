@@ -0,0 +1,49 @@
+//! Sender allowlist for closed-loop deployments.
+//!
+//! Enterprise deployments may restrict remittance creation to approved
+//! corporate senders. The allowlist is opt-in: while it is disabled every
+//! sender may create remittances, and approvals made in the meantime are kept
+//! so the list can be prepared before the mode is switched on.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+#[contracttype]
+#[derive(Clone)]
+enum AllowlistKey {
+    /// Whether the sender allowlist is enforced
+    Enabled,
+    /// Approved sender (persistent storage)
+    Sender(Address),
+}
+
+pub fn set_sender_allowlist_enabled(env: &Env, enabled: bool) {
+    env.storage().instance().set(&AllowlistKey::Enabled, &enabled);
+}
+
+pub fn is_sender_allowlist_enabled(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&AllowlistKey::Enabled)
+        .unwrap_or(false)
+}
+
+pub fn set_sender_allowed(env: &Env, sender: &Address, allowed: bool) {
+    let key = AllowlistKey::Sender(sender.clone());
+    if allowed {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Returns whether `sender` is on the allowlist, regardless of whether it is enforced.
+pub fn is_sender_listed(env: &Env, sender: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&AllowlistKey::Sender(sender.clone()))
+}
+
+/// Returns whether `sender` may create remittances under the current mode.
+pub fn is_sender_allowed(env: &Env, sender: &Address) -> bool {
+    !is_sender_allowlist_enabled(env) || is_sender_listed(env, sender)
+}
@@ -0,0 +1,176 @@
+//! Completion mirror to a registered analytics contract.
+//!
+//! When enabled, every completed remittance is forwarded as a compact
+//! `CompletionRecord` to the sink contract's `on_completion` function so
+//! on-chain analytics products (corridor indexes, volume oracles) can build on
+//! SwiftRemit data without replaying events. The call is best-effort: a failing
+//! sink never blocks a payout but is reported with a `diag` event, and at most `max_records_per_ledger` records are
+//! mirrored per ledger so a busy ledger cannot drain the transaction budget.
+//!
+//! Independently of the sink, volume and fee counters are rolled into daily
+//! epochs derived from the ledger timestamp, so daily volume can be charted
+//! from contract state with `get_epoch_stats` instead of an event pipeline.
+
+use soroban_sdk::{contracttype, symbol_short, Address, Env, IntoVal, InvokeError, Symbol, Val, Vec};
+
+use crate::{emit_operation_failed, ContractError, Remittance};
+
+/// Function invoked on the sink contract for each completed remittance.
+pub const ANALYTICS_SINK_FN: &str = "on_completion";
+
+/// Length of an analytics epoch in seconds (one day).
+pub const EPOCH_SECONDS: u64 = 86400;
+
+/// Analytics sink registration.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AnalyticsConfig {
+    /// Contract receiving completion records
+    pub sink: Address,
+    /// Whether records are currently mirrored
+    pub enabled: bool,
+    /// Maximum number of records mirrored per ledger
+    pub max_records_per_ledger: u32,
+}
+
+/// Compact record sent to the sink for a completed remittance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CompletionRecord {
+    /// ID of the completed remittance
+    pub remittance_id: u64,
+    /// Agent that paid out the remittance
+    pub agent: Address,
+    /// Remittance amount
+    pub amount: i128,
+    /// Platform fee charged
+    pub fee: i128,
+    /// Timestamp of completion
+    pub completed_at: u64,
+}
+
+/// Volume and fee counters of one epoch.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct EpochStats {
+    /// Number of remittances created
+    pub created_count: u32,
+    /// Total amount of remittances created
+    pub created_volume: i128,
+    /// Number of remittances paid out
+    pub completed_count: u32,
+    /// Total amount of remittances paid out
+    pub completed_volume: i128,
+    /// Platform fees earned on payouts, after any rebates
+    pub fees: i128,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum AnalyticsKey {
+    /// Sink registration
+    Config,
+    /// Ledger sequence and number of records mirrored in it (temporary storage)
+    LedgerCount,
+    /// Counters of an epoch (persistent storage)
+    Epoch(u64),
+}
+
+pub fn set_analytics_config(env: &Env, config: &AnalyticsConfig) {
+    env.storage().instance().set(&AnalyticsKey::Config, config);
+}
+
+pub fn get_analytics_config(env: &Env) -> Option<AnalyticsConfig> {
+    env.storage().instance().get(&AnalyticsKey::Config)
+}
+
+/// Forwards a completion record to the sink if one is registered and enabled.
+///
+/// Returns whether the sink accepted the record. Records beyond the per-ledger
+/// budget are dropped without calling the sink.
+pub fn mirror_completion(env: &Env, remittance: &Remittance) -> bool {
+    let config = match get_analytics_config(env) {
+        Some(config) if config.enabled => config,
+        _ => return false,
+    };
+
+    let ledger = env.ledger().sequence();
+    let (counted_ledger, count): (u32, u32) = env
+        .storage()
+        .temporary()
+        .get(&AnalyticsKey::LedgerCount)
+        .unwrap_or((ledger, 0));
+    let count = if counted_ledger == ledger { count } else { 0 };
+    if count >= config.max_records_per_ledger {
+        return false;
+    }
+    env.storage()
+        .temporary()
+        .set(&AnalyticsKey::LedgerCount, &(ledger, count + 1));
+
+    let record = CompletionRecord {
+        remittance_id: remittance.id,
+        agent: remittance.agent.clone(),
+        amount: remittance.amount,
+        fee: remittance.fee,
+        completed_at: env.ledger().timestamp(),
+    };
+    let args: Vec<Val> = (record,).into_val(env);
+
+    match env.try_invoke_contract::<(), InvokeError>(&config.sink, &Symbol::new(env, ANALYTICS_SINK_FN), args) {
+        Ok(Ok(())) => true,
+        result => {
+            let error_code = match result {
+                Err(Ok(InvokeError::Contract(code))) => code,
+                _ => 0,
+            };
+            emit_operation_failed(env, symbol_short!("analytics"), config.sink, error_code, remittance.id);
+            false
+        }
+    }
+}
+
+/// Returns the epoch containing `timestamp`.
+pub fn epoch_of(timestamp: u64) -> u64 {
+    timestamp / EPOCH_SECONDS
+}
+
+/// Returns the counters of `epoch`, all zero if nothing happened in it.
+pub fn get_epoch_stats(env: &Env, epoch: u64) -> EpochStats {
+    env.storage()
+        .persistent()
+        .get(&AnalyticsKey::Epoch(epoch))
+        .unwrap_or_default()
+}
+
+fn update_current_epoch(
+    env: &Env,
+    update: impl FnOnce(&mut EpochStats) -> Option<()>,
+) -> Result<(), ContractError> {
+    let epoch = epoch_of(env.ledger().timestamp());
+    let mut stats = get_epoch_stats(env, epoch);
+    update(&mut stats).ok_or(ContractError::Overflow)?;
+    env.storage()
+        .persistent()
+        .set(&AnalyticsKey::Epoch(epoch), &stats);
+    Ok(())
+}
+
+/// Counts a remittance of `amount` created now.
+pub fn record_epoch_created(env: &Env, amount: i128) -> Result<(), ContractError> {
+    update_current_epoch(env, |stats| {
+        stats.created_count = stats.created_count.checked_add(1)?;
+        stats.created_volume = stats.created_volume.checked_add(amount)?;
+        Some(())
+    })
+}
+
+/// Counts a remittance of `amount` paid out now, earning `fee`.
+pub fn record_epoch_completed(env: &Env, amount: i128, fee: i128) -> Result<(), ContractError> {
+    update_current_epoch(env, |stats| {
+        stats.completed_count = stats.completed_count.checked_add(1)?;
+        stats.completed_volume = stats.completed_volume.checked_add(amount)?;
+        stats.fees = stats.fees.checked_add(fee)?;
+        Some(())
+    })
+}
@@ -0,0 +1,127 @@
+//! Archival of closed remittances.
+//!
+//! Completed and cancelled remittances are kept in full persistent storage
+//! forever unless archived, and every entry pays ledger rent. Archiving
+//! replaces the full record with a compact `ArchivedRemittance` holding the ID,
+//! a SHA-256 hash of the full record's XDR (so off-chain copies can be
+//! verified) and the final status.
+//!
+//! A remittance becomes eligible once it has been closed for longer than the
+//! requested ledger. Archival walks IDs upwards from a cursor, so repeated
+//! calls with a small `limit` make steady progress within budget.
+
+use soroban_sdk::{contracttype, BytesN, Env, Vec};
+
+use crate::{
+    compute_record_hash, get_remittance, get_remittance_counter, get_reservation,
+    remove_remittance, ContractError, RemittanceStatus,
+};
+
+/// Maximum number of remittance IDs examined by one archival call.
+pub const MAX_ARCHIVE_BATCH: u32 = 100;
+
+/// Compact record kept for an archived remittance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArchivedRemittance {
+    /// Remittance ID
+    pub id: u64,
+    /// SHA-256 hash of the full remittance record's XDR at archival time
+    pub hash: BytesN<32>,
+    /// Final status of the remittance
+    pub status: RemittanceStatus,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum ArchiveKey {
+    /// Lowest remittance ID that may still hold a full record
+    Cursor,
+    /// Ledger sequence a remittance was closed in (persistent storage)
+    ClosedAt(u64),
+    /// Archived record (persistent storage)
+    Archived(u64),
+}
+
+/// Records the ledger a remittance reached a terminal status in.
+pub fn mark_remittance_closed(env: &Env, remittance_id: u64) {
+    env.storage()
+        .persistent()
+        .set(&ArchiveKey::ClosedAt(remittance_id), &env.ledger().sequence());
+}
+
+pub fn get_archived_remittance(env: &Env, remittance_id: u64) -> Option<ArchivedRemittance> {
+    env.storage()
+        .persistent()
+        .get(&ArchiveKey::Archived(remittance_id))
+}
+
+/// Archives remittances closed before `before_ledger`, examining at most `limit` IDs.
+///
+/// Pending remittances and those closed at or after `before_ledger` are left
+/// untouched. The cursor only advances past IDs that no longer hold a full
+/// record, so skipped remittances are examined again on the next call.
+///
+/// # Returns
+///
+/// * `Ok(Vec<u64>)` - IDs archived by this call
+/// * `Err(ContractError::InvalidAmount)` - Limit is zero
+/// * `Err(ContractError::LimitExceeded)` - Limit exceeds MAX_ARCHIVE_BATCH
+pub fn archive_remittances(
+    env: &Env,
+    before_ledger: u32,
+    limit: u32,
+) -> Result<Vec<u64>, ContractError> {
+    if limit == 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+    if limit > MAX_ARCHIVE_BATCH {
+        return Err(ContractError::LimitExceeded);
+    }
+
+    let counter = get_remittance_counter(env)?;
+    let mut cursor: u64 = env.storage().instance().get(&ArchiveKey::Cursor).unwrap_or(1);
+    let mut contiguous = true;
+    let mut archived = Vec::new(env);
+
+    let end = cursor.saturating_add(limit as u64).min(counter.saturating_add(1));
+    for id in cursor..end {
+        let remittance = match get_remittance(env, id) {
+            Ok(remittance) => remittance,
+            Err(_) => {
+                // Reserved IDs may still be created later; anything else is already archived
+                if get_reservation(env, id).is_some() {
+                    contiguous = false;
+                } else if contiguous {
+                    cursor = id + 1;
+                }
+                continue;
+            }
+        };
+
+        let closed_at: Option<u32> = env.storage().persistent().get(&ArchiveKey::ClosedAt(id));
+        let eligible = remittance.status != RemittanceStatus::Pending
+            && closed_at.map_or(false, |ledger| ledger < before_ledger);
+        if !eligible {
+            contiguous = false;
+            continue;
+        }
+
+        let record = ArchivedRemittance {
+            id,
+            hash: compute_record_hash(env, &remittance),
+            status: remittance.status,
+        };
+        env.storage().persistent().set(&ArchiveKey::Archived(id), &record);
+        env.storage().persistent().remove(&ArchiveKey::ClosedAt(id));
+        remove_remittance(env, id);
+        archived.push_back(id);
+
+        if contiguous {
+            cursor = id + 1;
+        }
+    }
+
+    env.storage().instance().set(&ArchiveKey::Cursor, &cursor);
+    Ok(archived)
+}
@@ -0,0 +1,77 @@
+//! Two-phase payouts with receipt attestation.
+//!
+//! In attested mode an agent's confirmation no longer releases funds. The
+//! remittance moves to `AwaitingAttestation` and the payout is released once
+//! the sender, or the configured attestation oracle, attests that the
+//! recipient was paid. If nobody attests before the timeout the payout can be
+//! released by anyone, so agents are not left unpaid by an unresponsive sender.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+/// Default time an agent waits for attestation before the payout can be released.
+pub const DEFAULT_ATTESTATION_TIMEOUT_SECONDS: u64 = 7 * 86_400;
+
+/// Attested payout mode configuration.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationConfig {
+    /// Whether agent confirmations wait for attestation
+    pub enabled: bool,
+    /// Oracle allowed to attest receipt in addition to the sender
+    pub oracle: Option<Address>,
+    /// Seconds after the agent's confirmation until the payout can be released unattested
+    pub timeout: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum AttestationKey {
+    /// Attested payout mode configuration
+    Config,
+    /// Timestamp after which an unattested payout may be released (persistent storage)
+    ReleaseAfter(u64),
+}
+
+pub fn set_attestation_config(env: &Env, config: &AttestationConfig) {
+    env.storage().instance().set(&AttestationKey::Config, config);
+}
+
+pub fn get_attestation_config(env: &Env) -> AttestationConfig {
+    env.storage()
+        .instance()
+        .get(&AttestationKey::Config)
+        .unwrap_or(AttestationConfig {
+            enabled: false,
+            oracle: None,
+            timeout: DEFAULT_ATTESTATION_TIMEOUT_SECONDS,
+        })
+}
+
+/// Returns whether `caller` may attest receipt of a remittance from `sender`.
+pub fn can_attest(env: &Env, sender: &Address, caller: &Address) -> bool {
+    caller == sender || get_attestation_config(env).oracle.as_ref() == Some(caller)
+}
+
+/// Starts the attestation window for a remittance the agent has confirmed.
+pub fn begin_attestation(env: &Env, remittance_id: u64) -> u64 {
+    let release_after = env
+        .ledger()
+        .timestamp()
+        .saturating_add(get_attestation_config(env).timeout);
+    env.storage()
+        .persistent()
+        .set(&AttestationKey::ReleaseAfter(remittance_id), &release_after);
+    release_after
+}
+
+pub fn get_attestation_deadline(env: &Env, remittance_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&AttestationKey::ReleaseAfter(remittance_id))
+}
+
+pub fn end_attestation(env: &Env, remittance_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&AttestationKey::ReleaseAfter(remittance_id));
+}
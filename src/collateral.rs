@@ -0,0 +1,287 @@
+//! Agent collateral and penalty slashing.
+//!
+//! Agents post settlement-token collateral that backs their payouts. When an
+//! agent is found to have failed a sender, for example by confirming a payout
+//! the recipient never received, the admin proposes a slash of that
+//! remittance's amount with the hash of the supporting evidence. The amount
+//! is locked in the agent's collateral for a challenge window, during which
+//! the agent can contest it by anchoring its own response. An unchallenged
+//! slash can be executed by anyone once the window has passed; a challenged
+//! one waits for the admin to uphold or drop it. Executing a slash pays the
+//! locked collateral to the remittance's sender.
+//!
+//! Collateral also earns a share of platform fees (see the `staking` module);
+//! its rewards are settled before every change to an agent's collateral.
+
+use soroban_sdk::{contracttype, Address, BytesN, Env};
+
+use crate::{adjust_liability, settle_staking_rewards, validate_amount, AccountClass, ContractError, Remittance, RemittanceStatus};
+
+/// Default time an agent has to challenge a proposed slash (3 days).
+pub const DEFAULT_CHALLENGE_WINDOW_SECONDS: u64 = 3 * 24 * 60 * 60;
+
+/// Lifecycle of a slash.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SlashStatus {
+    /// Proposed and open to challenge
+    Proposed,
+    /// Contested by the agent, awaiting the admin's decision
+    Challenged,
+    /// Dropped by the admin; the collateral was unlocked
+    Cancelled,
+    /// Executed; the collateral was paid to the sender
+    Executed,
+}
+
+/// A slash of an agent's collateral over one remittance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SlashProposal {
+    /// Remittance the slash is about
+    pub remittance_id: u64,
+    /// Agent whose collateral is slashed
+    pub agent: Address,
+    /// Sender compensated by the slash
+    pub sender: Address,
+    /// Collateral locked, and paid to the sender if the slash is executed
+    pub amount: i128,
+    /// Hash of the evidence supporting the slash
+    pub evidence_hash: BytesN<32>,
+    /// Hash of the agent's response, once challenged
+    pub response_hash: Option<BytesN<32>>,
+    /// Timestamp after which an unchallenged slash can be executed
+    pub executable_at: u64,
+    pub status: SlashStatus,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum CollateralKey {
+    /// Collateral posted by an agent (persistent storage)
+    Balance(Address),
+    /// Part of an agent's collateral locked by open slashes (persistent storage)
+    Locked(Address),
+    /// Slash over a remittance (persistent storage)
+    Slash(u64),
+    /// Challenge window in seconds
+    ChallengeWindow,
+}
+
+pub fn get_collateral(env: &Env, agent: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&CollateralKey::Balance(agent.clone()))
+        .unwrap_or(0)
+}
+
+fn set_collateral(env: &Env, agent: &Address, amount: i128) {
+    env.storage()
+        .persistent()
+        .set(&CollateralKey::Balance(agent.clone()), &amount);
+}
+
+/// Returns the part of an agent's collateral locked by open slashes.
+pub fn get_locked_collateral(env: &Env, agent: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&CollateralKey::Locked(agent.clone()))
+        .unwrap_or(0)
+}
+
+fn set_locked_collateral(env: &Env, agent: &Address, amount: i128) {
+    env.storage()
+        .persistent()
+        .set(&CollateralKey::Locked(agent.clone()), &amount);
+}
+
+/// Returns the collateral an agent can withdraw or have locked by a new slash.
+pub fn get_free_collateral(env: &Env, agent: &Address) -> i128 {
+    get_collateral(env, agent) - get_locked_collateral(env, agent)
+}
+
+pub fn set_challenge_window(env: &Env, seconds: u64) {
+    env.storage()
+        .instance()
+        .set(&CollateralKey::ChallengeWindow, &seconds);
+}
+
+pub fn get_challenge_window(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&CollateralKey::ChallengeWindow)
+        .unwrap_or(DEFAULT_CHALLENGE_WINDOW_SECONDS)
+}
+
+pub fn get_slash(env: &Env, remittance_id: u64) -> Option<SlashProposal> {
+    env.storage()
+        .persistent()
+        .get(&CollateralKey::Slash(remittance_id))
+}
+
+fn set_slash(env: &Env, slash: &SlashProposal) {
+    env.storage()
+        .persistent()
+        .set(&CollateralKey::Slash(slash.remittance_id), slash);
+}
+
+/// Books `amount` of collateral received from an agent.
+///
+/// # Returns
+///
+/// * `Ok(())` - Collateral credited
+/// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - Amount is not positive
+pub fn add_collateral(env: &Env, agent: &Address, amount: i128) -> Result<(), ContractError> {
+    validate_amount(amount)?;
+    settle_staking_rewards(env, agent)?;
+    let balance = get_collateral(env, agent)
+        .checked_add(amount)
+        .ok_or(ContractError::Overflow)?;
+    set_collateral(env, agent, balance);
+    adjust_liability(env, AccountClass::Collateral, amount)
+}
+
+/// Books `amount` of free collateral returned to an agent.
+///
+/// # Returns
+///
+/// * `Ok(())` - Collateral debited; the caller transfers it
+/// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - Amount is not positive
+/// * `Err(ContractError::InsufficientEscrow)` - Amount exceeds the free collateral
+pub fn remove_collateral(env: &Env, agent: &Address, amount: i128) -> Result<(), ContractError> {
+    validate_amount(amount)?;
+    if amount > get_free_collateral(env, agent) {
+        return Err(ContractError::InsufficientEscrow);
+    }
+    settle_staking_rewards(env, agent)?;
+    set_collateral(env, agent, get_collateral(env, agent) - amount);
+    adjust_liability(env, AccountClass::Collateral, -amount)
+}
+
+/// Proposes slashing the agent of `remittance` by its amount, locking as
+/// much of it as the agent's free collateral covers.
+///
+/// Only paid-out remittances can be slashed; the sender of a pending or
+/// refunded one still has, or got back, its escrow.
+///
+/// # Returns
+///
+/// * `Ok(SlashProposal)` - The stored proposal
+/// * `Err(ContractError::InvalidStatus)` - The remittance was not paid out, or was already
+///   slashed or is being slashed
+/// * `Err(ContractError::InsufficientEscrow)` - The agent has no free collateral
+pub fn propose_slash(
+    env: &Env,
+    remittance: &Remittance,
+    evidence_hash: BytesN<32>,
+) -> Result<SlashProposal, ContractError> {
+    if remittance.status != RemittanceStatus::Completed {
+        return Err(ContractError::InvalidStatus);
+    }
+    if let Some(existing) = get_slash(env, remittance.id) {
+        if existing.status != SlashStatus::Cancelled {
+            return Err(ContractError::InvalidStatus);
+        }
+    }
+
+    let agent = &remittance.agent;
+    let amount = remittance.amount.min(get_free_collateral(env, agent));
+    if amount <= 0 {
+        return Err(ContractError::InsufficientEscrow);
+    }
+    let locked = get_locked_collateral(env, agent)
+        .checked_add(amount)
+        .ok_or(ContractError::Overflow)?;
+    set_locked_collateral(env, agent, locked);
+
+    let slash = SlashProposal {
+        remittance_id: remittance.id,
+        agent: agent.clone(),
+        sender: remittance.sender.clone(),
+        amount,
+        evidence_hash,
+        response_hash: None,
+        executable_at: env
+            .ledger()
+            .timestamp()
+            .saturating_add(get_challenge_window(env)),
+        status: SlashStatus::Proposed,
+    };
+    set_slash(env, &slash);
+    Ok(slash)
+}
+
+/// Records the agent's challenge of a proposed slash.
+///
+/// # Returns
+///
+/// * `Ok(SlashProposal)` - The challenged proposal
+/// * `Err(ContractError::InvalidStatus)` - No open proposal, or the challenge window has passed
+pub fn challenge_slash(
+    env: &Env,
+    remittance_id: u64,
+    response_hash: BytesN<32>,
+) -> Result<SlashProposal, ContractError> {
+    let mut slash = get_slash(env, remittance_id).ok_or(ContractError::InvalidStatus)?;
+    if slash.status != SlashStatus::Proposed || env.ledger().timestamp() >= slash.executable_at {
+        return Err(ContractError::InvalidStatus);
+    }
+    slash.response_hash = Some(response_hash);
+    slash.status = SlashStatus::Challenged;
+    set_slash(env, &slash);
+    Ok(slash)
+}
+
+/// Drops an open slash and unlocks its collateral.
+///
+/// # Returns
+///
+/// * `Ok(SlashProposal)` - The cancelled proposal
+/// * `Err(ContractError::InvalidStatus)` - The slash is not open
+pub fn cancel_slash(env: &Env, remittance_id: u64) -> Result<SlashProposal, ContractError> {
+    let mut slash = get_open_slash(env, remittance_id)?;
+    set_locked_collateral(env, &slash.agent, get_locked_collateral(env, &slash.agent) - slash.amount);
+    slash.status = SlashStatus::Cancelled;
+    set_slash(env, &slash);
+    Ok(slash)
+}
+
+/// Takes the locked collateral of an open slash out of the agent's collateral.
+///
+/// A slash `upheld` by the admin must have been challenged; any other must
+/// be unchallenged and past its challenge window, so the agent always gets
+/// the full window to contest it.
+///
+/// # Returns
+///
+/// * `Ok(SlashProposal)` - The executed proposal; the caller pays `amount` to `sender`
+/// * `Err(ContractError::InvalidStatus)` - The slash is not open, is upheld without having
+///   been challenged, or is executed while challenged or within its challenge window
+pub fn execute_slash(env: &Env, remittance_id: u64, upheld: bool) -> Result<SlashProposal, ContractError> {
+    let mut slash = get_open_slash(env, remittance_id)?;
+    let executable = if upheld {
+        slash.status == SlashStatus::Challenged
+    } else {
+        slash.status == SlashStatus::Proposed && env.ledger().timestamp() >= slash.executable_at
+    };
+    if !executable {
+        return Err(ContractError::InvalidStatus);
+    }
+
+    let agent = &slash.agent;
+    settle_staking_rewards(env, agent)?;
+    set_locked_collateral(env, agent, get_locked_collateral(env, agent) - slash.amount);
+    set_collateral(env, agent, get_collateral(env, agent) - slash.amount);
+    adjust_liability(env, AccountClass::Collateral, -slash.amount)?;
+
+    slash.status = SlashStatus::Executed;
+    set_slash(env, &slash);
+    Ok(slash)
+}
+
+fn get_open_slash(env: &Env, remittance_id: u64) -> Result<SlashProposal, ContractError> {
+    match get_slash(env, remittance_id) {
+        Some(slash) if matches!(slash.status, SlashStatus::Proposed | SlashStatus::Challenged) => Ok(slash),
+        _ => Err(ContractError::InvalidStatus),
+    }
+}
@@ -0,0 +1,136 @@
+//! Merkle commitments to ranges of remittance records.
+//!
+//! The admin can commit a snapshot of the remittances in an ID range: the
+//! contract stores the Merkle root of their record hashes, built as described
+//! in `hashing.rs`. Archival later replaces full records with a hash, so an
+//! off-chain backup of the full records can still prove a record's contents
+//! at snapshot time against the committed root.
+
+use soroban_sdk::{contracttype, BytesN, Env, Vec};
+
+use crate::{
+    compute_merkle_root, compute_merkle_root_from_proof, compute_record_hash,
+    get_archived_remittance, get_remittance, get_remittance_counter, ContractError, Remittance,
+};
+
+/// Maximum number of remittances covered by one snapshot.
+pub const MAX_SNAPSHOT_RANGE: u64 = 100;
+
+/// Committed Merkle root of the remittances in an ID range.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RemittanceSnapshot {
+    /// First remittance ID covered
+    pub range_start: u64,
+    /// Last remittance ID covered, inclusive
+    pub range_end: u64,
+    /// Merkle root of the record hashes
+    pub root: BytesN<32>,
+    /// Ledger the snapshot was committed in
+    pub ledger: u32,
+}
+
+/// Proof that a remittance record is part of a snapshot.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordProof {
+    pub snapshot_id: u32,
+    /// Full record as it was when the snapshot was committed
+    pub record: Remittance,
+    /// Sibling hashes from the record's leaf up to the root
+    pub siblings: Vec<BytesN<32>>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum CommitmentKey {
+    /// Number of snapshots committed
+    Count,
+    /// Snapshot by ID, from 1 (persistent storage)
+    Snapshot(u32),
+}
+
+pub fn get_snapshot(env: &Env, snapshot_id: u32) -> Option<RemittanceSnapshot> {
+    env.storage()
+        .persistent()
+        .get(&CommitmentKey::Snapshot(snapshot_id))
+}
+
+pub fn get_snapshot_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&CommitmentKey::Count)
+        .unwrap_or(0)
+}
+
+/// Returns the leaf of a remittance: the hash of its full record, the hash
+/// kept when it was archived, or zero when it has no record.
+fn record_leaf(env: &Env, remittance_id: u64) -> BytesN<32> {
+    if let Ok(remittance) = get_remittance(env, remittance_id) {
+        return compute_record_hash(env, &remittance);
+    }
+    match get_archived_remittance(env, remittance_id) {
+        Some(archived) => archived.hash,
+        None => BytesN::from_array(env, &[0u8; 32]),
+    }
+}
+
+/// Commits the Merkle root of the remittances from `range_start` to `range_end`.
+///
+/// # Returns
+///
+/// * `Ok(RemittanceSnapshot)` - The stored snapshot, numbered `get_snapshot_count()`
+/// * `Err(ContractError::InvalidAmount)` - Range starts at 0 or ends before it starts
+/// * `Err(ContractError::RemittanceNotFound)` - Range ends past the last remittance
+/// * `Err(ContractError::LimitExceeded)` - Range covers more than MAX_SNAPSHOT_RANGE remittances
+pub fn commit_snapshot(
+    env: &Env,
+    range_start: u64,
+    range_end: u64,
+) -> Result<RemittanceSnapshot, ContractError> {
+    if range_start == 0 || range_end < range_start {
+        return Err(ContractError::InvalidAmount);
+    }
+    if range_end > get_remittance_counter(env)? {
+        return Err(ContractError::RemittanceNotFound);
+    }
+    if range_end - range_start >= MAX_SNAPSHOT_RANGE {
+        return Err(ContractError::LimitExceeded);
+    }
+
+    let mut leaves = Vec::new(env);
+    for remittance_id in range_start..=range_end {
+        leaves.push_back(record_leaf(env, remittance_id));
+    }
+
+    let snapshot = RemittanceSnapshot {
+        range_start,
+        range_end,
+        root: compute_merkle_root(env, &leaves),
+        ledger: env.ledger().sequence(),
+    };
+    let snapshot_id = get_snapshot_count(env) + 1;
+    env.storage()
+        .persistent()
+        .set(&CommitmentKey::Snapshot(snapshot_id), &snapshot);
+    env.storage().instance().set(&CommitmentKey::Count, &snapshot_id);
+    Ok(snapshot)
+}
+
+/// Returns whether `proof` shows its record was committed in its snapshot.
+pub fn verify_record(env: &Env, proof: &RecordProof) -> bool {
+    let snapshot = match get_snapshot(env, proof.snapshot_id) {
+        Some(snapshot) => snapshot,
+        None => return false,
+    };
+    let id = proof.record.id;
+    if id < snapshot.range_start || id > snapshot.range_end {
+        return false;
+    }
+
+    let leaf = compute_record_hash(env, &proof.record);
+    let index = (id - snapshot.range_start) as u32;
+    let leaf_count = (snapshot.range_end - snapshot.range_start + 1) as u32;
+    compute_merkle_root_from_proof(env, &leaf, index, leaf_count, &proof.siblings)
+        == Some(snapshot.root)
+}
@@ -0,0 +1,64 @@
+//! Delegated compliance checks.
+//!
+//! The admin can register an external compliance provider contract. Every new
+//! remittance is submitted to the provider's `check` function before any funds
+//! move, and creation aborts unless the provider approves it. Compliance rules
+//! can then change by registering a new provider, without upgrading the escrow
+//! contract itself.
+
+use soroban_sdk::{contracttype, Address, Env, IntoVal, InvokeError, String, Symbol, Val, Vec};
+
+use crate::ContractError;
+
+/// Function invoked on the compliance provider for each new remittance.
+///
+/// Expected signature: `check(sender: Address, agent: Address, amount: i128,
+/// corridor: Option<String>) -> bool`.
+pub const COMPLIANCE_CHECK_FN: &str = "check";
+
+#[contracttype]
+#[derive(Clone)]
+enum ComplianceKey {
+    /// Registered compliance provider contract
+    Hook,
+}
+
+pub fn set_compliance_hook(env: &Env, hook: &Address) {
+    env.storage().instance().set(&ComplianceKey::Hook, hook);
+}
+
+pub fn clear_compliance_hook(env: &Env) {
+    env.storage().instance().remove(&ComplianceKey::Hook);
+}
+
+pub fn get_compliance_hook(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&ComplianceKey::Hook)
+}
+
+/// Asks the registered compliance provider to approve a remittance.
+///
+/// Passes when no provider is registered. A provider that returns anything
+/// other than `true`, or fails, rejects the remittance.
+///
+/// # Returns
+///
+/// * `Ok(())` - No provider registered, or the provider approved
+/// * `Err(ContractError::ComplianceRejected)` - The provider rejected or failed
+pub fn check_compliance(
+    env: &Env,
+    sender: &Address,
+    agent: &Address,
+    amount: i128,
+    corridor: Option<String>,
+) -> Result<(), ContractError> {
+    let hook = match get_compliance_hook(env) {
+        Some(hook) => hook,
+        None => return Ok(()),
+    };
+
+    let args: Vec<Val> = (sender.clone(), agent.clone(), amount, corridor).into_val(env);
+    match env.try_invoke_contract::<bool, InvokeError>(&hook, &Symbol::new(env, COMPLIANCE_CHECK_FN), args) {
+        Ok(Ok(true)) => Ok(()),
+        _ => Err(ContractError::ComplianceRejected),
+    }
+}
@@ -0,0 +1,219 @@
+//! Payout corridors and their purpose-of-payment rules.
+//!
+//! The admin assigns each agent to the corridor it pays out in (for example
+//! `"US-PH"`). A remittance belongs to its agent's corridor, which lets
+//! corridor-specific rules apply without the sender naming the corridor.
+//!
+//! Receiving-country regulators often mandate a purpose code on every inbound
+//! transfer and restrict which purposes are acceptable. A corridor with no
+//! allowed purposes configured accepts every purpose code.
+//!
+//! A corridor can also carry its own platform fee, overriding the global fee
+//! for remittances paid out by its agents.
+//!
+//! A corridor can hold new remittances for a period during which the agent
+//! cannot confirm the payout, giving senders time to cancel mistakes.
+//!
+//! Corridors are named `"<origin>-<destination>"` after ISO country codes.
+//! The admin can block an origin and destination pair, e.g. an embargoed
+//! destination, and remittances through any agent whose corridor connects
+//! them are rejected.
+//!
+//! A sender cannot be the agent or payout recipient of its own remittance,
+//! unless the admin allows self-payout in the agent's corridor, e.g. for an
+//! agent that also moves its own float between countries.
+
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+
+use crate::{ContractError, PurposeCode};
+
+#[contracttype]
+#[derive(Clone)]
+enum CorridorKey {
+    /// Corridor an agent pays out in (persistent storage)
+    Agent(Address),
+    /// Purpose codes accepted in a corridor (persistent storage)
+    Purposes(String),
+    /// Platform fee override of a corridor in basis points (persistent storage)
+    Fee(String),
+    /// Blocked origin and destination country pair (persistent storage)
+    Blocked(String, String),
+    /// Hold period of new remittances in a corridor, in seconds (persistent storage)
+    Hold(String),
+    /// Whether senders may pay out to themselves in a corridor (persistent storage)
+    SelfPayout(String),
+}
+
+/// Longest corridor name, in bytes, that is split into its countries.
+const MAX_CORRIDOR_LEN: usize = 32;
+
+pub fn set_agent_corridor(env: &Env, agent: &Address, corridor: &String) {
+    env.storage()
+        .persistent()
+        .set(&CorridorKey::Agent(agent.clone()), corridor);
+}
+
+pub fn get_agent_corridor(env: &Env, agent: &Address) -> Option<String> {
+    env.storage()
+        .persistent()
+        .get(&CorridorKey::Agent(agent.clone()))
+}
+
+/// Sets the purpose codes accepted in `corridor`; an empty list accepts all.
+pub fn set_corridor_purposes(env: &Env, corridor: &String, purposes: &Vec<PurposeCode>) {
+    let key = CorridorKey::Purposes(corridor.clone());
+    if purposes.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, purposes);
+    }
+}
+
+/// Returns the purpose codes accepted in `corridor`, empty when all are accepted.
+pub fn get_corridor_purposes(env: &Env, corridor: &String) -> Vec<PurposeCode> {
+    env.storage()
+        .persistent()
+        .get(&CorridorKey::Purposes(corridor.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_corridor_fee(env: &Env, corridor: &String, fee_bps: u32) {
+    env.storage()
+        .persistent()
+        .set(&CorridorKey::Fee(corridor.clone()), &fee_bps);
+}
+
+pub fn clear_corridor_fee(env: &Env, corridor: &String) {
+    env.storage()
+        .persistent()
+        .remove(&CorridorKey::Fee(corridor.clone()));
+}
+
+pub fn get_corridor_fee(env: &Env, corridor: &String) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&CorridorKey::Fee(corridor.clone()))
+}
+
+/// Checks that `purpose` is accepted in the corridor `agent` pays out in.
+///
+/// # Returns
+///
+/// * `Ok(())` - The agent has no corridor, the corridor accepts all purposes,
+///   or `purpose` is among the accepted ones
+/// * `Err(ContractError::PurposeNotAllowed)` - The corridor does not accept `purpose`
+pub fn check_purpose_allowed(
+    env: &Env,
+    agent: &Address,
+    purpose: PurposeCode,
+) -> Result<(), ContractError> {
+    let corridor = match get_agent_corridor(env, agent) {
+        Some(corridor) => corridor,
+        None => return Ok(()),
+    };
+
+    let purposes = get_corridor_purposes(env, &corridor);
+    if purposes.is_empty() || purposes.contains(purpose) {
+        Ok(())
+    } else {
+        Err(ContractError::PurposeNotAllowed)
+    }
+}
+
+/// Sets the hold period of new remittances in `corridor`; 0 removes it.
+pub fn set_corridor_hold(env: &Env, corridor: &String, seconds: u64) {
+    let key = CorridorKey::Hold(corridor.clone());
+    if seconds > 0 {
+        env.storage().persistent().set(&key, &seconds);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Returns the hold period of new remittances in `corridor`, 0 when unset.
+pub fn get_corridor_hold(env: &Env, corridor: &String) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&CorridorKey::Hold(corridor.clone()))
+        .unwrap_or(0)
+}
+
+/// Returns when a remittance created now through `agent` leaves its hold
+/// period, or `None` when the agent's corridor has no hold period.
+pub fn hold_until_for(env: &Env, agent: &Address) -> Option<u64> {
+    let hold = get_agent_corridor(env, agent).map_or(0, |corridor| get_corridor_hold(env, &corridor));
+    if hold == 0 {
+        return None;
+    }
+    Some(env.ledger().timestamp().saturating_add(hold))
+}
+
+pub fn set_corridor_self_payout(env: &Env, corridor: &String, allowed: bool) {
+    let key = CorridorKey::SelfPayout(corridor.clone());
+    if allowed {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub fn is_corridor_self_payout_allowed(env: &Env, corridor: &String) -> bool {
+    env.storage()
+        .persistent()
+        .has(&CorridorKey::SelfPayout(corridor.clone()))
+}
+
+/// Returns whether the corridor `agent` pays out in allows self-payout.
+pub fn is_self_payout_allowed(env: &Env, agent: &Address) -> bool {
+    get_agent_corridor(env, agent).map_or(false, |corridor| is_corridor_self_payout_allowed(env, &corridor))
+}
+
+pub fn block_corridor(env: &Env, country_from: &String, country_to: &String) {
+    env.storage()
+        .persistent()
+        .set(&CorridorKey::Blocked(country_from.clone(), country_to.clone()), &true);
+}
+
+pub fn unblock_corridor(env: &Env, country_from: &String, country_to: &String) {
+    env.storage()
+        .persistent()
+        .remove(&CorridorKey::Blocked(country_from.clone(), country_to.clone()));
+}
+
+pub fn is_corridor_blocked(env: &Env, country_from: &String, country_to: &String) -> bool {
+    env.storage()
+        .persistent()
+        .has(&CorridorKey::Blocked(country_from.clone(), country_to.clone()))
+}
+
+/// Splits a corridor name such as `"US-PH"` into its origin and destination countries.
+///
+/// Returns `None` when the name has no `-` separator or is too long to split.
+pub fn corridor_countries(env: &Env, corridor: &String) -> Option<(String, String)> {
+    let len = corridor.len() as usize;
+    if len > MAX_CORRIDOR_LEN {
+        return None;
+    }
+    let mut buf = [0u8; MAX_CORRIDOR_LEN];
+    corridor.copy_into_slice(&mut buf[..len]);
+
+    let split = buf[..len].iter().position(|b| *b == b'-')?;
+    Some((
+        String::from_bytes(env, &buf[..split]),
+        String::from_bytes(env, &buf[split + 1..len]),
+    ))
+}
+
+/// Checks that the corridor `agent` pays out in is not blocked.
+///
+/// # Returns
+///
+/// * `Ok(())` - The agent has no corridor, or its countries are not blocked
+/// * `Err(ContractError::CorridorBlocked)` - Transfers between the corridor's countries are blocked
+pub fn check_corridor_not_blocked(env: &Env, agent: &Address) -> Result<(), ContractError> {
+    let countries = get_agent_corridor(env, agent).and_then(|corridor| corridor_countries(env, &corridor));
+    match countries {
+        Some((from, to)) if is_corridor_blocked(env, &from, &to) => Err(ContractError::CorridorBlocked),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,142 @@
+//! Agent geographic coverage and discovery.
+//!
+//! Each agent can be given the areas it pays out in, as country and optional
+//! city codes together with the payout currency. Areas are indexed by
+//! destination (country and currency), so wallets without a backend can look
+//! up the agents able to serve a recipient directly from contract state.
+
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+
+use crate::{is_agent_available, is_agent_registered, is_agent_suspended, ContractError, Currency};
+
+/// Maximum number of coverage areas per agent.
+pub const MAX_COVERAGE_AREAS: u32 = 20;
+
+/// Maximum number of agents returned by a single `find_agents` page.
+pub const MAX_AGENT_PAGE: u32 = 50;
+
+/// An area an agent pays out in.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CoverageArea {
+    /// ISO country code, e.g. `"PH"`
+    pub country: String,
+    /// City code within the country, or `None` for nationwide coverage
+    pub city: Option<String>,
+    /// Payout currency code, e.g. `"PHP"`, in any case
+    pub currency: String,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum CoverageKey {
+    /// Coverage areas of an agent (persistent storage)
+    Areas(Address),
+    /// Agents covering a (country, currency) destination (persistent storage)
+    Destination(String, Currency),
+}
+
+pub fn get_agent_coverage(env: &Env, agent: &Address) -> Vec<CoverageArea> {
+    env.storage()
+        .persistent()
+        .get(&CoverageKey::Areas(agent.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn get_destination_agents(env: &Env, country: &String, currency: &Currency) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&CoverageKey::Destination(country.clone(), currency.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_destination_agents(env: &Env, country: &String, currency: &Currency, agents: &Vec<Address>) {
+    let key = CoverageKey::Destination(country.clone(), currency.clone());
+    if agents.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, agents);
+    }
+}
+
+/// Replaces the coverage areas of `agent` and updates the destination index.
+///
+/// # Returns
+///
+/// * `Ok(())` - Coverage stored
+/// * `Err(ContractError::LimitExceeded)` - More than MAX_COVERAGE_AREAS areas
+/// * `Err(ContractError::CorridorClosed)` - An area's currency is not a 3-letter code
+pub fn set_agent_coverage(
+    env: &Env,
+    agent: &Address,
+    areas: &Vec<CoverageArea>,
+) -> Result<(), ContractError> {
+    if areas.len() > MAX_COVERAGE_AREAS {
+        return Err(ContractError::LimitExceeded);
+    }
+    let mut currencies = Vec::new(env);
+    for area in areas.iter() {
+        currencies.push_back(Currency::parse(env, &area.currency)?);
+    }
+
+    for area in get_agent_coverage(env, agent).iter() {
+        let currency = Currency::parse(env, &area.currency)?;
+        let mut agents = get_destination_agents(env, &area.country, &currency);
+        if let Some(index) = agents.first_index_of(agent.clone()) {
+            agents.remove(index);
+            set_destination_agents(env, &area.country, &currency, &agents);
+        }
+    }
+
+    // Several cities in one destination index the agent once
+    for (area, currency) in areas.iter().zip(currencies.iter()) {
+        let mut agents = get_destination_agents(env, &area.country, &currency);
+        if !agents.contains(agent.clone()) {
+            agents.push_back(agent.clone());
+            set_destination_agents(env, &area.country, &currency, &agents);
+        }
+    }
+
+    let key = CoverageKey::Areas(agent.clone());
+    if areas.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, areas);
+    }
+    Ok(())
+}
+
+/// Returns up to `limit` agents that can currently serve `country` in
+/// `currency`, skipping the first `start` of them.
+///
+/// Only registered agents that are neither suspended nor unavailable are
+/// returned, so `start` counts eligible agents.
+pub fn find_agents(
+    env: &Env,
+    country: &String,
+    currency: &Currency,
+    start: u32,
+    limit: u32,
+) -> Vec<Address> {
+    let limit = limit.min(MAX_AGENT_PAGE);
+    let mut found = Vec::new(env);
+    let mut skipped = 0;
+
+    for agent in get_destination_agents(env, country, currency).iter() {
+        if found.len() >= limit {
+            break;
+        }
+        if !is_agent_registered(env, &agent)
+            || is_agent_suspended(env, &agent)
+            || !is_agent_available(env, &agent)
+        {
+            continue;
+        }
+        if skipped < start {
+            skipped += 1;
+            continue;
+        }
+        found.push_back(agent);
+    }
+    found
+}
@@ -0,0 +1,43 @@
+//! ISO 4217 currency codes.
+//!
+//! Daily limits, FX rates and agent coverage are keyed by currency. The
+//! contract interface takes codes as strings and parses them into a
+//! `Currency`, which holds the code uppercased as a `Symbol`. `"usd"` and
+//! `"USD"` therefore name the same limit and rate, and malformed codes are
+//! rejected instead of being stored under keys nothing looks up.
+
+use soroban_sdk::{contracttype, Env, String, Symbol};
+
+use crate::ContractError;
+
+/// Length of an ISO 4217 currency code.
+pub const CURRENCY_CODE_LEN: u32 = 3;
+
+/// A validated, uppercase ISO 4217 currency code, e.g. `USD`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Currency(pub Symbol);
+
+impl Currency {
+    /// Parses a 3-letter currency code in any case.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Currency)` - The uppercased code
+    /// * `Err(ContractError::CorridorClosed)` - The code is not 3 ASCII letters
+    pub fn parse(env: &Env, code: &String) -> Result<Self, ContractError> {
+        if code.len() != CURRENCY_CODE_LEN {
+            return Err(ContractError::CorridorClosed);
+        }
+        let mut buf = [0u8; CURRENCY_CODE_LEN as usize];
+        code.copy_into_slice(&mut buf);
+        for byte in buf.iter_mut() {
+            if !byte.is_ascii_alphabetic() {
+                return Err(ContractError::CorridorClosed);
+            }
+            byte.make_ascii_uppercase();
+        }
+        let code = core::str::from_utf8(&buf).map_err(|_| ContractError::CorridorClosed)?;
+        Ok(Currency(Symbol::new(env, code)))
+    }
+}
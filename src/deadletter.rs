@@ -0,0 +1,89 @@
+//! Dead-letter queue for failed batch entries.
+//!
+//! Batch entry points skip entries that fail validation instead of aborting
+//! the whole batch. Each skipped entry is recorded here under the caller that
+//! submitted the batch, with the error code, so integrators can page through
+//! the failures and retry them deterministically.
+
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+
+use crate::ContractError;
+
+/// Maximum number of failed operations returned by a single page.
+pub const MAX_FAILED_PAGE: u32 = 50;
+
+/// A batch entry that was skipped because it failed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FailedOperation {
+    /// Batch operation that produced the failure (e.g. `confirm`, `create`)
+    pub operation: Symbol,
+    /// Remittance ID for confirmations, or the entry's index in the batch for creations
+    pub reference: u64,
+    /// Numeric `ContractError` code
+    pub error_code: u32,
+    /// Ledger sequence the failure was recorded in
+    pub ledger: u32,
+    /// Timestamp the failure was recorded at
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum DeadLetterKey {
+    /// Number of failures recorded for a caller (persistent storage)
+    Count(Address),
+    /// Failure by caller and sequence number (persistent storage)
+    Entry(Address, u32),
+}
+
+pub fn get_failed_operation_count(env: &Env, caller: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DeadLetterKey::Count(caller.clone()))
+        .unwrap_or(0)
+}
+
+/// Appends a failed batch entry to the caller's dead-letter queue.
+pub fn record_failed_operation(
+    env: &Env,
+    caller: &Address,
+    operation: Symbol,
+    reference: u64,
+    error: ContractError,
+) {
+    let index = get_failed_operation_count(env, caller);
+    let failed = FailedOperation {
+        operation,
+        reference,
+        error_code: error as u32,
+        ledger: env.ledger().sequence(),
+        timestamp: env.ledger().timestamp(),
+    };
+
+    env.storage()
+        .persistent()
+        .set(&DeadLetterKey::Entry(caller.clone(), index), &failed);
+    env.storage()
+        .persistent()
+        .set(&DeadLetterKey::Count(caller.clone()), &index.saturating_add(1));
+}
+
+/// Returns up to `limit` failures recorded for `caller`, oldest first,
+/// starting at sequence number `start`.
+pub fn get_failed_operations(env: &Env, caller: &Address, start: u32, limit: u32) -> Vec<FailedOperation> {
+    let count = get_failed_operation_count(env, caller);
+    let end = start.saturating_add(limit.min(MAX_FAILED_PAGE)).min(count);
+
+    let mut page = Vec::new(env);
+    for index in start..end {
+        if let Some(failed) = env
+            .storage()
+            .persistent()
+            .get(&DeadLetterKey::Entry(caller.clone(), index))
+        {
+            page.push_back(failed);
+        }
+    }
+    page
+}
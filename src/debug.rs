@@ -235,3 +235,73 @@ pub fn log_update_rate_limit(env: &Env, max_requests: u32, window_seconds: u64,
 /// Logs rate limit configuration update - no-op in release.
 #[cfg(not(feature = "debug-log"))]
 pub fn log_update_rate_limit(_env: &Env, _max_requests: u32, _window_seconds: u64, _enabled: bool) {}
+
+// ── Error Context ──────────────────────────────────────────────────
+//
+// Entry points call these just before returning an error so integrators see
+// the offending values in the diagnostic events of the failed transaction.
+// Each helper returns the error it was given so it can be used in `map_err`.
+
+/// Logs the remittance and its current status alongside an error in debug mode.
+#[cfg(feature = "debug-log")]
+pub fn log_remittance_error(
+    env: &Env,
+    op: soroban_sdk::Symbol,
+    error: crate::ContractError,
+    remittance_id: u64,
+    status: &crate::RemittanceStatus,
+) -> crate::ContractError {
+    soroban_sdk::log!(
+        env,
+        "Error context: op={}, error={}, remittance_id={}, status={}",
+        op,
+        error,
+        remittance_id,
+        status.clone()
+    );
+    error
+}
+
+/// Logs the offending value and the limit it breached alongside an error in debug mode.
+#[cfg(feature = "debug-log")]
+pub fn log_limit_error(
+    env: &Env,
+    op: soroban_sdk::Symbol,
+    error: crate::ContractError,
+    value: i128,
+    limit: i128,
+) -> crate::ContractError {
+    soroban_sdk::log!(
+        env,
+        "Error context: op={}, error={}, value={}, limit={}",
+        op,
+        error,
+        value,
+        limit
+    );
+    error
+}
+
+/// Logs remittance error context - no-op in release.
+#[cfg(not(feature = "debug-log"))]
+pub fn log_remittance_error(
+    _env: &Env,
+    _op: soroban_sdk::Symbol,
+    error: crate::ContractError,
+    _remittance_id: u64,
+    _status: &crate::RemittanceStatus,
+) -> crate::ContractError {
+    error
+}
+
+/// Logs limit error context - no-op in release.
+#[cfg(not(feature = "debug-log"))]
+pub fn log_limit_error(
+    _env: &Env,
+    _op: soroban_sdk::Symbol,
+    error: crate::ContractError,
+    _value: i128,
+    _limit: i128,
+) -> crate::ContractError {
+    error
+}
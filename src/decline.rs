@@ -0,0 +1,45 @@
+//! Remittances declined by their agent.
+//!
+//! An agent that cannot serve an assignment declines it with a reason, and
+//! the sender is refunded immediately. The reason is kept with the
+//! remittance, and each agent's declines are counted per reason so
+//! reputation scoring can tell liquidity problems from unreachable recipients.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::DeclineReason;
+
+#[contracttype]
+#[derive(Clone)]
+enum DeclineKey {
+    /// Reason a remittance was declined (persistent storage)
+    Reason(u64),
+    /// Number of remittances an agent declined for a reason (persistent storage)
+    Count(Address, DeclineReason),
+}
+
+/// Records that `agent` declined remittance `remittance_id` for `reason`.
+pub fn record_decline(env: &Env, remittance_id: u64, agent: &Address, reason: DeclineReason) {
+    env.storage()
+        .persistent()
+        .set(&DeclineKey::Reason(remittance_id), &reason);
+
+    let key = DeclineKey::Count(agent.clone(), reason);
+    let count = get_decline_count(env, agent, reason);
+    env.storage().persistent().set(&key, &count.saturating_add(1));
+}
+
+/// Returns the reason a remittance was declined, if it was.
+pub fn get_decline_reason(env: &Env, remittance_id: u64) -> Option<DeclineReason> {
+    env.storage()
+        .persistent()
+        .get(&DeclineKey::Reason(remittance_id))
+}
+
+/// Returns how many remittances `agent` declined for `reason`.
+pub fn get_decline_count(env: &Env, agent: &Address, reason: DeclineReason) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DeclineKey::Count(agent.clone(), reason))
+        .unwrap_or(0)
+}
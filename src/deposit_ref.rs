@@ -0,0 +1,48 @@
+//! Per-remittance deposit references.
+//!
+//! Every remittance gets a deterministic 64-bit deposit reference, derived
+//! from the contract address and the remittance ID as described in
+//! `hashing`. The reference has the shape of a muxed account ID, so off-ramp
+//! partners can credit the exact remittance a payment is for by paying to a
+//! muxed sub-account instead of passing the ID through a memo field. The
+//! reference is indexed back to the remittance when it is created.
+
+use soroban_sdk::{contracttype, Env};
+
+use crate::{compute_deposit_ref, ContractError};
+
+#[contracttype]
+#[derive(Clone)]
+enum DepositRefKey {
+    /// Remittance a deposit reference belongs to (persistent storage)
+    Remittance(u64),
+}
+
+/// Returns the deposit reference of a remittance.
+pub fn deposit_ref_of(env: &Env, remittance_id: u64) -> u64 {
+    compute_deposit_ref(env, &env.current_contract_address(), remittance_id)
+}
+
+/// Indexes the deposit reference of a new remittance.
+///
+/// A reference already indexed for another remittance is left in place;
+/// with 64-bit references this is not expected in practice.
+pub fn index_deposit_ref(env: &Env, remittance_id: u64) {
+    let key = DepositRefKey::Remittance(deposit_ref_of(env, remittance_id));
+    if !env.storage().persistent().has(&key) {
+        env.storage().persistent().set(&key, &remittance_id);
+    }
+}
+
+/// Finds the remittance a deposit reference belongs to.
+///
+/// # Returns
+///
+/// * `Ok(u64)` - ID of the remittance
+/// * `Err(ContractError::RemittanceNotFound)` - The reference is unknown
+pub fn resolve_deposit_ref(env: &Env, deposit_ref: u64) -> Result<u64, ContractError> {
+    env.storage()
+        .persistent()
+        .get(&DepositRefKey::Remittance(deposit_ref))
+        .ok_or(ContractError::RemittanceNotFound)
+}
@@ -0,0 +1,34 @@
+//! Non-custodial direct remittances between approved pairs.
+//!
+//! For low-risk sender and agent pairs with a history together, the admin
+//! can approve the pair for direct mode. A direct remittance moves the net
+//! amount straight from the sender to the agent, and the platform fee to the
+//! contract in a separate transfer, so nothing is held in escrow. The
+//! remittance is still recorded, already settled, so history, analytics,
+//! loyalty and volume tiers see it like any other paid-out remittance. There
+//! is no refund path: the sender relies on the agent as it would off-chain.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+#[contracttype]
+#[derive(Clone)]
+enum DirectKey {
+    /// Whether a (sender, agent) pair may use direct mode (persistent storage)
+    Pair(Address, Address),
+}
+
+pub fn set_direct_pair(env: &Env, sender: &Address, agent: &Address, enabled: bool) {
+    let key = DirectKey::Pair(sender.clone(), agent.clone());
+    if enabled {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Returns whether `sender` may send direct remittances to `agent`.
+pub fn is_direct_pair(env: &Env, sender: &Address, agent: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DirectKey::Pair(sender.clone(), agent.clone()))
+}
@@ -0,0 +1,124 @@
+//! Stand-ins for subsystems compiled out by cargo features.
+//!
+//! Remittance creation, payout and refund call into the KYC, corridor and
+//! loyalty subsystems. When one of their features is disabled its module,
+//! storage keys and entry points are not compiled, and the functions below
+//! take the place of the hooks the core paths call. Each behaves as the
+//! subsystem does while unconfigured: no compliance provider, allowlist,
+//! high-risk senders, risk bands or limit exemptions, no agent corridors, and no points or
+//! fee credit.
+
+#[cfg(not(feature = "kyc"))]
+mod kyc {
+    use soroban_sdk::{Address, Env, String};
+
+    use crate::ContractError;
+
+    pub fn check_compliance(
+        _env: &Env,
+        _sender: &Address,
+        _agent: &Address,
+        _amount: i128,
+        _corridor: Option<String>,
+    ) -> Result<(), ContractError> {
+        Ok(())
+    }
+
+    pub fn is_sender_allowed(_env: &Env, _sender: &Address) -> bool {
+        true
+    }
+
+    pub fn consume_intent(
+        _env: &Env,
+        _sender: &Address,
+        _agent: &Address,
+        _amount: i128,
+    ) -> Result<(), ContractError> {
+        Ok(())
+    }
+
+    pub fn check_risk_limits(_env: &Env, _sender: &Address, _amount: i128) -> Result<(), ContractError> {
+        Ok(())
+    }
+
+    pub fn record_risk_velocity(_env: &Env, _sender: &Address) {}
+
+    pub fn is_limit_exempt(_env: &Env, _sender: &Address) -> bool {
+        false
+    }
+}
+
+#[cfg(not(feature = "kyc"))]
+pub use kyc::*;
+
+#[cfg(not(feature = "corridors"))]
+mod corridors {
+    use soroban_sdk::{Address, Env, String};
+
+    use crate::{ContractError, PurposeCode};
+
+    pub fn get_agent_corridor(_env: &Env, _agent: &Address) -> Option<String> {
+        None
+    }
+
+    pub fn get_corridor_fee(_env: &Env, _corridor: &String) -> Option<u32> {
+        None
+    }
+
+    pub fn check_purpose_allowed(
+        _env: &Env,
+        _agent: &Address,
+        _purpose: PurposeCode,
+    ) -> Result<(), ContractError> {
+        Ok(())
+    }
+
+    pub fn hold_until_for(_env: &Env, _agent: &Address) -> Option<u64> {
+        None
+    }
+
+    pub fn check_corridor_not_blocked(_env: &Env, _agent: &Address) -> Result<(), ContractError> {
+        Ok(())
+    }
+
+    pub fn is_self_payout_allowed(_env: &Env, _agent: &Address) -> bool {
+        false
+    }
+}
+
+#[cfg(not(feature = "corridors"))]
+pub use corridors::*;
+
+#[cfg(not(feature = "loyalty"))]
+mod loyalty {
+    use soroban_sdk::{Address, Env};
+
+    use crate::ContractError;
+
+    pub fn get_fee_credit(_env: &Env, _user: &Address) -> i128 {
+        0
+    }
+
+    pub fn accrue_points(_env: &Env, _user: &Address, _volume: i128) -> Result<i128, ContractError> {
+        Ok(0)
+    }
+
+    pub fn apply_fee_credit(_env: &Env, _user: &Address, _remittance_id: u64, _fee: i128) -> i128 {
+        0
+    }
+
+    pub fn restore_fee_credit(
+        _env: &Env,
+        _user: &Address,
+        _remittance_id: u64,
+        _unreleased: i128,
+        _amount: i128,
+    ) -> Result<(), ContractError> {
+        Ok(())
+    }
+
+    pub fn settle_fee_discount(_env: &Env, _remittance_id: u64) {}
+}
+
+#[cfg(not(feature = "loyalty"))]
+pub use loyalty::*;
@@ -111,6 +111,90 @@ impl ErrorHandler {
                 ErrorCategory::Validation,
                 ErrorSeverity::Low,
             ),
+            ContractError::InvalidMigrationHash => (
+                20,
+                SorobanString::from_str(env, "Migration data failed hash verification"),
+                ErrorCategory::Validation,
+                ErrorSeverity::High,
+            ),
+            ContractError::InvalidMigrationBatch => (
+                22,
+                SorobanString::from_str(env, "Migration batch is out of order"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            ContractError::LimitExceeded => (
+                31,
+                SorobanString::from_str(env, "Request exceeds the allowed size"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            ContractError::PurposeNotAllowed => (
+                36,
+                SorobanString::from_str(env, "Purpose code not allowed in this corridor"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            ContractError::AmountAboveMaximum => (
+                37,
+                SorobanString::from_str(env, "Amount above maximum transfer amount"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            ContractError::AmountBelowMinimum => (
+                38,
+                SorobanString::from_str(env, "Amount below minimum transfer amount"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            ContractError::InsufficientPoints => (
+                39,
+                SorobanString::from_str(env, "Insufficient loyalty points"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            ContractError::DuplicateExternalRef => (
+                40,
+                SorobanString::from_str(env, "External reference already in use"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            ContractError::SignatureExpired => (
+                41,
+                SorobanString::from_str(env, "Signed operation has expired"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            ContractError::InvalidNonce => (
+                42,
+                SorobanString::from_str(env, "Invalid nonce"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            ContractError::ZeroAmount => (
+                47,
+                SorobanString::from_str(env, "Amount must not be zero"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            ContractError::NegativeAmount => (
+                48,
+                SorobanString::from_str(env, "Amount cannot be negative"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            ContractError::UnsupportedToken => (
+                49,
+                SorobanString::from_str(env, "Token does not implement the token interface"),
+                ErrorCategory::Validation,
+                ErrorSeverity::High,
+            ),
+            ContractError::SelfDealing => (
+                50,
+                SorobanString::from_str(env, "Sender cannot be the agent or recipient"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
             
             // Resource Errors
             ContractError::AgentNotRegistered => (
@@ -149,6 +233,18 @@ impl ErrorHandler {
                 ErrorCategory::Resource,
                 ErrorSeverity::Low,
             ),
+            ContractError::AgentSuspended => (
+                24,
+                SorobanString::from_str(env, "Agent is suspended"),
+                ErrorCategory::Resource,
+                ErrorSeverity::Low,
+            ),
+            ContractError::AgentUnavailable => (
+                27,
+                SorobanString::from_str(env, "Agent is unavailable"),
+                ErrorCategory::Resource,
+                ErrorSeverity::Low,
+            ),
             
             // State Errors
             ContractError::InvalidStatus => (
@@ -187,6 +283,85 @@ impl ErrorHandler {
                 ErrorCategory::State,
                 ErrorSeverity::Low,
             ),
+            ContractError::CoolingOffActive => (
+                25,
+                SorobanString::from_str(env, "Cooling-off period has not elapsed"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::RateLimited => (
+                26,
+                SorobanString::from_str(env, "Too many remittances created, try again later"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            
+            ContractError::MigrationInProgress => (
+                21,
+                SorobanString::from_str(env, "Migration already in progress"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::DailySendLimitExceeded => (
+                23,
+                SorobanString::from_str(env, "Daily send limit exceeded"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::RateLimitExceeded => (
+                28,
+                SorobanString::from_str(env, "Settlement cooldown has not elapsed"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::CorridorClosed => (
+                30,
+                SorobanString::from_str(env, "Payout corridor is closed"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::RateExpired => (
+                32,
+                SorobanString::from_str(env, "Exchange rate has expired"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::ComplianceRejected => (
+                33,
+                SorobanString::from_str(env, "Remittance rejected by compliance checks"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::SlippageExceeded => (
+                34,
+                SorobanString::from_str(env, "Swap output below minimum"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::PayoutHookFailed => (
+                43,
+                SorobanString::from_str(env, "Payout recipient contract rejected the payout"),
+                ErrorCategory::State,
+                ErrorSeverity::Medium,
+            ),
+            ContractError::CorridorBlocked => (
+                44,
+                SorobanString::from_str(env, "Remittances between the corridor's countries are blocked"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::PayoutOnHold => (
+                45,
+                SorobanString::from_str(env, "Payout is still within the corridor's hold period"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::AgentAtCapacity => (
+                46,
+                SorobanString::from_str(env, "Agent has reached its maximum pending remittances"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
             
             // Authorization Errors
             ContractError::Unauthorized => (
@@ -195,6 +370,12 @@ impl ErrorHandler {
                 ErrorCategory::Authorization,
                 ErrorSeverity::Medium,
             ),
+            ContractError::SenderNotAllowed => (
+                35,
+                SorobanString::from_str(env, "Sender is not approved to create remittances"),
+                ErrorCategory::Authorization,
+                ErrorSeverity::Low,
+            ),
             
             // System Errors
             ContractError::Overflow => (
@@ -203,6 +384,12 @@ impl ErrorHandler {
                 ErrorCategory::System,
                 ErrorSeverity::High,
             ),
+            ContractError::InsufficientEscrow => (
+                29,
+                SorobanString::from_str(env, "Escrow balance is insufficient"),
+                ErrorCategory::System,
+                ErrorSeverity::High,
+            ),
         }
     }
     
@@ -234,9 +421,24 @@ impl ErrorHandler {
         match error {
             ContractError::InvalidAmount
             | ContractError::InvalidFeeBps
-            | ContractError::InvalidAddress => ErrorCategory::Validation,
+            | ContractError::InvalidAddress
+            | ContractError::InvalidMigrationHash
+            | ContractError::InvalidMigrationBatch
+            | ContractError::LimitExceeded
+            | ContractError::PurposeNotAllowed
+            | ContractError::AmountAboveMaximum
+            | ContractError::AmountBelowMinimum
+            | ContractError::InsufficientPoints
+            | ContractError::DuplicateExternalRef
+            | ContractError::SignatureExpired
+            | ContractError::InvalidNonce
+            | ContractError::ZeroAmount
+            | ContractError::NegativeAmount
+            | ContractError::UnsupportedToken
+            | ContractError::SelfDealing => ErrorCategory::Validation,
             
-            ContractError::Unauthorized => ErrorCategory::Authorization,
+            ContractError::Unauthorized
+            | ContractError::SenderNotAllowed => ErrorCategory::Authorization,
             
             ContractError::AlreadyInitialized
             | ContractError::NotInitialized
@@ -245,16 +447,32 @@ impl ErrorHandler {
             | ContractError::DuplicateSettlement
             | ContractError::ContractPaused
             | ContractError::NoFeesToWithdraw
-            | ContractError::CannotRemoveLastAdmin => ErrorCategory::State,
+            | ContractError::CannotRemoveLastAdmin
+            | ContractError::CoolingOffActive
+            | ContractError::RateLimited
+            | ContractError::MigrationInProgress
+            | ContractError::DailySendLimitExceeded
+            | ContractError::RateLimitExceeded
+            | ContractError::CorridorClosed
+            | ContractError::RateExpired
+            | ContractError::ComplianceRejected
+            | ContractError::SlippageExceeded
+            | ContractError::PayoutHookFailed
+            | ContractError::CorridorBlocked
+            | ContractError::PayoutOnHold
+            | ContractError::AgentAtCapacity => ErrorCategory::State,
             
             ContractError::AgentNotRegistered
             | ContractError::RemittanceNotFound
             | ContractError::AdminNotFound
             | ContractError::AdminAlreadyExists
             | ContractError::TokenNotWhitelisted
-            | ContractError::TokenAlreadyWhitelisted => ErrorCategory::Resource,
+            | ContractError::TokenAlreadyWhitelisted
+            | ContractError::AgentSuspended
+            | ContractError::AgentUnavailable => ErrorCategory::Resource,
             
-            ContractError::Overflow => ErrorCategory::System,
+            ContractError::Overflow
+            | ContractError::InsufficientEscrow => ErrorCategory::System,
         }
     }
     
@@ -276,15 +494,46 @@ impl ErrorHandler {
             | ContractError::CannotRemoveLastAdmin
             | ContractError::TokenNotWhitelisted
             | ContractError::TokenAlreadyWhitelisted
-            | ContractError::AlreadyInitialized => ErrorSeverity::Low,
+            | ContractError::AlreadyInitialized
+            | ContractError::AgentSuspended
+            | ContractError::CoolingOffActive
+            | ContractError::RateLimited
+            | ContractError::AgentUnavailable
+            | ContractError::InvalidMigrationBatch
+            | ContractError::MigrationInProgress
+            | ContractError::DailySendLimitExceeded
+            | ContractError::RateLimitExceeded
+            | ContractError::CorridorClosed
+            | ContractError::LimitExceeded
+            | ContractError::RateExpired
+            | ContractError::ComplianceRejected
+            | ContractError::SlippageExceeded
+            | ContractError::SenderNotAllowed
+            | ContractError::PurposeNotAllowed
+            | ContractError::AmountAboveMaximum
+            | ContractError::AmountBelowMinimum
+            | ContractError::InsufficientPoints
+            | ContractError::DuplicateExternalRef
+            | ContractError::SignatureExpired
+            | ContractError::InvalidNonce
+            | ContractError::CorridorBlocked
+            | ContractError::PayoutOnHold
+            | ContractError::AgentAtCapacity
+            | ContractError::ZeroAmount
+            | ContractError::NegativeAmount
+            | ContractError::SelfDealing => ErrorSeverity::Low,
             
             // Medium severity - unexpected but recoverable
             ContractError::NotInitialized
             | ContractError::DuplicateSettlement
-            | ContractError::Unauthorized => ErrorSeverity::Medium,
+            | ContractError::Unauthorized
+            | ContractError::PayoutHookFailed => ErrorSeverity::Medium,
             
             // High severity - critical system errors
-            ContractError::Overflow => ErrorSeverity::High,
+            ContractError::Overflow
+            | ContractError::InvalidMigrationHash
+            | ContractError::InsufficientEscrow
+            | ContractError::UnsupportedToken => ErrorSeverity::High,
         }
     }
     
@@ -292,7 +541,17 @@ impl ErrorHandler {
     pub fn is_retryable(error: ContractError) -> bool {
         match error {
             // Transient errors that might succeed on retry
-            ContractError::ContractPaused => true,
+            ContractError::ContractPaused
+            | ContractError::CoolingOffActive
+            | ContractError::RateLimited
+            | ContractError::AgentUnavailable
+            | ContractError::DailySendLimitExceeded
+            | ContractError::RateLimitExceeded
+            | ContractError::CorridorClosed
+            | ContractError::RateExpired
+            | ContractError::SlippageExceeded
+            | ContractError::PayoutOnHold
+            | ContractError::AgentAtCapacity => true,
             
             // Permanent errors that won't succeed on retry
             ContractError::AlreadyInitialized
@@ -312,7 +571,28 @@ impl ErrorHandler {
             | ContractError::AdminNotFound
             | ContractError::CannotRemoveLastAdmin
             | ContractError::TokenNotWhitelisted
-            | ContractError::TokenAlreadyWhitelisted => false,
+            | ContractError::TokenAlreadyWhitelisted
+            | ContractError::AgentSuspended
+            | ContractError::InvalidMigrationHash
+            | ContractError::MigrationInProgress
+            | ContractError::InvalidMigrationBatch
+            | ContractError::InsufficientEscrow
+            | ContractError::LimitExceeded
+            | ContractError::ComplianceRejected
+            | ContractError::SenderNotAllowed
+            | ContractError::PurposeNotAllowed
+            | ContractError::AmountAboveMaximum
+            | ContractError::AmountBelowMinimum
+            | ContractError::InsufficientPoints
+            | ContractError::DuplicateExternalRef
+            | ContractError::SignatureExpired
+            | ContractError::InvalidNonce
+            | ContractError::PayoutHookFailed
+            | ContractError::CorridorBlocked
+            | ContractError::ZeroAmount
+            | ContractError::NegativeAmount
+            | ContractError::UnsupportedToken
+            | ContractError::SelfDealing => false,
         }
     }
     
@@ -467,6 +747,37 @@ mod tests {
             ContractError::CannotRemoveLastAdmin,
             ContractError::TokenNotWhitelisted,
             ContractError::TokenAlreadyWhitelisted,
+            ContractError::InvalidMigrationHash,
+            ContractError::MigrationInProgress,
+            ContractError::InvalidMigrationBatch,
+            ContractError::DailySendLimitExceeded,
+            ContractError::AgentSuspended,
+            ContractError::CoolingOffActive,
+            ContractError::RateLimited,
+            ContractError::AgentUnavailable,
+            ContractError::RateLimitExceeded,
+            ContractError::InsufficientEscrow,
+            ContractError::CorridorClosed,
+            ContractError::LimitExceeded,
+            ContractError::RateExpired,
+            ContractError::ComplianceRejected,
+            ContractError::SlippageExceeded,
+            ContractError::SenderNotAllowed,
+            ContractError::PurposeNotAllowed,
+            ContractError::AmountAboveMaximum,
+            ContractError::AmountBelowMinimum,
+            ContractError::InsufficientPoints,
+            ContractError::DuplicateExternalRef,
+            ContractError::SignatureExpired,
+            ContractError::InvalidNonce,
+            ContractError::PayoutHookFailed,
+            ContractError::CorridorBlocked,
+            ContractError::PayoutOnHold,
+            ContractError::AgentAtCapacity,
+            ContractError::ZeroAmount,
+            ContractError::NegativeAmount,
+            ContractError::UnsupportedToken,
+            ContractError::SelfDealing,
         ];
 
         let mut codes = std::collections::HashSet::new();
@@ -476,6 +787,56 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_error_codes_are_stable() {
+        // Codes are part of the public interface; see ERROR_REFERENCE.md
+        let env = Env::default();
+        let expected = [
+            (ContractError::AlreadyInitialized, 1),
+            (ContractError::NotInitialized, 2),
+            (ContractError::InvalidAmount, 3),
+            (ContractError::InvalidFeeBps, 4),
+            (ContractError::AgentNotRegistered, 5),
+            (ContractError::RemittanceNotFound, 6),
+            (ContractError::InvalidStatus, 7),
+            (ContractError::Overflow, 8),
+            (ContractError::NoFeesToWithdraw, 9),
+            (ContractError::InvalidAddress, 10),
+            (ContractError::SettlementExpired, 11),
+            (ContractError::DuplicateSettlement, 12),
+            (ContractError::ContractPaused, 13),
+            (ContractError::Unauthorized, 14),
+            (ContractError::AgentSuspended, 24),
+            (ContractError::RateLimitExceeded, 28),
+            (ContractError::InsufficientEscrow, 29),
+            (ContractError::CorridorClosed, 30),
+            (ContractError::LimitExceeded, 31),
+            (ContractError::RateExpired, 32),
+            (ContractError::SenderNotAllowed, 35),
+            (ContractError::PurposeNotAllowed, 36),
+            (ContractError::AmountAboveMaximum, 37),
+            (ContractError::AmountBelowMinimum, 38),
+            (ContractError::InsufficientPoints, 39),
+            (ContractError::DuplicateExternalRef, 40),
+            (ContractError::SignatureExpired, 41),
+            (ContractError::InvalidNonce, 42),
+            (ContractError::PayoutHookFailed, 43),
+            (ContractError::CorridorBlocked, 44),
+            (ContractError::PayoutOnHold, 45),
+            (ContractError::AgentAtCapacity, 46),
+            (ContractError::ZeroAmount, 47),
+            (ContractError::NegativeAmount, 48),
+            (ContractError::UnsupportedToken, 49),
+            (ContractError::SelfDealing, 50),
+        ];
+
+        for (error, code) in expected {
+            assert_eq!(error as u32, code);
+            // The handler's mapping must agree with the discriminant
+            assert_eq!(ErrorHandler::handle_error(&env, error).code, code);
+        }
+    }
+
     #[test]
     fn test_error_messages_are_user_friendly() {
         let env = Env::default();
@@ -2,6 +2,11 @@
 //!
 //! This module defines all possible error conditions that can occur
 //! during contract execution.
+//!
+//! Numeric codes are part of the public interface: wallets and integrators
+//! see them as `Error(Contract, #N)` and the test suite asserts on them. Codes
+//! are never reused or renumbered; new variants take the next free code. See
+//! `ERROR_REFERENCE.md` for the full mapping.
 
 use soroban_sdk::contracterror;
 
@@ -10,16 +15,58 @@ use soroban_sdk::contracterror;
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum ContractError {
+    /// Contract has already been initialized.
+    /// Cause: Calling initialize() more than once.
+    AlreadyInitialized = 1,
+
+    /// Contract has not been initialized yet.
+    /// Cause: Calling any entry point before initialize().
+    NotInitialized = 2,
+
+    /// Amount is invalid for the operation.
+    /// Cause: An amount exceeding what remains to release, or a fee larger than its amount.
+    InvalidAmount = 3,
+
+    /// Fee is outside the allowed range.
+    /// Cause: Fee or share above 10000 basis points, or a fee split not summing to 10000.
+    InvalidFeeBps = 4,
+
+    /// Agent is not registered.
+    /// Cause: Creating a remittance for, or acting as, an address that is not an approved agent.
+    AgentNotRegistered = 5,
+
+    /// Remittance does not exist.
+    /// Cause: Unknown remittance ID, or a remittance that has been archived.
+    RemittanceNotFound = 6,
+
+    /// Remittance or record is in the wrong status for the operation.
+    /// Cause: Confirming or cancelling a remittance that is no longer pending.
+    InvalidStatus = 7,
+
+    /// Arithmetic overflow.
+    /// Cause: Amount or accumulated total exceeding the i128 range.
+    Overflow = 8,
+
+    /// No fees are available to withdraw.
+    /// Cause: Calling withdraw_fees() when the token's accumulated fees are zero.
+    NoFeesToWithdraw = 9,
+
+    /// Address is invalid for the operation.
+    /// Cause: Malformed address, or an address that cannot take this role (e.g. an agent as its own sub-agent).
+    InvalidAddress = 10,
+
+    /// Settlement window has expired.
+    /// Cause: Calling confirm_payout() after the remittance expiry timestamp.
+    SettlementExpired = 11,
 
+    /// Settlement has already been executed.
+    /// Cause: Settling the same remittance twice.
     DuplicateSettlement = 12,
 
     /// Contract is paused. Settlements are temporarily disabled.
     /// Cause: Attempting confirm_payout() while contract is in paused state.
     ContractPaused = 13,
     
-    /// Rate limit exceeded. Sender must wait before submitting another settlement.
-    /// Cause: Attempting confirm_payout() before cooldown period has elapsed.
-    RateLimitExceeded = 14,
     /// Caller is not authorized to perform admin operations.
     /// Cause: Non-admin attempting to perform admin-only operations.
     Unauthorized = 14,
@@ -37,11 +84,11 @@ pub enum ContractError {
     CannotRemoveLastAdmin = 17,
     
     /// Token is not whitelisted for use in the system.
-    /// Cause: Attempting to initialize contract with non-whitelisted token.
+    /// Cause: Initializing the contract without any token, or removing a token that is not whitelisted.
     TokenNotWhitelisted = 18,
     
     /// Token is already whitelisted in the system.
-    /// Cause: Attempting to add a token that is already whitelisted.
+    /// Cause: Attempting to add a token that is already whitelisted, or listing a token twice in the initialize configuration.
     TokenAlreadyWhitelisted = 19,
     
     /// Migration hash verification failed.
@@ -59,4 +106,117 @@ pub enum ContractError {
     /// Daily send limit exceeded for this user.
     /// Cause: User's total transfers in the last 24 hours exceed the configured limit.
     DailySendLimitExceeded = 23,
+    
+    /// Agent is suspended and cannot accept new remittances.
+    /// Cause: Creating a remittance for an agent that is under review.
+    AgentSuspended = 24,
+    
+    /// Sender is still in the cooling-off period for this remittance.
+    /// Cause: High-risk sender creating a remittance without a matured declared intent.
+    CoolingOffActive = 25,
+    
+    /// Sender created too many remittances in the current ledger window.
+    /// Cause: Exceeding the admin-configured number of create_remittance calls per sender within N ledgers,
+    /// or creating a remittance during a cancellation cooldown.
+    RateLimited = 26,
+    
+    /// Agent is currently unavailable to accept remittances.
+    /// Cause: Creating a remittance for an agent that is offline or outside its working hours.
+    AgentUnavailable = 27,
+    
+    /// Rate limit exceeded. Sender must wait before submitting another settlement.
+    /// Cause: Attempting confirm_payout() before cooldown period has elapsed.
+    /// Previously shared code 14 with Unauthorized.
+    RateLimitExceeded = 28,
+    
+    /// Escrow holds less than the operation needs to release.
+    /// Cause: Releasing or refunding more than the total currently held in escrow.
+    InsufficientEscrow = 29,
+    
+    /// Payout corridor is closed.
+    /// Cause: Quoting or sending to a currency with no published FX rate, or naming a currency
+    /// that is not a 3-letter ISO 4217 code.
+    CorridorClosed = 30,
+    
+    /// A configured or built-in limit was exceeded.
+    /// Cause: Batch, page or delegation size above its maximum. The value and the
+    /// limit (or window) are recorded in the debug log.
+    LimitExceeded = 31,
+    
+    /// Published FX rate is too old to quote against.
+    /// Cause: Quoting after the rate's maximum age has elapsed without a new rate being published.
+    RateExpired = 32,
+    
+    /// Compliance provider rejected the remittance.
+    /// Cause: The registered compliance hook returned false or failed for this sender, agent and amount.
+    ComplianceRejected = 33,
+    
+    /// Token swap returned less than the requested minimum.
+    /// Cause: The swap router paid the agent less than min_out in confirm_payout_with_swap().
+    SlippageExceeded = 34,
+    
+    /// Sender is not on the allowlist.
+    /// Cause: The sender allowlist is enabled and the sender has not been approved with allow_sender().
+    SenderNotAllowed = 35,
+    
+    /// Purpose code is not accepted in the agent's corridor.
+    /// Cause: Sender declared a purpose the corridor's regulator does not allow.
+    PurposeNotAllowed = 36,
+    
+    /// Amount exceeds the maximum single-transfer amount.
+    /// Cause: A cap set with set_max_transfer_amount() for the token or the agent's corridor is lower than the amount.
+    AmountAboveMaximum = 37,
+    
+    /// Amount is below the minimum single-transfer amount.
+    /// Cause: The amount is lower than the minimum set with set_min_transfer_amount() for the token.
+    AmountBelowMinimum = 38,
+    
+    /// Sender has fewer loyalty points than requested.
+    /// Cause: redeem_points() was called for more points than the sender has earned.
+    InsufficientPoints = 39,
+    
+    /// External reference is already used by another remittance.
+    /// Cause: An integrator reused an external_ref given to an earlier remittance.
+    DuplicateExternalRef = 40,
+    
+    /// Signed operation has expired.
+    /// Cause: A relayer submitted a signed operation after its expiration ledger.
+    SignatureExpired = 41,
+    
+    /// Nonce does not match the signer's next nonce.
+    /// Cause: A relayed operation was replayed, or submitted out of order.
+    InvalidNonce = 42,
+    
+    /// Payout recipient contract rejected the payout.
+    /// Cause: The recipient contract named for the remittance failed or returned an error from on_payout.
+    PayoutHookFailed = 43,
+    
+    /// Remittances between the corridor's countries are blocked.
+    /// Cause: The agent's corridor connects countries the admin has blocked, e.g. an embargoed destination.
+    CorridorBlocked = 44,
+    
+    /// Payout is still within the corridor's hold period or the screening window.
+    /// Cause: The agent tried to confirm before the hold period, during which the sender may cancel,
+    /// or the screening window, during which compliance may freeze, has elapsed.
+    PayoutOnHold = 45,
+    
+    /// Agent already has the maximum number of pending remittances.
+    /// Cause: Assigning a remittance to an agent whose pending queue is at its max_pending limit.
+    AgentAtCapacity = 46,
+    
+    /// Amount is zero.
+    /// Cause: Passing 0 as an amount, top-up, partial payout, collateral, escrow deposit or limit.
+    ZeroAmount = 47,
+    
+    /// Amount is negative.
+    /// Cause: Passing a negative amount, top-up, partial payout, collateral, escrow deposit or limit.
+    NegativeAmount = 48,
+    
+    /// Token does not implement the token interface.
+    /// Cause: Initializing with, or whitelisting, an address whose decimals, balance or transfer call fails, such as a non-token contract.
+    UnsupportedToken = 49,
+    
+    /// Sender is also the agent or the payout recipient.
+    /// Cause: Creating or reassigning a remittance to the sender's own agent address, or directing its payout to the sender, outside a corridor that allows self-payout.
+    SelfDealing = 50,
 }
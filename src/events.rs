@@ -9,6 +9,7 @@ pub fn emit_paused(env: &Env, admin: Address) {
         (symbol_short!("admin"), symbol_short!("paused")),
         (
             SCHEMA_VERSION,
+            next_event_seq(env),
             env.ledger().sequence(),
             env.ledger().timestamp(),
             admin,
@@ -27,22 +28,73 @@ pub fn emit_unpaused(env: &Env, admin: Address) {
         (symbol_short!("admin"), symbol_short!("unpaused")),
         (
             SCHEMA_VERSION,
+            next_event_seq(env),
             env.ledger().sequence(),
             env.ledger().timestamp(),
             admin,
         ),
     );
 }
+
+/// Emits an event when an admin pauses or resumes a single operation.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Address of the admin who changed the pause
+/// * `op` - Name of the operation
+/// * `paused` - Whether the operation is now paused
+pub fn emit_op_paused(env: &Env, admin: Address, op: PausableOp, paused: bool) {
+    env.events().publish(
+        (symbol_short!("admin"), symbol_short!("op_paused")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+            op,
+            paused,
+        ),
+    );
+}
 //! Event emission functions for the SwiftRemit contract.
 //!
 //! This module provides functions to emit structured events for all significant
 //! contract operations. Events include schema versioning and ledger metadata
 //! for comprehensive audit trails.
 
-use soroban_sdk::{symbol_short, Address, Env};
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, String, Symbol, Vec};
+
+use crate::{
+    CancelReason, CriticalOp, DeclineReason, LimitScope, PausableOp, PayoutFx, Priority, PurposeCode, QuorumConfig,
+    RemittanceStatus, RoundingMode, SlaConfig, SlashStatus, TtlClass, YieldBeneficiary, YieldConfig,
+};
 
 /// Schema version for event structure compatibility
-const SCHEMA_VERSION: u32 = 1;
+const SCHEMA_VERSION: u32 = 2;
+
+#[contracttype]
+#[derive(Clone)]
+enum EventKey {
+    /// Sequence number of the last emitted event
+    Seq,
+}
+
+/// Returns the sequence number of the last event emitted by the contract.
+///
+/// Every event carries its `event_seq` right after the schema version. The
+/// sequence starts at 1 and increases by exactly one per event, so indexers
+/// can detect gaps and resume from the last sequence they processed.
+pub fn get_last_event_seq(env: &Env) -> u64 {
+    env.storage().instance().get(&EventKey::Seq).unwrap_or(0)
+}
+
+fn next_event_seq(env: &Env) -> u64 {
+    let seq = get_last_event_seq(env) + 1;
+    env.storage().instance().set(&EventKey::Seq, &seq);
+    seq
+}
 
 // ── Remittance Events ──────────────────────────────────────────────
 
@@ -56,6 +108,7 @@ const SCHEMA_VERSION: u32 = 1;
 /// * `agent` - Address of the assigned agent
 /// * `amount` - Total remittance amount
 /// * `fee` - Platform fee deducted
+/// * `purpose` - Declared purpose of the payment
 pub fn emit_remittance_created(
     env: &Env,
     remittance_id: u64,
@@ -63,11 +116,13 @@ pub fn emit_remittance_created(
     agent: Address,
     amount: i128,
     fee: i128,
+    purpose: PurposeCode,
 ) {
     env.events().publish(
         (symbol_short!("remit"), symbol_short!("created")),
         (
             SCHEMA_VERSION,
+            next_event_seq(env),
             env.ledger().sequence(),
             env.ledger().timestamp(),
             remittance_id,
@@ -75,6 +130,272 @@ pub fn emit_remittance_created(
             agent,
             amount,
             fee,
+            purpose,
+        ),
+    );
+}
+
+/// Emits an event whenever a remittance changes status.
+///
+/// Published in addition to the operation-specific events, with the same
+/// structure for every transition, so a subscription to this topic alone
+/// follows a remittance through its whole lifecycle.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the remittance
+/// * `old_status` - Status before the change; `None` when the remittance was just created
+/// * `new_status` - Status after the change
+/// * `actor` - Address whose call made the change; `None` for permissionless calls
+pub fn emit_status_changed(
+    env: &Env,
+    remittance_id: u64,
+    old_status: Option<RemittanceStatus>,
+    new_status: RemittanceStatus,
+    actor: Option<Address>,
+) {
+    env.events().publish(
+        (symbol_short!("status"), symbol_short!("changed")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            old_status,
+            new_status,
+            actor,
+        ),
+    );
+}
+
+/// Emits an event when the admin cancels a remittance for compliance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the cancelled remittance
+/// * `admin` - Admin who cancelled it
+/// * `reason` - Reason given for the cancellation
+/// * `amount` - Amount refunded, or held in quarantine
+/// * `quarantined` - Whether the funds were quarantined instead of refunded
+pub fn emit_remittance_admin_cancelled(
+    env: &Env,
+    remittance_id: u64,
+    admin: Address,
+    reason: CancelReason,
+    amount: i128,
+    quarantined: bool,
+) {
+    env.events().publish(
+        (symbol_short!("remit"), symbol_short!("adm_cncl")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            admin,
+            reason,
+            amount,
+            quarantined,
+        ),
+    );
+}
+
+/// Emits an event when an agent declines a remittance assigned to it.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the declined remittance
+/// * `agent` - Agent who declined it
+/// * `reason` - Reason given by the agent
+/// * `refund_amount` - Amount refunded to the sender
+pub fn emit_remittance_declined(
+    env: &Env,
+    remittance_id: u64,
+    agent: Address,
+    reason: DeclineReason,
+    refund_amount: i128,
+) {
+    env.events().publish(
+        (symbol_short!("remit"), symbol_short!("declined")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            agent,
+            reason,
+            refund_amount,
+        ),
+    );
+}
+
+/// Emits an event when the admin freezes a remittance into quarantine.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the frozen remittance
+/// * `admin` - Admin who froze it
+/// * `amount` - Amount moved into quarantine
+pub fn emit_remittance_frozen(env: &Env, remittance_id: u64, admin: Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("remit"), symbol_short!("frozen")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            admin,
+            amount,
+        ),
+    );
+}
+
+/// Emits an event when quarantined funds are released after an investigation.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the quarantined remittance
+/// * `admin` - Admin who released the funds
+/// * `to` - Recipient of the funds
+/// * `amount` - Amount released
+pub fn emit_quarantine_released(env: &Env, remittance_id: u64, admin: Address, to: Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("remit"), symbol_short!("qreleased")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            admin,
+            to,
+            amount,
+        ),
+    );
+}
+
+/// Emits an event when a sender reserves a remittance ID.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Reserved remittance ID
+/// * `sender` - Address of the sender holding the reservation
+pub fn emit_remittance_reserved(env: &Env, remittance_id: u64, sender: Address) {
+    env.events().publish(
+        (symbol_short!("remit"), symbol_short!("reserved")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            sender,
+        ),
+    );
+}
+
+/// Emits an event when a sender amends a pending remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the amended remittance
+/// * `agent` - Agent assigned after the amendment
+/// * `old_amount` - Amount before the amendment
+/// * `new_amount` - Amount after the amendment
+/// * `fee` - Recomputed platform fee
+pub fn emit_remittance_amended(
+    env: &Env,
+    remittance_id: u64,
+    agent: Address,
+    old_amount: i128,
+    new_amount: i128,
+    fee: i128,
+) {
+    env.events().publish(
+        (symbol_short!("remit"), symbol_short!("amended")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            agent,
+            old_amount,
+            new_amount,
+            fee,
+        ),
+    );
+}
+
+/// Emits an event when a sender tops up the escrow of a pending remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the topped-up remittance
+/// * `extra_amount` - Amount added by the sender
+/// * `extra_fee` - Part of `extra_amount` added to the platform fee
+/// * `new_amount` - Amount after the top-up
+pub fn emit_remittance_topped_up(
+    env: &Env,
+    remittance_id: u64,
+    extra_amount: i128,
+    extra_fee: i128,
+    new_amount: i128,
+) {
+    env.events().publish(
+        (symbol_short!("remit"), symbol_short!("topped_up")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            extra_amount,
+            extra_fee,
+            new_amount,
+        ),
+    );
+}
+
+/// Emits an event when a pending remittance is moved to another agent.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the reassigned remittance
+/// * `caller` - Sender or admin who reassigned it
+/// * `old_agent` - Agent the remittance was assigned to
+/// * `new_agent` - Agent the remittance is now assigned to
+pub fn emit_remittance_reassigned(
+    env: &Env,
+    remittance_id: u64,
+    caller: Address,
+    old_agent: Address,
+    new_agent: Address,
+) {
+    env.events().publish(
+        (symbol_short!("remit"), symbol_short!("reassign")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            caller,
+            old_agent,
+            new_agent,
         ),
     );
 }
@@ -85,23 +406,67 @@ pub fn emit_remittance_created(
 ///
 /// * `env` - The contract execution environment
 /// * `remittance_id` - ID of the completed remittance
+/// * `sender` - Address of the sender
 /// * `agent` - Address of the agent who received the payout
+/// * `token` - Token the payout was made in
 /// * `amount` - Payout amount (after fee deduction)
+/// * `fx` - Local-currency amount and rate the agent reported paying out, if any
 pub fn emit_remittance_completed(
     env: &Env,
     remittance_id: u64,
+    sender: Address,
     agent: Address,
+    token: Address,
     amount: i128,
+    fx: Option<PayoutFx>,
 ) {
     env.events().publish(
         (symbol_short!("remit"), symbol_short!("complete")),
         (
             SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            sender,
+            agent,
+            token,
+            amount,
+            fx,
+        ),
+    );
+}
+
+/// Emits an event when an agent confirms a partial delivery tranche.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the remittance
+/// * `agent` - Address of the agent that delivered the tranche
+/// * `amount` - Tranche amount released from escrow
+/// * `fee` - Fee charged on the tranche
+/// * `remaining` - Amount still held in escrow
+pub fn emit_tranche_released(
+    env: &Env,
+    remittance_id: u64,
+    agent: Address,
+    amount: i128,
+    fee: i128,
+    remaining: i128,
+) {
+    env.events().publish(
+        (symbol_short!("remit"), symbol_short!("tranche")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
             env.ledger().sequence(),
             env.ledger().timestamp(),
             remittance_id,
             agent,
             amount,
+            fee,
+            remaining,
         ),
     );
 }
@@ -124,6 +489,7 @@ pub fn emit_remittance_cancelled(
         (symbol_short!("remit"), symbol_short!("cancel")),
         (
             SCHEMA_VERSION,
+            next_event_seq(env),
             env.ledger().sequence(),
             env.ledger().timestamp(),
             remittance_id,
@@ -133,80 +499,2197 @@ pub fn emit_remittance_cancelled(
     );
 }
 
-// ── Agent Events ───────────────────────────────────────────────────
-
-/// Emits an event when a new agent is registered.
+/// Emits an event when a signed payout receipt hash is recorded.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `agent` - Address of the registered agent
-pub fn emit_agent_registered(env: &Env, agent: Address) {
+/// * `remittance_id` - ID of the paid-out remittance
+/// * `agent` - Address of the agent that issued the receipt
+/// * `receipt_hash` - Hash of the signed receipt document
+pub fn emit_receipt_recorded(env: &Env, remittance_id: u64, agent: Address, receipt_hash: BytesN<32>) {
     env.events().publish(
-        (symbol_short!("agent"), symbol_short!("register")),
+        (symbol_short!("remit"), symbol_short!("receipt")),
         (
             SCHEMA_VERSION,
+            next_event_seq(env),
             env.ledger().sequence(),
             env.ledger().timestamp(),
+            remittance_id,
             agent,
+            receipt_hash,
         ),
     );
 }
 
-/// Emits an event when an agent is removed.
+/// Emits an event when closed remittances are archived.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `agent` - Address of the removed agent
-pub fn emit_agent_removed(env: &Env, agent: Address) {
+/// * `count` - Number of remittances archived
+/// * `before_ledger` - Ledger before which archived remittances were closed
+pub fn emit_remittances_archived(env: &Env, count: u32, before_ledger: u32) {
     env.events().publish(
-        (symbol_short!("agent"), symbol_short!("removed")),
+        (symbol_short!("remit"), symbol_short!("archived")),
         (
             SCHEMA_VERSION,
+            next_event_seq(env),
             env.ledger().sequence(),
             env.ledger().timestamp(),
-            agent,
+            count,
+            before_ledger,
         ),
     );
 }
 
-// ── Fee Events ─────────────────────────────────────────────────────
+/// Emits an event when a snapshot of remittance records is committed.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `snapshot_id` - ID of the snapshot
+/// * `range_start` - First remittance ID covered
+/// * `range_end` - Last remittance ID covered
+/// * `root` - Merkle root of the record hashes
+pub fn emit_snapshot_committed(
+    env: &Env,
+    snapshot_id: u32,
+    range_start: u64,
+    range_end: u64,
+    root: BytesN<32>,
+) {
+    env.events().publish(
+        (symbol_short!("remit"), symbol_short!("snapshot")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            snapshot_id,
+            range_start,
+            range_end,
+            root,
+        ),
+    );
+}
 
-/// Emits an event when the platform fee is updated.
+/// Emits an event when a payout is swapped into the agent's preferred token.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `fee_bps` - New fee rate in basis points
-pub fn emit_fee_updated(env: &Env, fee_bps: u32) {
+/// * `remittance_id` - ID of the remittance paid out
+/// * `agent` - Address of the agent receiving the swapped tokens
+/// * `token_out` - Token the agent received
+/// * `amount_in` - Payout amount swapped (in USDC)
+/// * `amount_out` - Amount of `token_out` received by the agent
+pub fn emit_payout_swapped(
+    env: &Env,
+    remittance_id: u64,
+    agent: Address,
+    token_out: Address,
+    amount_in: i128,
+    amount_out: i128,
+) {
     env.events().publish(
-        (symbol_short!("fee"), symbol_short!("updated")),
+        (symbol_short!("remit"), symbol_short!("swapped")),
         (
             SCHEMA_VERSION,
+            next_event_seq(env),
             env.ledger().sequence(),
             env.ledger().timestamp(),
-            fee_bps,
+            remittance_id,
+            agent,
+            token_out,
+            amount_in,
+            amount_out,
         ),
     );
 }
 
-/// Emits an event when accumulated fees are withdrawn.
+/// Emits an event when a remittance is split across several agents.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `to` - Address that received the withdrawn fees
-/// * `amount` - Amount of fees withdrawn
-pub fn emit_fees_withdrawn(env: &Env, to: Address, amount: i128) {
+/// * `split_id` - ID of the split
+/// * `sender` - Address that funded the split
+/// * `total` - Total amount escrowed
+/// * `legs` - Number of legs
+pub fn emit_split_created(env: &Env, split_id: u64, sender: Address, total: i128, legs: u32) {
     env.events().publish(
-        (symbol_short!("fee"), symbol_short!("withdraw")),
+        (symbol_short!("split"), symbol_short!("created")),
         (
             SCHEMA_VERSION,
+            next_event_seq(env),
             env.ledger().sequence(),
             env.ledger().timestamp(),
-            to,
-            amount,
+            split_id,
+            sender,
+            total,
+            legs,
+        ),
+    );
+}
+
+// ── Route Events ───────────────────────────────────────────────────
+
+/// Emits an event when a remittance is routed through intermediary agents.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the routed remittance
+/// * `sender` - Address that funded the remittance
+/// * `agent` - Destination agent paying out the remittance
+/// * `hops` - Number of intermediaries
+/// * `hop_fee` - Fee paid to each intermediary
+pub fn emit_route_created(
+    env: &Env,
+    remittance_id: u64,
+    sender: Address,
+    agent: Address,
+    hops: u32,
+    hop_fee: i128,
+) {
+    env.events().publish(
+        (symbol_short!("route"), symbol_short!("created")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            sender,
+            agent,
+            hops,
+            hop_fee,
+        ),
+    );
+}
+
+/// Emits an event when an intermediary confirms its leg of a routed remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the routed remittance
+/// * `hop` - Intermediary that confirmed its leg
+/// * `hop_fee` - Fee paid to the intermediary
+/// * `hops_left` - Legs still to be confirmed
+pub fn emit_hop_confirmed(env: &Env, remittance_id: u64, hop: Address, hop_fee: i128, hops_left: u32) {
+    env.events().publish(
+        (symbol_short!("route"), symbol_short!("hop")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            hop,
+            hop_fee,
+            hops_left,
+        ),
+    );
+}
+
+/// Emits an event when the admin changes the per-hop fee.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `fee_bps` - New fee per hop in basis points
+pub fn emit_hop_fee_set(env: &Env, fee_bps: u32) {
+    env.events().publish(
+        (symbol_short!("route"), symbol_short!("fee")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            fee_bps,
+        ),
+    );
+}
+
+// ── Agent Events ───────────────────────────────────────────────────
+
+/// Emits an event when a new agent is registered.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Address of the registered agent
+pub fn emit_agent_registered(env: &Env, agent: Address) {
+    env.events().publish(
+        (symbol_short!("agent"), symbol_short!("register")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+        ),
+    );
+}
+
+/// Emits an event when an agent is removed.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Address of the removed agent
+pub fn emit_agent_removed(env: &Env, agent: Address) {
+    env.events().publish(
+        (symbol_short!("agent"), symbol_short!("removed")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+        ),
+    );
+}
+
+/// Emits an event when an agent is suspended.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Address of the suspended agent
+pub fn emit_agent_suspended(env: &Env, agent: Address) {
+    env.events().publish(
+        (symbol_short!("agent"), symbol_short!("suspend")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+        ),
+    );
+}
+
+/// Emits an event when a suspended agent is reinstated.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Address of the reinstated agent
+pub fn emit_agent_reinstated(env: &Env, agent: Address) {
+    env.events().publish(
+        (symbol_short!("agent"), symbol_short!("reinstat")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+        ),
+    );
+}
+
+/// Emits an event when an agent changes its availability.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Address of the agent
+/// * `available` - Whether the agent accepts new remittances
+pub fn emit_agent_availability(env: &Env, agent: Address, available: bool) {
+    env.events().publish(
+        (symbol_short!("agent"), symbol_short!("avail")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+            available,
+        ),
+    );
+}
+
+/// Emits an event when an agent's coverage areas are replaced.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Address of the agent
+/// * `areas` - Number of coverage areas now stored
+pub fn emit_agent_coverage_set(env: &Env, agent: Address, areas: u32) {
+    env.events().publish(
+        (symbol_short!("agent"), symbol_short!("coverage")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+            areas,
+        ),
+    );
+}
+
+/// Emits an event when an agent adds or removes a sub-agent.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Address of the master agent
+/// * `sub_agent` - Address of the sub-agent
+/// * `delegated` - `true` when payout rights were granted, `false` when revoked
+pub fn emit_sub_agent_changed(env: &Env, agent: Address, sub_agent: Address, delegated: bool) {
+    env.events().publish(
+        (symbol_short!("agent"), symbol_short!("subagent")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+            sub_agent,
+            delegated,
+        ),
+    );
+}
+
+/// Emits an event when a sub-agent confirms a payout on behalf of its master agent.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the confirmed remittance
+/// * `agent` - Address of the master agent credited with the payout
+/// * `sub_agent` - Address of the sub-agent that confirmed it
+pub fn emit_sub_agent_payout(env: &Env, remittance_id: u64, agent: Address, sub_agent: Address) {
+    env.events().publish(
+        (symbol_short!("agent"), symbol_short!("subpay")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            agent,
+            sub_agent,
+        ),
+    );
+}
+
+/// Emits an event when the matcher assigns an agent to a remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the auto-matched remittance
+/// * `agent` - Address of the selected agent
+/// * `weight` - Selected agent's weight in this matching round
+/// * `total_weight` - Sum of weights of all eligible agents in this round
+pub fn emit_agent_assigned(
+    env: &Env,
+    remittance_id: u64,
+    agent: Address,
+    weight: i128,
+    total_weight: i128,
+) {
+    env.events().publish(
+        (symbol_short!("agent"), symbol_short!("assigned")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            agent,
+            weight,
+            total_weight,
+        ),
+    );
+}
+
+// ── Fee Events ─────────────────────────────────────────────────────
+
+/// Emits an event when the platform fee is updated.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `fee_bps` - New fee rate in basis points
+pub fn emit_fee_updated(env: &Env, fee_bps: u32) {
+    env.events().publish(
+        (symbol_short!("fee"), symbol_short!("updated")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            fee_bps,
+        ),
+    );
+}
+
+/// Emits an event when a platform fee update is scheduled.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `fee_bps` - Fee rate in basis points from `effective_at`
+/// * `effective_at` - Ledger timestamp from which the fee applies
+pub fn emit_fee_scheduled(env: &Env, fee_bps: u32, effective_at: u64) {
+    env.events().publish(
+        (symbol_short!("fee"), symbol_short!("scheduled")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            fee_bps,
+            effective_at,
+        ),
+    );
+}
+
+/// Emits an event when accumulated fees are withdrawn.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `to` - Address that received the withdrawn fees
+/// * `amount` - Amount of fees withdrawn
+pub fn emit_fees_withdrawn(env: &Env, to: Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("fee"), symbol_short!("withdraw")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            to,
+            amount,
+        ),
+    );
+}
+
+/// Emits an event when a recipient receives its share of distributed fees.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `recipient` - Address that received the share
+/// * `share_bps` - Configured share in basis points
+/// * `amount` - Amount transferred to the recipient
+pub fn emit_fees_distributed(env: &Env, recipient: Address, share_bps: u32, amount: i128) {
+    env.events().publish(
+        (symbol_short!("fee"), symbol_short!("distrib")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            recipient,
+            share_bps,
+            amount,
+        ),
+    );
+}
+
+/// Emits an event when the fee rounding mode of a token changes.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `token` - Token the rounding mode applies to
+/// * `mode` - New rounding mode
+pub fn emit_fee_rounding_set(env: &Env, token: Address, mode: RoundingMode) {
+    env.events().publish(
+        (symbol_short!("fee"), symbol_short!("rounding")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            token,
+            mode,
+        ),
+    );
+}
+
+// ── Insurance Events ───────────────────────────────────────────────
+
+/// Emits an event when a sender buys insurance for a remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the insured remittance
+/// * `sender` - Address of the sender who paid the premium
+/// * `premium` - Premium amount paid
+pub fn emit_premium_paid(env: &Env, remittance_id: u64, sender: Address, premium: i128) {
+    env.events().publish(
+        (symbol_short!("insure"), symbol_short!("paid")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            sender,
+            premium,
+        ),
+    );
+}
+
+/// Emits an event when an unused insurance premium is refunded.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the insured remittance
+/// * `sender` - Address of the sender receiving the refund
+/// * `refund` - Premium amount returned to the sender
+pub fn emit_premium_refunded(env: &Env, remittance_id: u64, sender: Address, refund: i128) {
+    env.events().publish(
+        (symbol_short!("insure"), symbol_short!("refund")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            sender,
+            refund,
+        ),
+    );
+}
+
+// ── Accounting Events ──────────────────────────────────────────────
+
+/// Emits an event when reconciliation finds the token balance out of line
+/// with the contract's recorded liabilities.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `token_balance` - Actual token balance held by the contract
+/// * `expected_balance` - Escrow plus fees, insurance holdings and pending settlements
+/// * `discrepancy` - token_balance minus expected_balance
+pub fn emit_reconcile_discrepancy(
+    env: &Env,
+    token_balance: i128,
+    expected_balance: i128,
+    discrepancy: i128,
+) {
+    env.events().publish(
+        (symbol_short!("recon"), symbol_short!("mismatch")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            token_balance,
+            expected_balance,
+            discrepancy,
+        ),
+    );
+}
+
+// ── Intent Events ──────────────────────────────────────────────────
+
+/// Emits an event when a sender declares an intent to send a remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `sender` - Address of the sender declaring the intent
+/// * `agent` - Address of the intended agent
+/// * `amount` - Intended remittance amount
+/// * `available_at` - Earliest timestamp the remittance can be created
+pub fn emit_intent_declared(
+    env: &Env,
+    sender: Address,
+    agent: Address,
+    amount: i128,
+    available_at: u64,
+) {
+    env.events().publish(
+        (symbol_short!("intent"), symbol_short!("declared")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            sender,
+            agent,
+            amount,
+            available_at,
+        ),
+    );
+}
+
+// ── Settlement Schedule Events ─────────────────────────────────────
+
+/// Emits an event when an agent's batched payouts are settled.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Address of the agent paid
+/// * `window_end` - End of the settled batching window, or the settlement time when the agent settles early
+/// * `count` - Number of remittances in the batch
+/// * `amount` - Total amount transferred to the agent
+pub fn emit_agent_settled(env: &Env, agent: Address, window_end: u64, count: u32, amount: i128) {
+    env.events().publish(
+        (symbol_short!("settle"), symbol_short!("agent")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+            window_end,
+            count,
+            amount,
+        ),
+    );
+}
+
+// ── Organization Events ────────────────────────────────────────────
+
+/// Emits an event when an organization publishes a remittance template.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `org_id` - ID of the publishing organization
+/// * `template_id` - ID of the new template
+/// * `agent` - Agent the template pays out through
+/// * `amount` - Amount of each remittance
+/// * `max_uses` - Usage cap of the template
+pub fn emit_template_published(
+    env: &Env,
+    org_id: u64,
+    template_id: u64,
+    agent: Address,
+    amount: i128,
+    max_uses: u32,
+) {
+    env.events().publish(
+        (symbol_short!("template"), symbol_short!("publish")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            org_id,
+            template_id,
+            agent,
+            amount,
+            max_uses,
+        ),
+    );
+}
+
+/// Emits an event when a spender sends a remittance from a template.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `template_id` - ID of the template used
+/// * `spender` - Address of the spender that funded the remittance
+/// * `remittance_id` - ID of the created remittance
+/// * `uses` - Number of uses of the template including this one
+pub fn emit_template_used(env: &Env, template_id: u64, spender: Address, remittance_id: u64, uses: u32) {
+    env.events().publish(
+        (symbol_short!("template"), symbol_short!("used")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            template_id,
+            spender,
+            remittance_id,
+            uses,
+        ),
+    );
+}
+
+// ── Migration Events ───────────────────────────────────────────────
+
+/// Emits an event when remittances are imported from the v1 contract.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `count` - Number of remittances imported
+/// * `pending` - How many of them were pending and booked into escrow
+pub fn emit_remittances_imported(env: &Env, count: u32, pending: u32) {
+    env.events().publish(
+        (symbol_short!("migrate"), symbol_short!("imported")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            count,
+            pending,
+        ),
+    );
+}
+
+/// Emits an event when legacy imports are closed.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `last_id` - Highest remittance ID in use; new remittances are numbered after it
+pub fn emit_import_finalized(env: &Env, last_id: u64) {
+    env.events().publish(
+        (symbol_short!("migrate"), symbol_short!("final")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            last_id,
+        ),
+    );
+}
+
+// ── Batch Events ───────────────────────────────────────────────────
+
+/// Emits an event when a batch entry fails and is moved to the dead-letter queue.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `caller` - Address that submitted the batch
+/// * `operation` - Batch operation that failed (e.g. `confirm`, `create`)
+/// * `reference` - Remittance ID or batch entry index of the failed entry
+/// * `error_code` - Numeric error code of the failure
+pub fn emit_batch_entry_failed(env: &Env, caller: Address, operation: Symbol, reference: u64, error_code: u32) {
+    env.events().publish(
+        (symbol_short!("batch"), symbol_short!("failed")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            caller,
+            operation,
+            reference,
+            error_code,
+        ),
+    );
+}
+
+// ── Analytics Events ───────────────────────────────────────────────
+
+/// Emits an event when the analytics sink registration changes.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `sink` - Contract receiving completion records
+/// * `enabled` - Whether records are mirrored
+/// * `max_records_per_ledger` - Per-ledger mirroring budget
+pub fn emit_analytics_sink_set(env: &Env, sink: Address, enabled: bool, max_records_per_ledger: u32) {
+    env.events().publish(
+        (symbol_short!("analytic"), symbol_short!("sink")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            sink,
+            enabled,
+            max_records_per_ledger,
+        ),
+    );
+}
+
+// ── Compliance Events ──────────────────────────────────────────────
+
+/// Emits an event when the compliance provider is registered or cleared.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `hook` - Registered compliance provider, or `None` when cleared
+pub fn emit_compliance_hook_set(env: &Env, hook: Option<Address>) {
+    env.events().publish(
+        (symbol_short!("comply"), symbol_short!("hook")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            hook,
+        ),
+    );
+}
+
+// ── Sender Allowlist Events ────────────────────────────────────────
+
+/// Emits an event when the sender allowlist is switched on or off.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `enabled` - Whether the allowlist is now enforced
+pub fn emit_sender_allowlist_enabled(env: &Env, enabled: bool) {
+    env.events().publish(
+        (symbol_short!("allow"), symbol_short!("mode")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            enabled,
+        ),
+    );
+}
+
+/// Emits an event when a sender is added to or removed from the allowlist.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `sender` - Sender address
+/// * `allowed` - Whether the sender is now approved
+pub fn emit_sender_allowed(env: &Env, sender: Address, allowed: bool) {
+    env.events().publish(
+        (symbol_short!("allow"), symbol_short!("sender")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            sender,
+            allowed,
+        ),
+    );
+}
+
+// ── Risk Events ────────────────────────────────────────────────────
+
+/// Emits an event when an address is appointed or removed as a risk scorer.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `scorer` - Compliance officer or risk contract address
+/// * `enabled` - Whether the address may now set risk scores
+pub fn emit_risk_scorer_set(env: &Env, scorer: Address, enabled: bool) {
+    env.events().publish(
+        (symbol_short!("risk"), symbol_short!("scorer")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            scorer,
+            enabled,
+        ),
+    );
+}
+
+/// Emits an event when a sender's risk score changes.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `scorer` - Address that set the score
+/// * `sender` - Sender whose score changed
+/// * `score` - New score
+pub fn emit_risk_score_set(env: &Env, scorer: Address, sender: Address, score: u32) {
+    env.events().publish(
+        (symbol_short!("risk"), symbol_short!("score")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            scorer,
+            sender,
+            score,
+        ),
+    );
+}
+
+/// Emits an event when a sender is exempted from limits or the exemption is lifted.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `officer` - Risk scorer or admin that changed the exemption
+/// * `sender` - Institutional sender concerned
+/// * `exempt` - Whether the sender now bypasses band and creation rate limits
+pub fn emit_limit_exempt_set(env: &Env, officer: Address, sender: Address, exempt: bool) {
+    env.events().publish(
+        (symbol_short!("risk"), symbol_short!("exempt")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            officer,
+            sender,
+            exempt,
+        ),
+    );
+}
+
+/// Emits an event when the admin replaces the risk bands.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `bands` - Number of bands now configured
+pub fn emit_risk_bands_set(env: &Env, bands: u32) {
+    env.events().publish(
+        (symbol_short!("risk"), symbol_short!("bands")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            bands,
+        ),
+    );
+}
+
+/// Emits an event when a sender's cancellations start a cooldown.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `sender` - Sender who cannot create remittances until the cooldown ends
+/// * `until` - Timestamp at which the cooldown ends
+pub fn emit_cancel_cooldown_started(env: &Env, sender: Address, until: u64) {
+    env.events().publish(
+        (symbol_short!("risk"), symbol_short!("cooldown")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            sender,
+            until,
+        ),
+    );
+}
+
+// ── TTL Events ─────────────────────────────────────────────────────
+
+/// Emits an event when the admin changes the TTL policy of a data class.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `class` - Data class whose policy changed
+/// * `threshold` - TTL in ledgers below which writes extend its entries
+/// * `extend_to` - TTL in ledgers its entries are extended to
+pub fn emit_ttl_policy_set(env: &Env, class: TtlClass, threshold: u32, extend_to: u32) {
+    env.events().publish(
+        (symbol_short!("ttl"), symbol_short!("policy")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            class,
+            threshold,
+            extend_to,
+        ),
+    );
+}
+
+// ── Operator Events ────────────────────────────────────────────────
+
+/// Emits an event when an operator is appointed or removed.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Admin who changed the role
+/// * `operator` - Operator address
+/// * `enabled` - Whether the address is now an operator
+pub fn emit_operator_set(env: &Env, admin: Address, operator: Address, enabled: bool) {
+    env.events().publish(
+        (symbol_short!("operator"), symbol_short!("set")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+            operator,
+            enabled,
+        ),
+    );
+}
+
+// ── Diagnostic Events ──────────────────────────────────────────────
+
+/// Emits a diagnostic event when a critical step fails without failing the call.
+///
+/// A call that returns an error is rolled back together with its events, so
+/// this is emitted where the contract carries on past the failure: an
+/// unreachable analytics sink or yield adapter, or a reconciliation that finds
+/// the contract short of its liabilities. Monitoring can alert on the `diag`
+/// topic without parsing transaction meta.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `operation` - Operation that failed, e.g. `reconcile`
+/// * `source` - Contract that failed: this contract, or the external contract it called
+/// * `error_code` - `ContractError` code, or the external contract's error code (0 when it trapped)
+/// * `reference` - Remittance ID the failure relates to, 0 when none
+pub fn emit_operation_failed(env: &Env, operation: Symbol, source: Address, error_code: u32, reference: u64) {
+    env.events().publish(
+        (symbol_short!("diag"), operation),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            source,
+            error_code,
+            reference,
+        ),
+    );
+}
+
+// ── Fee Exemption Events ───────────────────────────────────────────
+
+/// Emits an event when a sender is exempted from, or returned to, the platform fee.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `sender` - Sender address
+/// * `exempt` - Whether the sender is now fee-exempt
+pub fn emit_fee_exemption_updated(env: &Env, sender: Address, exempt: bool) {
+    env.events().publish(
+        (symbol_short!("fee"), symbol_short!("exempt")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            sender,
+            exempt,
+        ),
+    );
+}
+
+// ── Volume Discount Events ─────────────────────────────────────────
+
+/// Emits an event when the volume discount tiers are replaced.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Admin who set the tiers
+/// * `tier_count` - Number of tiers now configured; 0 when discounts were removed
+pub fn emit_volume_tiers_set(env: &Env, admin: Address, tier_count: u32) {
+    env.events().publish(
+        (symbol_short!("fee"), symbol_short!("tiers")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+            tier_count,
+        ),
+    );
+}
+
+// ── Expiry Sweep Events ────────────────────────────────────────────
+
+/// Emits an event when a sweep refunds an expired remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the expired remittance
+/// * `sender` - Sender who was refunded
+/// * `refund_amount` - Amount returned to the sender
+pub fn emit_remittance_expired(env: &Env, remittance_id: u64, sender: Address, refund_amount: i128) {
+    env.events().publish(
+        (symbol_short!("remit"), symbol_short!("expired")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            sender,
+            refund_amount,
+        ),
+    );
+}
+
+/// Emits an event when a keeper completes a sweep of expired remittances.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `keeper` - Address that ran the sweep and received the bounty
+/// * `expired` - Number of remittances expired
+/// * `bounty` - Bounty paid to the keeper
+pub fn emit_expired_swept(env: &Env, keeper: Address, expired: u32, bounty: i128) {
+    env.events().publish(
+        (symbol_short!("sweep"), symbol_short!("bounty")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            keeper,
+            expired,
+            bounty,
+        ),
+    );
+}
+
+// ── Attestation Events ─────────────────────────────────────────────
+
+/// Emits an event when an agent's confirmation is held for receipt attestation.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the confirmed remittance
+/// * `agent` - Agent that confirmed the payout
+/// * `release_after` - Timestamp after which the payout can be released unattested
+pub fn emit_payout_held(env: &Env, remittance_id: u64, agent: Address, release_after: u64) {
+    env.events().publish(
+        (symbol_short!("remit"), symbol_short!("held")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            agent,
+            release_after,
+        ),
+    );
+}
+
+/// Emits an event when a held payout is released.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the released remittance
+/// * `attester` - Sender or oracle that attested receipt, or `None` when released after the timeout
+pub fn emit_payout_attested(env: &Env, remittance_id: u64, attester: Option<Address>) {
+    env.events().publish(
+        (symbol_short!("remit"), symbol_short!("attested")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            attester,
+        ),
+    );
+}
+
+// ── Corridor Events ────────────────────────────────────────────────
+
+/// Emits an event when an agent is assigned to a payout corridor.
+pub fn emit_agent_corridor_set(env: &Env, agent: Address, corridor: String) {
+    env.events().publish(
+        (symbol_short!("corridor"), symbol_short!("agent")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+            corridor,
+        ),
+    );
+}
+
+/// Emits an event when the purpose codes accepted in a corridor change.
+pub fn emit_corridor_purposes_set(env: &Env, corridor: String, purposes: Vec<PurposeCode>) {
+    env.events().publish(
+        (symbol_short!("corridor"), symbol_short!("purposes")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            corridor,
+            purposes,
+        ),
+    );
+}
+
+/// Emits an event when the fee override of a corridor is set or cleared.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `corridor` - Corridor identifier
+/// * `fee_bps` - New fee in basis points, or `None` when cleared
+pub fn emit_corridor_fee_set(env: &Env, corridor: String, fee_bps: Option<u32>) {
+    env.events().publish(
+        (symbol_short!("corridor"), symbol_short!("fee")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            corridor,
+            fee_bps,
+        ),
+    );
+}
+
+/// Emits an event when the hold period of a corridor changes.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `corridor` - Corridor identifier
+/// * `seconds` - New hold period, 0 when removed
+pub fn emit_corridor_hold_set(env: &Env, corridor: String, seconds: u64) {
+    env.events().publish(
+        (symbol_short!("corridor"), symbol_short!("hold")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            corridor,
+            seconds,
+        ),
+    );
+}
+
+/// Emits an event when self-payout is allowed or forbidden in a corridor.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `corridor` - Corridor identifier
+/// * `allowed` - Whether senders may now pay out to themselves
+pub fn emit_corridor_self_payout_set(env: &Env, corridor: String, allowed: bool) {
+    env.events().publish(
+        (symbol_short!("corridor"), symbol_short!("selfpay")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            corridor,
+            allowed,
+        ),
+    );
+}
+
+/// Emits an event when transfers between two countries are blocked or unblocked.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Admin who changed the block
+/// * `country_from` - Origin country
+/// * `country_to` - Destination country
+/// * `blocked` - Whether the pair is now blocked
+pub fn emit_corridor_blocked(
+    env: &Env,
+    admin: Address,
+    country_from: String,
+    country_to: String,
+    blocked: bool,
+) {
+    env.events().publish(
+        (symbol_short!("corridor"), symbol_short!("blocked")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+            country_from,
+            country_to,
+            blocked,
+        ),
+    );
+}
+
+// ── Transfer Limit Events ──────────────────────────────────────────
+
+/// Emits an event when a maximum single-transfer amount is set or cleared.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `scope` - Token or corridor the cap applies to
+/// * `amount` - New cap, or `None` when cleared
+pub fn emit_max_transfer_amount_set(env: &Env, scope: LimitScope, amount: Option<i128>) {
+    env.events().publish(
+        (symbol_short!("limit"), symbol_short!("max")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            scope,
+            amount,
+        ),
+    );
+}
+
+/// Emits an event when the minimum single-transfer amount of a token changes.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `token` - Token the minimum applies to
+/// * `amount` - New minimum, 0 when cleared
+pub fn emit_min_transfer_amount_set(env: &Env, token: Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("limit"), symbol_short!("min")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            token,
+            amount,
+        ),
+    );
+}
+
+// ── Loyalty Events ─────────────────────────────────────────────────
+
+/// Emits an event when the loyalty earn rate changes.
+pub fn emit_loyalty_rate_set(env: &Env, earn_bps: u32) {
+    env.events().publish(
+        (symbol_short!("loyalty"), symbol_short!("rate")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            earn_bps,
+        ),
+    );
+}
+
+/// Emits an event when a sender earns points for a completed remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the completed remittance
+/// * `sender` - Address of the sender credited
+/// * `points` - Points earned
+pub fn emit_points_earned(env: &Env, remittance_id: u64, sender: Address, points: i128) {
+    env.events().publish(
+        (symbol_short!("loyalty"), symbol_short!("earned")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            sender,
+            points,
+        ),
+    );
+}
+
+/// Emits an event when a sender redeems points into fee credit.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `user` - Address redeeming points
+/// * `points` - Points redeemed
+/// * `fee_credit` - Fee credit available after the redemption
+pub fn emit_points_redeemed(env: &Env, user: Address, points: i128, fee_credit: i128) {
+    env.events().publish(
+        (symbol_short!("loyalty"), symbol_short!("redeemed")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            user,
+            points,
+            fee_credit,
+        ),
+    );
+}
+
+// ── Payout Recipient Events ────────────────────────────────────────
+
+/// Emits an event when a remittance's payout recipient contract is set or cleared.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the remittance
+/// * `recipient` - New recipient contract, or `None` when cleared
+pub fn emit_payout_recipient_set(env: &Env, remittance_id: u64, recipient: Option<Address>) {
+    env.events().publish(
+        (symbol_short!("payout"), symbol_short!("recipient")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            recipient,
+        ),
+    );
+}
+
+// ── Yield Events ───────────────────────────────────────────────────
+
+/// Emits an event when the yield adapter is registered or removed.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `config` - New adapter and beneficiary, or `None` when removed
+pub fn emit_yield_adapter_set(env: &Env, config: Option<YieldConfig>) {
+    env.events().publish(
+        (symbol_short!("yield"), symbol_short!("adapter")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            config,
+        ),
+    );
+}
+
+/// Emits an event when escrow principal moves into or out of the yield adapter.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `deposited` - Amount deposited, negative when withdrawn
+/// * `principal` - Principal held in the adapter afterwards
+pub fn emit_yield_principal_moved(env: &Env, deposited: i128, principal: i128) {
+    env.events().publish(
+        (symbol_short!("yield"), symbol_short!("principal")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            deposited,
+            principal,
+        ),
+    );
+}
+
+/// Emits an event when withdrawable fees move into or out of the yield adapter.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `wrapped` - Amount wrapped, negative when unwrapped
+/// * `total` - Fees wrapped in the adapter afterwards
+pub fn emit_fees_wrapped(env: &Env, wrapped: i128, total: i128) {
+    env.events().publish(
+        (symbol_short!("yield"), symbol_short!("fees")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            wrapped,
+            total,
+        ),
+    );
+}
+
+/// Emits an event when the yield earned by wrapped fees is booked as fees.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `amount` - Yield booked as fees
+/// * `total` - Yield wrapped fees have earned in total
+pub fn emit_fee_yield_booked(env: &Env, amount: i128, total: i128) {
+    env.events().publish(
+        (symbol_short!("yield"), symbol_short!("fee_yield")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            amount,
+            total,
+        ),
+    );
+}
+
+/// Emits an event when earned yield is harvested.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `beneficiary` - Where the yield went
+/// * `amount` - Yield harvested
+pub fn emit_yield_harvested(env: &Env, beneficiary: YieldBeneficiary, amount: i128) {
+    env.events().publish(
+        (symbol_short!("yield"), symbol_short!("harvest")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            beneficiary,
+            amount,
+        ),
+    );
+}
+
+// ── SLA Events ─────────────────────────────────────────────────────
+
+/// Emits an event when the payout SLA of a priority is set or cleared.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `priority` - Priority the SLA applies to
+/// * `config` - New SLA window and rebate, or `None` when cleared
+pub fn emit_sla_set(env: &Env, priority: Priority, config: Option<SlaConfig>) {
+    env.events().publish(
+        (symbol_short!("sla"), symbol_short!("config")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            priority,
+            config,
+        ),
+    );
+}
+
+/// Emits an event when an agent confirms a payout after the SLA window.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the late remittance
+/// * `agent` - Agent the breach is recorded against
+/// * `delay` - Seconds from creation to confirmation
+/// * `rebate` - Fee rebated to the sender
+pub fn emit_sla_breached(env: &Env, remittance_id: u64, agent: Address, delay: u64, rebate: i128) {
+    env.events().publish(
+        (symbol_short!("sla"), symbol_short!("breach")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            agent,
+            delay,
+            rebate,
+        ),
+    );
+}
+
+// ── Priority Events ────────────────────────────────────────────────
+
+/// Emits an event when the admin changes the express surcharge.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `fee_bps` - New surcharge in basis points of the remittance amount
+pub fn emit_express_fee_set(env: &Env, fee_bps: u32) {
+    env.events().publish(
+        (symbol_short!("express"), symbol_short!("fee")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            fee_bps,
+        ),
+    );
+}
+
+/// Emits an event when a sender expedites a remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the expedited remittance
+/// * `agent` - Agent whose queue the remittance moved up in
+/// * `surcharge` - Surcharge paid by the sender
+pub fn emit_remittance_expedited(env: &Env, remittance_id: u64, agent: Address, surcharge: i128) {
+    env.events().publish(
+        (symbol_short!("express"), symbol_short!("upgraded")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            agent,
+            surcharge,
+        ),
+    );
+}
+
+// ── Quorum Events ──────────────────────────────────────────────────
+
+/// Emits an event when the treasury fee withdrawals are restricted to is set
+/// or cleared.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `treasury` - New treasury, `None` if cleared
+pub fn emit_treasury_set(env: &Env, treasury: Option<Address>) {
+    env.events().publish(
+        (symbol_short!("admin"), symbol_short!("treasury")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            treasury,
+        ),
+    );
+}
+
+/// Emits an event when the approval quorum for critical operations is set.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `config` - New signers, threshold and proposal lifetime
+pub fn emit_quorum_set(env: &Env, config: QuorumConfig) {
+    env.events().publish(
+        (symbol_short!("quorum"), symbol_short!("config")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            config,
+        ),
+    );
+}
+
+/// Emits an event when a signer proposes a critical operation.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `proposal_id` - ID of the new proposal
+/// * `proposer` - Signer who proposed it
+/// * `op` - Proposed operation
+/// * `expires_at_ledger` - Last ledger the proposal can be executed in
+pub fn emit_op_proposed(
+    env: &Env,
+    proposal_id: u64,
+    proposer: Address,
+    op: CriticalOp,
+    expires_at_ledger: u32,
+) {
+    env.events().publish(
+        (symbol_short!("quorum"), symbol_short!("proposed")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            proposal_id,
+            proposer,
+            op,
+            expires_at_ledger,
+        ),
+    );
+}
+
+/// Emits an event when a signer approves a proposed critical operation.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `proposal_id` - ID of the proposal
+/// * `signer` - Signer who approved it
+/// * `approvals` - Number of approvals so far
+pub fn emit_op_approved(env: &Env, proposal_id: u64, signer: Address, approvals: u32) {
+    env.events().publish(
+        (symbol_short!("quorum"), symbol_short!("approved")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            proposal_id,
+            signer,
+            approvals,
+        ),
+    );
+}
+
+/// Emits an event when an approved critical operation is executed.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `proposal_id` - ID of the executed proposal
+/// * `op` - Operation that was performed
+pub fn emit_op_executed(env: &Env, proposal_id: u64, op: CriticalOp) {
+    env.events().publish(
+        (symbol_short!("quorum"), symbol_short!("executed")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            proposal_id,
+            op,
+        ),
+    );
+}
+
+// ── Collateral Events ──────────────────────────────────────────────
+
+/// Emits an event when an agent deposits or withdraws collateral.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent whose collateral changed
+/// * `delta` - Amount deposited (positive) or withdrawn (negative)
+/// * `balance` - Agent's collateral afterwards
+pub fn emit_collateral_changed(env: &Env, agent: Address, delta: i128, balance: i128) {
+    env.events().publish(
+        (symbol_short!("collat"), symbol_short!("changed")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+            delta,
+            balance,
+        ),
+    );
+}
+
+/// Emits an event when the admin sets the share of fees distributed to staked agents.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `share_bps` - Share of each earned fee, in basis points
+pub fn emit_staking_share_set(env: &Env, share_bps: u32) {
+    env.events().publish(
+        (symbol_short!("collat"), symbol_short!("share")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            share_bps,
+        ),
+    );
+}
+
+/// Emits an event when an agent claims its staking rewards.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent paid
+/// * `amount` - Rewards paid
+pub fn emit_staking_rewards_claimed(env: &Env, agent: Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("collat"), symbol_short!("claimed")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+            amount,
+        ),
+    );
+}
+
+/// Emits an event when a slash of an agent's collateral is proposed.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Remittance the slash is about
+/// * `agent` - Agent whose collateral is locked
+/// * `amount` - Collateral locked
+/// * `evidence_hash` - Hash of the supporting evidence
+/// * `executable_at` - Timestamp the challenge window closes
+pub fn emit_slash_proposed(
+    env: &Env,
+    remittance_id: u64,
+    agent: Address,
+    amount: i128,
+    evidence_hash: BytesN<32>,
+    executable_at: u64,
+) {
+    env.events().publish(
+        (symbol_short!("slash"), symbol_short!("proposed")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            agent,
+            amount,
+            evidence_hash,
+            executable_at,
+        ),
+    );
+}
+
+/// Emits an event when an agent challenges a proposed slash.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Remittance the slash is about
+/// * `agent` - Agent challenging the slash
+/// * `response_hash` - Hash of the agent's response
+pub fn emit_slash_challenged(env: &Env, remittance_id: u64, agent: Address, response_hash: BytesN<32>) {
+    env.events().publish(
+        (symbol_short!("slash"), symbol_short!("challenge")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            agent,
+            response_hash,
+        ),
+    );
+}
+
+/// Emits an event when a slash is executed or cancelled.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Remittance the slash is about
+/// * `status` - `Executed` or `Cancelled`
+/// * `recipient` - Sender paid by an executed slash
+/// * `amount` - Collateral paid to the sender, or unlocked
+pub fn emit_slash_closed(env: &Env, remittance_id: u64, status: SlashStatus, recipient: Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("slash"), symbol_short!("closed")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            status,
+            recipient,
+            amount,
+        ),
+    );
+}
+
+// ── Capacity Events ────────────────────────────────────────────────
+
+/// Emits an event when the maximum pending remittances of an agent, or the
+/// default for all agents, is set.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent whose limit changed, or `None` for the default
+/// * `max_pending` - New limit (0 removes the agent's limit, or makes the default unlimited)
+pub fn emit_agent_max_pending_set(env: &Env, agent: Option<Address>, max_pending: u32) {
+    env.events().publish(
+        (symbol_short!("agent"), symbol_short!("capacity")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+            max_pending,
+        ),
+    );
+}
+
+// ── Screening Events ───────────────────────────────────────────────
+
+/// Emits an event when the admin changes the screening period.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `ledgers` - Ledgers new remittances are withheld from their agent; 0 disables screening
+pub fn emit_screening_period_set(env: &Env, ledgers: u32) {
+    env.events().publish(
+        (symbol_short!("screen"), symbol_short!("period")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            ledgers,
+        ),
+    );
+}
+
+// ── Direct Mode Events ─────────────────────────────────────────────
+
+/// Emits an event when a remittance is paid directly from sender to agent.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the recorded remittance
+/// * `sender` - Sender who paid
+/// * `agent` - Agent paid directly
+/// * `payout_amount` - Amount transferred to the agent
+/// * `fee` - Platform fee transferred to the contract
+pub fn emit_direct_remittance(
+    env: &Env,
+    remittance_id: u64,
+    sender: Address,
+    agent: Address,
+    payout_amount: i128,
+    fee: i128,
+) {
+    env.events().publish(
+        (symbol_short!("remit"), symbol_short!("direct")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            sender,
+            agent,
+            payout_amount,
+            fee,
+        ),
+    );
+}
+
+/// Emits an event when a pair is approved for or removed from direct mode.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `sender` - Sender of the pair
+/// * `agent` - Agent of the pair
+/// * `enabled` - Whether the pair may now use direct mode
+pub fn emit_direct_pair_set(env: &Env, sender: Address, agent: Address, enabled: bool) {
+    env.events().publish(
+        (symbol_short!("direct"), symbol_short!("pair")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            sender,
+            agent,
+            enabled,
+        ),
+    );
+}
+
+// ── Sponsor Events ─────────────────────────────────────────────────
+
+/// Emits an event when a sponsor deposits into or withdraws from its pool.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `sponsor` - Sponsor owning the pool
+/// * `delta` - Amount deposited (positive) or withdrawn (negative)
+/// * `balance` - Pool balance afterwards
+pub fn emit_sponsor_pool_changed(env: &Env, sponsor: Address, delta: i128, balance: i128) {
+    env.events().publish(
+        (symbol_short!("sponsor"), symbol_short!("changed")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            sponsor,
+            delta,
+            balance,
+        ),
+    );
+}
+
+/// Emits an event when a sponsor changes its relay fee.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `sponsor` - Sponsor paying the fee
+/// * `relay_fee` - Settlement tokens paid to the relayer per sponsored remittance
+pub fn emit_sponsor_relay_fee_set(env: &Env, sponsor: Address, relay_fee: i128) {
+    env.events().publish(
+        (symbol_short!("sponsor"), symbol_short!("fee")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            sponsor,
+            relay_fee,
+        ),
+    );
+}
+
+/// Emits an event when a sponsor approves or revokes a relayer.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `sponsor` - Sponsor whose pool the relayer draws on
+/// * `relayer` - Relayer approved or revoked
+/// * `approved` - Whether the relayer is approved
+pub fn emit_sponsor_relayer_set(env: &Env, sponsor: Address, relayer: Address, approved: bool) {
+    env.events().publish(
+        (symbol_short!("sponsor"), symbol_short!("relayer")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            sponsor,
+            relayer,
+            approved,
+        ),
+    );
+}
+
+/// Emits an event when a relayer submits a remittance on a sponsor's pool.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Sponsored remittance
+/// * `sponsor` - Sponsor that paid the relay fee
+/// * `relayer` - Relayer that submitted the remittance
+/// * `relay_fee` - Amount paid to the relayer from the pool
+pub fn emit_remittance_sponsored(
+    env: &Env,
+    remittance_id: u64,
+    sponsor: Address,
+    relayer: Address,
+    relay_fee: i128,
+) {
+    env.events().publish(
+        (symbol_short!("remit"), symbol_short!("sponsored")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            sponsor,
+            relayer,
+            relay_fee,
+        ),
+    );
+}
+
+/// Emits an event when a paid-out sponsored remittance returns its relay fee
+/// to the sponsor's pool.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Sponsored remittance that was paid out
+/// * `sponsor` - Sponsor whose pool was credited
+/// * `amount` - Amount moved from the platform fee to the pool
+pub fn emit_sponsor_reimbursed(env: &Env, remittance_id: u64, sponsor: Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("sponsor"), symbol_short!("reimburse")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            sponsor,
+            amount,
+        ),
+    );
+}
+
+// ── Watcher Events ─────────────────────────────────────────────────
+
+/// Emits an event when a watcher registers the topics it follows.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `watcher_id` - ID of the registration
+/// * `owner` - Address that registered the watcher
+/// * `topic_filter` - Event topics the watcher follows; empty for all
+pub fn emit_watcher_registered(env: &Env, watcher_id: u32, owner: Address, topic_filter: Vec<Symbol>) {
+    env.events().publish(
+        (symbol_short!("watcher"), symbol_short!("added")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            watcher_id,
+            owner,
+            topic_filter,
+        ),
+    );
+}
+
+/// Emits an event when a watcher registration is removed.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `watcher_id` - ID of the removed registration
+/// * `owner` - Address that registered the watcher
+pub fn emit_watcher_removed(env: &Env, watcher_id: u32, owner: Address) {
+    env.events().publish(
+        (symbol_short!("watcher"), symbol_short!("removed")),
+        (
+            SCHEMA_VERSION,
+            next_event_seq(env),
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            watcher_id,
+            owner,
         ),
     );
 }
@@ -0,0 +1,30 @@
+//! Platform fee exemptions.
+//!
+//! The admin can exempt specific senders, such as NGO partners or the
+//! operator's own treasury, from the platform fee. Exempt senders are charged
+//! a zero fee on every remittance they create, and quotes requested on their
+//! behalf reflect the exemption.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+#[contracttype]
+#[derive(Clone)]
+enum FeeExemptionKey {
+    /// Sender exempt from the platform fee (persistent storage)
+    Exempt(Address),
+}
+
+pub fn set_fee_exempt(env: &Env, sender: &Address, exempt: bool) {
+    let key = FeeExemptionKey::Exempt(sender.clone());
+    if exempt {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub fn is_fee_exempt(env: &Env, sender: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&FeeExemptionKey::Exempt(sender.clone()))
+}
@@ -24,8 +24,26 @@
 //! - Optional fields use 8 zero bytes when None
 //! - No separators between fields — fixed-width encoding eliminates ambiguity
 //! - Hash algorithm: SHA-256 via Soroban env.crypto().sha256()
+//!
+//! ## Deposit References
+//!
+//! A remittance's deposit reference is the first 8 bytes, read big-endian,
+//! of SHA-256 over the contract address (XDR) followed by `remittance_id`
+//! (u64 big-endian). It fits the 64-bit ID of a muxed account, so partners
+//! can derive it off-chain and pay against it without a memo.
+//!
+//! ## Remittance Commitments
+//!
+//! A snapshot commits to the remittances in an ID range with a Merkle root:
+//!
+//! - Leaf `i` is the record hash of remittance `range_start + i`: SHA-256 of
+//!   the remittance's XDR, the same hash an archived record keeps. IDs with
+//!   no record (e.g. reserved but never created) use 32 zero bytes.
+//! - Each parent is SHA-256 of its left child followed by its right child.
+//! - The last node of a level with an odd number of nodes has no sibling and
+//!   is carried up to the next level unchanged.
 
-use soroban_sdk::{Address, Bytes, BytesN, Env};
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, Vec};
 
 /// Canonical field ordering version — increment if ordering ever changes.
 /// External systems should record this alongside stored settlement IDs.
@@ -100,9 +118,101 @@ pub fn compute_settlement_id_from_remittance(
     )
 }
 
+/// Compute the deposit reference of a remittance held by `contract`.
+///
+/// # Returns
+/// 64-bit reference, suitable as a muxed account ID
+pub fn compute_deposit_ref(env: &Env, contract: &Address, remittance_id: u64) -> u64 {
+    let mut buf = address_to_bytes(env, contract);
+    buf.extend_from_array(&remittance_id.to_be_bytes());
+    let digest = env.crypto().sha256(&buf).to_array();
+
+    let mut prefix = [0u8; 8];
+    prefix.copy_from_slice(&digest[..8]);
+    u64::from_be_bytes(prefix)
+}
+
+/// Compute the record hash of a remittance: SHA-256 of its XDR.
+pub fn compute_record_hash(env: &Env, remittance: &crate::Remittance) -> BytesN<32> {
+    env.crypto().sha256(&remittance.clone().to_xdr(env)).into()
+}
+
+fn merkle_parent(env: &Env, left: &BytesN<32>, right: &BytesN<32>) -> BytesN<32> {
+    let mut buf = Bytes::from_array(env, &left.to_array());
+    buf.extend_from_array(&right.to_array());
+    env.crypto().sha256(&buf).into()
+}
+
+/// Compute the Merkle root of `leaves`; 32 zero bytes when there are none.
+pub fn compute_merkle_root(env: &Env, leaves: &Vec<BytesN<32>>) -> BytesN<32> {
+    if leaves.is_empty() {
+        return BytesN::from_array(env, &[0u8; 32]);
+    }
+    let mut level = leaves.clone();
+    while level.len() > 1 {
+        let mut next = Vec::new(env);
+        let mut i = 0;
+        while i < level.len() {
+            let left = level.get_unchecked(i);
+            if i + 1 < level.len() {
+                next.push_back(merkle_parent(env, &left, &level.get_unchecked(i + 1)));
+            } else {
+                next.push_back(left);
+            }
+            i += 2;
+        }
+        level = next;
+    }
+    level.get_unchecked(0)
+}
+
+/// Compute the Merkle root implied by a leaf and its proof.
+///
+/// # Arguments
+/// * `leaf`       - Hash of the leaf being proven
+/// * `index`      - Position of the leaf, from 0
+/// * `leaf_count` - Number of leaves in the tree
+/// * `siblings`   - Sibling hash at each level, leaf upwards, omitting the
+///                  levels where the node is carried up unpaired
+///
+/// # Returns
+/// The root, or None if the proof has the wrong number of siblings
+pub fn compute_merkle_root_from_proof(
+    env: &Env,
+    leaf: &BytesN<32>,
+    index: u32,
+    leaf_count: u32,
+    siblings: &Vec<BytesN<32>>,
+) -> Option<BytesN<32>> {
+    if index >= leaf_count {
+        return None;
+    }
+    let mut node = leaf.clone();
+    let mut index = index;
+    let mut width = leaf_count;
+    let mut used = 0;
+    while width > 1 {
+        let paired = index % 2 == 1 || index + 1 < width;
+        if paired {
+            let sibling = siblings.get(used)?;
+            used += 1;
+            node = if index % 2 == 1 {
+                merkle_parent(env, &sibling, &node)
+            } else {
+                merkle_parent(env, &node, &sibling)
+            };
+        }
+        index /= 2;
+        width = width.div_ceil(2);
+    }
+    if used != siblings.len() {
+        return None;
+    }
+    Some(node)
+}
+
 /// Serialize an Address to its canonical byte representation.
 /// Uses Soroban's built-in address serialization via to_xdr.
 fn address_to_bytes(env: &Env, address: &Address) -> Bytes {
-    use soroban_sdk::xdr::ToXdr;
     address.to_xdr(env)
 }
@@ -0,0 +1,282 @@
+//! Per-agent inbox of assigned remittances.
+//!
+//! Each agent has a compact queue of the IDs of its pending remittances. An ID
+//! is pushed when a remittance is assigned to the agent and removed once the
+//! remittance is settled, cancelled or reassigned, so agent apps can poll a
+//! single cheap read instead of scanning events. Express remittances are
+//! kept at the head of the queue, oldest first, ahead of standard ones.
+//!
+//! A contract-wide index of open remittances lets ops tooling list the ones
+//! that have gone unpaid for too long. It is split into buckets of
+//! `REMITTANCE_BUCKET_SIZE` consecutive IDs, so creating, paying out or
+//! refunding a remittance rewrites one bounded bucket however many
+//! remittances are open.
+//!
+//! The queue length also caps an agent's concurrent payouts: the admin can set
+//! a maximum per agent, and a default for agents without one, matching what
+//! each agent has the cash to pay out.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::{
+    extend_persistent_ttl, get_remittance_counter, get_remittance_timing, is_screened, ContractError, Priority,
+    TtlClass, REMITTANCE_BUCKET_SIZE,
+};
+
+/// Maximum number of assignments returned by a single page.
+pub const MAX_ASSIGNMENT_PAGE: u32 = 50;
+
+#[contracttype]
+#[derive(Clone)]
+enum InboxKey {
+    /// Pending remittance IDs assigned to an agent, express first, then
+    /// oldest first (persistent storage)
+    Pending(Address),
+    /// Number of express remittances at the head of an agent's queue (persistent storage)
+    ExpressCount(Address),
+    /// Maximum pending remittances of an agent (persistent storage)
+    MaxPending(Address),
+    /// Maximum pending remittances of agents without their own limit
+    DefaultMaxPending,
+    /// IDs of the remittances not yet paid out or refunded in bucket
+    /// `id / REMITTANCE_BUCKET_SIZE`, ascending (persistent storage)
+    OpenBucket(u64),
+    /// Lowest bucket that may hold open remittances
+    OpenHead,
+}
+
+fn get_assignments(env: &Env, agent: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&InboxKey::Pending(agent.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_assignments(env: &Env, agent: &Address, assignments: &Vec<u64>) {
+    let key = InboxKey::Pending(agent.clone());
+    if assignments.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, assignments);
+        extend_persistent_ttl(env, TtlClass::Indexes, &key);
+    }
+}
+
+fn get_express_count(env: &Env, agent: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&InboxKey::ExpressCount(agent.clone()))
+        .unwrap_or(0)
+}
+
+fn set_express_count(env: &Env, agent: &Address, count: u32) {
+    let key = InboxKey::ExpressCount(agent.clone());
+    if count == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &count);
+    }
+}
+
+/// Adds a remittance to the agent's inbox, after the other remittances of
+/// its priority.
+pub fn push_assignment(env: &Env, agent: &Address, remittance_id: u64, priority: Priority) {
+    let mut assignments = get_assignments(env, agent);
+    match priority {
+        Priority::Standard => assignments.push_back(remittance_id),
+        Priority::Express => {
+            let express = get_express_count(env, agent);
+            assignments.insert(express, remittance_id);
+            set_express_count(env, agent, express + 1);
+        }
+    }
+    set_assignments(env, agent, &assignments);
+}
+
+/// Removes a remittance from the agent's inbox, if present.
+pub fn pop_assignment(env: &Env, agent: &Address, remittance_id: u64) {
+    let mut assignments = get_assignments(env, agent);
+    if let Some(index) = assignments.first_index_of(remittance_id) {
+        assignments.remove(index);
+        set_assignments(env, agent, &assignments);
+        let express = get_express_count(env, agent);
+        if index < express {
+            set_express_count(env, agent, express - 1);
+        }
+    }
+}
+
+pub fn get_pending_assignment_count(env: &Env, agent: &Address) -> u32 {
+    get_assignments(env, agent).len()
+}
+
+/// Sets an agent's maximum pending remittances; 0 removes its own limit.
+pub fn set_agent_max_pending(env: &Env, agent: &Address, max_pending: u32) {
+    let key = InboxKey::MaxPending(agent.clone());
+    if max_pending == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &max_pending);
+    }
+}
+
+/// Sets the maximum pending remittances of agents without their own limit; 0 means unlimited.
+pub fn set_default_max_pending(env: &Env, max_pending: u32) {
+    env.storage()
+        .instance()
+        .set(&InboxKey::DefaultMaxPending, &max_pending);
+}
+
+pub fn get_default_max_pending(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&InboxKey::DefaultMaxPending)
+        .unwrap_or(0)
+}
+
+/// Returns the maximum pending remittances that applies to an agent, 0 if unlimited.
+pub fn get_agent_max_pending(env: &Env, agent: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&InboxKey::MaxPending(agent.clone()))
+        .unwrap_or_else(|| get_default_max_pending(env))
+}
+
+/// Checks that one more remittance can be assigned to `agent`.
+///
+/// # Returns
+///
+/// * `Ok(())` - The agent is below its limit, or has none
+/// * `Err(ContractError::AgentAtCapacity)` - The agent's queue is full
+pub fn check_agent_capacity(env: &Env, agent: &Address) -> Result<(), ContractError> {
+    let max_pending = get_agent_max_pending(env, agent);
+    if max_pending > 0 && get_pending_assignment_count(env, agent) >= max_pending {
+        return Err(ContractError::AgentAtCapacity);
+    }
+    Ok(())
+}
+
+/// Returns up to `limit` pending remittance IDs assigned to `agent`, starting at `start`.
+///
+/// Remittances still within their screening window are not yet visible to
+/// the agent and are skipped.
+pub fn get_pending_assignments(env: &Env, agent: &Address, start: u32, limit: u32) -> Vec<u64> {
+    let limit = limit.min(MAX_ASSIGNMENT_PAGE);
+    let mut page = Vec::new(env);
+    let mut skipped = 0u32;
+    for remittance_id in get_assignments(env, agent).iter() {
+        if page.len() >= limit {
+            break;
+        }
+        if !is_screened(env, remittance_id) {
+            continue;
+        }
+        if skipped < start {
+            skipped += 1;
+            continue;
+        }
+        page.push_back(remittance_id);
+    }
+    page
+}
+
+fn get_open_bucket(env: &Env, bucket_id: u64) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&InboxKey::OpenBucket(bucket_id))
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_open_bucket(env: &Env, bucket_id: u64, open: &Vec<u64>) {
+    let key = InboxKey::OpenBucket(bucket_id);
+    if open.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, open);
+        extend_persistent_ttl(env, TtlClass::Indexes, &key);
+    }
+}
+
+fn get_open_head(env: &Env) -> u64 {
+    env.storage().instance().get(&InboxKey::OpenHead).unwrap_or(0)
+}
+
+fn set_open_head(env: &Env, bucket_id: u64) {
+    env.storage().instance().set(&InboxKey::OpenHead, &bucket_id);
+}
+
+/// Returns the bucket of the most recently created remittance.
+fn last_open_bucket(env: &Env) -> u64 {
+    get_remittance_counter(env).unwrap_or(0) / REMITTANCE_BUCKET_SIZE
+}
+
+/// Adds a newly created or imported remittance to the open index.
+pub fn index_open(env: &Env, remittance_id: u64) {
+    let bucket_id = remittance_id / REMITTANCE_BUCKET_SIZE;
+    let mut open = get_open_bucket(env, bucket_id);
+    if let Err(index) = open.binary_search(remittance_id) {
+        open.insert(index, remittance_id);
+        set_open_bucket(env, bucket_id, &open);
+    }
+    if bucket_id < get_open_head(env) {
+        set_open_head(env, bucket_id);
+    }
+}
+
+/// Removes a remittance that was paid out or refunded from the open index, if present.
+///
+/// Once the head bucket empties, the head moves forward past empty buckets,
+/// so each bucket is skipped at most once over the contract's lifetime.
+pub fn unindex_open(env: &Env, remittance_id: u64) {
+    let bucket_id = remittance_id / REMITTANCE_BUCKET_SIZE;
+    let mut open = get_open_bucket(env, bucket_id);
+    let index = match open.binary_search(remittance_id) {
+        Ok(index) => index,
+        Err(_) => return,
+    };
+    open.remove(index);
+    set_open_bucket(env, bucket_id, &open);
+
+    let mut head = get_open_head(env);
+    if !open.is_empty() || bucket_id != head {
+        return;
+    }
+    let last = last_open_bucket(env);
+    while head < last && get_open_bucket(env, head).is_empty() {
+        head += 1;
+    }
+    set_open_head(env, head);
+}
+
+/// Returns up to `limit` open remittances created at least `older_than_secs`
+/// ago, starting at remittance ID `start_id`.
+///
+/// Buckets are walked in ID order, which is creation order, so the scan
+/// stops at the first remittance that is too recent. To continue paging,
+/// pass the last ID returned plus one as `start_id`.
+pub fn get_stale_remittances(env: &Env, older_than_secs: u64, start_id: u64, limit: u32) -> Vec<u64> {
+    let mut stale = Vec::new(env);
+    let now = env.ledger().timestamp();
+    if older_than_secs > now {
+        return stale;
+    }
+    let cutoff = now - older_than_secs;
+    let limit = limit.min(MAX_ASSIGNMENT_PAGE);
+    let first = get_open_head(env).max(start_id / REMITTANCE_BUCKET_SIZE);
+    for bucket_id in first..=last_open_bucket(env) {
+        for remittance_id in get_open_bucket(env, bucket_id).iter() {
+            if remittance_id < start_id {
+                continue;
+            }
+            if stale.len() >= limit {
+                return stale;
+            }
+            let created_at = get_remittance_timing(env, remittance_id).map_or(0, |timing| timing.created_at);
+            if created_at > cutoff {
+                return stale;
+            }
+            stale.push_back(remittance_id);
+        }
+    }
+    stale
+}
@@ -0,0 +1,77 @@
+//! Deployment configuration passed to `initialize`.
+//!
+//! `initialize` takes a single `InitConfig` instead of positional arguments,
+//! so new deployment settings become new fields rather than a new entrypoint
+//! signature. The configuration is validated as a whole before any state is
+//! written: a deployment either starts with every setting applied or fails
+//! without leaving a half-initialized contract behind.
+//!
+//! The optional treasury is the only address platform fees may be withdrawn
+//! to. Without one, the admin names the recipient of each withdrawal. The
+//! admin can later move or clear it with `set_treasury`. Recipients of the
+//! fee split are exempt: `distribute_fees` pays them directly, since the
+//! split is itself an admin-approved list of fee recipients.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::PausableOp;
+
+/// Settings a contract is deployed with.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitConfig {
+    /// Address that will have administrative privileges
+    pub admin: Address,
+    /// Whitelisted tokens; the first is the settlement token
+    pub tokens: Vec<Address>,
+    /// Platform fee in basis points (1 bps = 0.01%, max 10000 = 100%)
+    pub fee_bps: u32,
+    /// Seconds a sender must wait between settlements, 0 for none
+    pub rate_limit_cooldown: u64,
+    /// Minimum single-transfer amount in the settlement token, 0 for none
+    pub min_transfer_amount: i128,
+    /// Maximum single-transfer amount in the settlement token
+    pub max_transfer_amount: Option<i128>,
+    /// Only recipient of fee withdrawals
+    pub treasury: Option<Address>,
+    /// Whether the contract starts paused
+    pub paused: bool,
+    /// Operations that start paused (see `pause_op`)
+    pub paused_ops: Vec<PausableOp>,
+}
+
+impl InitConfig {
+    /// Returns a configuration with `token` as the only token, no limits, no
+    /// treasury and nothing paused.
+    pub fn new(env: &Env, admin: &Address, token: &Address, fee_bps: u32) -> Self {
+        InitConfig {
+            admin: admin.clone(),
+            tokens: Vec::from_array(env, [token.clone()]),
+            fee_bps,
+            rate_limit_cooldown: 0,
+            min_transfer_amount: 0,
+            max_transfer_amount: None,
+            treasury: None,
+            paused: false,
+            paused_ops: Vec::new(env),
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum InitKey {
+    /// Only recipient of fee withdrawals
+    Treasury,
+}
+
+pub fn set_treasury(env: &Env, treasury: &Option<Address>) {
+    match treasury {
+        Some(treasury) => env.storage().instance().set(&InitKey::Treasury, treasury),
+        None => env.storage().instance().remove(&InitKey::Treasury),
+    }
+}
+
+pub fn get_treasury(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&InitKey::Treasury)
+}
@@ -0,0 +1,246 @@
+//! Escrow timeout insurance for the SwiftRemit contract.
+//!
+//! Senders may opt in to insurance on a pending remittance by paying a premium
+//! on top of the escrowed amount. The premium lifecycle is tracked in an
+//! `InsurancePolicy` record so integrators can show it on the user's receipt:
+//! - Cancelled before acceptance: the unused premium is refunded in full
+//! - Early refunds (e.g. expiry): the premium is pro-rated over the coverage window
+//! - Paid out: the premium is consumed into the insurance fund
+
+use soroban_sdk::{contracttype, Env};
+
+use crate::{account_balance, adjust_liability, AccountClass, ContractError, Remittance};
+
+/// Default coverage window used when a remittance has no expiry (7 days).
+pub const DEFAULT_COVERAGE_SECONDS: u64 = 604_800;
+
+/// Lifecycle state of an insurance premium.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PremiumStatus {
+    /// Premium is held while the remittance is pending
+    Active,
+    /// Premium was returned to the sender in full
+    Refunded,
+    /// Part of the premium was returned to the sender, the rest went to the fund
+    PartiallyRefunded,
+    /// Remittance was paid out and the premium moved to the insurance fund
+    Consumed,
+}
+
+/// Insurance policy attached to a single remittance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsurancePolicy {
+    /// ID of the insured remittance
+    pub remittance_id: u64,
+    /// Premium paid by the sender
+    pub premium: i128,
+    /// Timestamp the premium was paid
+    pub purchased_at: u64,
+    /// Timestamp coverage ends
+    pub coverage_until: u64,
+    /// Amount of the premium returned to the sender so far
+    pub refunded: i128,
+    /// Current premium lifecycle state
+    pub status: PremiumStatus,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum InsuranceKey {
+    /// Premium rate in basis points of the remittance amount (0 = disabled)
+    PremiumBps,
+    /// Premiums of active policies, still refundable to senders
+    PremiumsHeld,
+    /// Policy for a remittance (persistent storage)
+    Policy(u64),
+}
+
+pub fn set_insurance_premium_bps(env: &Env, bps: u32) {
+    env.storage().instance().set(&InsuranceKey::PremiumBps, &bps);
+}
+
+pub fn get_insurance_premium_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&InsuranceKey::PremiumBps)
+        .unwrap_or(0)
+}
+
+/// Returns the premiums retained by the platform after payout or partial refund.
+///
+/// The ledger's `Insurance` account holds the fund and the premiums of active
+/// policies; the fund is what is not held for active policies.
+pub fn get_insurance_fund(env: &Env) -> i128 {
+    account_balance(env, AccountClass::Insurance) - get_premiums_held(env)
+}
+
+/// Returns premiums of active policies that may still be refunded.
+pub fn get_premiums_held(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&InsuranceKey::PremiumsHeld)
+        .unwrap_or(0)
+}
+
+fn adjust_premiums_held(env: &Env, delta: i128) -> Result<(), ContractError> {
+    let held = get_premiums_held(env)
+        .checked_add(delta)
+        .ok_or(ContractError::Overflow)?;
+    env.storage().instance().set(&InsuranceKey::PremiumsHeld, &held);
+    Ok(())
+}
+
+/// Credits `amount` received by the contract to the insurance fund.
+pub fn add_to_insurance_fund(env: &Env, amount: i128) -> Result<(), ContractError> {
+    adjust_liability(env, AccountClass::Insurance, amount)
+}
+
+pub fn get_insurance_policy(env: &Env, remittance_id: u64) -> Option<InsurancePolicy> {
+    env.storage()
+        .persistent()
+        .get(&InsuranceKey::Policy(remittance_id))
+}
+
+fn set_insurance_policy(env: &Env, policy: &InsurancePolicy) {
+    env.storage()
+        .persistent()
+        .set(&InsuranceKey::Policy(policy.remittance_id), policy);
+}
+
+/// Creates an active policy for a pending remittance and returns it.
+///
+/// The caller is responsible for collecting the premium from the sender.
+pub fn open_policy(env: &Env, remittance: &Remittance) -> Result<InsurancePolicy, ContractError> {
+    let bps = get_insurance_premium_bps(env);
+    if bps == 0 {
+        return Err(ContractError::InvalidStatus);
+    }
+    if get_insurance_policy(env, remittance.id).is_some() {
+        return Err(ContractError::InvalidStatus);
+    }
+
+    let premium = remittance
+        .amount
+        .checked_mul(bps as i128)
+        .ok_or(ContractError::Overflow)?
+        .checked_div(10000)
+        .ok_or(ContractError::Overflow)?;
+    if premium <= 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+
+    let now = env.ledger().timestamp();
+    let coverage_until = match remittance.expiry {
+        Some(expiry) if expiry > now => expiry,
+        _ => now.saturating_add(DEFAULT_COVERAGE_SECONDS),
+    };
+
+    let policy = InsurancePolicy {
+        remittance_id: remittance.id,
+        premium,
+        purchased_at: now,
+        coverage_until,
+        refunded: 0,
+        status: PremiumStatus::Active,
+    };
+    set_insurance_policy(env, &policy);
+    adjust_premiums_held(env, premium)?;
+    adjust_liability(env, AccountClass::Insurance, premium)?;
+
+    Ok(policy)
+}
+
+/// Computes the unused part of a premium at `now`.
+///
+/// With `full` set the whole premium is unused (cancelled before acceptance);
+/// otherwise the premium is pro-rated over the remaining coverage window.
+pub fn compute_premium_refund(policy: &InsurancePolicy, now: u64, full: bool) -> i128 {
+    if full {
+        return policy.premium;
+    }
+
+    let window = policy.coverage_until.saturating_sub(policy.purchased_at);
+    if window == 0 {
+        return 0;
+    }
+    let remaining = policy.coverage_until.saturating_sub(now).min(window);
+
+    policy.premium * remaining as i128 / window as i128
+}
+
+/// Settles the premium of a refunded remittance.
+///
+/// Returns the amount owed back to the sender (zero when the remittance was not
+/// insured). Any retained portion is credited to the insurance fund.
+pub fn refund_premium(env: &Env, remittance_id: u64, full: bool) -> Result<i128, ContractError> {
+    let mut policy = match get_insurance_policy(env, remittance_id) {
+        Some(policy) if policy.status == PremiumStatus::Active => policy,
+        _ => return Ok(0),
+    };
+
+    let refund = compute_premium_refund(&policy, env.ledger().timestamp(), full);
+    let retained = policy
+        .premium
+        .checked_sub(refund)
+        .ok_or(ContractError::Overflow)?;
+    // The retained part stays in the insurance account, now as fund
+    adjust_liability(env, AccountClass::Insurance, -refund)?;
+    adjust_premiums_held(env, -policy.premium)?;
+
+    policy.refunded = refund;
+    policy.status = if retained == 0 {
+        PremiumStatus::Refunded
+    } else {
+        PremiumStatus::PartiallyRefunded
+    };
+    set_insurance_policy(env, &policy);
+
+    Ok(refund)
+}
+
+/// Moves the premium of a paid-out remittance into the insurance fund.
+pub fn consume_premium(env: &Env, remittance_id: u64) -> Result<(), ContractError> {
+    let mut policy = match get_insurance_policy(env, remittance_id) {
+        Some(policy) if policy.status == PremiumStatus::Active => policy,
+        _ => return Ok(()),
+    };
+
+    adjust_premiums_held(env, -policy.premium)?;
+    policy.status = PremiumStatus::Consumed;
+    set_insurance_policy(env, &policy);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(premium: i128, purchased_at: u64, coverage_until: u64) -> InsurancePolicy {
+        InsurancePolicy {
+            remittance_id: 1,
+            premium,
+            purchased_at,
+            coverage_until,
+            refunded: 0,
+            status: PremiumStatus::Active,
+        }
+    }
+
+    #[test]
+    fn test_full_refund_returns_whole_premium() {
+        let p = policy(100, 1000, 2000);
+        assert_eq!(compute_premium_refund(&p, 1500, true), 100);
+    }
+
+    #[test]
+    fn test_pro_rated_refund() {
+        let p = policy(100, 1000, 2000);
+        assert_eq!(compute_premium_refund(&p, 1000, false), 100);
+        assert_eq!(compute_premium_refund(&p, 1250, false), 75);
+        assert_eq!(compute_premium_refund(&p, 2000, false), 0);
+        assert_eq!(compute_premium_refund(&p, 5000, false), 0);
+    }
+}
@@ -0,0 +1,119 @@
+//! Remittance intent pre-registration for high-risk senders.
+//!
+//! Senders flagged as high-risk by the admin must first declare the agent and
+//! amount they intend to send. The remittance itself can only be created once
+//! the configured cooling-off delay has elapsed, which gives victims of
+//! real-time social-engineering scams a window to reconsider. Senders that are
+//! not flagged are unaffected.
+
+use soroban_sdk::{contracttype, symbol_short, Address, Env};
+
+use crate::{log_limit_error, ContractError};
+
+/// Default cooling-off delay applied when none is configured (24 hours).
+pub const DEFAULT_COOLING_OFF_SECONDS: u64 = 86_400;
+
+/// A declared intent to send a remittance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RemittanceIntent {
+    /// Agent the sender intends to pay out through
+    pub agent: Address,
+    /// Amount the sender intends to send
+    pub amount: i128,
+    /// Timestamp the intent was declared
+    pub declared_at: u64,
+    /// Earliest timestamp the remittance can be created
+    pub available_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum IntentKey {
+    /// Cooling-off delay in seconds
+    CoolingOff,
+    /// High-risk flag per sender (persistent storage)
+    HighRisk(Address),
+    /// Pending intent per sender (persistent storage)
+    Intent(Address),
+}
+
+pub fn set_cooling_off_period(env: &Env, seconds: u64) {
+    env.storage().instance().set(&IntentKey::CoolingOff, &seconds);
+}
+
+pub fn get_cooling_off_period(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&IntentKey::CoolingOff)
+        .unwrap_or(DEFAULT_COOLING_OFF_SECONDS)
+}
+
+pub fn set_high_risk_sender(env: &Env, sender: &Address, high_risk: bool) {
+    env.storage()
+        .persistent()
+        .set(&IntentKey::HighRisk(sender.clone()), &high_risk);
+}
+
+pub fn is_high_risk_sender(env: &Env, sender: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&IntentKey::HighRisk(sender.clone()))
+        .unwrap_or(false)
+}
+
+pub fn get_intent(env: &Env, sender: &Address) -> Option<RemittanceIntent> {
+    env.storage()
+        .persistent()
+        .get(&IntentKey::Intent(sender.clone()))
+}
+
+/// Records an intent for `sender`, replacing any previous one.
+///
+/// Re-declaring restarts the cooling-off delay.
+pub fn declare_intent(env: &Env, sender: &Address, agent: &Address, amount: i128) -> RemittanceIntent {
+    let now = env.ledger().timestamp();
+    let intent = RemittanceIntent {
+        agent: agent.clone(),
+        amount,
+        declared_at: now,
+        available_at: now.saturating_add(get_cooling_off_period(env)),
+    };
+    env.storage()
+        .persistent()
+        .set(&IntentKey::Intent(sender.clone()), &intent);
+    intent
+}
+
+/// Checks that a high-risk sender holds a matured intent for this remittance
+/// and consumes it. Senders not flagged as high-risk always pass.
+///
+/// # Returns
+///
+/// * `Ok(())` - Sender is not high-risk or the matching intent has matured
+/// * `Err(ContractError::CoolingOffActive)` - No matching intent, or the delay has not elapsed
+pub fn consume_intent(env: &Env, sender: &Address, agent: &Address, amount: i128) -> Result<(), ContractError> {
+    if !is_high_risk_sender(env, sender) {
+        return Ok(());
+    }
+
+    let intent = get_intent(env, sender).ok_or(ContractError::CoolingOffActive)?;
+    if intent.agent != *agent || intent.amount != amount {
+        return Err(ContractError::CoolingOffActive);
+    }
+    let now = env.ledger().timestamp();
+    if now < intent.available_at {
+        return Err(log_limit_error(
+            env,
+            symbol_short!("create"),
+            ContractError::CoolingOffActive,
+            now as i128,
+            intent.available_at as i128,
+        ));
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&IntentKey::Intent(sender.clone()));
+    Ok(())
+}
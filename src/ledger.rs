@@ -0,0 +1,193 @@
+//! Double-entry ledger of the settlement token.
+//!
+//! Every movement of the settlement token the contract accounts for is posted
+//! as an entry that debits one account class and credits another by the same
+//! amount, so fund flows can be audited from the per-class totals and the
+//! ledger always balances. `Holdings` is the asset side: the tokens the
+//! contract holds, including escrow deposited for yield. Every other class is
+//! a liability the holdings are owed to, and its balance is its credits minus
+//! its debits:
+//!
+//! - Funds received for a remittance debit holdings and credit escrow and fees
+//! - A payout debits escrow and credits holdings as the tokens leave
+//! - A hop fee moves from escrow to commissions before it is paid out
+//!
+//! Escrow, fees in the settlement token, insurance, quarantine, queued
+//! settlements, agent collateral, sponsor pools and staking rewards are read
+//! from these balances rather than kept as separate counters.
+
+use soroban_sdk::{contracttype, Env, Vec};
+
+use crate::ContractError;
+
+/// Account classes of the ledger.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AccountClass {
+    /// Settlement tokens held by the contract (asset)
+    Holdings,
+    /// Net amounts owed to agents for pending remittances
+    Escrow,
+    /// Platform fees in the settlement token awaiting withdrawal
+    Fees,
+    /// Hop fees owed to intermediaries of routed remittances
+    Commissions,
+    /// Premiums of active policies plus the insurance fund
+    Insurance,
+    /// Funds of quarantined remittances
+    Quarantine,
+    /// Confirmed payouts queued for agents on a settlement schedule
+    Settlements,
+    /// Collateral posted by agents
+    Collateral,
+    /// Pools deposited by sponsors to pay relayers
+    Sponsorship,
+    /// Platform fees shared with agents and not yet claimed
+    StakingRewards,
+}
+
+/// Classes in trial-balance order.
+const ACCOUNT_CLASSES: [AccountClass; 10] = [
+    AccountClass::Holdings,
+    AccountClass::Escrow,
+    AccountClass::Fees,
+    AccountClass::Commissions,
+    AccountClass::Insurance,
+    AccountClass::Quarantine,
+    AccountClass::Settlements,
+    AccountClass::Collateral,
+    AccountClass::Sponsorship,
+    AccountClass::StakingRewards,
+];
+
+/// Cumulative debits and credits of an account class.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AccountTotals {
+    pub debits: i128,
+    pub credits: i128,
+}
+
+/// One line of the trial balance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TrialBalanceLine {
+    pub class: AccountClass,
+    pub debits: i128,
+    pub credits: i128,
+}
+
+/// Totals of every account class and of the whole ledger.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TrialBalance {
+    pub lines: Vec<TrialBalanceLine>,
+    pub total_debits: i128,
+    pub total_credits: i128,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum LedgerKey {
+    /// Cumulative debits and credits of an account class
+    Account(AccountClass),
+}
+
+/// Returns the cumulative debits and credits of `class`.
+pub fn get_account(env: &Env, class: AccountClass) -> AccountTotals {
+    env.storage()
+        .instance()
+        .get(&LedgerKey::Account(class))
+        .unwrap_or_default()
+}
+
+fn set_account(env: &Env, class: AccountClass, totals: &AccountTotals) {
+    env.storage()
+        .instance()
+        .set(&LedgerKey::Account(class), totals);
+}
+
+/// Returns the balance of `class`: debits minus credits for holdings,
+/// credits minus debits for every liability class.
+pub fn account_balance(env: &Env, class: AccountClass) -> i128 {
+    let totals = get_account(env, class);
+    match class {
+        AccountClass::Holdings => totals.debits - totals.credits,
+        _ => totals.credits - totals.debits,
+    }
+}
+
+/// Posts `amount` as a debit to `debit` and a credit to `credit`.
+///
+/// # Returns
+///
+/// * `Ok(())` - Entry posted; a zero amount posts nothing
+/// * `Err(ContractError::InvalidAmount)` - Amount is negative
+/// * `Err(ContractError::Overflow)` - A cumulative total overflowed
+pub fn post_entry(
+    env: &Env,
+    debit: AccountClass,
+    credit: AccountClass,
+    amount: i128,
+) -> Result<(), ContractError> {
+    if amount < 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let mut debited = get_account(env, debit);
+    debited.debits = debited
+        .debits
+        .checked_add(amount)
+        .ok_or(ContractError::Overflow)?;
+    set_account(env, debit, &debited);
+
+    let mut credited = get_account(env, credit);
+    credited.credits = credited
+        .credits
+        .checked_add(amount)
+        .ok_or(ContractError::Overflow)?;
+    set_account(env, credit, &credited);
+
+    Ok(())
+}
+
+/// Posts a change of `delta` to the balance of liability `class` against holdings.
+pub fn adjust_liability(env: &Env, class: AccountClass, delta: i128) -> Result<(), ContractError> {
+    if delta >= 0 {
+        post_entry(env, AccountClass::Holdings, class, delta)
+    } else {
+        let amount = delta.checked_neg().ok_or(ContractError::Overflow)?;
+        post_entry(env, class, AccountClass::Holdings, amount)
+    }
+}
+
+/// Returns the debits and credits of every account class and their totals.
+pub fn get_trial_balance(env: &Env) -> Result<TrialBalance, ContractError> {
+    let mut lines = Vec::new(env);
+    let mut total_debits: i128 = 0;
+    let mut total_credits: i128 = 0;
+
+    for class in ACCOUNT_CLASSES {
+        let totals = get_account(env, class);
+        total_debits = total_debits
+            .checked_add(totals.debits)
+            .ok_or(ContractError::Overflow)?;
+        total_credits = total_credits
+            .checked_add(totals.credits)
+            .ok_or(ContractError::Overflow)?;
+        lines.push_back(TrialBalanceLine {
+            class,
+            debits: totals.debits,
+            credits: totals.credits,
+        });
+    }
+
+    Ok(TrialBalance {
+        lines,
+        total_debits,
+        total_credits,
+    })
+}
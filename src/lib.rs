@@ -4,36 +4,166 @@
 //! with built-in duplicate settlement protection and expiry mechanisms.
 
 #![no_std]
+#[cfg(feature = "kyc")]
+mod allowlist;
+mod accounting;
+mod analytics;
+mod archive;
+mod attestation;
+mod collateral;
+mod commitment;
+#[cfg(feature = "kyc")]
+mod compliance;
+#[cfg(feature = "corridors")]
+mod corridor;
+mod coverage;
+mod currency;
+mod deadletter;
 mod debug;
+mod decline;
+mod deposit_ref;
+mod direct;
+mod disabled;
 mod error_handler;
 mod errors;
 mod events;
+mod fee_exemption;
 mod hashing;
+mod inbox;
+mod init;
+mod insurance;
+#[cfg(feature = "kyc")]
+mod intent;
+mod ledger;
+#[cfg(feature = "loyalty")]
+mod loyalty;
+mod matching;
 mod migration;
+mod native;
 mod netting;
+mod nonces;
+mod operator;
+mod org;
+mod pause;
+mod payout_hook;
+mod pickup;
+mod platform_fee;
+mod preview;
+mod priority;
+mod quarantine;
+mod quorum;
+#[cfg(feature = "oracle")]
+mod quote;
 mod rate_limit;
+#[cfg(feature = "kyc")]
+mod risk;
+mod rounding;
+mod route;
+mod schedule;
+mod screening;
+mod sla;
+mod split;
+mod sponsor;
+mod staking;
 mod storage;
+mod sweep;
+mod swap;
+mod subagent;
+mod tranche;
+mod transfer_limits;
+mod ttl;
 mod types;
 mod validation;
+mod volume_discount;
+mod watcher;
+mod yield_adapter;
 #[cfg(test)]
 mod test;
+#[cfg(test)]
+mod test_auth;
+#[cfg(test)]
+mod test_proptest;
+#[cfg(any(test, feature = "testutils"))]
+pub mod testutils;
 
 #[cfg(test)]
 mod test; 
 
-use soroban_sdk::{contract, contractimpl, token, Address, Env, Vec};
-
+use soroban_sdk::{contract, contractimpl, symbol_short, token, Address, BytesN, Env, IntoVal, String, Symbol, Vec};
+
+pub use accounting::*;
+#[cfg(feature = "kyc")]
+pub use allowlist::*;
+pub use analytics::*;
+pub use archive::*;
+pub use attestation::*;
+pub use collateral::*;
+pub use commitment::*;
+#[cfg(feature = "kyc")]
+pub use compliance::*;
+#[cfg(feature = "corridors")]
+pub use corridor::*;
+pub use coverage::*;
+pub use currency::*;
+pub use deadletter::*;
 pub use debug::*;
+pub use decline::*;
+pub use deposit_ref::*;
+pub use direct::*;
+pub use disabled::*;
 pub use error_handler::*;
 pub use errors::ContractError;
 pub use events::*;
+pub use fee_exemption::*;
 pub use hashing::*;
+pub use inbox::*;
+pub use init::*;
+pub use insurance::*;
+#[cfg(feature = "kyc")]
+pub use intent::*;
+pub use ledger::*;
+#[cfg(feature = "loyalty")]
+pub use loyalty::*;
+pub use matching::*;
 pub use migration::*;
+pub use native::*;
 pub use netting::*;
+pub use nonces::*;
+pub use operator::*;
+pub use org::*;
+pub use pause::*;
+pub use payout_hook::*;
+pub use pickup::*;
+pub use platform_fee::*;
+pub use preview::*;
+pub use priority::*;
+pub use quarantine::*;
+pub use quorum::*;
+#[cfg(feature = "oracle")]
+pub use quote::*;
 pub use rate_limit::*;
+#[cfg(feature = "kyc")]
+pub use risk::*;
+pub use rounding::*;
+pub use route::*;
+pub use schedule::*;
+pub use screening::*;
+pub use sla::*;
+pub use split::*;
+pub use sponsor::*;
+pub use staking::*;
 pub use storage::*;
+pub use sweep::*;
+pub use swap::*;
+pub use subagent::*;
+pub use tranche::*;
+pub use transfer_limits::*;
+pub use ttl::*;
 pub use types::*;
 pub use validation::*;
+pub use volume_discount::*;
+pub use watcher::*;
+pub use yield_adapter::*;
 
 /// The main SwiftRemit contract for managing cross-border remittances.
 ///
@@ -48,38 +178,40 @@ pub struct SwiftRemitContract;
 
 #[contractimpl]
 impl SwiftRemitContract {
-    /// Initializes the contract with admin, token, and fee configuration.
+    /// Initializes the contract from its deployment configuration.
     ///
-    /// This function can only be called once. It sets up the contract's core parameters
+    /// This function can only be called once. The configuration is validated
+    /// as a whole before anything is stored; it then sets up the contract's
+    /// core parameters, whitelists its tokens, applies its limits and pauses,
     /// and initializes all counters and accumulators to zero.
     ///
     /// # Arguments
     ///
     /// * `env` - The contract execution environment
-    /// * `admin` - Address that will have administrative privileges
-    /// * `usdc_token` - Address of the USDC token contract used for transactions
-    /// * `fee_bps` - Platform fee in basis points (1 bps = 0.01%, max 10000 = 100%)
+    /// * `config` - Admin, tokens (the first settles remittances), fee, limits,
+    ///   treasury and pause defaults
     ///
     /// # Returns
     ///
     /// * `Ok(())` - Contract successfully initialized
     /// * `Err(ContractError::AlreadyInitialized)` - Contract was already initialized
     /// * `Err(ContractError::InvalidFeeBps)` - Fee exceeds maximum allowed (10000 bps)
+    /// * `Err(ContractError::TokenNotWhitelisted)` - No token was given
+    /// * `Err(ContractError::TokenAlreadyWhitelisted)` - A token is listed twice
+    /// * `Err(ContractError::UnsupportedToken)` - A token is not a token contract
+    /// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - A limit is out of range
+    /// * `Err(ContractError::InvalidAmount)` - The minimum transfer amount exceeds the maximum
+    /// * `Err(ContractError::InvalidAddress)` - The treasury is the contract itself
     ///
     /// # Examples
     ///
     /// ```ignore
-    /// contract.initialize(env, admin_addr, usdc_addr, 250); // 2.5% fee
+    /// contract.initialize(&InitConfig::new(&env, &admin_addr, &usdc_addr, 250)); // 2.5% fee
     /// ```
-    pub fn initialize(
-        env: Env,
-        admin: Address,
-        usdc_token: Address,
-        fee_bps: u32,
-        rate_limit_cooldown: u64,
-    ) -> Result<(), ContractError> {
+    pub fn initialize(env: Env, config: InitConfig) -> Result<(), ContractError> {
         // Centralized validation before business logic
-        validate_initialize_request(&env, &admin, &usdc_token, fee_bps)?;
+        let usdc_token = validate_initialize_request(&env, &config)?;
+        let admin = config.admin;
 
         // Set legacy admin for backward compatibility
         set_admin(&env, &admin);
@@ -88,16 +220,34 @@ impl SwiftRemitContract {
         set_admin_role(&env, &admin, true);
         set_admin_count(&env, 1);
         
+        for token in config.tokens.iter() {
+            if !is_token_whitelisted(&env, &token) {
+                set_token_whitelisted(&env, &token, true);
+                emit_token_whitelisted(&env, admin.clone(), token);
+            }
+        }
         set_usdc_token(&env, &usdc_token);
-        set_platform_fee_bps(&env, fee_bps);
+        set_platform_fee_bps(&env, config.fee_bps);
         set_remittance_counter(&env, 0);
-        set_accumulated_fees(&env, 0);
-        set_rate_limit_cooldown(&env, rate_limit_cooldown);
+        set_accumulated_fees(&env, 0)?;
+        set_rate_limit_cooldown(&env, config.rate_limit_cooldown);
+
+        if config.min_transfer_amount > 0 {
+            set_min_transfer_amount(&env, &usdc_token, config.min_transfer_amount);
+        }
+        if let Some(max) = config.max_transfer_amount {
+            set_max_transfer_amount(&env, &LimitScope::Token(usdc_token.clone()), max);
+        }
+        set_treasury(&env, &config.treasury);
+        set_paused(&env, config.paused);
+        for op in config.paused_ops.iter() {
+            set_op_paused(&env, op, true);
+        }
 
         // Initialize rate limiting with default configuration
         init_rate_limit(&env);
 
-        log_initialize(&env, &admin, &usdc_token, fee_bps);
+        log_initialize(&env, &admin, &usdc_token, config.fee_bps);
 
         Ok(())
     }
@@ -172,10 +322,153 @@ impl SwiftRemitContract {
         Ok(())
     }
 
+    /// Registers several agents in one call.
+    ///
+    /// Agents that are already registered, including duplicates within the
+    /// batch, are skipped and reported with their error code instead of
+    /// aborting the batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agents` - Addresses of the agents to register
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<AgentBatchOutcome>)` - Outcome of each entry, in order
+    /// * `Err(ContractError::InvalidAmount)` - Batch is empty
+    /// * `Err(ContractError::LimitExceeded)` - Batch exceeds MAX_BATCH_SIZE
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn register_agents(env: Env, agents: Vec<Address>) -> Result<Vec<AgentBatchOutcome>, ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+        validate_agent_batch(&env, &agents)?;
+
+        let mut outcomes = Vec::new(&env);
+        for agent in agents.iter() {
+            let error = if is_agent_registered(&env, &agent) {
+                Some(ContractError::InvalidStatus as u32)
+            } else {
+                set_agent_registered(&env, &agent, true);
+                emit_agent_registered(&env, agent.clone());
+                None
+            };
+            outcomes.push_back(AgentBatchOutcome { agent, error });
+        }
+
+        Ok(outcomes)
+    }
+
+    /// Removes several agents in one call.
+    ///
+    /// Addresses that are not registered agents are skipped and reported with
+    /// their error code instead of aborting the batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agents` - Addresses of the agents to remove
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<AgentBatchOutcome>)` - Outcome of each entry, in order
+    /// * `Err(ContractError::InvalidAmount)` - Batch is empty
+    /// * `Err(ContractError::LimitExceeded)` - Batch exceeds MAX_BATCH_SIZE
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn remove_agents(env: Env, agents: Vec<Address>) -> Result<Vec<AgentBatchOutcome>, ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+        validate_agent_batch(&env, &agents)?;
+
+        let mut outcomes = Vec::new(&env);
+        for agent in agents.iter() {
+            let error = if is_agent_registered(&env, &agent) {
+                set_agent_registered(&env, &agent, false);
+                emit_agent_removed(&env, agent.clone());
+                None
+            } else {
+                Some(ContractError::AgentNotRegistered as u32)
+            };
+            outcomes.push_back(AgentBatchOutcome { agent, error });
+        }
+
+        Ok(outcomes)
+    }
+
+    /// Suspends an agent without removing it.
+    ///
+    /// A suspended agent cannot be assigned new remittances but can still confirm
+    /// payouts for remittances already assigned to it. Use this instead of
+    /// `remove_agent` while an agent is under review.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Address of the agent to suspend
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Agent suspended
+    /// * `Err(ContractError::AgentNotRegistered)` - Agent is not registered
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn suspend_agent(env: Env, agent: Address) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        validate_agent_registered(&env, &agent)?;
+
+        set_agent_suspended(&env, &agent, true);
+        emit_agent_suspended(&env, agent);
+
+        Ok(())
+    }
+
+    /// Lifts an agent's suspension so it can accept new remittances again.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Address of the agent to reinstate
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Agent reinstated
+    /// * `Err(ContractError::AgentNotRegistered)` - Agent is not registered
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn reinstate_agent(env: Env, agent: Address) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        validate_agent_registered(&env, &agent)?;
+
+        set_agent_suspended(&env, &agent, false);
+        emit_agent_reinstated(&env, agent);
+
+        Ok(())
+    }
+
+    /// Checks if an agent is suspended.
+    pub fn is_agent_suspended(env: Env, agent: Address) -> bool {
+        is_agent_suspended(&env, &agent)
+    }
+
     /// Updates the platform fee rate.
     ///
     /// Only the contract admin can update the fee. The new fee applies to all
-    /// remittances created after the update.
+    /// remittances created after the update, until the next scheduled update
+    /// takes effect.
     ///
     /// # Arguments
     ///
@@ -198,6 +491,7 @@ impl SwiftRemitContract {
         let caller = get_admin(&env)?;
         require_admin(&env, &caller)?;
 
+        apply_due_fees(&env);
         let old_fee = get_platform_fee_bps(&env)?;
         set_platform_fee_bps(&env, fee_bps);
         emit_fee_updated(&env, caller.clone(), old_fee, fee_bps);
@@ -219,64 +513,307 @@ impl SwiftRemitContract {
     /// * `sender` - Address initiating the remittance
     /// * `agent` - Address of the registered agent who will receive the payout
     /// * `amount` - Amount to remit in USDC (must be positive)
+    /// * `purpose` - Declared purpose of the payment
     /// * `expiry` - Optional expiry timestamp (seconds since epoch) after which settlement fails
+    /// * `idempotency_key` - Optional client-supplied key; reusing a key returns the original
+    ///   remittance instead of creating a duplicate
+    /// * `originator_hash` - Optional hash of the off-chain Travel Rule originator payload
+    /// * `beneficiary_hash` - Optional hash of the off-chain Travel Rule beneficiary payload
+    /// * `external_ref` - Optional integrator reference, e.g. a PSP order ID, to look the
+    ///   remittance up with `get_remittance_by_external_ref`
     ///
     /// # Returns
     ///
-    /// * `Ok(remittance_id)` - Unique ID of the created remittance
-    /// * `Err(ContractError::InvalidAmount)` - Amount is zero or negative
+    /// * `Ok(remittance_id)` - Unique ID of the created remittance, or of the existing one
+    ///   when the sender already used `idempotency_key`
+    /// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - Amount is zero or negative
+    /// * `Err(ContractError::AmountAboveMaximum)` - Amount exceeds the token or corridor cap
+    /// * `Err(ContractError::AmountBelowMinimum)` - Amount is below the token minimum
     /// * `Err(ContractError::AgentNotRegistered)` - Specified agent is not registered
+    /// * `Err(ContractError::AgentSuspended)` - Specified agent is suspended
+    /// * `Err(ContractError::AgentAtCapacity)` - Agent has its maximum number of pending remittances
+    /// * `Err(ContractError::RateLimited)` - Sender exceeded the creation limit for the ledger window,
+    ///   or is in a cancellation cooldown
+    /// * `Err(ContractError::CoolingOffActive)` - High-risk sender has no matured intent
+    /// * `Err(ContractError::ComplianceRejected)` - The registered compliance provider rejected it
+    /// * `Err(ContractError::PurposeNotAllowed)` - The agent's corridor does not accept `purpose`
+    /// * `Err(ContractError::DuplicateExternalRef)` - `external_ref` is used by another remittance
+    /// * `Err(ContractError::ContractPaused)` - Remittance creation is paused
     /// * `Err(ContractError::Overflow)` - Arithmetic overflow in fee calculation
     /// * `Err(ContractError::NotInitialized)` - Contract not initialized
     ///
     /// # Authorization
     ///
-    /// Requires authentication from the sender address.
+    /// Requires authentication from the sender address. A contract sender,
+    /// such as a payroll contract, is authenticated as the direct invoker; it
+    /// must also authorize the escrow transfer of `amount` from itself to this
+    /// contract with `authorize_as_current_contract` before calling.
    pub fn create_remittance(
     env: Env,
     sender: Address,
     agent: Address,
     amount: i128,
+    purpose: PurposeCode,
     expiry: Option<u64>,
+    idempotency_key: Option<BytesN<32>>,
+    originator_hash: Option<BytesN<32>>,
+    beneficiary_hash: Option<BytesN<32>>,
+    external_ref: Option<BytesN<32>>,
 ) -> Result<u64, ContractError> {
     validate_create_remittance_request(&env, &sender, &agent, amount)?;
 
     sender.require_auth();
 
-    let fee_bps = get_platform_fee_bps(&env)?;
-    let fee = amount
-        .checked_mul(fee_bps as i128)
-        .ok_or(ContractError::Overflow)?
-        .checked_div(10000)
-        .ok_or(ContractError::Overflow)?;
+    // A retried request with a key the sender already used returns the original remittance
+    if let Some(key) = &idempotency_key {
+        if let Some(existing_id) = get_idempotent_remittance(&env, &sender, key) {
+            return Ok(existing_id);
+        }
+    }
 
-    let usdc_token = get_usdc_token(&env)?;
-    let token_client = token::Client::new(&env, &usdc_token);
-    token_client.transfer(&sender, &env.current_contract_address(), &amount);
+    if let Some(external_ref) = &external_ref {
+        if get_external_ref(&env, external_ref).is_some() {
+            return Err(ContractError::DuplicateExternalRef);
+        }
+    }
 
     let counter = get_remittance_counter(&env)?;
     let remittance_id = counter.checked_add(1).ok_or(ContractError::Overflow)?;
 
-    let remittance = Remittance {
-        id: remittance_id,
-        sender: sender.clone(),
-        agent: agent.clone(),
-        amount,
-        fee,
-        status: RemittanceStatus::Pending,
-        expiry,
-    };
-
-    set_remittance(&env, remittance_id, &remittance);
+    Self::open_remittance(&env, remittance_id, &sender, &agent, amount, purpose, expiry)?;
     set_remittance_counter(&env, remittance_id);
 
+    // Anchor the Travel Rule payload hashes and reference supplied by integrators
+    if originator_hash.is_some() || beneficiary_hash.is_some() || external_ref.is_some() {
+        let mut remittance = get_remittance(&env, remittance_id)?;
+        remittance.originator_hash = originator_hash;
+        remittance.beneficiary_hash = beneficiary_hash;
+        remittance.external_ref = external_ref.clone();
+        set_remittance(&env, remittance_id, &remittance);
+    }
+    if let Some(external_ref) = &external_ref {
+        set_external_ref(&env, external_ref, remittance_id);
+    }
+
+    if let Some(key) = &idempotency_key {
+        set_idempotent_remittance(&env, &sender, key, remittance_id);
+    }
+
     Ok(remittance_id)  // ← capital O
 }
+
+    /// Records a remittance paid straight from the sender to the agent.
+    ///
+    /// Non-custodial mode for sender and agent pairs approved with
+    /// `set_direct_pair`. The net amount is transferred from the sender to
+    /// the agent and the platform fee from the sender to the contract, and
+    /// the remittance is recorded as already settled with purpose `Other`.
+    /// Nothing is escrowed, so it cannot be cancelled or refunded.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Address paying the remittance
+    /// * `agent` - Registered agent paid directly
+    /// * `amount` - Amount to remit in USDC, fee included
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(remittance_id)` - ID of the recorded remittance
+    /// * `Err(ContractError::Unauthorized)` - The pair is not approved for direct mode
+    /// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - Amount is zero or negative
+    /// * `Err(ContractError::AgentNotRegistered)` - Specified agent is not registered
+    /// * `Err(ContractError::RateLimited)` - Sender exceeded the creation limit for the ledger window,
+    ///   or is in a cancellation cooldown
+    /// * `Err(ContractError::ComplianceRejected)` - The registered compliance provider rejected it
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address.
+    pub fn record_direct_remittance(
+        env: Env,
+        sender: Address,
+        agent: Address,
+        amount: i128,
+    ) -> Result<u64, ContractError> {
+        validate_create_remittance_request(&env, &sender, &agent, amount)?;
+
+        sender.require_auth();
+
+        if !is_direct_pair(&env, &sender, &agent) {
+            return Err(ContractError::Unauthorized);
+        }
+        let purpose = PurposeCode::Other;
+        check_purpose_allowed(&env, &agent, purpose)?;
+        check_compliance(&env, &sender, &agent, amount, get_agent_corridor(&env, &agent))?;
+        check_create_rate_limit(&env, &sender)?;
+        check_cancel_cooldown(&env, &sender)?;
+        consume_intent(&env, &sender, &agent, amount)?;
+
+        let remittance_id = get_remittance_counter(&env)?
+            .checked_add(1)
+            .ok_or(ContractError::Overflow)?;
+        let fee = calculate_platform_fee(&env, Some(&sender), Some(&agent), amount)?;
+        let fee = fee - apply_fee_credit(&env, &sender, remittance_id, fee);
+        let payout_amount = net_amount(amount, fee)?;
+
+        let token_client = token::Client::new(&env, &get_usdc_token(&env)?);
+        token_client.transfer(&sender, &agent, &payout_amount);
+        if fee > 0 {
+            token_client.transfer(&sender, &env.current_contract_address(), &fee);
+            book_direct_fee(&env, fee)?;
+        }
+
+        let remittance = Remittance {
+            id: remittance_id,
+            sender: sender.clone(),
+            agent: agent.clone(),
+            amount,
+            fee,
+            status: RemittanceStatus::Completed,
+            expiry: None,
+            purpose,
+            originator_hash: None,
+            beneficiary_hash: None,
+            external_ref: None,
+            hold_until: None,
+            priority: Priority::Standard,
+            payout_fx: None,
+        };
+        set_remittance(&env, remittance_id, &remittance);
+        set_remittance_counter(&env, remittance_id);
+        set_settlement_hash(&env, remittance_id);
+        mark_remittance_closed(&env, remittance_id);
+        record_risk_velocity(&env, &sender);
+        record_epoch_created(&env, amount)?;
+        record_epoch_completed(&env, amount, fee)?;
+        Self::reward_sender(&env, &remittance)?;
+
+        emit_remittance_created(&env, remittance_id, sender.clone(), agent.clone(), amount, fee, purpose);
+        emit_status_changed(&env, remittance_id, None, RemittanceStatus::Completed, Some(sender.clone()));
+        emit_direct_remittance(&env, remittance_id, sender, agent, payout_amount, fee);
+
+        Ok(remittance_id)
+    }
+
+    /// Approves or revokes direct mode for a sender and agent pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Sender of the pair
+    /// * `agent` - Agent of the pair
+    /// * `enabled` - Whether the pair may use `record_direct_remittance`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_direct_pair(env: Env, sender: Address, agent: Address, enabled: bool) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_direct_pair(&env, &sender, &agent, enabled);
+        emit_direct_pair_set(&env, sender, agent, enabled);
+
+        Ok(())
+    }
+
+    /// Returns whether a sender and agent pair may use direct mode.
+    pub fn is_direct_pair(env: Env, sender: Address, agent: Address) -> bool {
+        is_direct_pair(&env, &sender, &agent)
+    }
+
+    /// Escrows the sender's funds and stores a pending remittance under `remittance_id`.
+    ///
+    /// Shared by `create_remittance`, `create_reserved` and `batch_create_remittances`;
+    /// callers validate the request and authenticate the sender first.
+    fn open_remittance(
+        env: &Env,
+        remittance_id: u64,
+        sender: &Address,
+        agent: &Address,
+        amount: i128,
+        purpose: PurposeCode,
+        expiry: Option<u64>,
+    ) -> Result<(), ContractError> {
+        Self::record_remittance(env, remittance_id, sender, agent, amount, purpose, expiry)?;
+
+        let usdc_token = get_usdc_token(env)?;
+        let token_client = token::Client::new(env, &usdc_token);
+        token_client.transfer(sender, &env.current_contract_address(), &amount);
+
+        Ok(())
+    }
+
+    /// Runs the creation checks and stores a pending remittance, adding its
+    /// amount to the escrow total without moving any funds.
+    ///
+    /// Callers transfer the sender's funds themselves; `create_split_remittance`
+    /// uses this to escrow several legs with a single transfer. Creation paths
+    /// that take no purpose from the sender record `PurposeCode::Other`, so
+    /// corridors that do not accept it can only be reached via `create_remittance`.
+    fn record_remittance(
+        env: &Env,
+        remittance_id: u64,
+        sender: &Address,
+        agent: &Address,
+        amount: i128,
+        purpose: PurposeCode,
+        expiry: Option<u64>,
+    ) -> Result<(), ContractError> {
+        check_purpose_allowed(env, agent, purpose)?;
+        check_agent_capacity(env, agent)?;
+        check_compliance(env, sender, agent, amount, get_agent_corridor(env, agent))?;
+        check_create_rate_limit(env, sender)?;
+        check_cancel_cooldown(env, sender)?;
+        consume_intent(env, sender, agent, amount)?;
+
+        let fee = calculate_platform_fee(env, Some(sender), Some(agent), amount)?;
+        let fee = fee - apply_fee_credit(env, sender, remittance_id, fee);
+        book_remittance(env, amount, fee)?;
+
+        let remittance = Remittance {
+            id: remittance_id,
+            sender: sender.clone(),
+            agent: agent.clone(),
+            amount,
+            fee,
+            status: RemittanceStatus::Pending,
+            expiry,
+            purpose,
+            originator_hash: None,
+            beneficiary_hash: None,
+            external_ref: None,
+            hold_until: hold_until_for(env, agent),
+            priority: Priority::Standard,
+            payout_fx: None,
+        };
+
+        set_remittance(env, remittance_id, &remittance);
+        emit_status_changed(env, remittance_id, None, RemittanceStatus::Pending, Some(sender.clone()));
+        record_created(env, remittance_id);
+        record_risk_velocity(env, sender);
+        record_epoch_created(env, amount)?;
+        Self::record_transfer(env, sender, amount);
+        assign_pickup_code(env, remittance_id);
+        index_deposit_ref(env, remittance_id);
+        begin_screening(env, remittance_id);
+        push_assignment(env, agent, remittance_id, Priority::Standard);
+        index_open(env, remittance_id);
+        if let Some(expiry) = expiry {
+            index_expiry(env, remittance_id, expiry);
+        }
+        emit_remittance_created(env, remittance_id, sender.clone(), agent.clone(), amount, fee, purpose);
+
+        Ok(())
+    }
+
     /// Confirms a remittance payout to the agent.
     ///
     /// Transfers the remittance amount (minus platform fee) to the agent and marks
     /// the remittance as completed. Includes duplicate settlement protection and
-    /// expiry validation.
+    /// expiry validation. In attested mode the funds are held instead and the
+    /// remittance moves to `AwaitingAttestation` until `attest_received`.
     ///
     /// # Arguments
     ///
@@ -291,6 +828,7 @@ impl SwiftRemitContract {
     /// * `Err(ContractError::DuplicateSettlement)` - Settlement already executed
     /// * `Err(ContractError::SettlementExpired)` - Current time exceeds expiry timestamp
     /// * `Err(ContractError::InvalidAddress)` - Agent address validation failed
+    /// * `Err(ContractError::ContractPaused)` - Contract or payouts are paused
     /// * `Err(ContractError::Overflow)` - Arithmetic overflow in payout calculation
     ///
     /// # Authorization
@@ -298,73 +836,174 @@ impl SwiftRemitContract {
     /// Requires authentication from the agent address assigned to the remittance.
     pub fn confirm_payout(env: Env, remittance_id: u64) -> Result<(), ContractError> {
         // Centralized validation before business logic
-        let mut remittance = validate_confirm_payout_request(&env, remittance_id)?;
+        let remittance = validate_confirm_payout_request(&env, remittance_id)?;
 
         remittance.agent.require_auth();
 
-        if remittance.status != RemittanceStatus::Pending {
-            return Err(ContractError::InvalidStatus);
+        // Check rate limit for sender
+        check_rate_limit(&env, &remittance.sender)?;
+
+        let agent = remittance.agent.clone();
+        Self::settle_payout(&env, remittance, None, Some(agent))
+    }
+
+    /// Pays out a validated pending remittance to its agent.
+    ///
+    /// Shared by `confirm_payout`, `confirm_payout_as`, `confirm_payout_with_swap`
+    /// and `batch_confirm_payouts`; callers validate the request, authenticate
+    /// the agent and apply the sender rate limit first. With `swap` set to
+    /// `(target_token, min_out)` the payout is swapped and paid immediately,
+    /// bypassing any settlement schedule.
+    ///
+    /// In attested mode a pending remittance is only moved to
+    /// `AwaitingAttestation`; the payout happens when it is passed in again by
+    /// `attest_received` or `release_unattested`.
+    fn settle_payout(
+        env: &Env,
+        mut remittance: Remittance,
+        swap: Option<(Address, i128)>,
+        actor: Option<Address>,
+    ) -> Result<(), ContractError> {
+        let remittance_id = remittance.id;
+
+        match remittance.status {
+            RemittanceStatus::Pending => {}
+            // The agent confirmed before expiry, so only the attestation is late
+            RemittanceStatus::AwaitingAttestation => end_attestation(env, remittance_id),
+            _ => return Err(ContractError::InvalidStatus),
         }
 
         // Check for duplicate settlement execution
-        if has_settlement_hash(&env, remittance_id) {
+        if has_settlement_hash(env, remittance_id) {
             return Err(ContractError::DuplicateSettlement);
         }
 
-        // Check if settlement has expired
-        if let Some(expiry_time) = remittance.expiry {
-            let current_time = env.ledger().timestamp();
-            if current_time > expiry_time {
-                return Err(ContractError::SettlementExpired);
+        if remittance.status == RemittanceStatus::Pending {
+            // Check if settlement has expired
+            if let Some(expiry_time) = remittance.expiry {
+                let current_time = env.ledger().timestamp();
+                if current_time > expiry_time {
+                    return Err(ContractError::SettlementExpired);
+                }
             }
-        }
+            // The SLA is measured to the agent's confirmation, not the release
+            record_confirmed(env, remittance_id);
 
-        // Check rate limit for sender
-        check_rate_limit(&env, &remittance.sender)?;
+            if get_attestation_config(env).enabled {
+                // A held payout cannot remember the agent's swap terms
+                if swap.is_some() {
+                    return Err(ContractError::InvalidStatus);
+                }
+                let release_after = begin_attestation(env, remittance_id);
+                Self::set_status(env, &mut remittance, RemittanceStatus::AwaitingAttestation, actor);
+                set_remittance(env, remittance_id, &remittance);
+                emit_payout_held(env, remittance_id, remittance.agent.clone(), release_after);
+                return Ok(());
+            }
+        }
 
         // Validate the agent address before transfer
         validate_address(&remittance.agent)?;
 
-        let payout_amount = remittance
+        // A programmable recipient is paid in the settlement token, never swapped
+        let recipient = get_payout_recipient(env, remittance_id);
+        if recipient.is_some() && swap.is_some() {
+            return Err(ContractError::InvalidStatus);
+        }
+
+        // Tranches released with confirm_partial have already been paid out
+        let released = get_released_totals(env, remittance_id);
+        let remaining_amount = remittance
             .amount
-            .checked_sub(remittance.fee)
+            .checked_sub(released.amount)
             .ok_or(ContractError::Overflow)?;
-
-        let usdc_token = get_usdc_token(&env)?;
-        let token_client = token::Client::new(&env, &usdc_token);
-        token_client.transfer(
-            &env.current_contract_address(),
-            &remittance.agent,
-            &payout_amount,
-        );
-
-        let current_fees = get_accumulated_fees(&env)?;
-        let new_fees = current_fees
-            .checked_add(remittance.fee)
+        let remaining_fee = remittance
+            .fee
+            .checked_sub(released.fee)
             .ok_or(ContractError::Overflow)?;
-        set_accumulated_fees(&env, new_fees);
 
-        remittance.status = RemittanceStatus::Settled;
-        set_remittance(&env, remittance_id, &remittance);
+        let payout_amount = book_payout(env, remaining_amount, remaining_fee)?;
+
+        let usdc_token = get_usdc_token(env)?;
+        // Swapped payouts are paid immediately; otherwise agents on a netting
+        // schedule are paid later by run_settlement
+        if let Some((target_token, min_out)) = swap {
+            let amount_out = swap_payout(env, &usdc_token, &target_token, payout_amount, min_out, &remittance.agent)?;
+            emit_payout_swapped(env, remittance_id, remittance.agent.clone(), target_token, payout_amount, amount_out);
+        } else if let Some(recipient) = &recipient {
+            // Recipient contracts are paid immediately so they can act on the funds
+            token::Client::new(env, &usdc_token).transfer(
+                &env.current_contract_address(),
+                recipient,
+                &payout_amount,
+            );
+        } else if !queue_payout(env, &remittance.agent, remittance_id, payout_amount)? {
+            let token_client = token::Client::new(env, &usdc_token);
+            token_client.transfer(
+                &env.current_contract_address(),
+                &remittance.agent,
+                &payout_amount,
+            );
+        }
+        consume_premium(env, remittance_id)?;
+
+        Self::set_status(env, &mut remittance, RemittanceStatus::Completed, actor);
+        set_remittance(env, remittance_id, &remittance);
+        mark_remittance_closed(env, remittance_id);
+        unindex_open(env, remittance_id);
+        pop_assignment(env, &remittance.agent, remittance_id);
+        Self::record_transfer(env, &remittance.agent, payout_amount);
+        Self::reward_sender(env, &remittance)?;
+
+        // Late confirmations rebate part of the fee to the sender
+        let mut fee_earned = remittance.fee;
+        if let Some((delay, rebate)) = apply_sla(env, remittance_id, &remittance.agent, remittance.priority, remittance.fee)? {
+            if rebate > 0 {
+                book_fee_rebate(env, rebate)?;
+                token::Client::new(env, &usdc_token).transfer(
+                    &env.current_contract_address(),
+                    &remittance.sender,
+                    &rebate,
+                );
+            }
+            emit_sla_breached(env, remittance_id, remittance.agent.clone(), delay, rebate);
+            fee_earned -= rebate;
+        }
+        // Sponsored remittances return their relay fee to the sponsor's pool
+        if let Some((sponsor, amount)) = reimburse_sponsor(env, remittance_id, fee_earned)? {
+            emit_sponsor_reimbursed(env, remittance_id, sponsor, amount);
+            fee_earned -= amount;
+        }
+        // Part of the rest is shared with agents pro-rata to their collateral
+        fee_earned -= share_staking_rewards(env, fee_earned)?;
+        record_epoch_completed(env, remittance.amount, fee_earned)?;
 
         // Mark settlement as executed to prevent duplicates
-        set_settlement_hash(&env, remittance_id);
+        set_settlement_hash(env, remittance_id);
         
         // Update last settlement time for rate limiting
         let current_time = env.ledger().timestamp();
-        set_last_settlement_time(&env, &remittance.sender, current_time);
+        set_last_settlement_time(env, &remittance.sender, current_time);
+
+        // The recipient is notified once the remittance is settled; if it
+        // fails, the whole payout is rolled back
+        if let Some(recipient) = recipient {
+            notify_payout_recipient(env, &recipient, remittance_id, payout_amount)?;
+        }
 
         // Event: Remittance completed - Fires when agent confirms fiat payout and USDC is released
         // Used by off-chain systems to track successful settlements and update transaction status
-        emit_remittance_completed(&env, remittance_id, remittance.sender.clone(), remittance.agent.clone(), usdc_token.clone(), payout_amount);
+        emit_remittance_completed(env, remittance_id, remittance.sender.clone(), remittance.agent.clone(), usdc_token.clone(), payout_amount, remittance.payout_fx.clone());
         
         // Event: Settlement completed - Fires with final executed settlement values
         // Used by off-chain systems for reconciliation and audit trails of completed transactions
-        emit_settlement_completed(&env, remittance.sender.clone(), remittance.agent.clone(), usdc_token.clone(), payout_amount);
+        emit_settlement_completed(env, remittance.sender.clone(), remittance.agent.clone(), usdc_token.clone(), payout_amount);
 
-        log_confirm_payout(&env, remittance_id, payout_amount);
+        log_confirm_payout(env, remittance_id, payout_amount);
 
-        Ok(remittance_id)
+        mirror_completion(env, &remittance);
+
+        Ok(())
     }
 
     pub fn finalize_remittance(env: Env, caller: Address, remittance_id: u64) -> Result<(), ContractError> {
@@ -383,8 +1022,8 @@ impl SwiftRemitContract {
 
     /// Cancels a pending remittance and refunds the sender.
     ///
-    /// Returns the full remittance amount to the sender and marks the remittance
-    /// as cancelled. Can only be called by the original sender.
+    /// Returns the remittance amount not yet released in tranches to the sender
+    /// and marks the remittance as cancelled. Can only be called by the original sender.
     ///
     /// # Arguments
     ///
@@ -396,6 +1035,7 @@ impl SwiftRemitContract {
     /// * `Ok(())` - Remittance successfully cancelled and refunded
     /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
     /// * `Err(ContractError::InvalidStatus)` - Remittance is not in Pending status
+    /// * `Err(ContractError::ContractPaused)` - Cancellation is paused
     ///
     /// # Authorization
     ///
@@ -406,51 +1046,267 @@ impl SwiftRemitContract {
 
         remittance.sender.require_auth();
 
+        let sender = remittance.sender.clone();
+        let refund_amount = Self::refund_remittance(&env, &mut remittance, RemittanceStatus::Cancelled, Some(sender), true)?;
         let usdc_token = get_usdc_token(&env)?;
-        let token_client = token::Client::new(&env, &usdc_token);
-        token_client.transfer(
-            &env.current_contract_address(),
-            &remittance.sender,
-            &remittance.amount,
-        );
-
-        remittance.status = RemittanceStatus::Failed;
-        set_remittance(&env, remittance_id, &remittance);
 
         // Event: Remittance cancelled - Fires when sender cancels a pending remittance and receives full refund
         // Used by off-chain systems to track cancellations and update transaction status
-        emit_remittance_cancelled(&env, remittance_id, remittance.sender.clone(), remittance.agent.clone(), usdc_token.clone(), remittance.amount);
+        emit_remittance_cancelled(&env, remittance_id, remittance.sender.clone(), remittance.agent.clone(), usdc_token.clone(), refund_amount);
+
+        if let Some(until) = record_cancellation(&env, &remittance.sender) {
+            emit_cancel_cooldown_started(&env, remittance.sender.clone(), until);
+        }
 
         log_cancel_remittance(&env, remittance_id);
 
         Ok(())
     }
 
-    /// Withdraws accumulated platform fees to a specified address.
+    /// Moves a remittance to `status` and emits the uniform `status_changed`
+    /// event; the caller stores the remittance.
     ///
-    /// Transfers all accumulated fees to the recipient address and resets the
-    /// fee counter to zero. Only the contract admin can withdraw fees.
+    /// Every status change goes through here, so one subscription to
+    /// `status_changed` covers the whole lifecycle alongside the legacy topics.
+    fn set_status(env: &Env, remittance: &mut Remittance, status: RemittanceStatus, actor: Option<Address>) {
+        let old = remittance.status.clone();
+        remittance.status = status.clone();
+        emit_status_changed(env, remittance.id, Some(old), status, actor);
+    }
+
+    /// Credits the sender of a paid-out remittance with loyalty points and
+    /// adds its amount to the sender's lifetime volume.
     ///
-    /// # Arguments
+    /// Shared by `settle_payout` and `batch_settle_with_netting`.
+    fn reward_sender(env: &Env, remittance: &Remittance) -> Result<(), ContractError> {
+        settle_fee_discount(env, remittance.id);
+        record_sender_volume(env, &remittance.sender, remittance.amount)?;
+        let earned = accrue_points(env, &remittance.sender, remittance.amount)?;
+        if earned > 0 {
+            emit_points_earned(env, remittance.id, remittance.sender.clone(), earned);
+        }
+        Ok(())
+    }
+
+    /// Appends a transfer at the current ledger time to a user's history.
     ///
-    /// * `env` - The contract execution environment
-    /// * `to` - Address to receive the withdrawn fees
+    /// Senders are recorded when a remittance is created and agents when it is
+    /// paid out, so the history covers both sides of every completed transfer.
+    fn record_transfer(env: &Env, user: &Address, amount: i128) {
+        let record = TransferRecord {
+            timestamp: env.ledger().timestamp(),
+            amount,
+        };
+        append_user_transfer(env, user, &record);
+    }
+
+    /// Refunds the unreleased part of a pending remittance and closes it with `status`.
     ///
-    /// # Returns
+    /// Shared by `cancel_remittance`, `relay_cancel_remittance`, `admin_cancel_remittance`,
+    /// `agent_decline_remittance` and `sweep_expired`. The caller is responsible for
+    /// authorization and for emitting its own event.
     ///
-    /// * `Ok(())` - Fees successfully withdrawn
-    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
-    /// * `Err(ContractError::NoFeesToWithdraw)` - No fees available (balance is zero or negative)
-    /// * `Err(ContractError::InvalidAddress)` - Recipient address validation failed
+    /// `full_premium` is set when the remittance is cancelled before acceptance,
+    /// by its sender or declined by its agent, so the insurance premium was
+    /// never used; expiries and admin refunds get the premium pro-rated over
+    /// the coverage window left.
     ///
-    /// # Authorization
+    /// # Returns
     ///
-    /// Requires authentication from the contract admin.
-    pub fn withdraw_fees(env: Env, to: Address) -> Result<(), ContractError> {
-        let admin = get_admin(&env)?;
-        admin.require_auth();
-
-        remittance.status = RemittanceStatus::Failed;
+    /// * `Ok(i128)` - Amount refunded to the sender, excluding any insurance premium
+    fn refund_remittance(
+        env: &Env,
+        remittance: &mut Remittance,
+        status: RemittanceStatus,
+        actor: Option<Address>,
+        full_premium: bool,
+    ) -> Result<i128, ContractError> {
+        let remittance_id = remittance.id;
+
+        // Only the part not already delivered in tranches is refunded, with its fee
+        let released = get_released_totals(env, remittance_id);
+        let unreleased = remittance
+            .amount
+            .checked_sub(released.amount)
+            .ok_or(ContractError::Overflow)?;
+        restore_fee_credit(env, &remittance.sender, remittance_id, unreleased, remittance.amount)?;
+        drop_sponsor_claim(env, remittance_id);
+        let refund_amount = book_refund(
+            env,
+            unreleased,
+            remittance
+                .fee
+                .checked_sub(released.fee)
+                .ok_or(ContractError::Overflow)?,
+        )?;
+
+        let usdc_token = get_usdc_token(env)?;
+        let token_client = token::Client::new(env, &usdc_token);
+        token_client.transfer(
+            &env.current_contract_address(),
+            &remittance.sender,
+            &refund_amount,
+        );
+
+        let premium_refund = refund_premium(env, remittance_id, full_premium)?;
+        if premium_refund > 0 {
+            token_client.transfer(
+                &env.current_contract_address(),
+                &remittance.sender,
+                &premium_refund,
+            );
+            emit_premium_refunded(env, remittance_id, remittance.sender.clone(), premium_refund);
+        }
+
+        Self::set_status(env, remittance, status, actor);
+        set_remittance(env, remittance_id, remittance);
+        mark_remittance_closed(env, remittance_id);
+        unindex_open(env, remittance_id);
+        pop_assignment(env, &remittance.agent, remittance_id);
+
+        Ok(refund_amount)
+    }
+
+    /// Transfers `fees` of `token` to `to` and books them out of accumulated fees.
+    ///
+    /// Shared by `withdraw_fees` and quorum-approved fee withdrawals; callers
+    /// validate the request and authorize it first.
+    fn pay_out_fees(
+        env: &Env,
+        caller: &Address,
+        token: &Address,
+        to: &Address,
+        fees: i128,
+    ) -> Result<(), ContractError> {
+        if *token == get_usdc_token(env)? {
+            unwrap_fees_for(env, fees)?;
+        }
+        let token_client = token::Client::new(env, token);
+        token_client.transfer(&env.current_contract_address(), to, &fees);
+
+        // Fees of open remittances stay behind until they are paid out
+        let remaining = get_accumulated_fees_for(env, token)
+            .checked_sub(fees)
+            .ok_or(ContractError::Overflow)?;
+        set_accumulated_fees_for(env, token, remaining)?;
+
+        // Event: Fees withdrawn - Fires when accumulated platform fees are withdrawn
+        // Used by off-chain systems to track revenue collection and maintain financial records
+        emit_fees_withdrawn(env, caller.clone(), to.clone(), token.clone(), fees);
+
+        log_withdraw_fees(env, to, fees);
+
+        Ok(())
+    }
+
+    /// Executes a slash and pays its locked collateral to the sender.
+    ///
+    /// Shared by `execute_slash` and `resolve_slash`; `upheld` skips the
+    /// challenge checks for slashes the admin decided.
+    fn pay_out_slash(env: &Env, remittance_id: u64, upheld: bool) -> Result<i128, ContractError> {
+        let slash = execute_slash(env, remittance_id, upheld)?;
+        let usdc_token = get_usdc_token(env)?;
+        token::Client::new(env, &usdc_token).transfer(
+            &env.current_contract_address(),
+            &slash.sender,
+            &slash.amount,
+        );
+
+        emit_slash_closed(env, remittance_id, slash.status, slash.sender, slash.amount);
+        Ok(slash.amount)
+    }
+
+    /// Returns how much of `token` the contract holds beyond its liabilities.
+    fn rescuable_balance(env: &Env, token: &Address) -> Result<i128, ContractError> {
+        let surplus = if *token == get_usdc_token(env)? {
+            let report = Self::reconcile(env.clone())?;
+            report.discrepancy.min(report.token_balance)
+        } else {
+            token::Client::new(env, token)
+                .balance(&env.current_contract_address())
+                .checked_sub(get_accumulated_fees_for(env, token))
+                .ok_or(ContractError::Overflow)?
+        };
+        Ok(surplus.max(0))
+    }
+
+    /// Moves the unreleased funds of a pending remittance into quarantine.
+    ///
+    /// Booked like a refund, including any insurance premium, but the funds
+    /// stay in the contract under the remittance's quarantine entry. The
+    /// caller is responsible for authorization and for emitting its own event.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(i128)` - Amount held in quarantine
+    fn quarantine_remittance(
+        env: &Env,
+        remittance: &mut Remittance,
+        actor: Option<Address>,
+    ) -> Result<i128, ContractError> {
+        let remittance_id = remittance.id;
+
+        let released = get_released_totals(env, remittance_id);
+        let mut held = book_quarantine(
+            env,
+            remittance_id,
+            remittance
+                .amount
+                .checked_sub(released.amount)
+                .ok_or(ContractError::Overflow)?,
+            remittance
+                .fee
+                .checked_sub(released.fee)
+                .ok_or(ContractError::Overflow)?,
+        )?;
+
+        let premium = refund_premium(env, remittance_id, true)?;
+        if premium > 0 {
+            quarantine_funds(env, remittance_id, premium)?;
+            held = held.checked_add(premium).ok_or(ContractError::Overflow)?;
+        }
+        settle_fee_discount(env, remittance_id);
+        drop_sponsor_claim(env, remittance_id);
+
+        Self::set_status(env, remittance, RemittanceStatus::Quarantined, actor);
+        set_remittance(env, remittance_id, remittance);
+        mark_remittance_closed(env, remittance_id);
+        unindex_open(env, remittance_id);
+        pop_assignment(env, &remittance.agent, remittance_id);
+
+        Ok(held)
+    }
+
+    /// Withdraws accumulated platform fees in one token to a specified address.
+    ///
+    /// Transfers the withdrawable fees accumulated in `token` to the recipient
+    /// address. Fees of remittances that are still open stay behind, since a
+    /// cancellation would refund them. Fees held in other tokens are untouched.
+    /// Only the contract admin can withdraw fees.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `token` - Token whose accumulated fees are withdrawn
+    /// * `to` - Address to receive the withdrawn fees
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Fees successfully withdrawn
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    /// * `Err(ContractError::NoFeesToWithdraw)` - No fees available (balance is zero or negative)
+    /// * `Err(ContractError::InvalidAddress)` - Recipient address validation failed
+    /// * `Err(ContractError::Unauthorized)` - A quorum is configured; withdraw through `propose_op`,
+    ///   or `to` is not the configured treasury
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin, and no quorum to be configured.
+    pub fn withdraw_fees(env: Env, token: Address, to: Address) -> Result<(), ContractError> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+
+        remittance.status = RemittanceStatus::Failed;
         set_remittance(&env, remittance_id, &remittance);
 
         log_cancel_remittance(&env, remittance_id);
@@ -458,28 +1314,56 @@ impl SwiftRemitContract {
         Ok(())
     }
 
-    pub fn withdraw_fees(env: Env, to: Address) -> Result<(), ContractError> {
+    pub fn withdraw_fees(env: Env, token: Address, to: Address) -> Result<(), ContractError> {
         // Centralized validation before business logic
-        let fees = validate_withdraw_fees_request(&env, &to)?;
+        let fees = validate_withdraw_fees_request(&env, &token, &to)?;
         
         let caller = get_admin(&env)?;
         require_admin(&env, &caller)?;
+        require_no_quorum(&env)?;
 
-        let usdc_token = get_usdc_token(&env)?;
-        let token_client = token::Client::new(&env, &usdc_token);
-        token_client.transfer(&env.current_contract_address(), &to, &fees);
-
-        set_accumulated_fees(&env, 0);
+        Self::pay_out_fees(&env, &caller, &token, &to, fees)
+    }
 
-        // Event: Fees withdrawn - Fires when admin withdraws accumulated platform fees
-        // Used by off-chain systems to track revenue collection and maintain financial records
-        emit_fees_withdrawn(&env, caller.clone(), to.clone(), usdc_token.clone(), fees);
+    /// Sets or clears the treasury fee withdrawals are restricted to.
+    ///
+    /// Recipients of the fee split are exempt; `distribute_fees` pays them
+    /// directly.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `treasury` - New treasury, or `None` to let the admin name each recipient
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Treasury updated
+    /// * `Err(ContractError::InvalidAddress)` - The treasury is the contract itself
+    /// * `Err(ContractError::Unauthorized)` - A quorum is configured; propose `CriticalOp::SetTreasury`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin, and no quorum to be configured.
+    pub fn set_treasury(env: Env, treasury: Option<Address>) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+        require_no_quorum(&env)?;
 
-        log_withdraw_fees(&env, &to, fees);
+        if let Some(treasury) = &treasury {
+            validate_treasury(&env, treasury)?;
+        }
 
+        set_treasury(&env, &treasury);
+        emit_treasury_set(&env, treasury);
         Ok(())
     }
 
+    /// Returns the treasury fees can only be withdrawn to, if one is
+    /// configured.
+    pub fn get_treasury(env: Env) -> Option<Address> {
+        get_treasury(&env)
+    }
+
     /// Retrieves a remittance record by ID.
     ///
     /// # Arguments
@@ -495,6 +1379,131 @@ impl SwiftRemitContract {
         get_remittance(&env, remittance_id)
     }
 
+    /// Breaks down where the money of a paid-out remittance went.
+    ///
+    /// Computed from the stored remittance, route, insurance policy, SLA
+    /// rebate and sponsor reimbursement, for support staff answering
+    /// disputes. `net_payout` is the gross amount less the platform fee and
+    /// the hop fees; rebates and sponsor shares come out of the platform fee,
+    /// and the insurance premium was paid on top of the amount.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SettlementBreakdown)` - Amounts making up the settlement
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist or was archived
+    /// * `Err(ContractError::InvalidStatus)` - The remittance was not paid out
+    pub fn get_settlement_breakdown(env: Env, remittance_id: u64) -> Result<SettlementBreakdown, ContractError> {
+        let remittance = get_remittance(&env, remittance_id)?;
+        if remittance.status != RemittanceStatus::Completed {
+            return Err(ContractError::InvalidStatus);
+        }
+
+        let agent_commission = match get_route(&env, remittance_id) {
+            Some(route) => route
+                .hop_fee
+                .checked_mul(route.confirmed as i128)
+                .ok_or(ContractError::Overflow)?,
+            None => 0,
+        };
+        let insurance_premium = get_insurance_policy(&env, remittance_id)
+            .map_or(0, |policy| policy.premium - policy.refunded);
+        let net_payout = remittance
+            .amount
+            .checked_sub(remittance.fee)
+            .and_then(|net| net.checked_sub(agent_commission))
+            .ok_or(ContractError::Overflow)?;
+
+        Ok(SettlementBreakdown {
+            gross_amount: remittance.amount,
+            platform_fee: remittance.fee,
+            agent_commission,
+            sponsor_share: get_sponsor_reimbursement(&env, remittance_id),
+            sla_rebate: get_sla_rebate(&env, remittance_id),
+            insurance_premium,
+            net_payout,
+        })
+    }
+
+    /// Retrieves the remittance tagged with an integrator's external reference.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `external_ref` - Reference passed to `create_remittance`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Remittance)` - The remittance record
+    /// * `Err(ContractError::RemittanceNotFound)` - No remittance uses the reference
+    pub fn get_remittance_by_external_ref(
+        env: Env,
+        external_ref: BytesN<32>,
+    ) -> Result<Remittance, ContractError> {
+        let remittance_id = get_external_ref(&env, &external_ref).ok_or(ContractError::RemittanceNotFound)?;
+        get_remittance(&env, remittance_id)
+    }
+
+    /// Retrieves the remittance a recipient's pickup code belongs to.
+    ///
+    /// Lets an agent find the remittance a walk-in recipient quotes. Case is
+    /// ignored, and `I`, `L` and `O` are read as `1`, `1` and `0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `code` - 8-character pickup code
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Remittance)` - The remittance record
+    /// * `Err(ContractError::RemittanceNotFound)` - The code is malformed or unknown
+    pub fn lookup_by_code(env: Env, code: String) -> Result<Remittance, ContractError> {
+        let remittance_id = find_by_pickup_code(&env, &code)?;
+        get_remittance(&env, remittance_id)
+    }
+
+    /// Returns the pickup code the sender passes on to the recipient.
+    pub fn get_pickup_code(env: Env, remittance_id: u64) -> Option<String> {
+        get_pickup_code(&env, remittance_id)
+    }
+
+    /// Returns the deposit reference of a remittance.
+    ///
+    /// The reference is a 64-bit muxed account ID that partners can also
+    /// derive off-chain from the contract address and remittance ID.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - The deposit reference
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    pub fn get_deposit_ref(env: Env, remittance_id: u64) -> Result<u64, ContractError> {
+        validate_remittance_exists(&env, remittance_id)?;
+        Ok(deposit_ref_of(&env, remittance_id))
+    }
+
+    /// Resolves a deposit reference to the remittance it belongs to.
+    ///
+    /// Lets an off-ramp partner credit the exact remittance a payment to a
+    /// muxed sub-account was made for.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `deposit_ref` - Deposit reference, i.e. the muxed account ID paid to
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - ID of the remittance
+    /// * `Err(ContractError::RemittanceNotFound)` - The reference is unknown
+    pub fn resolve_deposit_ref(env: Env, deposit_ref: u64) -> Result<u64, ContractError> {
+        resolve_deposit_ref(&env, deposit_ref)
+    }
+
     /// Query a remittance with a standardized response wrapper and request ID.
     pub fn query_remittance(
         env: Env,
@@ -512,6 +1521,42 @@ impl SwiftRemitContract {
         get_accumulated_fees(&env)
     }
 
+    /// Returns the accumulated USDC fees that may be withdrawn now.
+    ///
+    /// Excludes fees of open remittances, which are refunded if the remittance
+    /// is cancelled or expires.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(i128)` - Withdrawable fees
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    pub fn get_withdrawable_fees(env: Env) -> Result<i128, ContractError> {
+        get_withdrawable_fees(&env)
+    }
+
+    /// Returns the platform fees accumulated in `token` and not yet withdrawn.
+    pub fn get_accumulated_fees_for(env: Env, token: Address) -> i128 {
+        get_accumulated_fees_for(&env, &token)
+    }
+
+    /// Returns the address of the native XLM asset contract on this network.
+    ///
+    /// Pass it to `initialize` to run a deployment whose remittances, fees
+    /// and refunds are denominated in XLM.
+    pub fn get_native_token(env: Env) -> Address {
+        get_native_token(&env)
+    }
+
+    /// Checks whether the contract settles in native XLM rather than an issued asset.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - True if the settlement token is the native asset contract
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    pub fn is_native_settlement(env: Env) -> Result<bool, ContractError> {
+        Ok(is_native_token(&env, &get_usdc_token(&env)?))
+    }
+
     /// Checks if an address is registered as an agent.
     ///
     /// # Arguments
@@ -527,7 +1572,8 @@ impl SwiftRemitContract {
         is_agent_registered(&env, &agent)
     }
 
-    /// Retrieves the current platform fee rate.
+    /// Retrieves the current platform fee rate, including any scheduled
+    /// update that has taken effect.
     ///
     /// # Arguments
     ///
@@ -538,7 +1584,7 @@ impl SwiftRemitContract {
     /// * `Ok(u32)` - Platform fee in basis points (1 bps = 0.01%)
     /// * `Err(ContractError::NotInitialized)` - Contract not initialized
     pub fn get_platform_fee_bps(env: Env) -> Result<u32, ContractError> {
-        get_platform_fee_bps(&env)
+        get_effective_fee_bps(&env, env.ledger().timestamp())
     }
 
     pub fn pause(env: Env) -> Result<(), ContractError> {
@@ -562,6 +1608,66 @@ impl SwiftRemitContract {
     pub fn is_paused(env: Env) -> bool {
         crate::storage::is_paused(&env)
     }
+
+    /// Pauses a single operation, leaving the rest of the contract running.
+    ///
+    /// Every entrypoint performing the operation, including its batch,
+    /// relayed and sponsored variants, fails with `ContractPaused` until
+    /// `unpause_op` is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `op` - Operation to pause
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Operation paused
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin address.
+    pub fn pause_op(env: Env, op: PausableOp) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_op_paused(&env, op, true);
+        emit_op_paused(&env, caller, op, true);
+        Ok(())
+    }
+
+    /// Resumes an operation paused with `pause_op`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `op` - Operation to resume
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Operation resumed
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin address.
+    pub fn unpause_op(env: Env, op: PausableOp) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_op_paused(&env, op, false);
+        emit_op_paused(&env, caller, op, false);
+        Ok(())
+    }
+
+    pub fn is_op_paused(env: Env, op: PausableOp) -> bool {
+        is_op_paused(&env, op)
+    }
+
+    pub fn get_paused_ops(env: Env) -> Vec<PausableOp> {
+        get_paused_ops(&env)
+    }
     
     pub fn update_rate_limit(env: Env, cooldown_seconds: u64) -> Result<(), ContractError> {
         let admin = get_admin(&env)?;
@@ -664,6 +1770,17 @@ impl SwiftRemitContract {
                 return Err(ContractError::DuplicateSettlement);
             }
 
+            validate_hold_elapsed(&env, remittance.hold_until)?;
+            validate_screened(&env, remittance_id)?;
+
+            // Routed remittances have paid hop fees out of escrow, and remittances
+            // with a recipient contract are paid to it; both settle individually
+            if get_route(&env, remittance_id).is_some()
+                || get_payout_recipient(&env, remittance_id).is_some()
+            {
+                return Err(ContractError::InvalidStatus);
+            }
+
             // Check expiry
             if let Some(expiry_time) = remittance.expiry {
                 let current_time = env.ledger().timestamp();
@@ -716,13 +1833,6 @@ impl SwiftRemitContract {
                 &payout_amount,
             );
 
-            // Accumulate fees
-            let current_fees = get_accumulated_fees(&env)?;
-            let new_fees = current_fees
-                .checked_add(transfer.total_fees)
-                .ok_or(ContractError::Overflow)?;
-            set_accumulated_fees(&env, new_fees);
-
             // Emit settlement event
             emit_settlement_completed(&env, from, to, usdc_token.clone(), payout_amount);
         }
@@ -732,9 +1842,17 @@ impl SwiftRemitContract {
 
         for i in 0..remittances.len() {
             let mut remittance = remittances.get_unchecked(i);
-            remittance.status = RemittanceStatus::Settled;
+            Self::set_status(&env, &mut remittance, RemittanceStatus::Completed, None);
             set_remittance(&env, remittance.id, &remittance);
+            mark_remittance_closed(&env, remittance.id);
+            unindex_open(&env, remittance.id);
+            pop_assignment(&env, &remittance.agent, remittance.id);
             set_settlement_hash(&env, remittance.id);
+            book_payout(&env, remittance.amount, remittance.fee)?;
+            Self::reward_sender(&env, &remittance)?;
+            if let Some((sponsor, amount)) = reimburse_sponsor(&env, remittance.id, remittance.fee)? {
+                emit_sponsor_reimbursed(&env, remittance.id, sponsor, amount);
+            }
             settled_ids.push_back(remittance.id);
 
             // Emit individual remittance completion event
@@ -742,6 +1860,7 @@ impl SwiftRemitContract {
                 .amount
                 .checked_sub(remittance.fee)
                 .ok_or(ContractError::Overflow)?;
+            Self::record_transfer(&env, &remittance.agent, payout_amount);
             emit_remittance_completed(
                 &env,
                 remittance.id,
@@ -749,6 +1868,7 @@ impl SwiftRemitContract {
                 remittance.agent.clone(),
                 usdc_token.clone(),
                 payout_amount,
+                None,
             );
         }
 
@@ -756,6 +1876,9 @@ impl SwiftRemitContract {
     }
 
     /// Add a token to the whitelist. Only admins can call this.
+    ///
+    /// The token is probed first and its decimals stored; an address that is
+    /// not a token contract fails with `UnsupportedToken`.
     pub fn whitelist_token(env: Env, caller: Address, token: Address) -> Result<(), ContractError> {
         // Centralized validation
         validate_admin_operation(&env, &caller, &token)?;
@@ -763,6 +1886,7 @@ impl SwiftRemitContract {
         if is_token_whitelisted(&env, &token) {
             return Err(ContractError::TokenAlreadyWhitelisted);
         }
+        verify_token(&env, &token)?;
 
         set_token_whitelisted(&env, &token, true);
         
@@ -1022,7 +2146,84 @@ impl SwiftRemitContract {
         migration::import_batch(&env, batch)
     }
 
-    /// Sets the daily send limit for a specific currency-country pair.
+    /// Imports remittances from the v1 contract into a fresh deployment.
+    ///
+    /// Remittances keep their v1 IDs and statuses. Pending ones are booked
+    /// into escrow and fees, assigned to their agent and given a pickup code
+    /// and deposit reference, so they can be paid out or refunded here;
+    /// completed, cancelled and expired ones are kept as history. Call
+    /// repeatedly for large migrations, then `finalize_import` once the v1
+    /// escrow has been transferred.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittances` - Up to MAX_MIGRATION_BATCH_SIZE v1 remittances
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u32)` - Number of remittances imported
+    /// * `Err(ContractError::MigrationInProgress)` - Imports were finalized
+    /// * `Err(ContractError::LimitExceeded)` - Too many remittances in one call
+    /// * `Err(ContractError::InvalidMigrationBatch)` - An ID is 0 or already in use
+    /// * `Err(ContractError::InvalidAmount)` - An amount or fee is invalid
+    /// * `Err(ContractError::InvalidStatus)` - A remittance is awaiting attestation or quarantined
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn import_remittances(env: Env, remittances: Vec<LegacyRemittance>) -> Result<u32, ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        let pending = migration::import_legacy_remittances(&env, &remittances)?;
+        for remittance in pending.iter() {
+            record_created(&env, remittance.id);
+            assign_pickup_code(&env, remittance.id);
+            index_deposit_ref(&env, remittance.id);
+            push_assignment(&env, &remittance.agent, remittance.id, Priority::Standard);
+            index_open(&env, remittance.id);
+            if let Some(expiry) = remittance.expiry {
+                index_expiry(&env, remittance.id, expiry);
+            }
+        }
+
+        emit_remittances_imported(&env, remittances.len(), pending.len());
+
+        Ok(remittances.len())
+    }
+
+    /// Closes legacy imports so `import_remittances` can no longer be called.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Imports closed
+    /// * `Err(ContractError::MigrationInProgress)` - Imports were already finalized
+    /// * `Err(ContractError::InsufficientEscrow)` - The contract holds fewer tokens than the
+    ///   escrow and fees booked, e.g. the v1 escrow has not been transferred yet
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn finalize_import(env: Env) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        migration::finalize_import(&env)?;
+        emit_import_finalized(&env, get_remittance_counter(&env)?);
+
+        Ok(())
+    }
+
+    /// Returns whether legacy imports have been finalized.
+    pub fn is_import_finalized(env: Env) -> bool {
+        is_import_finalized(&env)
+    }
+
+    /// Sets the daily send limit override for a specific currency-country pair.
+    /// 
+    /// The override takes precedence over the currency default and the global
+    /// default, so it only needs to be set for countries that differ from them.
     /// 
     /// # Parameters
     /// - `currency`: Currency code (e.g., "USD", "EUR")
@@ -1033,7 +2234,8 @@ impl SwiftRemitContract {
     /// Requires admin authentication
     /// 
     /// # Errors
-    /// - InvalidAmount: If limit is negative
+    /// - NegativeAmount: If limit is negative
+    /// - CorridorClosed: If currency is not a 3-letter code
     /// - Unauthorized: If caller is not admin
     pub fn set_daily_limit(
         env: Env,
@@ -1044,35 +2246,5211 @@ impl SwiftRemitContract {
         let admin = get_admin(&env)?;
         admin.require_auth();
 
-        if limit < 0 {
-            return Err(ContractError::InvalidAmount);
-        }
-
+        validate_non_negative(limit)?;
 
-        let currency = normalize_symbol(&env, &currency);
+        let currency = Currency::parse(&env, &currency)?;
         let country = normalize_symbol(&env, &country);
 
-
         set_daily_limit(&env, &currency, &country, limit);
 
         Ok(())
     }
 
-    /// Gets the configured daily send limit for a currency-country pair.
+    /// Removes the daily send limit override of a currency-country pair, so
+    /// the currency default or global default applies again.
     /// 
-    /// # Parameters
+    /// # Authorization
+    /// Requires admin authentication
+    pub fn remove_daily_limit(env: Env, currency: String, country: String) -> Result<(), ContractError> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+
+        let currency = Currency::parse(&env, &currency)?;
+        let country = normalize_symbol(&env, &country);
+
+        remove_daily_limit(&env, &currency, &country);
+
+        Ok(())
+    }
+
+    /// Sets the default daily send limit for every country of a currency.
+    /// 
+    /// # Parameters
+    /// - `currency`: Currency code (e.g., "USD", "EUR")
+    /// - `limit`: Maximum amount that can be sent in 24 hours
+    /// 
+    /// # Authorization
+    /// Requires admin authentication
+    /// 
+    /// # Errors
+    /// - NegativeAmount: If limit is negative
+    /// - CorridorClosed: If currency is not a 3-letter code
+    /// - Unauthorized: If caller is not admin
+    pub fn set_currency_daily_limit(env: Env, currency: String, limit: i128) -> Result<(), ContractError> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+
+        validate_non_negative(limit)?;
+
+        let currency = Currency::parse(&env, &currency)?;
+        set_currency_daily_limit(&env, &currency, limit);
+
+        Ok(())
+    }
+
+    /// Removes the default daily send limit of a currency.
+    /// 
+    /// # Authorization
+    /// Requires admin authentication
+    pub fn remove_currency_daily_limit(env: Env, currency: String) -> Result<(), ContractError> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+
+        let currency = Currency::parse(&env, &currency)?;
+        remove_currency_daily_limit(&env, &currency);
+
+        Ok(())
+    }
+
+    /// Returns the default daily send limit of a currency, if one is set.
+    pub fn get_currency_daily_limit(env: Env, currency: String) -> Option<i128> {
+        let currency = Currency::parse(&env, &currency).ok()?;
+        get_currency_daily_limit(&env, &currency)
+    }
+
+    /// Sets the global default daily send limit, used for currencies with no
+    /// default and pairs with no override.
+    /// 
+    /// # Authorization
+    /// Requires admin authentication
+    /// 
+    /// # Errors
+    /// - NegativeAmount: If limit is negative
+    /// - Unauthorized: If caller is not admin
+    pub fn set_default_daily_limit(env: Env, limit: i128) -> Result<(), ContractError> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+
+        validate_non_negative(limit)?;
+
+        set_default_daily_limit(&env, limit);
+
+        Ok(())
+    }
+
+    /// Removes the global default daily send limit.
+    /// 
+    /// # Authorization
+    /// Requires admin authentication
+    pub fn remove_default_daily_limit(env: Env) -> Result<(), ContractError> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+
+        remove_default_daily_limit(&env);
+
+        Ok(())
+    }
+
+    /// Returns the global default daily send limit, if one is set.
+    pub fn get_default_daily_limit(env: Env) -> Option<i128> {
+        get_default_daily_limit(&env)
+    }
+
+    /// Gets the daily send limit that applies to a currency-country pair.
+    /// 
+    /// Resolved from the pair's override, then the currency default, then the
+    /// global default.
+    /// 
+    /// # Parameters
     /// - `currency`: Currency code (e.g., "USD", "EUR")
     /// - `country`: Country code (e.g., "US", "UK")
     /// 
     /// # Returns
-    /// - `Some(DailyLimit)`: If a limit is configured
-    /// - `None`: If no limit is configured (unlimited)
+    /// - `Some(DailyLimit)`: The most specific limit configured, with its level
+    /// - `None`: If no limit is configured at any level (unlimited), or
+    ///   currency is not a 3-letter code
     pub fn get_daily_limit(env: Env, currency: String, country: String) -> Option<DailyLimit> {
-
-    let currency = normalize_symbol(&env, &currency);
-    let country = normalize_symbol(&env, &country);
+        let currency = Currency::parse(&env, &currency).ok()?;
+        let country = normalize_symbol(&env, &country);
 
         get_daily_limit(&env, &currency, &country)
     }
-}
+
+    /// Returns the retained transfer history of a user, oldest first.
+    ///
+    /// Records remittances the user created and payouts the user received as
+    /// an agent. Only the most recent `TRANSFER_CHUNK_SIZE * TRANSFER_HISTORY_CHUNKS`
+    /// transfers are kept.
+    pub fn get_user_transfers(env: Env, user: Address) -> Vec<TransferRecord> {
+        get_user_transfers(&env, &user)
+    }
+
+    /// Sets the insurance premium rate charged on opt-in.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `premium_bps` - Premium in basis points of the remittance amount (0 disables insurance)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Premium rate updated
+    /// * `Err(ContractError::InvalidFeeBps)` - Rate exceeds 10000 bps
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_insurance_premium(env: Env, premium_bps: u32) -> Result<(), ContractError> {
+        validate_fee_bps(premium_bps)?;
+
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_insurance_premium_bps(&env, premium_bps);
+
+        Ok(())
+    }
+
+    /// Opts a pending remittance in to escrow timeout insurance.
+    ///
+    /// Collects the premium from the sender. The premium is refunded in full if
+    /// the remittance is cancelled before acceptance, pro-rated on early refunds,
+    /// and moved to the insurance fund on payout.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to insure
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(InsurancePolicy)` - The newly opened policy
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance not pending, already insured, or insurance disabled
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address who created the remittance.
+    pub fn purchase_insurance(env: Env, remittance_id: u64) -> Result<InsurancePolicy, ContractError> {
+        let remittance = get_remittance(&env, remittance_id)?;
+        validate_remittance_pending(&remittance)?;
+
+        remittance.sender.require_auth();
+
+        let policy = open_policy(&env, &remittance)?;
+
+        let usdc_token = get_usdc_token(&env)?;
+        let token_client = token::Client::new(&env, &usdc_token);
+        token_client.transfer(
+            &remittance.sender,
+            &env.current_contract_address(),
+            &policy.premium,
+        );
+
+        emit_premium_paid(&env, remittance_id, remittance.sender, policy.premium);
+
+        Ok(policy)
+    }
+
+    /// Returns the insurance policy of a remittance, if it was insured.
+    ///
+    /// Includes the premium status and refunded amount so the premium
+    /// lifecycle can be shown on the remittance receipt.
+    pub fn get_insurance_policy(env: Env, remittance_id: u64) -> Option<InsurancePolicy> {
+        get_insurance_policy(&env, remittance_id)
+    }
+
+    /// Returns total premiums retained by the insurance fund.
+    pub fn get_insurance_fund(env: Env) -> i128 {
+        get_insurance_fund(&env)
+    }
+
+    /// Returns the total amount currently locked in escrow for pending remittances.
+    ///
+    /// Escrow holds the net amount owed to agents; fees accrue separately at
+    /// creation (see the `accounting` module).
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    pub fn get_escrow_balance(env: Env) -> i128 {
+        get_total_escrow(&env)
+    }
+
+    /// Checks the contract's token balance against its recorded liabilities.
+    ///
+    /// The invariant is `token balance + escrow and fees deposited for yield == escrow +
+    /// accumulated fees + insurance holdings + pending agent settlements + quarantined funds +
+    /// agent collateral + sponsor pools + unclaimed staking rewards`.
+    /// When it does not hold, a discrepancy event is emitted so operations can alert
+    /// on it; a shortfall is also reported as a `diag` event with the
+    /// `InsufficientEscrow` code. Callable by anyone; it does not modify accounting state.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ReconciliationReport)` - Balances used in the check and the discrepancy
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    pub fn reconcile(env: Env) -> Result<ReconciliationReport, ContractError> {
+        let usdc_token = get_usdc_token(&env)?;
+        let token_client = token::Client::new(&env, &usdc_token);
+        let token_balance = token_client.balance(&env.current_contract_address());
+        let yield_principal = get_yield_principal(&env)
+            .checked_add(get_wrapped_fees(&env))
+            .ok_or(ContractError::Overflow)?;
+
+        let escrow = get_total_escrow(&env);
+        let accumulated_fees = get_accumulated_fees(&env)?;
+        let insurance = get_premiums_held(&env)
+            .checked_add(get_insurance_fund(&env))
+            .ok_or(ContractError::Overflow)?;
+
+        let pending_settlements = get_pending_settlements(&env);
+        let quarantined = get_total_quarantined(&env);
+        let collateral = account_balance(&env, AccountClass::Collateral);
+        let sponsorship = account_balance(&env, AccountClass::Sponsorship);
+        let staking_rewards = account_balance(&env, AccountClass::StakingRewards);
+
+        let expected_balance = escrow
+            .checked_add(accumulated_fees)
+            .and_then(|total| total.checked_add(insurance))
+            .and_then(|total| total.checked_add(pending_settlements))
+            .and_then(|total| total.checked_add(quarantined))
+            .and_then(|total| total.checked_add(collateral))
+            .and_then(|total| total.checked_add(sponsorship))
+            .and_then(|total| total.checked_add(staking_rewards))
+            .ok_or(ContractError::Overflow)?;
+        let discrepancy = token_balance
+            .checked_add(yield_principal)
+            .and_then(|held| held.checked_sub(expected_balance))
+            .ok_or(ContractError::Overflow)?;
+
+        if discrepancy != 0 {
+            emit_reconcile_discrepancy(&env, token_balance, expected_balance, discrepancy);
+        }
+        if discrepancy < 0 {
+            emit_operation_failed(
+                &env,
+                symbol_short!("reconcile"),
+                env.current_contract_address(),
+                ContractError::InsufficientEscrow as u32,
+                0,
+            );
+        }
+
+        Ok(ReconciliationReport {
+            token_balance,
+            yield_principal,
+            escrow,
+            accumulated_fees,
+            insurance,
+            pending_settlements,
+            quarantined,
+            collateral,
+            sponsorship,
+            staking_rewards,
+            discrepancy,
+        })
+    }
+
+    /// Returns the double-entry ledger's debits and credits per account class.
+    ///
+    /// Escrow, settlement-token fees, commissions, insurance, quarantine and
+    /// queued settlements are each credited as funds are owed to them and
+    /// debited as they are released, against the contract's holdings. Total
+    /// debits always equal total credits.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(TrialBalance)` - Cumulative debits and credits per class, and their totals
+    pub fn get_trial_balance(env: Env) -> Result<TrialBalance, ContractError> {
+        get_trial_balance(&env)
+    }
+
+    /// Returns the balance of one ledger account class.
+    pub fn get_account_balance(env: Env, class: AccountClass) -> i128 {
+        account_balance(&env, class)
+    }
+
+    /// Retrieves summaries of the remittances with IDs in an inclusive range.
+    ///
+    /// Backed by bucketed summaries so the read cost scales with the number of
+    /// buckets touched rather than the number of IDs; fetch full records with
+    /// `get_remittance`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `from_id` - First remittance ID (inclusive)
+    /// * `to_id` - Last remittance ID (inclusive)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<RemittanceSummary>)` - Remittances in the range, ordered by ID
+    /// * `Err(ContractError::InvalidAmount)` - Range is inverted or exceeds `MAX_RANGE_QUERY` IDs
+    pub fn get_remittances_in_range(
+        env: Env,
+        from_id: u64,
+        to_id: u64,
+    ) -> Result<Vec<RemittanceSummary>, ContractError> {
+        get_remittances_in_range(&env, from_id, to_id)
+    }
+
+    /// Sets the multipliers used to weight agents during auto-matching.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `weights` - Multipliers for stake coverage, reputation, and capacity scores
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_matching_weights(env: Env, weights: MatchingWeights) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_matching_weights(&env, &weights);
+
+        Ok(())
+    }
+
+    /// Sets an agent's matching profile and adds it to the auto-matching pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Registered agent address
+    /// * `profile` - Stake coverage, reputation, and capacity scores in basis points
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Profile stored
+    /// * `Err(ContractError::AgentNotRegistered)` - Agent is not registered
+    /// * `Err(ContractError::InvalidFeeBps)` - A score exceeds 10000 bps
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_agent_profile(
+        env: Env,
+        agent: Address,
+        profile: AgentProfile,
+    ) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        validate_agent_registered(&env, &agent)?;
+        validate_fee_bps(profile.stake_coverage_bps)?;
+        validate_fee_bps(profile.reputation_bps)?;
+        validate_fee_bps(profile.capacity_bps)?;
+
+        set_agent_profile(&env, &agent, &profile);
+
+        Ok(())
+    }
+
+    /// Returns an agent's matching profile, if one was set.
+    pub fn get_agent_profile(env: Env, agent: Address) -> Option<AgentProfile> {
+        get_agent_profile(&env, &agent)
+    }
+
+    /// Creates a remittance assigned to an agent chosen by the weighted matcher.
+    ///
+    /// Agents are selected by smooth weighted round-robin over the matching pool,
+    /// so assignment frequency follows stake coverage, reputation, and remaining
+    /// capacity. The weights used are logged in the assignment event.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Address initiating the remittance
+    /// * `amount` - Amount to remit in USDC (must be positive)
+    /// * `expiry` - Optional expiry timestamp (seconds since epoch)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(remittance_id)` - ID of the created remittance
+    /// * `Err(ContractError::AgentNotRegistered)` - No eligible agent in the pool
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address.
+    pub fn create_remittance_auto(
+        env: Env,
+        sender: Address,
+        amount: i128,
+        expiry: Option<u64>,
+    ) -> Result<u64, ContractError> {
+        validate_amount(amount)?;
+        let matched = select_agent(&env)?;
+
+        let remittance_id = Self::create_remittance(
+            env.clone(),
+            sender,
+            matched.agent.clone(),
+            amount,
+            PurposeCode::Other,
+            expiry,
+            None,
+            None,
+            None,
+            None,
+        )?;
+
+        emit_agent_assigned(
+            &env,
+            remittance_id,
+            matched.agent,
+            matched.weight,
+            matched.total_weight,
+        );
+
+        Ok(remittance_id)
+    }
+
+    /// Configures how withdrawn platform fees are split between recipients.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `splits` - Recipient addresses paired with their share in basis points
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Split stored
+    /// * `Err(ContractError::InvalidFeeBps)` - Empty split, zero share, or shares not summing to 10000
+    /// * `Err(ContractError::InvalidAddress)` - A recipient appears more than once
+    /// * `Err(ContractError::Unauthorized)` - A quorum is configured; propose `CriticalOp::SetFeeSplit`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin, and no quorum to be configured.
+    pub fn set_fee_split(env: Env, splits: Vec<(Address, u32)>) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+        require_no_quorum(&env)?;
+
+        validate_fee_split(&splits)?;
+
+        set_fee_split(&env, &splits);
+        Ok(())
     }
+
+    /// Returns the configured fee split.
+    pub fn get_fee_split(env: Env) -> Vec<(Address, u32)> {
+        get_fee_split(&env)
+    }
+
+    /// Distributes all accumulated platform fees across the configured recipients.
+    ///
+    /// Each recipient receives its share rounded down; any rounding remainder goes
+    /// to the last recipient so the full balance is paid out. Split recipients
+    /// are exempt from the treasury restriction on `withdraw_fees`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(i128)` - Total amount distributed
+    /// * `Err(ContractError::NoFeesToWithdraw)` - No fees available
+    /// * `Err(ContractError::InvalidStatus)` - No fee split is configured
+    /// * `Err(ContractError::Unauthorized)` - A quorum is configured; propose `CriticalOp::DistributeFees`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin, and no quorum to be configured.
+    pub fn distribute_fees(env: Env) -> Result<i128, ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+        require_no_quorum(&env)?;
+
+        Self::pay_out_fee_split(&env)
+    }
+
+    /// Pays the withdrawable fees out across the fee split.
+    ///
+    /// Shared by `distribute_fees` and `execute_op`; callers authorize the
+    /// distribution.
+    fn pay_out_fee_split(env: &Env) -> Result<i128, ContractError> {
+        check_op_not_paused(env, PausableOp::WithdrawFees)?;
+        let fees = get_withdrawable_fees(env)?;
+        validate_fees_available(fees)?;
+
+        let splits = get_fee_split(env);
+        if splits.is_empty() {
+            return Err(ContractError::InvalidStatus);
+        }
+        unwrap_fees_for(env, fees)?;
+
+        let usdc_token = get_usdc_token(env)?;
+        let token_client = token::Client::new(env, &usdc_token);
+
+        let last = splits.len() - 1;
+        let mut distributed: i128 = 0;
+        for (i, (recipient, share_bps)) in splits.iter().enumerate() {
+            let amount = if i as u32 == last {
+                fees.checked_sub(distributed).ok_or(ContractError::Overflow)?
+            } else {
+                fees.checked_mul(share_bps as i128)
+                    .ok_or(ContractError::Overflow)?
+                    .checked_div(10000)
+                    .ok_or(ContractError::Overflow)?
+            };
+            if amount > 0 {
+                token_client.transfer(&env.current_contract_address(), &recipient, &amount);
+            }
+            distributed = distributed.checked_add(amount).ok_or(ContractError::Overflow)?;
+            emit_fees_distributed(env, recipient, share_bps, amount);
+        }
+
+        book_fee_withdrawal(env, distributed)?;
+
+        Ok(distributed)
+    }
+
+    /// Chooses how the calling agent's confirmed payouts are settled.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Address of the agent
+    /// * `schedule` - Instant, daily netting, weekly netting, or on-demand netting
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Schedule stored; applies to payouts confirmed from now on
+    /// * `Err(ContractError::AgentNotRegistered)` - Agent is not registered
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent address.
+    pub fn set_settlement_schedule(
+        env: Env,
+        agent: Address,
+        schedule: SettlementSchedule,
+    ) -> Result<(), ContractError> {
+        validate_agent_registered(&env, &agent)?;
+
+        agent.require_auth();
+
+        set_settlement_schedule(&env, &agent, schedule);
+        Ok(())
+    }
+
+    /// Returns an agent's settlement schedule.
+    pub fn get_settlement_schedule(env: Env, agent: Address) -> SettlementSchedule {
+        get_settlement_schedule(&env, &agent)
+    }
+
+    /// Returns the agent's open settlement batch, if any.
+    pub fn get_open_settlement(env: Env, agent: Address) -> Option<AgentSettlement> {
+        get_open_settlement(&env, &agent)
+    }
+
+    /// Transfers an agent's batched payouts once the batch window has closed.
+    ///
+    /// Callable by anyone, so settlement does not depend on the agent or admin
+    /// being online.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Address of the agent to settle
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(AgentSettlement)` - The executed settlement batch
+    /// * `Err(ContractError::InvalidStatus)` - No open batch, or its window has not closed
+    pub fn run_settlement(env: Env, agent: Address) -> Result<AgentSettlement, ContractError> {
+        let settlement = take_due_settlement(&env, &agent)?;
+
+        if settlement.amount > 0 {
+            let usdc_token = get_usdc_token(&env)?;
+            let token_client = token::Client::new(&env, &usdc_token);
+            token_client.transfer(&env.current_contract_address(), &agent, &settlement.amount);
+        }
+
+        emit_agent_settled(
+            &env,
+            agent,
+            settlement.window_end,
+            settlement.remittance_ids.len(),
+            settlement.amount,
+        );
+
+        Ok(settlement)
+    }
+
+    /// Transfers an agent's accrued payouts in a single transfer, without
+    /// waiting for the batch window to close.
+    ///
+    /// Intended for agents on the on-demand schedule, whose confirmed payouts
+    /// accrue to a receivable balance until they collect it.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `caller` - The agent, or an operator collecting on its behalf
+    /// * `agent` - Address of the agent collecting its payouts
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(AgentSettlement)` - The executed settlement batch; the payouts always go to `agent`
+    /// * `Err(ContractError::InvalidStatus)` - The agent has no accrued payouts
+    /// * `Err(ContractError::Unauthorized)` - `caller` is neither the agent, an operator nor an admin
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from `caller`.
+    pub fn settle_agent(env: Env, caller: Address, agent: Address) -> Result<AgentSettlement, ContractError> {
+        if caller == agent {
+            agent.require_auth();
+        } else {
+            require_operator(&env, &caller)?;
+        }
+
+        let settlement = take_open_settlement(&env, &agent)?;
+
+        if settlement.amount > 0 {
+            let usdc_token = get_usdc_token(&env)?;
+            let token_client = token::Client::new(&env, &usdc_token);
+            token_client.transfer(&env.current_contract_address(), &agent, &settlement.amount);
+        }
+
+        emit_agent_settled(
+            &env,
+            agent,
+            env.ledger().timestamp(),
+            settlement.remittance_ids.len(),
+            settlement.amount,
+        );
+
+        Ok(settlement)
+    }
+
+    /// Confirms a remittance payout and records the hash of the signed cash receipt.
+    ///
+    /// Behaves exactly like `confirm_payout`, additionally storing `receipt_hash`
+    /// so auditors can later verify the off-chain receipt against the chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to confirm
+    /// * `receipt_hash` - SHA-256 hash of the signed receipt document
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Payout confirmed and receipt hash recorded
+    /// * `Err(ContractError)` - Any error returned by `confirm_payout`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent address assigned to the remittance.
+    pub fn confirm_payout_with_receipt(
+        env: Env,
+        remittance_id: u64,
+        receipt_hash: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        Self::confirm_payout(env.clone(), remittance_id)?;
+
+        let remittance = get_remittance(&env, remittance_id)?;
+        set_receipt_hash(&env, remittance_id, &receipt_hash);
+        emit_receipt_recorded(&env, remittance_id, remittance.agent, receipt_hash);
+
+        Ok(())
+    }
+
+    /// Returns the receipt hash recorded at payout, if any.
+    pub fn get_receipt_hash(env: Env, remittance_id: u64) -> Option<BytesN<32>> {
+        get_receipt_hash(&env, remittance_id)
+    }
+
+    /// Limits how many remittances a single sender can create within a ledger window.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `max_creates` - Remittances allowed per sender per window (0 disables the limit)
+    /// * `window_ledgers` - Window length in ledgers
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_create_rate_limit(
+        env: Env,
+        max_creates: u32,
+        window_ledgers: u32,
+    ) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_create_rate_limit(
+            &env,
+            &CreateRateLimit {
+                max_creates,
+                window_ledgers,
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the current remittance creation limit.
+    pub fn get_create_rate_limit(env: Env) -> CreateRateLimit {
+        get_create_rate_limit(&env)
+    }
+
+    /// Stops senders who cancel repeatedly from creating remittances for a while.
+    ///
+    /// A sender who cancels `max_cancels` remittances within `window_seconds`
+    /// cannot create remittances for `cooldown_seconds`, which stops
+    /// create-and-cancel loops that game FX rate locks. Only cancellations by
+    /// the sender count.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `max_cancels` - Cancellations per window that start a cooldown (0 disables it)
+    /// * `window_seconds` - Window length in seconds
+    /// * `cooldown_seconds` - Cooldown length in seconds
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_cancel_cooldown(
+        env: Env,
+        max_cancels: u32,
+        window_seconds: u64,
+        cooldown_seconds: u64,
+    ) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_cancel_cooldown(
+            &env,
+            &CancelCooldown {
+                max_cancels,
+                window_seconds,
+                cooldown_seconds,
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the current cancellation cooldown configuration.
+    pub fn get_cancel_cooldown(env: Env) -> CancelCooldown {
+        get_cancel_cooldown(&env)
+    }
+
+    /// Returns when a sender's cancellation cooldown ends, or `None` when the
+    /// sender can create remittances.
+    pub fn get_cooldown(env: Env, sender: Address) -> Option<u64> {
+        get_cooldown(&env, &sender)
+    }
+
+    /// Creates an organization owned by the caller.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `owner` - Address that will manage the organization's spenders and templates
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - ID of the new organization
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the owner address.
+    pub fn create_organization(env: Env, owner: Address) -> Result<u64, ContractError> {
+        owner.require_auth();
+
+        let org = create_organization(&env, &owner)?;
+        Ok(org.id)
+    }
+
+    /// Authorizes or revokes a spender for an organization's templates.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `org_id` - ID of the organization
+    /// * `spender` - Address of the treasurer or member
+    /// * `authorized` - Whether the spender may send from the organization's templates
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Spender updated
+    /// * `Err(ContractError::InvalidStatus)` - Organization does not exist
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the organization owner.
+    pub fn set_org_spender(
+        env: Env,
+        org_id: u64,
+        spender: Address,
+        authorized: bool,
+    ) -> Result<(), ContractError> {
+        let org = get_organization(&env, org_id)?;
+        org.owner.require_auth();
+
+        set_org_spender(&env, org_id, &spender, authorized);
+        Ok(())
+    }
+
+    /// Publishes a shared remittance template for an organization.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `org_id` - ID of the organization
+    /// * `agent` - Agent that pays out remittances sent from the template
+    /// * `amount` - Amount of each remittance
+    /// * `max_uses` - Maximum number of remittances that can be sent from the template
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - ID of the new template
+    /// * `Err(ContractError::InvalidStatus)` - Organization does not exist
+    /// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - Amount is not positive
+    /// * `Err(ContractError::InvalidAmount)` - Usage cap is zero
+    /// * `Err(ContractError::AgentNotRegistered)` - Agent is not registered
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the organization owner.
+    pub fn publish_template(
+        env: Env,
+        org_id: u64,
+        agent: Address,
+        amount: i128,
+        max_uses: u32,
+    ) -> Result<u64, ContractError> {
+        let org = get_organization(&env, org_id)?;
+        org.owner.require_auth();
+
+        validate_amount(amount)?;
+        validate_agent_registered(&env, &agent)?;
+        if max_uses == 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        let template = RemittanceTemplate {
+            id: next_template_id(&env)?,
+            org_id,
+            agent: agent.clone(),
+            amount,
+            max_uses,
+            uses: 0,
+            active: true,
+        };
+        set_template(&env, &template);
+
+        emit_template_published(&env, org_id, template.id, agent, amount, max_uses);
+
+        Ok(template.id)
+    }
+
+    /// Deactivates a template so it can no longer be used.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the organization owner.
+    pub fn deactivate_template(env: Env, template_id: u64) -> Result<(), ContractError> {
+        let mut template = get_template(&env, template_id)?;
+        let org = get_organization(&env, template.org_id)?;
+        org.owner.require_auth();
+
+        template.active = false;
+        set_template(&env, &template);
+        Ok(())
+    }
+
+    /// Retrieves a remittance template.
+    pub fn get_template(env: Env, template_id: u64) -> Result<RemittanceTemplate, ContractError> {
+        get_template(&env, template_id)
+    }
+
+    /// Sends a remittance from an organization template.
+    ///
+    /// The remittance is funded by the spender and counts against the template's
+    /// usage cap.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `spender` - Authorized spender funding the remittance
+    /// * `template_id` - ID of the template to use
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - ID of the created remittance
+    /// * `Err(ContractError::Unauthorized)` - Spender is not authorized by the organization
+    /// * `Err(ContractError::InvalidStatus)` - Template is missing, inactive, or exhausted
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the spender address.
+    pub fn send_from_template(
+        env: Env,
+        spender: Address,
+        template_id: u64,
+    ) -> Result<u64, ContractError> {
+        let template = get_template(&env, template_id)?;
+        let org = get_organization(&env, template.org_id)?;
+        if !is_org_spender(&env, &org, &spender) {
+            return Err(ContractError::Unauthorized);
+        }
+
+        let template = use_template(&env, template_id)?;
+        let remittance_id = Self::create_remittance(
+            env.clone(),
+            spender.clone(),
+            template.agent.clone(),
+            template.amount,
+            PurposeCode::Other,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )?;
+
+        emit_template_used(&env, template_id, spender, remittance_id, template.uses);
+
+        Ok(remittance_id)
+    }
+
+    /// Reserves the next remittance ID for a sender without escrowing funds.
+    ///
+    /// The ID can be printed on offline pickup paperwork and filled later with
+    /// `create_reserved`. Reserved IDs are never handed out to other remittances.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Address of the sender reserving the ID
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - The reserved remittance ID
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address.
+    pub fn reserve_id(env: Env, sender: Address) -> Result<u64, ContractError> {
+        sender.require_auth();
+
+        let counter = get_remittance_counter(&env)?;
+        let remittance_id = counter.checked_add(1).ok_or(ContractError::Overflow)?;
+        set_remittance_counter(&env, remittance_id);
+        set_reservation(&env, remittance_id, &sender);
+
+        emit_remittance_reserved(&env, remittance_id, sender);
+
+        Ok(remittance_id)
+    }
+
+    /// Creates a remittance under an ID previously reserved with `reserve_id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - Reserved remittance ID to fill
+    /// * `agent` - Address of the registered agent who will receive the payout
+    /// * `amount` - Amount to remit in USDC (must be positive)
+    /// * `expiry` - Optional expiry timestamp after which settlement fails
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Remittance created under the reserved ID
+    /// * `Err(ContractError::RemittanceNotFound)` - ID is not reserved or was already filled
+    /// * `Err(ContractError)` - Any validation error returned by `create_remittance`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender that reserved the ID.
+    pub fn create_reserved(
+        env: Env,
+        remittance_id: u64,
+        agent: Address,
+        amount: i128,
+        expiry: Option<u64>,
+    ) -> Result<(), ContractError> {
+        let sender = get_reservation(&env, remittance_id).ok_or(ContractError::RemittanceNotFound)?;
+
+        validate_create_remittance_request(&env, &sender, &agent, amount)?;
+
+        sender.require_auth();
+
+        Self::open_remittance(&env, remittance_id, &sender, &agent, amount, PurposeCode::Other, expiry)?;
+        remove_reservation(&env, remittance_id);
+
+        Ok(())
+    }
+
+    /// Returns the sender holding an unfilled reservation for `remittance_id`, if any.
+    pub fn get_reservation(env: Env, remittance_id: u64) -> Option<Address> {
+        get_reservation(&env, remittance_id)
+    }
+
+    /// Amends the amount and agent of a pending remittance.
+    ///
+    /// The fee is recomputed at the current platform rate. If the amount grows,
+    /// the difference is pulled from the sender; if it shrinks, the difference
+    /// is refunded. High-risk senders need a matured intent for the new agent and
+    /// amount when the agent changes or the amount increases.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to amend
+    /// * `new_amount` - Corrected amount
+    /// * `new_agent` - Agent to pay out through (may be unchanged)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Remittance amended
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not Pending or was partially released
+    /// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - New amount is zero or negative
+    /// * `Err(ContractError::AgentNotRegistered)` - New agent is not registered
+    /// * `Err(ContractError::AgentSuspended)` - New agent is suspended
+    /// * `Err(ContractError::PurposeNotAllowed)` - New agent's corridor does not accept the purpose
+    /// * `Err(ContractError::AmountAboveMaximum)` - New amount exceeds the token or corridor cap
+    /// * `Err(ContractError::AmountBelowMinimum)` - New amount is below the token minimum
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender of the remittance.
+    pub fn amend_remittance(
+        env: Env,
+        remittance_id: u64,
+        new_amount: i128,
+        new_agent: Address,
+    ) -> Result<(), ContractError> {
+        let mut remittance = validate_remittance_exists(&env, remittance_id)?;
+        validate_remittance_pending(&remittance)?;
+        validate_create_remittance_request(&env, &remittance.sender, &new_agent, new_amount)?;
+        if get_released_totals(&env, remittance_id).amount > 0 {
+            return Err(ContractError::InvalidStatus);
+        }
+
+        remittance.sender.require_auth();
+
+        let old_amount = remittance.amount;
+        if new_agent != remittance.agent {
+            check_purpose_allowed(&env, &new_agent, remittance.purpose)?;
+            check_agent_capacity(&env, &new_agent)?;
+        }
+        if new_agent != remittance.agent || new_amount > old_amount {
+            consume_intent(&env, &remittance.sender, &new_agent, new_amount)?;
+        }
+
+        // The loyalty discount is redrawn against the new fee
+        restore_fee_credit(&env, &remittance.sender, remittance_id, old_amount, old_amount)?;
+        let new_fee = calculate_platform_fee(&env, Some(&remittance.sender), Some(&new_agent), new_amount)?;
+        let new_fee = new_fee - apply_fee_credit(&env, &remittance.sender, remittance_id, new_fee);
+        // Express remittances keep paying the surcharge on the new amount
+        let new_fee = match remittance.priority {
+            Priority::Standard => new_fee,
+            Priority::Express => new_fee
+                .checked_add(express_surcharge(&env, new_amount)?)
+                .ok_or(ContractError::Overflow)?,
+        };
+
+        let usdc_token = get_usdc_token(&env)?;
+        let token_client = token::Client::new(&env, &usdc_token);
+        let difference = new_amount.checked_sub(old_amount).ok_or(ContractError::Overflow)?;
+        if difference > 0 {
+            token_client.transfer(&remittance.sender, &env.current_contract_address(), &difference);
+        } else if difference < 0 {
+            token_client.transfer(&env.current_contract_address(), &remittance.sender, &-difference);
+        }
+        // Rebook the remittance at its new amount and fee
+        book_refund(&env, old_amount, remittance.fee)?;
+        book_remittance(&env, new_amount, new_fee)?;
+
+        if new_agent != remittance.agent {
+            pop_assignment(&env, &remittance.agent, remittance_id);
+            push_assignment(&env, &new_agent, remittance_id, remittance.priority);
+        }
+
+        remittance.amount = new_amount;
+        remittance.fee = new_fee;
+        remittance.agent = new_agent.clone();
+        set_remittance(&env, remittance_id, &remittance);
+
+        emit_remittance_amended(&env, remittance_id, new_agent, old_amount, new_amount, new_fee);
+
+        Ok(())
+    }
+
+    /// Sets whether an agent is available to accept new remittances.
+    ///
+    /// Remittances already assigned to the agent are unaffected and can still
+    /// be confirmed.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Address of the agent
+    /// * `available` - `false` while the agent is offline
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Availability updated
+    /// * `Err(ContractError::AgentNotRegistered)` - Agent is not registered
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent address.
+    pub fn set_availability(env: Env, agent: Address, available: bool) -> Result<(), ContractError> {
+        validate_agent_registered(&env, &agent)?;
+
+        agent.require_auth();
+
+        set_agent_available(&env, &agent, available);
+        emit_agent_availability(&env, agent, available);
+
+        Ok(())
+    }
+
+    /// Sets or clears the daily UTC working hours of an agent.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Address of the agent
+    /// * `hours` - Working hours, or `None` to accept remittances at any time
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Working hours updated
+    /// * `Err(ContractError::AgentNotRegistered)` - Agent is not registered
+    /// * `Err(ContractError::InvalidAmount)` - An hour is outside 0-23 or the window is empty
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent address.
+    pub fn set_working_hours(
+        env: Env,
+        agent: Address,
+        hours: Option<WorkingHours>,
+    ) -> Result<(), ContractError> {
+        validate_agent_registered(&env, &agent)?;
+
+        agent.require_auth();
+
+        if let Some(window) = &hours {
+            if window.start_hour > 23 || window.end_hour > 23 || window.start_hour == window.end_hour {
+                return Err(ContractError::InvalidAmount);
+            }
+        }
+
+        set_agent_working_hours(&env, &agent, &hours);
+        Ok(())
+    }
+
+    /// Returns the daily working hours of an agent, if configured.
+    pub fn get_working_hours(env: Env, agent: Address) -> Option<WorkingHours> {
+        get_agent_working_hours(&env, &agent)
+    }
+
+    /// Checks if an agent is available to accept new remittances right now.
+    pub fn is_agent_available(env: Env, agent: Address) -> bool {
+        is_agent_available(&env, &agent)
+    }
+
+    /// Confirms delivery of part of a remittance and releases that share of escrow.
+    ///
+    /// The agent receives the tranche amount minus a pro-rated share of the fee.
+    /// The unreleased remainder stays in escrow: it can be confirmed with further
+    /// tranches or `confirm_payout`, or refunded with `cancel_remittance`. A
+    /// tranche covering the whole remainder completes the remittance.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance
+    /// * `amount` - Portion of the remittance amount delivered
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PayoutTranche)` - The recorded tranche
+    /// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - Amount is not positive
+    /// * `Err(ContractError::InvalidAmount)` - Amount exceeds the unreleased amount
+    /// * `Err(ContractError)` - Any error returned by `confirm_payout` validation
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent address assigned to the remittance.
+    pub fn confirm_partial(
+        env: Env,
+        remittance_id: u64,
+        amount: i128,
+    ) -> Result<PayoutTranche, ContractError> {
+        validate_amount(amount)?;
+        let remittance = validate_confirm_payout_request(&env, remittance_id)?;
+
+        remittance.agent.require_auth();
+
+        let remaining = remittance
+            .amount
+            .checked_sub(get_released_totals(&env, remittance_id).amount)
+            .ok_or(ContractError::Overflow)?;
+        if amount == remaining {
+            // Final tranche: settle the remittance, then keep the tranche for receipts
+            Self::confirm_payout(env.clone(), remittance_id)?;
+            let tranche = record_tranche(&env, &remittance, amount)?;
+            emit_tranche_released(&env, remittance_id, remittance.agent, amount, tranche.fee, 0);
+            return Ok(tranche);
+        }
+
+        // A recipient contract is paid the whole remittance in one payout
+        if get_payout_recipient(&env, remittance_id).is_some() {
+            return Err(ContractError::InvalidStatus);
+        }
+
+        let tranche = record_tranche(&env, &remittance, amount)?;
+        let payout_amount = book_payout(&env, amount, tranche.fee)?;
+
+        if !queue_payout(&env, &remittance.agent, remittance_id, payout_amount)? {
+            let usdc_token = get_usdc_token(&env)?;
+            let token_client = token::Client::new(&env, &usdc_token);
+            token_client.transfer(
+                &env.current_contract_address(),
+                &remittance.agent,
+                &payout_amount,
+            );
+        }
+
+        let still_held = remaining.checked_sub(amount).ok_or(ContractError::Overflow)?;
+        emit_tranche_released(&env, remittance_id, remittance.agent, amount, tranche.fee, still_held);
+
+        Ok(tranche)
+    }
+
+    /// Returns the delivery tranches confirmed for a remittance.
+    pub fn get_tranches(env: Env, remittance_id: u64) -> Vec<PayoutTranche> {
+        get_tranches(&env, remittance_id)
+    }
+
+    /// Confirms payouts for several remittances assigned to one agent.
+    ///
+    /// Entries that fail validation are skipped instead of aborting the batch
+    /// and are recorded in the agent's dead-letter queue with their error code
+    /// (see `get_failed_operations`), so they can be retried individually.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Agent confirming the payouts
+    /// * `remittance_ids` - IDs of the remittances to confirm
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u64>)` - IDs of the remittances that were paid out
+    /// * `Err(ContractError::InvalidAmount)` - Batch is empty
+    /// * `Err(ContractError::LimitExceeded)` - Batch exceeds MAX_BATCH_SIZE
+    /// * `Err(ContractError::ContractPaused)` - Contract is paused
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent address.
+    pub fn batch_confirm_payouts(
+        env: Env,
+        agent: Address,
+        remittance_ids: Vec<u64>,
+    ) -> Result<Vec<u64>, ContractError> {
+        validate_not_paused(&env)?;
+        if remittance_ids.is_empty() {
+            return Err(ContractError::InvalidAmount);
+        }
+        if remittance_ids.len() > MAX_BATCH_SIZE {
+            return Err(log_limit_error(
+                &env,
+                symbol_short!("batch"),
+                ContractError::LimitExceeded,
+                remittance_ids.len() as i128,
+                MAX_BATCH_SIZE as i128,
+            ));
+        }
+
+        agent.require_auth();
+
+        let mut settled = Vec::new(&env);
+        for remittance_id in remittance_ids.iter() {
+            let validated = validate_confirm_payout_request(&env, remittance_id).and_then(|remittance| {
+                if remittance.agent != agent {
+                    return Err(ContractError::Unauthorized);
+                }
+                check_rate_limit(&env, &remittance.sender)?;
+                Ok(remittance)
+            });
+
+            match validated {
+                Ok(remittance) => {
+                    Self::settle_payout(&env, remittance, None, Some(agent.clone()))?;
+                    settled.push_back(remittance_id);
+                }
+                Err(error) => {
+                    record_failed_operation(&env, &agent, symbol_short!("confirm"), remittance_id, error);
+                    emit_batch_entry_failed(&env, agent.clone(), symbol_short!("confirm"), remittance_id, error as u32);
+                }
+            }
+        }
+
+        Ok(settled)
+    }
+
+    /// Creates several remittances funded by one sender.
+    ///
+    /// Entries that fail validation are skipped instead of aborting the batch
+    /// and are recorded in the sender's dead-letter queue under their index in
+    /// `entries` (see `get_failed_operations`).
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Sender funding the remittances
+    /// * `entries` - Agent, amount and expiry of each remittance
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u64>)` - IDs of the remittances that were created, in entry order
+    /// * `Err(ContractError::InvalidAmount)` - Batch is empty
+    /// * `Err(ContractError::LimitExceeded)` - Batch exceeds MAX_BATCH_SIZE
+    /// * `Err(ContractError::ContractPaused)` - Contract is paused
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address.
+    pub fn batch_create_remittances(
+        env: Env,
+        sender: Address,
+        entries: Vec<BatchCreateEntry>,
+    ) -> Result<Vec<u64>, ContractError> {
+        validate_not_paused(&env)?;
+        if entries.is_empty() {
+            return Err(ContractError::InvalidAmount);
+        }
+        if entries.len() > MAX_BATCH_SIZE {
+            return Err(log_limit_error(
+                &env,
+                symbol_short!("batch"),
+                ContractError::LimitExceeded,
+                entries.len() as i128,
+                MAX_BATCH_SIZE as i128,
+            ));
+        }
+
+        sender.require_auth();
+
+        let mut created = Vec::new(&env);
+        let mut counter = get_remittance_counter(&env)?;
+        for (index, entry) in entries.iter().enumerate() {
+            let remittance_id = counter.checked_add(1).ok_or(ContractError::Overflow)?;
+            let result = validate_create_remittance_request(&env, &sender, &entry.agent, entry.amount)
+                .and_then(|_| {
+                    Self::open_remittance(&env, remittance_id, &sender, &entry.agent, entry.amount, PurposeCode::Other, entry.expiry)
+                });
+
+            match result {
+                Ok(()) => {
+                    counter = remittance_id;
+                    created.push_back(remittance_id);
+                }
+                Err(error) => {
+                    record_failed_operation(&env, &sender, symbol_short!("create"), index as u64, error);
+                    emit_batch_entry_failed(&env, sender.clone(), symbol_short!("create"), index as u64, error as u32);
+                }
+            }
+        }
+        set_remittance_counter(&env, counter);
+
+        Ok(created)
+    }
+
+    /// Returns failed batch entries recorded for `caller`, oldest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `caller` - Agent or sender that submitted the batches
+    /// * `start` - Index of the first failure to return
+    /// * `limit` - Maximum number of failures to return (capped at MAX_FAILED_PAGE)
+    pub fn get_failed_operations(env: Env, caller: Address, start: u32, limit: u32) -> Vec<FailedOperation> {
+        get_failed_operations(&env, &caller, start, limit)
+    }
+
+    /// Returns the number of failed batch entries recorded for `caller`.
+    pub fn get_failed_operation_count(env: Env, caller: Address) -> u32 {
+        get_failed_operation_count(&env, &caller)
+    }
+
+    /// Registers the analytics contract that receives a record for every completed remittance.
+    ///
+    /// The sink must expose `on_completion(record: CompletionRecord)`. Mirroring is
+    /// best-effort and never blocks a payout.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sink` - Analytics contract address
+    /// * `max_records_per_ledger` - Maximum number of records mirrored per ledger
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_analytics_sink(env: Env, sink: Address, max_records_per_ledger: u32) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        let config = AnalyticsConfig {
+            sink,
+            enabled: true,
+            max_records_per_ledger,
+        };
+        set_analytics_config(&env, &config);
+        emit_analytics_sink_set(&env, config.sink, config.enabled, config.max_records_per_ledger);
+
+        Ok(())
+    }
+
+    /// Turns mirroring to the registered analytics sink on or off.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Toggle updated
+    /// * `Err(ContractError::InvalidStatus)` - No analytics sink is registered
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_analytics_enabled(env: Env, enabled: bool) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        let mut config = get_analytics_config(&env).ok_or(ContractError::InvalidStatus)?;
+        config.enabled = enabled;
+        set_analytics_config(&env, &config);
+        emit_analytics_sink_set(&env, config.sink, config.enabled, config.max_records_per_ledger);
+
+        Ok(())
+    }
+
+    /// Returns the analytics sink registration, if any.
+    pub fn get_analytics_config(env: Env) -> Option<AnalyticsConfig> {
+        get_analytics_config(&env)
+    }
+
+    /// Returns the volume and fee counters of a daily epoch.
+    ///
+    /// Epoch `n` covers ledger timestamps from `n * 86400` up to, but not
+    /// including, `(n + 1) * 86400`, so it is the UTC day number.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `epoch` - Epoch to read
+    ///
+    /// # Returns
+    ///
+    /// * `EpochStats` - Counters of the epoch, all zero if nothing happened in it
+    pub fn get_epoch_stats(env: Env, epoch: u64) -> EpochStats {
+        get_epoch_stats(&env, epoch)
+    }
+
+    /// Returns the epoch of the current ledger timestamp.
+    pub fn get_current_epoch(env: Env) -> u64 {
+        epoch_of(env.ledger().timestamp())
+    }
+
+    /// Delegates payout rights from an agent to a sub-agent (e.g. field staff).
+    ///
+    /// The sub-agent can then confirm payouts with `confirm_payout_as`; payouts,
+    /// commission and reputation still accrue to the master agent.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Address of the master agent
+    /// * `sub_agent` - Address receiving payout rights
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Sub-agent added
+    /// * `Err(ContractError::AgentNotRegistered)` - Master agent is not registered
+    /// * `Err(ContractError::InvalidAddress)` - Sub-agent is the master itself
+    /// * `Err(ContractError::InvalidStatus)` - Sub-agent already has a master
+    /// * `Err(ContractError::LimitExceeded)` - Agent already has MAX_SUB_AGENTS sub-agents
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent address.
+    pub fn add_sub_agent(env: Env, agent: Address, sub_agent: Address) -> Result<(), ContractError> {
+        validate_agent_registered(&env, &agent)?;
+
+        agent.require_auth();
+
+        add_sub_agent(&env, &agent, &sub_agent)?;
+        emit_sub_agent_changed(&env, agent, sub_agent, true);
+
+        Ok(())
+    }
+
+    /// Revokes payout rights previously delegated to a sub-agent.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Sub-agent removed
+    /// * `Err(ContractError::InvalidStatus)` - Address is not a sub-agent of `agent`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent address.
+    pub fn remove_sub_agent(env: Env, agent: Address, sub_agent: Address) -> Result<(), ContractError> {
+        agent.require_auth();
+
+        remove_sub_agent(&env, &agent, &sub_agent)?;
+        emit_sub_agent_changed(&env, agent, sub_agent, false);
+
+        Ok(())
+    }
+
+    /// Returns the sub-agents of an agent.
+    pub fn get_sub_agents(env: Env, agent: Address) -> Vec<Address> {
+        get_sub_agents(&env, &agent)
+    }
+
+    /// Returns the master agent of a sub-agent, if any.
+    pub fn get_master_agent(env: Env, sub_agent: Address) -> Option<Address> {
+        get_master_agent(&env, &sub_agent)
+    }
+
+    /// Confirms a payout on behalf of the assigned agent.
+    ///
+    /// Behaves like `confirm_payout` but is authorized by `caller`, which must
+    /// be the assigned agent or one of its sub-agents. The payout is always
+    /// credited to the assigned agent.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `caller` - Assigned agent or one of its sub-agents
+    /// * `remittance_id` - ID of the remittance to confirm
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Payout confirmed
+    /// * `Err(ContractError::Unauthorized)` - Caller may not confirm for the assigned agent
+    /// * Any error returned by `confirm_payout`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the caller address.
+    pub fn confirm_payout_as(env: Env, caller: Address, remittance_id: u64) -> Result<(), ContractError> {
+        let remittance = validate_confirm_payout_request(&env, remittance_id)?;
+        if !can_confirm_for(&env, &remittance.agent, &caller) {
+            return Err(ContractError::Unauthorized);
+        }
+
+        caller.require_auth();
+
+        check_rate_limit(&env, &remittance.sender)?;
+
+        let agent = remittance.agent.clone();
+        Self::settle_payout(&env, remittance, None, Some(caller.clone()))?;
+        if caller != agent {
+            emit_sub_agent_payout(&env, remittance_id, agent, caller);
+        }
+
+        Ok(())
+    }
+
+    /// Sets the TTL policy of a class of persistent data.
+    ///
+    /// Writes to an entry of the class extend its TTL to `extend_to` ledgers
+    /// once it has fallen below `threshold`. Longer TTLs cost more rent but
+    /// keep records out of the archive for longer. The change applies from
+    /// the next write to each entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `class` - Remittances, indexes or history
+    /// * `policy` - Threshold and extension in ledgers
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Policy stored
+    /// * `Err(ContractError::InvalidAmount)` - `extend_to` is zero or below `threshold`
+    /// * `Err(ContractError::LimitExceeded)` - `extend_to` exceeds the network's maximum TTL
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_ttl_policy(env: Env, class: TtlClass, policy: TtlPolicy) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_class_ttl_policy(&env, class, &policy)?;
+        emit_ttl_policy_set(&env, class, policy.threshold, policy.extend_to);
+
+        Ok(())
+    }
+
+    /// Returns the TTL policy of every data class, defaults included.
+    pub fn get_ttl_policy(env: Env) -> TtlConfig {
+        get_ttl_config(&env)
+    }
+
+    /// Appoints or removes an operator.
+    ///
+    /// Operators are keeper bots allowed to call `archive_remittances` and
+    /// `settle_agent`, so they can run with low-privilege keys instead of the
+    /// admin's.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `operator` - Address to appoint or remove
+    /// * `enabled` - Whether the address is an operator
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_operator(env: Env, operator: Address, enabled: bool) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_operator(&env, &operator, enabled);
+        emit_operator_set(&env, caller, operator, enabled);
+
+        Ok(())
+    }
+
+    /// Returns whether an address is an operator.
+    pub fn is_operator(env: Env, operator: Address) -> bool {
+        is_operator(&env, &operator)
+    }
+
+    /// Archives remittances closed before `before_ledger` to cut ledger rent.
+    ///
+    /// Each archived remittance's full record is replaced with a compact
+    /// `ArchivedRemittance` (ID, hash of the full record, final status). Safe
+    /// to call repeatedly from a cron job; each call examines at most `limit` IDs.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `caller` - Operator or admin running the archival
+    /// * `before_ledger` - Only remittances closed before this ledger are archived
+    /// * `limit` - Maximum number of remittance IDs to examine (at most MAX_ARCHIVE_BATCH)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u64>)` - IDs archived by this call
+    /// * `Err(ContractError::InvalidAmount)` - Limit is zero
+    /// * `Err(ContractError::LimitExceeded)` - Limit exceeds MAX_ARCHIVE_BATCH
+    /// * `Err(ContractError::Unauthorized)` - `caller` is neither an operator nor an admin
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from `caller`, an operator or an admin.
+    pub fn archive_remittances(
+        env: Env,
+        caller: Address,
+        before_ledger: u32,
+        limit: u32,
+    ) -> Result<Vec<u64>, ContractError> {
+        require_operator(&env, &caller)?;
+
+        let archived = archive_remittances(&env, before_ledger, limit)?;
+        if !archived.is_empty() {
+            emit_remittances_archived(&env, archived.len(), before_ledger);
+        }
+
+        Ok(archived)
+    }
+
+    /// Returns the compact record of an archived remittance, if any.
+    pub fn get_archived_remittance(env: Env, remittance_id: u64) -> Option<ArchivedRemittance> {
+        get_archived_remittance(&env, remittance_id)
+    }
+
+    /// Commits a Merkle root of the remittance records in an ID range.
+    ///
+    /// Off-chain backups keep the full records; once archival has replaced
+    /// them on-chain with a hash, `verify_record` still proves a backed-up
+    /// record against the committed root. See `hashing.rs` for how leaves and
+    /// the tree are built.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `range_start` - First remittance ID to commit
+    /// * `range_end` - Last remittance ID to commit, inclusive
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u32)` - ID of the new snapshot
+    /// * `Err(ContractError::InvalidAmount)` - Range starts at 0 or ends before it starts
+    /// * `Err(ContractError::RemittanceNotFound)` - Range ends past the last remittance
+    /// * `Err(ContractError::LimitExceeded)` - Range covers more than MAX_SNAPSHOT_RANGE remittances
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn commit_snapshot(env: Env, range_start: u64, range_end: u64) -> Result<u32, ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        let snapshot = commit_snapshot(&env, range_start, range_end)?;
+        let snapshot_id = get_snapshot_count(&env);
+        emit_snapshot_committed(&env, snapshot_id, range_start, range_end, snapshot.root);
+
+        Ok(snapshot_id)
+    }
+
+    /// Returns a committed snapshot, if any.
+    pub fn get_snapshot(env: Env, snapshot_id: u32) -> Option<RemittanceSnapshot> {
+        get_snapshot(&env, snapshot_id)
+    }
+
+    /// Returns whether a proof shows its remittance record was committed in its snapshot.
+    pub fn verify_record(env: Env, proof: RecordProof) -> bool {
+        verify_record(&env, &proof)
+    }
+
+    /// Registers the AMM router used by `confirm_payout_with_swap`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `router` - Contract implementing the `SwapRouter` interface
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_swap_router(env: Env, router: Address) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_swap_router(&env, &router);
+
+        Ok(())
+    }
+
+    /// Returns the registered swap router, if any.
+    pub fn get_swap_router(env: Env) -> Option<Address> {
+        get_swap_router(&env)
+    }
+
+    /// Confirms a payout and swaps it into the agent's preferred token.
+    ///
+    /// Behaves like `confirm_payout`, except the payout is handed to the
+    /// registered swap router and the agent receives `target_token` instead of
+    /// USDC. The payout is made immediately even if the agent is on a
+    /// settlement schedule.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to confirm
+    /// * `target_token` - Token the agent wants to receive
+    /// * `min_out` - Minimum amount of `target_token` the agent accepts
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Payout swapped and delivered
+    /// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - `min_out` is not positive
+    /// * `Err(ContractError::InvalidStatus)` - No swap router is registered, or attested mode is enabled
+    /// * `Err(ContractError::SlippageExceeded)` - Agent received less than `min_out`
+    /// * Any error returned by `confirm_payout`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent address assigned to the remittance.
+    pub fn confirm_payout_with_swap(
+        env: Env,
+        remittance_id: u64,
+        target_token: Address,
+        min_out: i128,
+    ) -> Result<(), ContractError> {
+        validate_amount(min_out)?;
+
+        let remittance = validate_confirm_payout_request(&env, remittance_id)?;
+
+        remittance.agent.require_auth();
+
+        check_rate_limit(&env, &remittance.sender)?;
+
+        let agent = remittance.agent.clone();
+        Self::settle_payout(&env, remittance, Some((target_token, min_out)), Some(agent))
+    }
+
+    /// Creates a remittance paid out by several agents, each confirming its leg independently.
+    ///
+    /// The total is escrowed with a single transfer. Each leg is stored as a
+    /// regular pending remittance, confirmed with `confirm_payout` by its agent
+    /// or cancelled with `cancel_remittance`, and counts as one creation for
+    /// rate limiting.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Address funding the remittance
+    /// * `legs` - Agent and amount of each leg
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - ID of the split; see `get_split` for the leg remittance IDs
+    /// * `Err(ContractError::InvalidAmount)` - No legs
+    /// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - A leg amount is not positive
+    /// * `Err(ContractError::LimitExceeded)` - More than MAX_SPLIT_LEGS legs
+    /// * Any error returned by `create_remittance` for a leg
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address.
+    pub fn create_split_remittance(
+        env: Env,
+        sender: Address,
+        legs: Vec<(Address, i128)>,
+    ) -> Result<u64, ContractError> {
+        if legs.is_empty() {
+            return Err(ContractError::InvalidAmount);
+        }
+        if legs.len() > MAX_SPLIT_LEGS {
+            return Err(ContractError::LimitExceeded);
+        }
+        for (agent, amount) in legs.iter() {
+            validate_create_remittance_request(&env, &sender, &agent, amount)?;
+        }
+
+        sender.require_auth();
+
+        let mut counter = get_remittance_counter(&env)?;
+        let mut total: i128 = 0;
+        let mut leg_ids = Vec::new(&env);
+        for (agent, amount) in legs.iter() {
+            counter = counter.checked_add(1).ok_or(ContractError::Overflow)?;
+            Self::record_remittance(&env, counter, &sender, &agent, amount, PurposeCode::Other, None)?;
+            total = total.checked_add(amount).ok_or(ContractError::Overflow)?;
+            leg_ids.push_back(counter);
+        }
+        set_remittance_counter(&env, counter);
+
+        let usdc_token = get_usdc_token(&env)?;
+        let token_client = token::Client::new(&env, &usdc_token);
+        token_client.transfer(&sender, &env.current_contract_address(), &total);
+
+        let split = SplitRemittance {
+            id: next_split_id(&env)?,
+            sender: sender.clone(),
+            total,
+            leg_ids,
+        };
+        set_split(&env, &split);
+        emit_split_created(&env, split.id, sender, total, split.leg_ids.len());
+
+        Ok(split.id)
+    }
+
+    /// Returns a split remittance, if it exists.
+    pub fn get_split(env: Env, split_id: u64) -> Option<SplitRemittance> {
+        get_split(&env, split_id)
+    }
+
+    /// Returns the legs of a split remittance with their current status.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Remittance>)` - Leg remittances in the order they were given
+    /// * `Err(ContractError::RemittanceNotFound)` - Split or one of its legs does not exist
+    pub fn get_split_legs(env: Env, split_id: u64) -> Result<Vec<Remittance>, ContractError> {
+        let split = get_split(&env, split_id).ok_or(ContractError::RemittanceNotFound)?;
+        let mut legs = Vec::new(&env);
+        for leg_id in split.leg_ids.iter() {
+            legs.push_back(get_remittance(&env, leg_id)?);
+        }
+        Ok(legs)
+    }
+
+    /// Returns the split a remittance is a leg of, if any.
+    pub fn get_split_of(env: Env, remittance_id: u64) -> Option<u64> {
+        get_split_of(&env, remittance_id)
+    }
+
+    /// Returns a page of the pending remittance IDs assigned to an agent, oldest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Agent whose inbox is read
+    /// * `start` - Index of the first assignment to return
+    /// * `limit` - Maximum number of IDs to return, capped at MAX_ASSIGNMENT_PAGE
+    pub fn get_pending_assignments(env: Env, agent: Address, start: u32, limit: u32) -> Vec<u64> {
+        get_pending_assignments(&env, &agent, start, limit)
+    }
+
+    /// Returns the number of pending remittances assigned to an agent.
+    pub fn get_pending_assignment_count(env: Env, agent: Address) -> u32 {
+        get_pending_assignment_count(&env, &agent)
+    }
+
+    /// Caps the pending remittances assigned to an agent at its cash capacity.
+    ///
+    /// Creating, amending or reassigning a remittance to an agent at its limit
+    /// fails with `AgentAtCapacity`. Remittances already pending are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Agent to limit
+    /// * `max_pending` - Maximum concurrent pending remittances; 0 removes the
+    ///   agent's own limit so the default applies
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_agent_max_pending(env: Env, agent: Address, max_pending: u32) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_agent_max_pending(&env, &agent, max_pending);
+        emit_agent_max_pending_set(&env, Some(agent), max_pending);
+        Ok(())
+    }
+
+    /// Sets the maximum pending remittances of agents without their own limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `max_pending` - Maximum concurrent pending remittances; 0 means unlimited
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_default_max_pending(env: Env, max_pending: u32) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_default_max_pending(&env, max_pending);
+        emit_agent_max_pending_set(&env, None, max_pending);
+        Ok(())
+    }
+
+    /// Returns the maximum pending remittances that applies to an agent, 0 if unlimited.
+    pub fn get_agent_max_pending(env: Env, agent: Address) -> u32 {
+        get_agent_max_pending(&env, &agent)
+    }
+
+    /// Exempts a sender from the platform fee.
+    ///
+    /// Applies to remittances created or amended after the call; fees already
+    /// recorded on existing remittances are unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Sender to exempt (e.g. an NGO partner or the operator's treasury)
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn exempt_from_fees(env: Env, sender: Address) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_fee_exempt(&env, &sender, true);
+        emit_fee_exemption_updated(&env, sender, true);
+
+        Ok(())
+    }
+
+    /// Removes a sender's platform fee exemption.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Sender whose exemption is removed
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn remove_fee_exemption(env: Env, sender: Address) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_fee_exempt(&env, &sender, false);
+        emit_fee_exemption_updated(&env, sender, false);
+
+        Ok(())
+    }
+
+    /// Returns whether a sender is exempt from the platform fee.
+    pub fn is_fee_exempt(env: Env, sender: Address) -> bool {
+        is_fee_exempt(&env, &sender)
+    }
+
+    /// Configures fee discounts by sender lifetime volume.
+    ///
+    /// A sender whose completed volume has reached a tier's `min_volume` has
+    /// the tier's `discount_bps` share of the platform fee waived; the highest
+    /// tier reached applies. Applies to remittances created or amended, and
+    /// quotes issued, after the call.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `tiers` - Tiers in strictly ascending `min_volume` order; empty removes discounts
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Tiers updated
+    /// * `Err(ContractError::LimitExceeded)` - More than MAX_VOLUME_TIERS tiers
+    /// * `Err(ContractError::InvalidAmount)` - Thresholds are negative or not ascending
+    /// * `Err(ContractError::InvalidFeeBps)` - A discount above 10000 basis points
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_volume_tiers(env: Env, tiers: Vec<VolumeTier>) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_volume_tiers(&env, &tiers)?;
+        emit_volume_tiers_set(&env, caller, tiers.len());
+
+        Ok(())
+    }
+
+    /// Returns the volume discount tiers, lowest threshold first.
+    pub fn get_volume_tiers(env: Env) -> Vec<VolumeTier> {
+        get_volume_tiers(&env)
+    }
+
+    /// Returns the lifetime volume of a sender's paid-out remittances.
+    pub fn get_sender_volume(env: Env, sender: Address) -> i128 {
+        get_sender_volume(&env, &sender)
+    }
+
+    /// Returns the fee discount a sender's volume currently earns, in basis points of the fee.
+    pub fn get_volume_discount_bps(env: Env, sender: Address) -> u32 {
+        get_volume_discount_bps(&env, &sender)
+    }
+
+    /// Refunds pending remittances whose expiry has passed and marks them `Expired`.
+    ///
+    /// Permissionless: any keeper may call it. Remittances are processed one
+    /// expiry window at a time, oldest first, and the keeper receives the configured bounty for each
+    /// remittance expired, paid out of withdrawable USDC fees and capped at the
+    /// fees available.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `keeper` - Address that receives the bounty
+    /// * `limit` - Maximum number of index entries to examine, capped at MAX_SWEEP_BATCH
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u32)` - Number of remittances expired by this call
+    /// * `Err(ContractError::InvalidAmount)` - Limit is zero
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the keeper address.
+    pub fn sweep_expired(env: Env, keeper: Address, limit: u32) -> Result<u32, ContractError> {
+        if limit == 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+        keeper.require_auth();
+
+        let now = env.ledger().timestamp();
+        let mut expired: u32 = 0;
+        for remittance_id in take_expired(&env, now, limit.min(MAX_SWEEP_BATCH)).iter() {
+            // Settled, cancelled and archived remittances simply drop out of the index
+            let mut remittance = match get_remittance(&env, remittance_id) {
+                Ok(remittance) if remittance.status == RemittanceStatus::Pending => remittance,
+                _ => continue,
+            };
+
+            let refund_amount = Self::refund_remittance(&env, &mut remittance, RemittanceStatus::Expired, Some(keeper.clone()), false)?;
+            emit_remittance_expired(&env, remittance_id, remittance.sender, refund_amount);
+            expired += 1;
+        }
+
+        let bounty = get_sweep_bounty(&env)
+            .checked_mul(expired as i128)
+            .ok_or(ContractError::Overflow)?
+            .min(get_withdrawable_fees(&env)?);
+        if bounty > 0 {
+            unwrap_fees_for(&env, bounty)?;
+            book_fee_withdrawal(&env, bounty)?;
+            let usdc_token = get_usdc_token(&env)?;
+            token::Client::new(&env, &usdc_token).transfer(&env.current_contract_address(), &keeper, &bounty);
+        }
+        if expired > 0 {
+            emit_expired_swept(&env, keeper, expired, bounty);
+        }
+
+        Ok(expired)
+    }
+
+    /// Sets the bounty paid to keepers per remittance expired by `sweep_expired`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `bounty` - Bounty per expired remittance, in USDC (zero disables it)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Bounty updated
+    /// * `Err(ContractError::NegativeAmount)` - Bounty is negative
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_sweep_bounty(env: Env, bounty: i128) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        validate_non_negative(bounty)?;
+        set_sweep_bounty(&env, bounty);
+
+        Ok(())
+    }
+
+    /// Returns the bounty paid per remittance expired by `sweep_expired`.
+    pub fn get_sweep_bounty(env: Env) -> i128 {
+        get_sweep_bounty(&env)
+    }
+
+    /// Configures attested payout mode.
+    ///
+    /// While enabled, `confirm_payout` holds the funds and the remittance waits
+    /// in `AwaitingAttestation` for the sender or `oracle` to call
+    /// `attest_received`. Payouts already held keep the timeout they started with.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `enabled` - Whether agent confirmations wait for attestation
+    /// * `oracle` - Attestation oracle allowed to attest in addition to the sender
+    /// * `timeout` - Seconds after confirmation until a payout can be released unattested
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Configuration updated
+    /// * `Err(ContractError::InvalidAmount)` - Timeout is zero
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_attestation_mode(
+        env: Env,
+        enabled: bool,
+        oracle: Option<Address>,
+        timeout: u64,
+    ) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        if timeout == 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+        set_attestation_config(&env, &AttestationConfig { enabled, oracle, timeout });
+
+        Ok(())
+    }
+
+    /// Returns the attested payout mode configuration.
+    pub fn get_attestation_config(env: Env) -> AttestationConfig {
+        get_attestation_config(&env)
+    }
+
+    /// Attests that the recipient was paid and releases the held payout to the agent.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `caller` - Sender of the remittance or the attestation oracle
+    /// * `remittance_id` - ID of the remittance awaiting attestation
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Receipt attested and payout released
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not awaiting attestation
+    /// * `Err(ContractError::Unauthorized)` - Caller is neither the sender nor the oracle
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the caller.
+    pub fn attest_received(env: Env, caller: Address, remittance_id: u64) -> Result<(), ContractError> {
+        let remittance = get_remittance(&env, remittance_id)?;
+        if remittance.status != RemittanceStatus::AwaitingAttestation {
+            return Err(ContractError::InvalidStatus);
+        }
+        if !can_attest(&env, &remittance.sender, &caller) {
+            return Err(ContractError::Unauthorized);
+        }
+        caller.require_auth();
+
+        Self::settle_payout(&env, remittance, None, Some(caller.clone()))?;
+        emit_payout_attested(&env, remittance_id, Some(caller));
+
+        Ok(())
+    }
+
+    /// Releases a held payout whose attestation timeout has passed.
+    ///
+    /// Permissionless, so an agent is paid even if the sender never attests.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance awaiting attestation
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Payout released
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not awaiting attestation or the timeout has not passed
+    pub fn release_unattested(env: Env, remittance_id: u64) -> Result<(), ContractError> {
+        let remittance = get_remittance(&env, remittance_id)?;
+        if remittance.status != RemittanceStatus::AwaitingAttestation {
+            return Err(ContractError::InvalidStatus);
+        }
+        let release_after = get_attestation_deadline(&env, remittance_id).ok_or(ContractError::InvalidStatus)?;
+        if env.ledger().timestamp() < release_after {
+            return Err(ContractError::InvalidStatus);
+        }
+
+        Self::settle_payout(&env, remittance, None, None)?;
+        emit_payout_attested(&env, remittance_id, None);
+
+        Ok(())
+    }
+
+    /// Returns the contract's configuration and totals in a single call.
+    ///
+    /// Intended for operational dashboards that would otherwise query the
+    /// admin, token, fee rate, pause state, escrow, fees, agent count and
+    /// remittance counter separately.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ContractState)` - Current state snapshot
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    pub fn get_contract_state(env: Env) -> Result<ContractState, ContractError> {
+        Ok(ContractState {
+            admin: get_admin(&env)?,
+            usdc_token: get_usdc_token(&env)?,
+            platform_fee_bps: get_effective_fee_bps(&env, env.ledger().timestamp())?,
+            paused: is_paused(&env),
+            total_escrow: get_total_escrow(&env),
+            accumulated_fees: get_accumulated_fees(&env)?,
+            agent_count: get_agent_count(&env),
+            next_remittance_id: get_remittance_counter(&env)?
+                .checked_add(1)
+                .ok_or(ContractError::Overflow)?,
+        })
+    }
+
+    /// Returns the sequence number of the last event emitted by the contract.
+    ///
+    /// Every event carries an `event_seq` that increases by exactly one per
+    /// event. Indexers compare it with the last sequence they processed to
+    /// detect missed events and resume deterministically after an outage.
+    ///
+    /// # Returns
+    ///
+    /// * `u64` - Last emitted event sequence, or 0 if no event was emitted
+    pub fn get_last_event_seq(env: Env) -> u64 {
+        get_last_event_seq(&env)
+    }
+
+    /// Registers a watcher and the event topics it follows.
+    ///
+    /// Metadata for operators and integrators: events are published to every
+    /// subscriber regardless. Subscribing to `status_changed` alone is enough
+    /// to follow every remittance status transition.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `owner` - Address registering the watcher
+    /// * `topic_filter` - Event topics followed, e.g. `status`; empty for all
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u32)` - ID of the registration
+    /// * `Err(ContractError::LimitExceeded)` - The filter has more than MAX_WATCHER_TOPICS topics
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the owner.
+    pub fn register_watcher(env: Env, owner: Address, topic_filter: Vec<Symbol>) -> Result<u32, ContractError> {
+        owner.require_auth();
+
+        let watcher_id = register_watcher(&env, &owner, topic_filter.clone())?;
+        emit_watcher_registered(&env, watcher_id, owner, topic_filter);
+
+        Ok(watcher_id)
+    }
+
+    /// Removes a watcher registration.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `watcher_id` - ID returned by `register_watcher`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Registration removed
+    /// * `Err(ContractError::InvalidStatus)` - No registration with this ID
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the watcher's owner.
+    pub fn unregister_watcher(env: Env, watcher_id: u32) -> Result<(), ContractError> {
+        let watcher = get_watcher(&env, watcher_id).ok_or(ContractError::InvalidStatus)?;
+        watcher.owner.require_auth();
+
+        remove_watcher(&env, watcher_id);
+        emit_watcher_removed(&env, watcher_id, watcher.owner);
+
+        Ok(())
+    }
+
+    /// Returns a watcher registration, if it exists.
+    pub fn get_watcher(env: Env, watcher_id: u32) -> Option<Watcher> {
+        get_watcher(&env, watcher_id)
+    }
+
+    /// Caps the amount of any single remittance in a token or corridor.
+    ///
+    /// `create_remittance` and `amend_remittance` reject amounts above the cap
+    /// with `AmountAboveMaximum`. Caps are independent of the daily send limits.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `scope` - Token or corridor the cap applies to
+    /// * `amount` - Maximum single-transfer amount (must be positive)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Cap set
+    /// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - Amount is zero or negative
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_max_transfer_amount(env: Env, scope: LimitScope, amount: i128) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        validate_amount(amount)?;
+
+        set_max_transfer_amount(&env, &scope, amount);
+        emit_max_transfer_amount_set(&env, scope, Some(amount));
+
+        Ok(())
+    }
+
+    /// Removes the single-transfer cap of a token or corridor.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `scope` - Token or corridor whose cap is removed
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn clear_max_transfer_amount(env: Env, scope: LimitScope) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        clear_max_transfer_amount(&env, &scope);
+        emit_max_transfer_amount_set(&env, scope, None);
+
+        Ok(())
+    }
+
+    /// Returns the single-transfer cap of a token or corridor, if any.
+    pub fn get_max_transfer_amount(env: Env, scope: LimitScope) -> Option<i128> {
+        get_max_transfer_amount(&env, &scope)
+    }
+
+    /// Sets the minimum amount of a single remittance in a token.
+    ///
+    /// Rejects dust remittances, whose fee rounds to zero and whose payout
+    /// costs more in network fees than it is worth, with `AmountBelowMinimum`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `token` - Token the minimum applies to
+    /// * `amount` - Minimum single-transfer amount; 0 removes the minimum
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Minimum updated
+    /// * `Err(ContractError::NegativeAmount)` - Amount is negative
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_min_transfer_amount(env: Env, token: Address, amount: i128) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        validate_non_negative(amount)?;
+
+        set_min_transfer_amount(&env, &token, amount);
+        emit_min_transfer_amount_set(&env, token, amount);
+
+        Ok(())
+    }
+
+    /// Returns the minimum single-transfer amount in a token, 0 when unset.
+    pub fn get_min_transfer_amount(env: Env, token: Address) -> i128 {
+        get_min_transfer_amount(&env, &token)
+    }
+
+    /// Replaces the geographic coverage areas of an agent.
+    ///
+    /// Coverage feeds `find_agents`, which wallets use to discover agents for
+    /// a destination. An empty list removes the agent from discovery.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Registered agent address
+    /// * `areas` - Country, optional city and currency of each area served
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Coverage replaced
+    /// * `Err(ContractError::AgentNotRegistered)` - Agent is not registered
+    /// * `Err(ContractError::LimitExceeded)` - More than MAX_COVERAGE_AREAS areas
+    /// * `Err(ContractError::CorridorClosed)` - An area's currency is not a 3-letter code
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_agent_coverage(
+        env: Env,
+        agent: Address,
+        areas: Vec<CoverageArea>,
+    ) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        if !is_agent_registered(&env, &agent) {
+            return Err(ContractError::AgentNotRegistered);
+        }
+
+        set_agent_coverage(&env, &agent, &areas)?;
+        emit_agent_coverage_set(&env, agent, areas.len());
+
+        Ok(())
+    }
+
+    /// Returns the coverage areas of an agent.
+    pub fn get_agent_coverage(env: Env, agent: Address) -> Vec<CoverageArea> {
+        get_agent_coverage(&env, &agent)
+    }
+
+    /// Returns agents that can currently pay out in a country and currency.
+    ///
+    /// Only registered agents that are not suspended and are available are
+    /// listed, in the order they gained coverage of the destination.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `country` - Destination country code
+    /// * `currency` - Payout currency code, in any case
+    /// * `start` - Number of eligible agents to skip
+    /// * `limit` - Maximum agents to return (capped at MAX_AGENT_PAGE)
+    pub fn find_agents(
+        env: Env,
+        country: String,
+        currency: String,
+        start: u32,
+        limit: u32,
+    ) -> Vec<Address> {
+        match Currency::parse(&env, &currency) {
+            Ok(currency) => find_agents(&env, &country, &currency, start, limit),
+            Err(_) => Vec::new(&env),
+        }
+    }
+
+    /// Cancels a pending remittance on behalf of compliance.
+    ///
+    /// Unlike `cancel_remittance`, which the sender initiates, this records
+    /// why the platform cancelled. The sender is refunded, except for a
+    /// `Sanctions` reason: the funds are then moved into quarantine instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to cancel
+    /// * `reason` - Reason for the cancellation
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Remittance refunded or quarantined
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not Pending
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn admin_cancel_remittance(
+        env: Env,
+        remittance_id: u64,
+        reason: CancelReason,
+    ) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        let mut remittance = validate_cancel_remittance_request(&env, remittance_id)?;
+
+        let quarantined = reason == CancelReason::Sanctions;
+        let amount = if quarantined {
+            Self::quarantine_remittance(&env, &mut remittance, Some(caller.clone()))?
+        } else {
+            Self::refund_remittance(&env, &mut remittance, RemittanceStatus::Cancelled, Some(caller.clone()), false)?
+        };
+
+        emit_remittance_admin_cancelled(&env, remittance_id, caller, reason, amount, quarantined);
+
+        Ok(())
+    }
+
+    /// Returns the amount held in quarantine for a remittance, 0 if none.
+    pub fn get_quarantined_amount(env: Env, remittance_id: u64) -> i128 {
+        get_quarantined_amount(&env, remittance_id)
+    }
+
+    /// Freezes a remittance under investigation, moving its funds into quarantine.
+    ///
+    /// The remittance can no longer be paid out, cancelled or expired. Its
+    /// unreleased amount, fee and insurance premium are held until the admin
+    /// decides where they go with `release_quarantine`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to freeze
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Funds moved into quarantine
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is neither Pending nor AwaitingAttestation
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn freeze_remittance(env: Env, remittance_id: u64) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        let mut remittance = get_remittance(&env, remittance_id)?;
+        match remittance.status {
+            RemittanceStatus::Pending => {}
+            // A payout held for attestation is frozen before it is released
+            RemittanceStatus::AwaitingAttestation => end_attestation(&env, remittance_id),
+            _ => return Err(ContractError::InvalidStatus),
+        }
+
+        let amount = Self::quarantine_remittance(&env, &mut remittance, Some(caller.clone()))?;
+        emit_remittance_frozen(&env, remittance_id, caller, amount);
+
+        Ok(())
+    }
+
+    /// Sends the quarantined funds of a remittance to the party an investigation names.
+    ///
+    /// The recipient is usually the sender or the agent, or an authority when
+    /// the funds are seized. The remittance stays `Quarantined`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the quarantined remittance
+    /// * `to` - Recipient of the funds
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(i128)` - Amount released
+    /// * `Err(ContractError::InvalidStatus)` - Nothing is held in quarantine for the remittance
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn release_quarantine(env: Env, remittance_id: u64, to: Address) -> Result<i128, ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        let amount = release_quarantined(&env, remittance_id)?;
+
+        let usdc_token = get_usdc_token(&env)?;
+        token::Client::new(&env, &usdc_token).transfer(&env.current_contract_address(), &to, &amount);
+        emit_quarantine_released(&env, remittance_id, caller, to, amount);
+
+        Ok(amount)
+    }
+
+    /// Returns the total amount held in quarantine across all remittances.
+    pub fn get_total_quarantined(env: Env) -> i128 {
+        get_total_quarantined(&env)
+    }
+
+    /// Creates a remittance routed through intermediary agents to its destination agent.
+    ///
+    /// For corridors without a direct agent. The last address of `route` is the
+    /// destination agent the remittance is created for; the addresses before it
+    /// are intermediaries that confirm their legs in order with `confirm_hop`,
+    /// each taking the hop fee set with `set_hop_fee`. The destination agent can
+    /// confirm the payout once every leg is confirmed and receives what is left
+    /// after hop fees and the platform fee. Cancelling refunds the amount not yet
+    /// paid to intermediaries.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Address funding the remittance
+    /// * `route` - Intermediary agents in order, followed by the destination agent
+    /// * `amount` - Amount to send
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - ID of the created remittance
+    /// * `Err(ContractError::InvalidAddress)` - Route is empty
+    /// * `Err(ContractError::LimitExceeded)` - Route is longer than MAX_ROUTE_LENGTH
+    /// * `Err(ContractError::AgentNotRegistered)` - An agent on the route is not registered
+    /// * `Err(ContractError::AgentSuspended)` - An agent on the route is suspended
+    /// * `Err(ContractError::InvalidAmount)` - Amount does not cover the hop fees and platform fee
+    /// * Any error returned by `create_remittance` for the destination agent
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address.
+    pub fn create_routed_remittance(
+        env: Env,
+        sender: Address,
+        route: Vec<Address>,
+        amount: i128,
+    ) -> Result<u64, ContractError> {
+        let agent = route.last().ok_or(ContractError::InvalidAddress)?;
+        if route.len() > MAX_ROUTE_LENGTH {
+            return Err(ContractError::LimitExceeded);
+        }
+        validate_create_remittance_request(&env, &sender, &agent, amount)?;
+
+        let hops = route.slice(0..route.len() - 1);
+        for hop in hops.iter() {
+            if !is_agent_registered(&env, &hop) {
+                return Err(ContractError::AgentNotRegistered);
+            }
+            if is_agent_suspended(&env, &hop) {
+                return Err(ContractError::AgentSuspended);
+            }
+        }
+
+        // Every intermediary and the platform must be paid out of the amount
+        let hop_fee = amount
+            .checked_mul(get_hop_fee_bps(&env) as i128)
+            .ok_or(ContractError::Overflow)?
+            / 10000;
+        let platform_fee = calculate_platform_fee(&env, Some(&sender), Some(&agent), amount)?;
+        let total_fees = hop_fee
+            .checked_mul(hops.len() as i128)
+            .and_then(|fees| fees.checked_add(platform_fee))
+            .ok_or(ContractError::Overflow)?;
+        if total_fees > amount {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        sender.require_auth();
+
+        let counter = get_remittance_counter(&env)?;
+        let remittance_id = counter.checked_add(1).ok_or(ContractError::Overflow)?;
+
+        Self::open_remittance(&env, remittance_id, &sender, &agent, amount, PurposeCode::Other, None)?;
+        set_remittance_counter(&env, remittance_id);
+
+        let hop_count = hops.len();
+        set_route(&env, remittance_id, &Route { hops, hop_fee, confirmed: 0 });
+        emit_route_created(&env, remittance_id, sender, agent, hop_count, hop_fee);
+
+        Ok(remittance_id)
+    }
+
+    /// Confirms the next leg of a routed remittance and pays its hop fee.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the routed remittance
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Address)` - Intermediary whose leg was confirmed
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not pending, not routed,
+    ///   or all its legs are already confirmed
+    /// * `Err(ContractError::SettlementExpired)` - Remittance has expired
+    /// * `Err(ContractError::ContractPaused)` - Contract is paused
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the intermediary whose leg is next on the route.
+    pub fn confirm_hop(env: Env, remittance_id: u64) -> Result<Address, ContractError> {
+        validate_not_paused(&env)?;
+        let remittance = get_remittance(&env, remittance_id)?;
+        validate_remittance_pending(&remittance)?;
+        validate_settlement_not_expired(&env, remittance.expiry)?;
+        validate_hold_elapsed(&env, remittance.hold_until)?;
+        validate_screened(&env, remittance_id)?;
+
+        let mut route = get_route(&env, remittance_id).ok_or(ContractError::InvalidStatus)?;
+        let hop = next_hop(&route).ok_or(ContractError::InvalidStatus)?;
+
+        hop.require_auth();
+
+        if route.hop_fee > 0 {
+            record_hop_release(&env, remittance_id, route.hop_fee)?;
+            book_commission(&env, route.hop_fee)?;
+
+            let usdc_token = get_usdc_token(&env)?;
+            let token_client = token::Client::new(&env, &usdc_token);
+            token_client.transfer(&env.current_contract_address(), &hop, &route.hop_fee);
+        }
+
+        route.confirmed += 1;
+        set_route(&env, remittance_id, &route);
+        emit_hop_confirmed(&env, remittance_id, hop.clone(), route.hop_fee, route.hops.len() - route.confirmed);
+
+        Ok(hop)
+    }
+
+    /// Returns the route of a routed remittance, if it is one.
+    pub fn get_route(env: Env, remittance_id: u64) -> Option<Route> {
+        get_route(&env, remittance_id)
+    }
+
+    /// Sets the fee each intermediary takes from routed remittances created afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `fee_bps` - Fee per hop in basis points of the remittance amount (0-10000)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Fee updated
+    /// * `Err(ContractError::InvalidFeeBps)` - Fee above 10000
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_hop_fee(env: Env, fee_bps: u32) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        validate_fee_bps(fee_bps)?;
+
+        set_hop_fee_bps(&env, fee_bps);
+        emit_hop_fee_set(&env, fee_bps);
+
+        Ok(())
+    }
+
+    /// Returns the fee per hop in basis points.
+    pub fn get_hop_fee(env: Env) -> u32 {
+        get_hop_fee_bps(&env)
+    }
+
+    /// Returns the nonce the next relayed operation signed by `user` must carry.
+    pub fn get_nonce(env: Env, user: Address) -> u64 {
+        get_nonce(&env, &user)
+    }
+
+    /// Creates a remittance from an operation the sender signed for a relayer to submit.
+    ///
+    /// The sender signs `(agent, amount, purpose, nonce, expiration_ledger)`
+    /// together with the token transfer into escrow. Each signature can be
+    /// submitted once, by any relayer, until `expiration_ledger`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Address funding the remittance
+    /// * `agent` - Agent paying out the remittance
+    /// * `amount` - Amount to send
+    /// * `purpose` - Declared purpose of the payment
+    /// * `nonce` - Sender's next nonce, see `get_nonce`
+    /// * `expiration_ledger` - Last ledger the signed operation may be submitted in
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - ID of the created remittance
+    /// * `Err(ContractError::SignatureExpired)` - The current ledger is past `expiration_ledger`
+    /// * `Err(ContractError::InvalidNonce)` - `nonce` was already used or is out of order
+    /// * Any error returned by `create_remittance`
+    ///
+    /// # Authorization
+    ///
+    /// Requires the sender's signature over the arguments above.
+    pub fn relay_create_remittance(
+        env: Env,
+        sender: Address,
+        agent: Address,
+        amount: i128,
+        purpose: PurposeCode,
+        nonce: u64,
+        expiration_ledger: u32,
+    ) -> Result<u64, ContractError> {
+        validate_create_remittance_request(&env, &sender, &agent, amount)?;
+
+        sender.require_auth_for_args((agent.clone(), amount, purpose, nonce, expiration_ledger).into_val(&env));
+        consume_nonce(&env, &sender, nonce, expiration_ledger)?;
+
+        let counter = get_remittance_counter(&env)?;
+        let remittance_id = counter.checked_add(1).ok_or(ContractError::Overflow)?;
+
+        Self::open_remittance(&env, remittance_id, &sender, &agent, amount, purpose, None)?;
+        set_remittance_counter(&env, remittance_id);
+
+        Ok(remittance_id)
+    }
+
+    /// Cancels a pending remittance from an operation the sender signed for a relayer to submit.
+    ///
+    /// The sender signs `(remittance_id, nonce, expiration_ledger)`. The refund
+    /// goes to the sender as with `cancel_remittance`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to cancel
+    /// * `nonce` - Sender's next nonce, see `get_nonce`
+    /// * `expiration_ledger` - Last ledger the signed operation may be submitted in
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Remittance cancelled and refunded
+    /// * `Err(ContractError::SignatureExpired)` - The current ledger is past `expiration_ledger`
+    /// * `Err(ContractError::InvalidNonce)` - `nonce` was already used or is out of order
+    /// * Any error returned by `cancel_remittance`
+    ///
+    /// # Authorization
+    ///
+    /// Requires the signature of the remittance's sender over the arguments above.
+    pub fn relay_cancel_remittance(
+        env: Env,
+        remittance_id: u64,
+        nonce: u64,
+        expiration_ledger: u32,
+    ) -> Result<(), ContractError> {
+        let mut remittance = validate_cancel_remittance_request(&env, remittance_id)?;
+
+        remittance
+            .sender
+            .require_auth_for_args((remittance_id, nonce, expiration_ledger).into_val(&env));
+        consume_nonce(&env, &remittance.sender, nonce, expiration_ledger)?;
+
+        let sender = remittance.sender.clone();
+        let refund_amount = Self::refund_remittance(&env, &mut remittance, RemittanceStatus::Cancelled, Some(sender), true)?;
+        let usdc_token = get_usdc_token(&env)?;
+        emit_remittance_cancelled(&env, remittance_id, remittance.sender.clone(), remittance.agent.clone(), usdc_token, refund_amount);
+
+        // Relayed cancellations count towards the cooldown like direct ones
+        if let Some(until) = record_cancellation(&env, &remittance.sender) {
+            emit_cancel_cooldown_started(&env, remittance.sender.clone(), until);
+        }
+
+        log_cancel_remittance(&env, remittance_id);
+
+        Ok(())
+    }
+
+    /// Deposits settlement tokens into a sponsor's pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sponsor` - Sponsor funding the pool
+    /// * `amount` - Amount to deposit
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SponsorBudget)` - The sponsor's pool afterwards
+    /// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - Amount is not positive
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sponsor.
+    pub fn fund_sponsor_pool(env: Env, sponsor: Address, amount: i128) -> Result<SponsorBudget, ContractError> {
+        sponsor.require_auth();
+
+        let budget = fund_sponsor_pool(&env, &sponsor, amount)?;
+        let usdc_token = get_usdc_token(&env)?;
+        token::Client::new(&env, &usdc_token).transfer(&sponsor, &env.current_contract_address(), &amount);
+
+        emit_sponsor_pool_changed(&env, sponsor, amount, budget.balance);
+        Ok(budget)
+    }
+
+    /// Withdraws unspent tokens from a sponsor's pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sponsor` - Sponsor owning the pool
+    /// * `amount` - Amount to withdraw
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SponsorBudget)` - The sponsor's pool afterwards
+    /// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - Amount is not positive
+    /// * `Err(ContractError::InsufficientEscrow)` - Amount exceeds the pool's balance
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sponsor.
+    pub fn withdraw_sponsor_pool(env: Env, sponsor: Address, amount: i128) -> Result<SponsorBudget, ContractError> {
+        sponsor.require_auth();
+
+        let budget = drain_sponsor_pool(&env, &sponsor, amount)?;
+        let usdc_token = get_usdc_token(&env)?;
+        token::Client::new(&env, &usdc_token).transfer(&env.current_contract_address(), &sponsor, &amount);
+
+        emit_sponsor_pool_changed(&env, sponsor, -amount, budget.balance);
+        Ok(budget)
+    }
+
+    /// Sets the relay fee a sponsor pays relayers per sponsored remittance.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sponsor` - Sponsor paying the fee
+    /// * `relay_fee` - Settlement tokens paid per remittance; 0 sponsors for free
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Relay fee updated
+    /// * `Err(ContractError::NegativeAmount)` - Relay fee is negative
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sponsor.
+    pub fn set_sponsor_relay_fee(env: Env, sponsor: Address, relay_fee: i128) -> Result<(), ContractError> {
+        sponsor.require_auth();
+
+        set_sponsor_relay_fee(&env, &sponsor, relay_fee)?;
+        emit_sponsor_relay_fee_set(&env, sponsor, relay_fee);
+
+        Ok(())
+    }
+
+    /// Approves or revokes a relayer allowed to submit remittances on a sponsor's pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sponsor` - Sponsor whose pool the relayer draws on
+    /// * `relayer` - Relayer to approve or revoke
+    /// * `approved` - Whether the relayer is approved
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sponsor.
+    pub fn set_sponsor_relayer(env: Env, sponsor: Address, relayer: Address, approved: bool) {
+        sponsor.require_auth();
+
+        set_sponsor_relayer(&env, &sponsor, &relayer, approved);
+        emit_sponsor_relayer_set(&env, sponsor, relayer, approved);
+    }
+
+    /// Returns a sponsor's pool balance, relay fee and spending.
+    pub fn get_sponsor_budget(env: Env, sponsor: Address) -> SponsorBudget {
+        get_sponsor_budget(&env, &sponsor)
+    }
+
+    /// Returns whether a sponsor approved a relayer.
+    pub fn is_sponsor_relayer(env: Env, sponsor: Address, relayer: Address) -> bool {
+        is_sponsor_relayer(&env, &sponsor, &relayer)
+    }
+
+    /// Creates a first-time sender's remittance, submitted by a relayer and
+    /// paid for by a sponsor.
+    ///
+    /// Works like `relay_create_remittance`, with the sender also signing the
+    /// sponsor it accepts. The sponsor's pool pays the relayer its relay fee,
+    /// and up to that fee is returned to the pool from the platform fee when
+    /// the remittance is paid out. Only a sender's first relayed operation can
+    /// be sponsored.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sponsor` - Sponsor paying the relay fee
+    /// * `relayer` - Relayer submitting the operation and receiving the relay fee
+    /// * `sender` - Address funding the remittance
+    /// * `agent` - Agent paying out the remittance
+    /// * `amount` - Amount to send
+    /// * `purpose` - Declared purpose of the payment
+    /// * `nonce` - Sender's next nonce, see `get_nonce`
+    /// * `expiration_ledger` - Last ledger the signed operation may be submitted in
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - ID of the created remittance
+    /// * `Err(ContractError::InvalidStatus)` - The sender already submitted a relayed operation
+    /// * `Err(ContractError::Unauthorized)` - The sponsor has not approved the relayer
+    /// * `Err(ContractError::InsufficientEscrow)` - The sponsor's pool cannot cover the relay fee
+    /// * Any error returned by `relay_create_remittance`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the relayer, and the sender's signature
+    /// over `(sponsor, agent, amount, purpose, nonce, expiration_ledger)`.
+    pub fn relay_create_sponsored(
+        env: Env,
+        sponsor: Address,
+        relayer: Address,
+        sender: Address,
+        agent: Address,
+        amount: i128,
+        purpose: PurposeCode,
+        nonce: u64,
+        expiration_ledger: u32,
+    ) -> Result<u64, ContractError> {
+        validate_create_remittance_request(&env, &sender, &agent, amount)?;
+        if get_nonce(&env, &sender) != 0 {
+            return Err(ContractError::InvalidStatus);
+        }
+
+        relayer.require_auth();
+        sender.require_auth_for_args(
+            (sponsor.clone(), agent.clone(), amount, purpose, nonce, expiration_ledger).into_val(&env),
+        );
+        consume_nonce(&env, &sender, nonce, expiration_ledger)?;
+
+        let counter = get_remittance_counter(&env)?;
+        let remittance_id = counter.checked_add(1).ok_or(ContractError::Overflow)?;
+
+        Self::open_remittance(&env, remittance_id, &sender, &agent, amount, purpose, None)?;
+        set_remittance_counter(&env, remittance_id);
+
+        let fee = get_remittance(&env, remittance_id)?.fee;
+        let relay_fee = charge_sponsor(&env, &sponsor, &relayer, remittance_id, fee)?;
+        if relay_fee > 0 {
+            let usdc_token = get_usdc_token(&env)?;
+            token::Client::new(&env, &usdc_token).transfer(&env.current_contract_address(), &relayer, &relay_fee);
+        }
+        emit_remittance_sponsored(&env, remittance_id, sponsor, relayer, relay_fee);
+
+        Ok(remittance_id)
+    }
+
+    /// Previews what `confirm_payout` would do for a remittance, without changing state.
+    ///
+    /// Runs the payout checks (status, expiry, route, rate limit and the
+    /// contract's token balance) so agent apps can avoid submitting a
+    /// confirmation that would revert.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PayoutPreview)` - Net payout, fee, token, and the error code the payout
+    ///   would fail with, if any
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    pub fn simulate_payout(env: Env, remittance_id: u64) -> Result<PayoutPreview, ContractError> {
+        preview_payout(&env, remittance_id)
+    }
+
+    /// Sets how platform fees in a token are rounded to whole token units.
+    ///
+    /// Applies to remittances created or amended afterwards. Low-decimal tokens
+    /// typically use `Ceil` or `HalfUp` so fractional fees are not lost.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `token` - Token the rounding mode applies to
+    /// * `mode` - `Floor`, `Ceil` or `HalfUp`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_fee_rounding(env: Env, token: Address, mode: RoundingMode) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_fee_rounding(&env, &token, mode);
+        emit_fee_rounding_set(&env, token, mode);
+
+        Ok(())
+    }
+
+    /// Returns the fee rounding mode of a token and the token's decimals.
+    pub fn get_fee_rounding(env: Env, token: Address) -> FeeRoundingPolicy {
+        get_fee_rounding(&env, &token)
+    }
+
+    /// Directs the payout of a pending remittance to a recipient contract.
+    ///
+    /// When the agent confirms the payout, the net amount is transferred to
+    /// `recipient` instead of the agent, and `recipient.on_payout(remittance_id,
+    /// amount)` is invoked. The payout is rolled back if the recipient fails.
+    /// Such remittances cannot be paid in tranches, swapped or netted.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the pending remittance
+    /// * `recipient` - Contract implementing `on_payout(remittance_id: u64, amount: i128)`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Recipient set
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not pending or has released tranches
+    /// * `Err(ContractError::SelfDealing)` - The recipient is the sender
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender of the remittance.
+    pub fn set_payout_recipient(env: Env, remittance_id: u64, recipient: Address) -> Result<(), ContractError> {
+        let remittance = get_remittance(&env, remittance_id)?;
+        remittance.sender.require_auth();
+
+        if remittance.status != RemittanceStatus::Pending
+            || get_released_totals(&env, remittance_id).amount > 0
+        {
+            return Err(ContractError::InvalidStatus);
+        }
+        validate_not_self_dealing(&env, &remittance.sender, &recipient, &remittance.agent)?;
+
+        set_payout_recipient(&env, remittance_id, &recipient);
+        emit_payout_recipient_set(&env, remittance_id, Some(recipient));
+
+        Ok(())
+    }
+
+    /// Removes the recipient contract of a pending remittance so the agent is paid directly.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender of the remittance.
+    pub fn clear_payout_recipient(env: Env, remittance_id: u64) -> Result<(), ContractError> {
+        let remittance = get_remittance(&env, remittance_id)?;
+        remittance.sender.require_auth();
+
+        if remittance.status != RemittanceStatus::Pending {
+            return Err(ContractError::InvalidStatus);
+        }
+
+        clear_payout_recipient(&env, remittance_id);
+        emit_payout_recipient_set(&env, remittance_id, None);
+
+        Ok(())
+    }
+
+    /// Returns the recipient contract a remittance is paid out to, if any.
+    pub fn get_payout_recipient(env: Env, remittance_id: u64) -> Option<Address> {
+        get_payout_recipient(&env, remittance_id)
+    }
+
+    /// Declines a pending remittance assigned to the calling agent.
+    ///
+    /// The sender is refunded immediately, as on cancellation, and the reason
+    /// is recorded against the remittance and the agent's decline counts.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to decline
+    /// * `reason` - Why the agent cannot pay out
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Remittance declined and the sender refunded
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not in Pending status
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent assigned to the remittance.
+    pub fn agent_decline_remittance(
+        env: Env,
+        remittance_id: u64,
+        reason: DeclineReason,
+    ) -> Result<(), ContractError> {
+        let mut remittance = validate_cancel_remittance_request(&env, remittance_id)?;
+
+        remittance.agent.require_auth();
+
+        let agent = remittance.agent.clone();
+        let refund_amount = Self::refund_remittance(&env, &mut remittance, RemittanceStatus::Cancelled, Some(agent), true)?;
+        record_decline(&env, remittance_id, &remittance.agent, reason);
+        emit_remittance_declined(&env, remittance_id, remittance.agent.clone(), reason, refund_amount);
+
+        log_cancel_remittance(&env, remittance_id);
+
+        Ok(())
+    }
+
+    /// Returns the reason an agent gave for declining a remittance, if it was declined.
+    pub fn get_decline_reason(env: Env, remittance_id: u64) -> Option<DeclineReason> {
+        get_decline_reason(&env, remittance_id)
+    }
+
+    /// Returns how many remittances an agent has declined for a reason.
+    pub fn get_decline_count(env: Env, agent: Address, reason: DeclineReason) -> u32 {
+        get_decline_count(&env, &agent, reason)
+    }
+
+    /// Moves a pending remittance to another agent, keeping its escrow.
+    ///
+    /// The amount and fee are unchanged, so the sender neither pays again nor
+    /// is refunded. Remittances an agent declined have already been refunded
+    /// and cannot be reassigned; create a new remittance instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `caller` - The remittance's sender, or an admin
+    /// * `remittance_id` - ID of the remittance to reassign
+    /// * `new_agent` - Agent to pay out through
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Remittance reassigned
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not Pending, is routed, or was partially released
+    /// * `Err(ContractError::Unauthorized)` - Caller is neither the sender nor an admin
+    /// * `Err(ContractError::AgentNotRegistered)` - New agent is not registered
+    /// * `Err(ContractError::AgentSuspended)` - New agent is suspended
+    /// * `Err(ContractError::PurposeNotAllowed)` - New agent's corridor does not accept the purpose
+    /// * `Err(ContractError::AmountAboveMaximum)` - Amount exceeds the new corridor's cap
+    /// * `Err(ContractError::CorridorBlocked)` - The new agent's corridor is blocked
+    /// * `Err(ContractError::SelfDealing)` - The new agent is the sender
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from `caller`.
+    pub fn reassign_remittance(
+        env: Env,
+        caller: Address,
+        remittance_id: u64,
+        new_agent: Address,
+    ) -> Result<(), ContractError> {
+        let mut remittance = validate_remittance_exists(&env, remittance_id)?;
+        validate_remittance_pending(&remittance)?;
+        if get_released_totals(&env, remittance_id).amount > 0
+            || get_route(&env, remittance_id).is_some()
+        {
+            return Err(ContractError::InvalidStatus);
+        }
+
+        if caller == remittance.sender {
+            caller.require_auth();
+            consume_intent(&env, &remittance.sender, &new_agent, remittance.amount)?;
+        } else {
+            require_admin(&env, &caller)?;
+        }
+
+        if !is_agent_registered(&env, &new_agent) {
+            return Err(ContractError::AgentNotRegistered);
+        }
+        if is_agent_suspended(&env, &new_agent) {
+            return Err(ContractError::AgentSuspended);
+        }
+        check_purpose_allowed(&env, &new_agent, remittance.purpose)?;
+        check_max_transfer_amount(&env, &new_agent, remittance.amount)?;
+        check_corridor_not_blocked(&env, &new_agent)?;
+        validate_not_self_dealing(&env, &remittance.sender, &new_agent, &new_agent)?;
+        check_agent_capacity(&env, &new_agent)?;
+
+        let old_agent = remittance.agent.clone();
+        pop_assignment(&env, &old_agent, remittance_id);
+        push_assignment(&env, &new_agent, remittance_id, remittance.priority);
+
+        remittance.agent = new_agent.clone();
+        set_remittance(&env, remittance_id, &remittance);
+
+        emit_remittance_reassigned(&env, remittance_id, caller, old_agent, new_agent);
+
+        Ok(())
+    }
+
+    /// Registers the yield adapter idle escrow can be deposited into.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `adapter` - Contract implementing the `YieldAdapter` interface
+    /// * `beneficiary` - Whether yield goes to the insurance fund or a treasury address
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Adapter registered
+    /// * `Err(ContractError::InvalidStatus)` - Principal is still deposited in another adapter
+    /// * `Err(ContractError::Unauthorized)` - A quorum is configured; propose `CriticalOp::SetYieldAdapter`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin, and no quorum to be configured.
+    pub fn set_yield_adapter(
+        env: Env,
+        adapter: Address,
+        beneficiary: YieldBeneficiary,
+    ) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+        require_no_quorum(&env)?;
+
+        Self::register_yield_adapter(&env, adapter, beneficiary)
+    }
+
+    /// Registers a yield adapter, shared by `set_yield_adapter` and `execute_op`.
+    fn register_yield_adapter(
+        env: &Env,
+        adapter: Address,
+        beneficiary: YieldBeneficiary,
+    ) -> Result<(), ContractError> {
+        let switching = get_yield_config(env).map_or(false, |config| config.adapter != adapter);
+        if switching && (get_yield_principal(env) > 0 || get_wrapped_fees(env) > 0) {
+            return Err(ContractError::InvalidStatus);
+        }
+
+        let config = YieldConfig { adapter, beneficiary };
+        set_yield_config(env, &config);
+        emit_yield_adapter_set(env, Some(config));
+
+        Ok(())
+    }
+
+    /// Removes the yield adapter once all principal has been withdrawn from it.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn clear_yield_adapter(env: Env) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        if get_yield_principal(&env) > 0 || get_wrapped_fees(&env) > 0 {
+            return Err(ContractError::InvalidStatus);
+        }
+
+        clear_yield_config(&env);
+        emit_yield_adapter_set(&env, None);
+
+        Ok(())
+    }
+
+    /// Returns the registered yield adapter and beneficiary, if any.
+    pub fn get_yield_adapter(env: Env) -> Option<YieldConfig> {
+        get_yield_config(&env)
+    }
+
+    /// Returns the escrow principal currently deposited in the yield adapter.
+    pub fn get_yield_principal(env: Env) -> i128 {
+        get_yield_principal(&env)
+    }
+
+    /// Deposits idle escrow into the yield adapter.
+    ///
+    /// Principal can never exceed escrow; it is withdrawn automatically as
+    /// escrow is paid out or refunded.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `amount` - Amount of escrow to deposit
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Escrow deposited
+    /// * `Err(ContractError::InvalidStatus)` - No yield adapter is registered
+    /// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - Amount is not positive
+    /// * `Err(ContractError::InsufficientEscrow)` - Principal would exceed escrow
+    /// * `Err(ContractError::Unauthorized)` - A quorum is configured; propose `CriticalOp::DepositIdleEscrow`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin, and no quorum to be configured.
+    pub fn deposit_idle_escrow(env: Env, amount: i128) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+        require_no_quorum(&env)?;
+
+        deposit_to_yield(&env, amount)?;
+        emit_yield_principal_moved(&env, amount, get_yield_principal(&env));
+
+        Ok(())
+    }
+
+    /// Withdraws escrow principal from the yield adapter back into the contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `amount` - Amount of principal to withdraw
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Principal withdrawn
+    /// * `Err(ContractError::InvalidStatus)` - No yield adapter is registered
+    /// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - Amount is not positive
+    /// * `Err(ContractError::InsufficientEscrow)` - Amount exceeds the principal, or the
+    ///   adapter paid back less than requested
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn withdraw_idle_escrow(env: Env, amount: i128) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        withdraw_from_yield(&env, amount)?;
+        emit_yield_principal_moved(&env, -amount, get_yield_principal(&env));
+
+        Ok(())
+    }
+
+    /// Collects yield earned above the escrow principal and wrapped fees.
+    ///
+    /// The wrapped fees' pro-rata share is booked as platform fees and the
+    /// rest goes to the configured beneficiary. Callable by anyone, since the
+    /// yield can only go where the admin decided. An adapter that fails to
+    /// report its balance harvests nothing and emits a `diag` event.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(i128)` - Yield harvested, fee share included, 0 if there was none
+    /// * `Err(ContractError::InvalidStatus)` - No yield adapter is registered
+    /// * `Err(ContractError::InsufficientEscrow)` - The adapter paid out less than it reported
+    pub fn harvest_yield(env: Env) -> Result<i128, ContractError> {
+        let (beneficiary_yield, fee_yield) = harvest_yield(&env)?;
+        if beneficiary_yield > 0 {
+            if let Some(config) = get_yield_config(&env) {
+                emit_yield_harvested(&env, config.beneficiary, beneficiary_yield);
+            }
+        }
+        if fee_yield > 0 {
+            emit_fee_yield_booked(&env, fee_yield, get_fee_yield(&env));
+        }
+        beneficiary_yield.checked_add(fee_yield).ok_or(ContractError::Overflow)
+    }
+
+    /// Wraps withdrawable platform fees in the yield adapter so they earn
+    /// yield until they are withdrawn.
+    ///
+    /// Wrapped fees are converted back automatically when fees are withdrawn,
+    /// distributed or pay a keeper bounty.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `amount` - Amount of withdrawable fees to wrap
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Fees wrapped
+    /// * `Err(ContractError::InvalidStatus)` - No yield adapter is registered
+    /// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - Amount is not positive
+    /// * `Err(ContractError::InsufficientEscrow)` - Wrapped fees would exceed the withdrawable fees
+    /// * `Err(ContractError::Unauthorized)` - A quorum is configured; propose `CriticalOp::WrapFees`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin, and no quorum to be configured.
+    pub fn wrap_idle_fees(env: Env, amount: i128) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+        require_no_quorum(&env)?;
+
+        wrap_fees(&env, amount)?;
+        emit_fees_wrapped(&env, amount, get_wrapped_fees(&env));
+
+        Ok(())
+    }
+
+    /// Converts wrapped fees back into the contract without withdrawing them.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `amount` - Amount of wrapped fees to unwrap
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Fees unwrapped
+    /// * `Err(ContractError::InvalidStatus)` - No yield adapter is registered
+    /// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - Amount is not positive
+    /// * `Err(ContractError::InsufficientEscrow)` - Amount exceeds the wrapped fees, or the
+    ///   adapter paid back less than requested
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn unwrap_idle_fees(env: Env, amount: i128) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        unwrap_fees(&env, amount)?;
+        emit_fees_wrapped(&env, -amount, get_wrapped_fees(&env));
+
+        Ok(())
+    }
+
+    /// Returns the withdrawable fees currently wrapped in the yield adapter.
+    pub fn get_wrapped_fees(env: Env) -> i128 {
+        get_wrapped_fees(&env)
+    }
+
+    /// Returns the yield wrapped fees have earned in total, booked as fees.
+    pub fn get_fee_yield(env: Env) -> i128 {
+        get_fee_yield(&env)
+    }
+
+    /// Sets the payout SLA of a priority: how soon after creation agents
+    /// should confirm remittances of that priority.
+    ///
+    /// When an agent confirms later than `window_seconds` after creation,
+    /// `rebate_bps` of the platform fee is rebated to the sender on payout and
+    /// a breach is counted against the agent. Applies to payouts confirmed
+    /// after the change, including of remittances already pending.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `priority` - Priority the SLA applies to
+    /// * `window_seconds` - Time from creation within which payouts should be confirmed
+    /// * `rebate_bps` - Share of the fee rebated on a breach, in basis points (0-10000)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - SLA set
+    /// * `Err(ContractError::InvalidFeeBps)` - Rebate above 10000
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_sla(
+        env: Env,
+        priority: Priority,
+        window_seconds: u64,
+        rebate_bps: u32,
+    ) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+        validate_fee_bps(rebate_bps)?;
+
+        let config = SlaConfig { window_seconds, rebate_bps };
+        set_sla_config(&env, priority, &config);
+        emit_sla_set(&env, priority, Some(config));
+
+        Ok(())
+    }
+
+    /// Removes the payout SLA of a priority, so its late payouts are no longer rebated.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn clear_sla(env: Env, priority: Priority) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        clear_sla_config(&env, priority);
+        emit_sla_set(&env, priority, None);
+
+        Ok(())
+    }
+
+    /// Returns the payout SLA of a priority, if one is set.
+    pub fn get_sla(env: Env, priority: Priority) -> Option<SlaConfig> {
+        get_sla_config(&env, priority)
+    }
+
+    /// Returns when a remittance was created and when its agent confirmed the payout.
+    pub fn get_remittance_timing(env: Env, remittance_id: u64) -> Option<RemittanceTiming> {
+        get_remittance_timing(&env, remittance_id)
+    }
+
+    /// Returns how many payouts an agent confirmed after the SLA window.
+    pub fn get_sla_breaches(env: Env, agent: Address) -> u32 {
+        get_sla_breaches(&env, &agent)
+    }
+
+    /// Places fee withdrawals, upgrades and token rescues under an M-of-N quorum.
+    ///
+    /// Once set, `withdraw_fees`, `set_fee_split`, `distribute_fees`,
+    /// `set_yield_adapter` and `deposit_idle_escrow` are closed to the single
+    /// admin and these operations go through `propose_op`, `approve_op` and `execute_op`. The
+    /// quorum can only be set once this way; later changes are themselves
+    /// proposed as `CriticalOp::SetQuorum`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `signers` - Addresses that can propose and approve operations
+    /// * `threshold` - Number of approvals an operation needs
+    /// * `ttl_ledgers` - Ledgers a proposal stays open after it is made
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Quorum set
+    /// * `Err(ContractError::InvalidAmount)` - Threshold or TTL is zero, or the threshold exceeds the signers
+    /// * `Err(ContractError::AdminAlreadyExists)` - A signer is listed twice
+    /// * `Err(ContractError::Unauthorized)` - A quorum is already configured
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_quorum(
+        env: Env,
+        signers: Vec<Address>,
+        threshold: u32,
+        ttl_ledgers: u32,
+    ) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+        require_no_quorum(&env)?;
+
+        let config = QuorumConfig {
+            signers,
+            threshold,
+            ttl_ledgers,
+        };
+        validate_quorum_config(&config)?;
+        set_quorum_config(&env, &config);
+        emit_quorum_set(&env, config);
+
+        Ok(())
+    }
+
+    /// Returns the quorum for critical operations, if one is configured.
+    pub fn get_quorum(env: Env) -> Option<QuorumConfig> {
+        get_quorum_config(&env)
+    }
+
+    /// Proposes a critical operation, counting the proposer's approval.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `proposer` - Signer making the proposal
+    /// * `op` - Operation to perform once approved
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - ID of the proposal
+    /// * `Err(ContractError::InvalidStatus)` - No quorum is configured
+    /// * `Err(ContractError::Unauthorized)` - `proposer` is not a signer
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from `proposer`.
+    pub fn propose_op(env: Env, proposer: Address, op: CriticalOp) -> Result<u64, ContractError> {
+        let proposal = propose_op(&env, &proposer, op)?;
+        emit_op_proposed(
+            &env,
+            proposal.id,
+            proposer,
+            proposal.op,
+            proposal.expires_at_ledger,
+        );
+        Ok(proposal.id)
+    }
+
+    /// Approves a proposed critical operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `signer` - Signer approving the proposal
+    /// * `proposal_id` - ID of the proposal
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u32)` - Number of approvals so far
+    /// * `Err(ContractError::Unauthorized)` - `signer` is not a signer
+    /// * `Err(ContractError::InvalidStatus)` - Unknown or executed proposal, or already approved by `signer`
+    /// * `Err(ContractError::SignatureExpired)` - The proposal has expired
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from `signer`.
+    pub fn approve_op(env: Env, signer: Address, proposal_id: u64) -> Result<u32, ContractError> {
+        let approvals = approve_op(&env, &signer, proposal_id)?;
+        emit_op_approved(&env, proposal_id, signer, approvals);
+        Ok(approvals)
+    }
+
+    /// Performs a critical operation approved by the quorum.
+    ///
+    /// Token rescues are limited to what the contract holds beyond its
+    /// liabilities: the reconciliation surplus for the settlement token, and
+    /// the balance above accumulated fees for any other token.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `proposal_id` - ID of the approved proposal
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Operation performed
+    /// * `Err(ContractError::InvalidStatus)` - Unknown or executed proposal
+    /// * `Err(ContractError::SignatureExpired)` - The proposal has expired
+    /// * `Err(ContractError::Unauthorized)` - Fewer than `threshold` current signers approved
+    /// * `Err(ContractError::NoFeesToWithdraw)` - A fee withdrawal found no withdrawable fees
+    /// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - A rescue amount is not positive
+    /// * `Err(ContractError::InvalidAmount)` - A rescue exceeds the surplus
+    /// * `Err(ContractError::InvalidStatus)` - A distribution found no fee split, or an adapter
+    ///   change found principal in the old adapter
+    /// * `Err(ContractError::InsufficientEscrow)` - An escrow deposit would exceed escrow, or
+    ///   wrapped fees would exceed the withdrawable fees
+    ///
+    /// # Authorization
+    ///
+    /// None beyond the recorded approvals; anyone can execute an approved proposal.
+    pub fn execute_op(env: Env, proposal_id: u64) -> Result<(), ContractError> {
+        let proposal = take_approved_op(&env, proposal_id)?;
+        let proposer = proposal.approvals.get_unchecked(0);
+
+        match proposal.op.clone() {
+            CriticalOp::WithdrawFees(token, to) => {
+                let fees = validate_withdraw_fees_request(&env, &token, &to)?;
+                Self::pay_out_fees(&env, &proposer, &token, &to, fees)?;
+            }
+            CriticalOp::Upgrade(wasm_hash) => {
+                env.deployer().update_current_contract_wasm(wasm_hash);
+            }
+            CriticalOp::RescueTokens(token, to, amount) => {
+                validate_address(&to)?;
+                validate_amount(amount)?;
+                if amount > Self::rescuable_balance(&env, &token)? {
+                    return Err(ContractError::InvalidAmount);
+                }
+                token::Client::new(&env, &token).transfer(&env.current_contract_address(), &to, &amount);
+            }
+            CriticalOp::SetQuorum(config) => {
+                set_quorum_config(&env, &config);
+                emit_quorum_set(&env, config);
+            }
+            CriticalOp::SetFeeSplit(splits) => {
+                set_fee_split(&env, &splits);
+            }
+            CriticalOp::DistributeFees => {
+                Self::pay_out_fee_split(&env)?;
+            }
+            CriticalOp::SetYieldAdapter(adapter, beneficiary) => {
+                Self::register_yield_adapter(&env, adapter, beneficiary)?;
+            }
+            CriticalOp::DepositIdleEscrow(amount) => {
+                deposit_to_yield(&env, amount)?;
+                emit_yield_principal_moved(&env, amount, get_yield_principal(&env));
+            }
+            CriticalOp::SetTreasury(treasury) => {
+                set_treasury(&env, &treasury);
+                emit_treasury_set(&env, treasury);
+            }
+            CriticalOp::WrapFees(amount) => {
+                wrap_fees(&env, amount)?;
+                emit_fees_wrapped(&env, amount, get_wrapped_fees(&env));
+            }
+        }
+
+        emit_op_executed(&env, proposal_id, proposal.op);
+        Ok(())
+    }
+
+    /// Retrieves a proposed critical operation and its approvals.
+    pub fn get_op_proposal(env: Env, proposal_id: u64) -> Option<OpProposal> {
+        get_op_proposal(&env, proposal_id)
+    }
+
+    /// Deposits collateral backing an agent's payouts.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Registered agent posting the collateral
+    /// * `amount` - Amount of the settlement token to deposit
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(i128)` - Agent's collateral after the deposit
+    /// * `Err(ContractError::AgentNotRegistered)` - `agent` is not registered
+    /// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - Amount is not positive
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from `agent`.
+    pub fn deposit_collateral(env: Env, agent: Address, amount: i128) -> Result<i128, ContractError> {
+        agent.require_auth();
+        if !is_agent_registered(&env, &agent) {
+            return Err(ContractError::AgentNotRegistered);
+        }
+
+        add_collateral(&env, &agent, amount)?;
+        let usdc_token = get_usdc_token(&env)?;
+        token::Client::new(&env, &usdc_token).transfer(&agent, &env.current_contract_address(), &amount);
+
+        let balance = get_collateral(&env, &agent);
+        emit_collateral_changed(&env, agent, amount, balance);
+        Ok(balance)
+    }
+
+    /// Withdraws collateral not locked by an open slash.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Agent withdrawing its collateral
+    /// * `amount` - Amount to withdraw
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(i128)` - Agent's collateral after the withdrawal
+    /// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - Amount is not positive
+    /// * `Err(ContractError::InsufficientEscrow)` - Amount exceeds the collateral not locked by slashes
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from `agent`.
+    pub fn withdraw_collateral(env: Env, agent: Address, amount: i128) -> Result<i128, ContractError> {
+        agent.require_auth();
+
+        remove_collateral(&env, &agent, amount)?;
+        let usdc_token = get_usdc_token(&env)?;
+        token::Client::new(&env, &usdc_token).transfer(&env.current_contract_address(), &agent, &amount);
+
+        let balance = get_collateral(&env, &agent);
+        emit_collateral_changed(&env, agent, -amount, balance);
+        Ok(balance)
+    }
+
+    /// Returns an agent's collateral and the part of it locked by open slashes.
+    pub fn get_agent_collateral(env: Env, agent: Address) -> (i128, i128) {
+        (get_collateral(&env, &agent), get_locked_collateral(&env, &agent))
+    }
+
+    /// Sets the share of earned fees distributed to agents pro-rata to their collateral.
+    ///
+    /// Applies to remittances paid out afterwards, after SLA rebates and
+    /// sponsor reimbursements. 0 turns sharing off.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `share_bps` - Share of each earned fee, in basis points
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Share updated
+    /// * `Err(ContractError::InvalidFeeBps)` - Share above 10000 basis points
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_staking_share(env: Env, share_bps: u32) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        validate_fee_bps(share_bps)?;
+        set_staking_share_bps(&env, share_bps);
+        emit_staking_share_set(&env, share_bps);
+
+        Ok(())
+    }
+
+    /// Returns the share of earned fees distributed to staked agents, in basis points.
+    pub fn get_staking_share(env: Env) -> u32 {
+        get_staking_share_bps(&env)
+    }
+
+    /// Returns the staking rewards an agent can claim now.
+    pub fn get_staking_rewards(env: Env, agent: Address) -> Result<i128, ContractError> {
+        get_staking_rewards(&env, &agent)
+    }
+
+    /// Pays an agent the fee share its collateral has earned.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Agent claiming its rewards
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(i128)` - Amount paid to the agent
+    /// * `Err(ContractError::NoFeesToWithdraw)` - The agent has no rewards to claim
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from `agent`.
+    pub fn claim_staking_rewards(env: Env, agent: Address) -> Result<i128, ContractError> {
+        agent.require_auth();
+        check_op_not_paused(&env, PausableOp::WithdrawFees)?;
+
+        let amount = claim_staking_rewards(&env, &agent)?;
+        let usdc_token = get_usdc_token(&env)?;
+        token::Client::new(&env, &usdc_token).transfer(&env.current_contract_address(), &agent, &amount);
+
+        emit_staking_rewards_claimed(&env, agent, amount);
+        Ok(amount)
+    }
+
+    /// Sets how long an agent has to challenge a proposed slash.
+    ///
+    /// Applies to slashes proposed afterwards. Defaults to 3 days.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_slash_challenge_window(env: Env, seconds: u64) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_challenge_window(&env, seconds);
+        Ok(())
+    }
+
+    /// Proposes slashing an agent's collateral to compensate a remittance's sender.
+    ///
+    /// Locks the remittance amount, or as much of it as the agent's free
+    /// collateral covers, for the challenge window.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Agent of the remittance
+    /// * `remittance_id` - Remittance the agent failed
+    /// * `evidence_hash` - Hash of the evidence supporting the slash
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SlashProposal)` - The proposal, with the amount locked
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance does not exist
+    /// * `Err(ContractError::Unauthorized)` - `agent` is not the remittance's agent
+    /// * `Err(ContractError::InvalidStatus)` - The remittance was not paid out, or was already
+    ///   slashed or is being slashed
+    /// * `Err(ContractError::InsufficientEscrow)` - The agent has no free collateral
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn propose_slash(
+        env: Env,
+        agent: Address,
+        remittance_id: u64,
+        evidence_hash: BytesN<32>,
+    ) -> Result<SlashProposal, ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        let remittance = get_remittance(&env, remittance_id)?;
+        if remittance.agent != agent {
+            return Err(ContractError::Unauthorized);
+        }
+
+        let slash = propose_slash(&env, &remittance, evidence_hash)?;
+        emit_slash_proposed(
+            &env,
+            remittance_id,
+            agent,
+            slash.amount,
+            slash.evidence_hash.clone(),
+            slash.executable_at,
+        );
+        Ok(slash)
+    }
+
+    /// Contests a proposed slash within its challenge window.
+    ///
+    /// The slash then waits for the admin's `resolve_slash` instead of
+    /// becoming executable when the window closes.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - Remittance the slash is about
+    /// * `response_hash` - Hash of the agent's response to the evidence
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Slash challenged
+    /// * `Err(ContractError::InvalidStatus)` - No open proposal, or the challenge window has passed
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the slashed agent.
+    pub fn challenge_slash(env: Env, remittance_id: u64, response_hash: BytesN<32>) -> Result<(), ContractError> {
+        let slash = get_slash(&env, remittance_id).ok_or(ContractError::InvalidStatus)?;
+        slash.agent.require_auth();
+
+        let slash = challenge_slash(&env, remittance_id, response_hash.clone())?;
+        emit_slash_challenged(&env, remittance_id, slash.agent, response_hash);
+        Ok(())
+    }
+
+    /// Executes an unchallenged slash after its challenge window, paying the
+    /// locked collateral to the remittance's sender.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - Remittance the slash is about
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(i128)` - Amount paid to the sender
+    /// * `Err(ContractError::InvalidStatus)` - The slash is not open, is challenged,
+    ///   or is still within its challenge window
+    ///
+    /// # Authorization
+    ///
+    /// None; anyone can execute a slash once it is due.
+    pub fn execute_slash(env: Env, remittance_id: u64) -> Result<i128, ContractError> {
+        Self::pay_out_slash(&env, remittance_id, false)
+    }
+
+    /// Decides an open slash: upholding a challenged slash pays the sender
+    /// immediately, otherwise the slash is cancelled and the collateral
+    /// unlocked. Unchallenged slashes cannot be upheld; they are executed
+    /// with `execute_slash` once their challenge window has passed.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - Remittance the slash is about
+    /// * `uphold` - Whether to execute the slash
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(i128)` - Amount paid to the sender, 0 when cancelled
+    /// * `Err(ContractError::InvalidStatus)` - The slash is not open, or is upheld without
+    ///   having been challenged
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn resolve_slash(env: Env, remittance_id: u64, uphold: bool) -> Result<i128, ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        if uphold {
+            return Self::pay_out_slash(&env, remittance_id, true);
+        }
+        let slash = cancel_slash(&env, remittance_id)?;
+        emit_slash_closed(&env, remittance_id, slash.status, slash.agent, slash.amount);
+        Ok(0)
+    }
+
+    /// Retrieves the slash proposed over a remittance, if any.
+    pub fn get_slash(env: Env, remittance_id: u64) -> Option<SlashProposal> {
+        get_slash(&env, remittance_id)
+    }
+
+    /// Sets the surcharge for expediting a remittance.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `fee_bps` - Surcharge in basis points of the remittance amount (0-10000)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Surcharge updated
+    /// * `Err(ContractError::InvalidFeeBps)` - Surcharge above 10000
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_express_fee(env: Env, fee_bps: u32) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        validate_fee_bps(fee_bps)?;
+
+        set_express_fee_bps(&env, fee_bps);
+        emit_express_fee_set(&env, fee_bps);
+
+        Ok(())
+    }
+
+    /// Returns the surcharge for expediting a remittance in basis points.
+    pub fn get_express_fee(env: Env) -> u32 {
+        get_express_fee_bps(&env)
+    }
+
+    /// Upgrades a pending remittance to express priority.
+    ///
+    /// The sender pays the express surcharge on the remittance amount, which
+    /// is added to both the amount and the platform fee so the payout is
+    /// unchanged. The remittance moves ahead of the standard remittances in
+    /// its agent's pending queue and is measured against the express SLA.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to expedite
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(i128)` - Surcharge paid
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not Pending, is
+    ///   already express, or was partially released
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender of the remittance.
+    pub fn expedite_remittance(env: Env, remittance_id: u64) -> Result<i128, ContractError> {
+        let mut remittance = validate_remittance_exists(&env, remittance_id)?;
+        validate_remittance_pending(&remittance)?;
+        if remittance.priority == Priority::Express
+            || get_released_totals(&env, remittance_id).amount > 0
+        {
+            return Err(ContractError::InvalidStatus);
+        }
+
+        remittance.sender.require_auth();
+
+        let surcharge = express_surcharge(&env, remittance.amount)?;
+        if surcharge > 0 {
+            let usdc_token = get_usdc_token(&env)?;
+            token::Client::new(&env, &usdc_token).transfer(
+                &remittance.sender,
+                &env.current_contract_address(),
+                &surcharge,
+            );
+            book_remittance(&env, surcharge, surcharge)?;
+        }
+
+        remittance.amount = remittance
+            .amount
+            .checked_add(surcharge)
+            .ok_or(ContractError::Overflow)?;
+        remittance.fee = remittance
+            .fee
+            .checked_add(surcharge)
+            .ok_or(ContractError::Overflow)?;
+        remittance.priority = Priority::Express;
+        set_remittance(&env, remittance_id, &remittance);
+
+        pop_assignment(&env, &remittance.agent, remittance_id);
+        push_assignment(&env, &remittance.agent, remittance_id, Priority::Express);
+
+        emit_remittance_expedited(&env, remittance_id, remittance.agent.clone(), surcharge);
+
+        Ok(surcharge)
+    }
+
+    /// Confirms a payout with the local-currency amount the recipient received.
+    ///
+    /// Behaves like `confirm_payout`, and also records on the remittance and
+    /// in its completed event the local amount the agent paid out and the
+    /// rate it applied, so the effective FX spread charged to consumers can
+    /// be tracked against published rates.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to confirm
+    /// * `local_amount` - Amount paid to the recipient in the smallest unit of the local currency
+    /// * `rate` - Local currency units per USDC applied, scaled by 10^7
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Payout confirmed and its FX details recorded
+    /// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - `local_amount` or `rate` is not positive
+    /// * Any error returned by `confirm_payout`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent address assigned to the remittance.
+    pub fn confirm_payout_with_fx(
+        env: Env,
+        remittance_id: u64,
+        local_amount: i128,
+        rate: i128,
+    ) -> Result<(), ContractError> {
+        validate_amount(local_amount)?;
+        validate_amount(rate)?;
+
+        let mut remittance = validate_confirm_payout_request(&env, remittance_id)?;
+
+        remittance.agent.require_auth();
+
+        check_rate_limit(&env, &remittance.sender)?;
+
+        remittance.payout_fx = Some(PayoutFx { local_amount, rate });
+        let agent = remittance.agent.clone();
+        Self::settle_payout(&env, remittance, None, Some(agent))
+    }
+
+    /// Schedules the platform fee to change at a future time.
+    ///
+    /// The new fee applies automatically to remittances created from
+    /// `effective_time`, so clients can requote ahead of the change instead of
+    /// having signed quotes broken by an immediate `update_fee`. Scheduling
+    /// again for the same time replaces the earlier update.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `new_bps` - Platform fee in basis points from `effective_time` (0-10000)
+    /// * `effective_time` - Ledger timestamp from which the fee applies
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Update scheduled
+    /// * `Err(ContractError::InvalidFeeBps)` - Fee above 10000
+    /// * `Err(ContractError::InvalidAmount)` - `effective_time` is not in the future
+    /// * `Err(ContractError::LimitExceeded)` - MAX_SCHEDULED_FEE_UPDATES are already scheduled
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn schedule_fee_update(env: Env, new_bps: u32, effective_time: u64) -> Result<(), ContractError> {
+        validate_update_fee_request(new_bps)?;
+
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        schedule_fee(&env, new_bps, effective_time)?;
+        emit_fee_scheduled(&env, new_bps, effective_time);
+
+        Ok(())
+    }
+
+    /// Returns the platform fee in effect at `at_time`, including scheduled updates.
+    pub fn get_effective_fee(env: Env, at_time: u64) -> Result<u32, ContractError> {
+        get_effective_fee_bps(&env, at_time)
+    }
+
+    /// Returns the platform fee updates that have not taken effect yet, earliest first.
+    pub fn get_scheduled_fee_updates(env: Env) -> Vec<ScheduledFee> {
+        let now = env.ledger().timestamp();
+        let mut pending = Vec::new(&env);
+        for scheduled in get_scheduled_fees(&env).iter() {
+            if scheduled.effective_at > now {
+                pending.push_back(scheduled);
+            }
+        }
+        pending
+    }
+
+    /// Lists remittances still awaiting payout more than `older_than_secs`
+    /// after creation, oldest first, for ops alerting.
+    ///
+    /// Remittances held in attested mode count until their payout is
+    /// released. Pages through the bucketed open-remittance index: pass the
+    /// last ID returned plus one as `start_id` to continue.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `older_than_secs` - Minimum age of the remittances returned, in seconds
+    /// * `start_id` - Lowest remittance ID to return
+    /// * `limit` - Maximum IDs to return, capped at MAX_ASSIGNMENT_PAGE
+    pub fn get_stale_remittances(env: Env, older_than_secs: u64, start_id: u64, limit: u32) -> Vec<u64> {
+        get_stale_remittances(&env, older_than_secs, start_id, limit)
+    }
+
+    /// Adds funds to the escrow of a pending remittance.
+    ///
+    /// Lets a sender cover a payout that now needs more source tokens, for
+    /// example after an FX move, without cancelling and recreating the
+    /// remittance. The fee grows in proportion to the amount, at the rate the
+    /// remittance was charged, so discounts and surcharges carry over.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to top up
+    /// * `extra_amount` - Amount to add
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(i128)` - Amount of the remittance after the top-up
+    /// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - `extra_amount` is zero or negative
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not Pending or was partially released
+    /// * `Err(ContractError::AmountAboveMaximum)` - New amount exceeds the token or corridor cap
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender of the remittance.
+    pub fn top_up_remittance(env: Env, remittance_id: u64, extra_amount: i128) -> Result<i128, ContractError> {
+        validate_amount(extra_amount)?;
+        let mut remittance = validate_remittance_exists(&env, remittance_id)?;
+        validate_remittance_pending(&remittance)?;
+        if get_released_totals(&env, remittance_id).amount > 0 {
+            return Err(ContractError::InvalidStatus);
+        }
+        let new_amount = remittance
+            .amount
+            .checked_add(extra_amount)
+            .ok_or(ContractError::Overflow)?;
+        validate_create_remittance_request(&env, &remittance.sender, &remittance.agent, new_amount)?;
+
+        remittance.sender.require_auth();
+
+        consume_intent(&env, &remittance.sender, &remittance.agent, new_amount)?;
+
+        let usdc_token = get_usdc_token(&env)?;
+        let extra_fee = div_round(
+            remittance
+                .fee
+                .checked_mul(extra_amount)
+                .ok_or(ContractError::Overflow)?,
+            remittance.amount,
+            get_fee_rounding_mode(&env, &usdc_token),
+        )?;
+
+        token::Client::new(&env, &usdc_token).transfer(
+            &remittance.sender,
+            &env.current_contract_address(),
+            &extra_amount,
+        );
+        book_remittance(&env, extra_amount, extra_fee)?;
+
+        remittance.amount = new_amount;
+        remittance.fee = remittance
+            .fee
+            .checked_add(extra_fee)
+            .ok_or(ContractError::Overflow)?;
+        set_remittance(&env, remittance_id, &remittance);
+
+        emit_remittance_topped_up(&env, remittance_id, extra_amount, extra_fee, new_amount);
+
+        Ok(new_amount)
+    }
+
+    /// Sets how many ledgers new remittances are withheld from their agent.
+    ///
+    /// During the screening window a remittance is funded but does not appear
+    /// in the agent's pending assignments and cannot be confirmed, giving
+    /// compliance time to freeze it with `freeze_remittance`. Remittances
+    /// already created keep the window they were created with.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `ledgers` - Length of the screening window in ledgers; 0 disables screening
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Screening period updated
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_screening_period(env: Env, ledgers: u32) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_screening_ledgers(&env, ledgers);
+        emit_screening_period_set(&env, ledgers);
+
+        Ok(())
+    }
+
+    /// Returns the screening period in ledgers; 0 when screening is disabled.
+    pub fn get_screening_period(env: Env) -> u32 {
+        get_screening_ledgers(&env)
+    }
+
+    /// Returns the first ledger at which a remittance becomes visible to its
+    /// agent and payable, if it was created with a screening window.
+    pub fn get_visible_at_ledger(env: Env, remittance_id: u64) -> Option<u32> {
+        get_visible_at(&env, remittance_id)
+    }
+}
+    }
+
+#[cfg(feature = "kyc")]
+#[contractimpl]
+impl SwiftRemitContract {
+    // ═══════════════════════════════════════════════════════════════════════════
+    // KYC Functions (`kyc` feature)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Registers the external compliance provider consulted on every new remittance.
+    ///
+    /// The provider must expose `check(sender, agent, amount, corridor) -> bool`;
+    /// remittance creation aborts with `ComplianceRejected` unless it returns `true`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `hook` - Compliance provider contract address
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_compliance_hook(env: Env, hook: Address) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_compliance_hook(&env, &hook);
+        emit_compliance_hook_set(&env, Some(hook));
+
+        Ok(())
+    }
+
+    /// Removes the compliance provider so remittances are no longer checked externally.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn clear_compliance_hook(env: Env) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        clear_compliance_hook(&env);
+        emit_compliance_hook_set(&env, None);
+
+        Ok(())
+    }
+
+    /// Returns the registered compliance provider, if any.
+    pub fn get_compliance_hook(env: Env) -> Option<Address> {
+        get_compliance_hook(&env)
+    }
+
+    /// Turns the sender allowlist on or off.
+    ///
+    /// While enabled, only senders approved with `allow_sender` may create
+    /// remittances. Approvals are kept while the allowlist is disabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `enabled` - Whether to enforce the allowlist
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_sender_allowlist_enabled(env: Env, enabled: bool) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_sender_allowlist_enabled(&env, enabled);
+        emit_sender_allowlist_enabled(&env, enabled);
+
+        Ok(())
+    }
+
+    /// Approves a sender to create remittances while the allowlist is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Sender address to approve
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn allow_sender(env: Env, sender: Address) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_sender_allowed(&env, &sender, true);
+        emit_sender_allowed(&env, sender, true);
+
+        Ok(())
+    }
+
+    /// Removes a sender from the allowlist.
+    ///
+    /// Remittances the sender already created are unaffected.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Sender address to remove
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn disallow_sender(env: Env, sender: Address) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_sender_allowed(&env, &sender, false);
+        emit_sender_allowed(&env, sender, false);
+
+        Ok(())
+    }
+
+    /// Returns whether the sender allowlist is enforced.
+    pub fn is_sender_allowlist_enabled(env: Env) -> bool {
+        is_sender_allowlist_enabled(&env)
+    }
+
+    /// Returns whether a sender may currently create remittances.
+    pub fn is_sender_allowed(env: Env, sender: Address) -> bool {
+        is_sender_allowed(&env, &sender)
+    }
+
+    /// Sets the cooling-off delay high-risk senders must wait after declaring an intent.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `seconds` - Delay between `declare_intent` and `create_remittance`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_cooling_off_period(env: Env, seconds: u64) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_cooling_off_period(&env, seconds);
+        Ok(())
+    }
+
+    /// Flags or unflags a sender as high-risk.
+    ///
+    /// High-risk senders must declare an intent and wait out the cooling-off
+    /// delay before each remittance.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Sender address
+    /// * `high_risk` - Whether the two-step send is required
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_high_risk_sender(env: Env, sender: Address, high_risk: bool) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_high_risk_sender(&env, &sender, high_risk);
+        Ok(())
+    }
+
+    /// Declares an intent to send a remittance, starting the cooling-off delay.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Address of the sender
+    /// * `agent` - Agent the remittance will be sent through
+    /// * `amount` - Amount that will be sent
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RemittanceIntent)` - The recorded intent
+    /// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - Amount is zero or negative
+    /// * `Err(ContractError::AgentNotRegistered)` - Agent is not registered
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address.
+    pub fn declare_intent(
+        env: Env,
+        sender: Address,
+        agent: Address,
+        amount: i128,
+    ) -> Result<RemittanceIntent, ContractError> {
+        validate_create_remittance_request(&env, &sender, &agent, amount)?;
+
+        sender.require_auth();
+
+        let intent = declare_intent(&env, &sender, &agent, amount);
+        emit_intent_declared(&env, sender, agent, amount, intent.available_at);
+
+        Ok(intent)
+    }
+
+    /// Returns the pending intent of a sender, if any.
+    pub fn get_intent(env: Env, sender: Address) -> Option<RemittanceIntent> {
+        get_intent(&env, &sender)
+    }
+
+    /// Checks if a sender is flagged as high-risk.
+    pub fn is_high_risk_sender(env: Env, sender: Address) -> bool {
+        is_high_risk_sender(&env, &sender)
+    }
+
+    /// Appoints or removes a risk scorer.
+    ///
+    /// Risk scorers keep the senders' risk scores with `set_risk_score`. A
+    /// scorer can be a compliance officer's account or an external risk
+    /// contract that calls in with its own address.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `scorer` - Address to appoint or remove
+    /// * `enabled` - Whether the address may set risk scores
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_risk_scorer(env: Env, scorer: Address, enabled: bool) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_risk_scorer(&env, &scorer, enabled);
+        emit_risk_scorer_set(&env, scorer, enabled);
+
+        Ok(())
+    }
+
+    /// Returns whether an address may set risk scores.
+    pub fn is_risk_scorer(env: Env, scorer: Address) -> bool {
+        is_risk_scorer(&env, &scorer)
+    }
+
+    /// Sets the risk score of a sender.
+    ///
+    /// The score places the sender in one of the risk bands, whose limits
+    /// apply to the sender's next remittances.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `scorer` - Risk scorer or admin setting the score
+    /// * `sender` - Sender to score
+    /// * `score` - New score; higher scores are riskier
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Score updated
+    /// * `Err(ContractError::Unauthorized)` - `scorer` is neither a risk scorer nor an admin
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from `scorer`.
+    pub fn set_risk_score(env: Env, scorer: Address, sender: Address, score: u32) -> Result<(), ContractError> {
+        scorer.require_auth();
+        if !is_risk_scorer(&env, &scorer) && !is_admin(&env, &scorer) {
+            return Err(ContractError::Unauthorized);
+        }
+
+        set_risk_score(&env, &sender, score);
+        emit_risk_score_set(&env, scorer, sender, score);
+
+        Ok(())
+    }
+
+    /// Returns the risk score of a sender; 0 if the sender was never scored.
+    pub fn get_risk_score(env: Env, sender: Address) -> u32 {
+        get_risk_score(&env, &sender)
+    }
+
+    /// Exempts a verified institutional sender from the risk band limits and
+    /// the creation rate limit, or lifts the exemption.
+    ///
+    /// Meant for audited partners whose volume would otherwise require limits
+    /// high enough to apply to every sender. Compliance checks, transfer
+    /// amount caps and cancellation cooldowns still apply.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `officer` - Risk scorer or admin changing the exemption
+    /// * `sender` - Institutional sender
+    /// * `exempt` - Whether the sender bypasses the limits
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Exemption updated
+    /// * `Err(ContractError::Unauthorized)` - `officer` is neither a risk scorer nor an admin
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from `officer`.
+    pub fn set_limit_exempt(env: Env, officer: Address, sender: Address, exempt: bool) -> Result<(), ContractError> {
+        officer.require_auth();
+        if !is_risk_scorer(&env, &officer) && !is_admin(&env, &officer) {
+            return Err(ContractError::Unauthorized);
+        }
+
+        set_limit_exempt(&env, &sender, exempt);
+        emit_limit_exempt_set(&env, officer, sender, exempt);
+
+        Ok(())
+    }
+
+    /// Returns whether a sender is exempt from risk band and creation rate limits.
+    pub fn is_limit_exempt(env: Env, sender: Address) -> bool {
+        is_limit_exempt(&env, &sender)
+    }
+
+    /// Replaces the risk bands that gate remittances by sender score.
+    ///
+    /// Each band applies from its `min_score` up to the next band's, capping
+    /// single remittances at `max_amount` and the remittances a sender can
+    /// create per 24 hours at `max_per_day`. Senders scoring below the first
+    /// band are not limited.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `bands` - Bands in strictly ascending order of `min_score`; empty to remove all limits
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Bands replaced
+    /// * `Err(ContractError::LimitExceeded)` - More than `MAX_RISK_BANDS` bands
+    /// * `Err(ContractError::InvalidAmount)` - Bands out of order
+    /// * `Err(ContractError::NegativeAmount)` - A band has a negative `max_amount`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_risk_bands(env: Env, bands: Vec<RiskBand>) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_risk_bands(&env, &bands)?;
+        emit_risk_bands_set(&env, bands.len());
+
+        Ok(())
+    }
+
+    /// Returns the risk bands in ascending order of `min_score`.
+    pub fn get_risk_bands(env: Env) -> Vec<RiskBand> {
+        get_risk_bands(&env)
+    }
+}
+
+#[cfg(feature = "corridors")]
+#[contractimpl]
+impl SwiftRemitContract {
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Corridor Functions (`corridors` feature)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Assigns an agent to the payout corridor it serves.
+    ///
+    /// Remittances sent to the agent are subject to the corridor's rules, such
+    /// as its accepted purpose codes. Existing remittances keep their purpose.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Agent address
+    /// * `corridor` - Corridor identifier, e.g. `"US-PH"`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Corridor assigned
+    /// * `Err(ContractError::AgentNotRegistered)` - Agent is not registered
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_agent_corridor(env: Env, agent: Address, corridor: String) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        if !is_agent_registered(&env, &agent) {
+            return Err(ContractError::AgentNotRegistered);
+        }
+
+        set_agent_corridor(&env, &agent, &corridor);
+        emit_agent_corridor_set(&env, agent, corridor);
+
+        Ok(())
+    }
+
+    /// Returns the payout corridor an agent is assigned to, if any.
+    pub fn get_agent_corridor(env: Env, agent: Address) -> Option<String> {
+        get_agent_corridor(&env, &agent)
+    }
+
+    /// Sets the purpose codes accepted for remittances into a corridor.
+    ///
+    /// `create_remittance` rejects purposes outside the list with
+    /// `PurposeNotAllowed`. An empty list accepts every purpose code.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `corridor` - Corridor identifier
+    /// * `purposes` - Accepted purpose codes
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_corridor_purposes(
+        env: Env,
+        corridor: String,
+        purposes: Vec<PurposeCode>,
+    ) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_corridor_purposes(&env, &corridor, &purposes);
+        emit_corridor_purposes_set(&env, corridor, purposes);
+
+        Ok(())
+    }
+
+    /// Returns the purpose codes accepted in a corridor, empty when all are accepted.
+    pub fn get_corridor_purposes(env: Env, corridor: String) -> Vec<PurposeCode> {
+        get_corridor_purposes(&env, &corridor)
+    }
+
+    /// Sets the platform fee charged on remittances paid out in a corridor.
+    ///
+    /// Overrides the global fee for agents assigned to the corridor; `quote`
+    /// applies it when given the agent. Fee exemptions still take precedence.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `corridor` - Corridor identifier
+    /// * `fee_bps` - Fee in basis points (0-10000)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Fee override set
+    /// * `Err(ContractError::InvalidFeeBps)` - Fee above 10000
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_corridor_fee(env: Env, corridor: String, fee_bps: u32) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        validate_fee_bps(fee_bps)?;
+
+        set_corridor_fee(&env, &corridor, fee_bps);
+        emit_corridor_fee_set(&env, corridor, Some(fee_bps));
+
+        Ok(())
+    }
+
+    /// Removes the fee override of a corridor, reverting it to the global fee.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `corridor` - Corridor identifier
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn clear_corridor_fee(env: Env, corridor: String) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        clear_corridor_fee(&env, &corridor);
+        emit_corridor_fee_set(&env, corridor, None);
+
+        Ok(())
+    }
+
+    /// Returns the fee override of a corridor in basis points, if any.
+    pub fn get_corridor_fee(env: Env, corridor: String) -> Option<u32> {
+        get_corridor_fee(&env, &corridor)
+    }
+
+    /// Sets how long new remittances in a corridor are held before payout.
+    ///
+    /// During the hold the agent cannot confirm the payout, so the sender has
+    /// time to cancel a mistaken transfer. The hold expiry is fixed on each
+    /// remittance at creation as `hold_until`; changing the period does not
+    /// affect remittances already created.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `corridor` - Corridor identifier
+    /// * `seconds` - Hold period, e.g. 600 for ten minutes; 0 removes the hold
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_corridor_hold(env: Env, corridor: String, seconds: u64) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_corridor_hold(&env, &corridor, seconds);
+        emit_corridor_hold_set(&env, corridor, seconds);
+
+        Ok(())
+    }
+
+    /// Returns the hold period of new remittances in a corridor in seconds, 0 when unset.
+    pub fn get_corridor_hold(env: Env, corridor: String) -> u64 {
+        get_corridor_hold(&env, &corridor)
+    }
+
+    /// Allows or forbids senders to pay out to themselves in a corridor.
+    ///
+    /// By default a remittance whose sender is also its agent or payout
+    /// recipient is rejected with `SelfDealing`. Some corridors legitimately
+    /// need it, e.g. an agent moving its own float between countries.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `corridor` - Corridor identifier
+    /// * `allowed` - Whether self-payout is allowed
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_corridor_self_payout(env: Env, corridor: String, allowed: bool) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_corridor_self_payout(&env, &corridor, allowed);
+        emit_corridor_self_payout_set(&env, corridor, allowed);
+
+        Ok(())
+    }
+
+    /// Returns whether senders may pay out to themselves in a corridor.
+    pub fn is_corridor_self_payout_allowed(env: Env, corridor: String) -> bool {
+        is_corridor_self_payout_allowed(&env, &corridor)
+    }
+
+    /// Blocks remittances from one country to another, e.g. into an embargoed destination.
+    ///
+    /// Remittances through agents whose corridor is `"<country_from>-<country_to>"`
+    /// are rejected with `CorridorBlocked` on creation. Remittances already
+    /// pending are not affected.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `country_from` - ISO code of the origin country, e.g. `"US"`
+    /// * `country_to` - ISO code of the destination country
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn block_corridor(env: Env, country_from: String, country_to: String) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        block_corridor(&env, &country_from, &country_to);
+        emit_corridor_blocked(&env, caller, country_from, country_to, true);
+
+        Ok(())
+    }
+
+    /// Lifts the block on remittances from one country to another.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn unblock_corridor(env: Env, country_from: String, country_to: String) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        unblock_corridor(&env, &country_from, &country_to);
+        emit_corridor_blocked(&env, caller, country_from, country_to, false);
+
+        Ok(())
+    }
+
+    /// Returns whether remittances from `country_from` to `country_to` are blocked.
+    pub fn is_corridor_blocked(env: Env, country_from: String, country_to: String) -> bool {
+        is_corridor_blocked(&env, &country_from, &country_to)
+    }
+}
+
+#[cfg(feature = "loyalty")]
+#[contractimpl]
+impl SwiftRemitContract {
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Loyalty Functions (`loyalty` feature)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Sets the rate at which senders earn loyalty points on completed remittances.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `earn_bps` - Points earned per 10000 units sent (0-10000); 0 stops accrual
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Rate updated
+    /// * `Err(ContractError::InvalidFeeBps)` - Rate above 10000
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_loyalty_rate(env: Env, earn_bps: u32) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        validate_fee_bps(earn_bps)?;
+
+        set_loyalty_earn_bps(&env, earn_bps);
+        emit_loyalty_rate_set(&env, earn_bps);
+
+        Ok(())
+    }
+
+    /// Returns the loyalty earn rate in points per 10000 units sent.
+    pub fn get_loyalty_rate(env: Env) -> u32 {
+        get_loyalty_earn_bps(&env)
+    }
+
+    /// Returns the unredeemed loyalty points of a user.
+    pub fn get_points(env: Env, user: Address) -> i128 {
+        get_points(&env, &user)
+    }
+
+    /// Returns the redeemed fee credit a user has left for future remittances.
+    pub fn get_fee_credit(env: Env, user: Address) -> i128 {
+        get_fee_credit(&env, &user)
+    }
+
+    /// Converts loyalty points into a discount on future platform fees.
+    ///
+    /// Each point becomes one token unit of fee credit, which is drawn down to
+    /// reduce the fee of the user's next remittances.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `user` - Address redeeming points
+    /// * `amount` - Number of points to redeem
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(i128)` - Fee credit available after the redemption
+    /// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - Amount is zero or negative
+    /// * `Err(ContractError::InsufficientPoints)` - User has fewer points than `amount`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the user.
+    pub fn redeem_points(env: Env, user: Address, amount: i128) -> Result<i128, ContractError> {
+        user.require_auth();
+
+        let fee_credit = redeem_points(&env, &user, amount)?;
+        emit_points_redeemed(&env, user, amount, fee_credit);
+
+        Ok(fee_credit)
+    }
+}
+
+#[cfg(feature = "oracle")]
+#[contractimpl]
+impl SwiftRemitContract {
+    // ═══════════════════════════════════════════════════════════════════════════
+    // FX Oracle Functions (`oracle` feature)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Publishes the FX rate used in quotes for a payout currency.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `currency` - Payout currency code (e.g., "KES", "NGN")
+    /// * `rate` - Units of currency per USDC, scaled by FX_RATE_SCALE
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Rate published
+    /// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - Rate is not positive
+    /// * `Err(ContractError::CorridorClosed)` - Currency is not a 3-letter code
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_fx_rate(env: Env, currency: String, rate: i128) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        validate_amount(rate)?;
+
+        let currency = Currency::parse(&env, &currency)?;
+        set_fx_rate(&env, &currency, rate);
+
+        Ok(())
+    }
+
+    /// Quotes the fee, payout and FX rate for sending `amount` to `currency`.
+    ///
+    /// Read-only. The fee matches what `create_remittance` charges for the same
+    /// amount as long as the fee rate is not updated before the quote expires.
+    /// Pass the sender to have fee exemptions applied, and the agent to have
+    /// its corridor's fee applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `amount` - Amount to send (in USDC)
+    /// * `currency` - Payout currency code
+    /// * `sender` - Sender the quote is for, if known
+    /// * `agent` - Agent the remittance will be sent to, if known
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Quote)` - Quote valid until its `expiry`
+    /// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - Amount is not positive
+    /// * `Err(ContractError::CorridorClosed)` - Currency is not a 3-letter code, or no FX rate
+    ///   is published for it
+    /// * `Err(ContractError::RateExpired)` - The published rate is older than FX_RATE_MAX_AGE_SECONDS
+    pub fn quote(
+        env: Env,
+        amount: i128,
+        currency: String,
+        sender: Option<Address>,
+        agent: Option<Address>,
+    ) -> Result<Quote, ContractError> {
+        let currency = Currency::parse(&env, &currency)?;
+        build_quote(&env, amount, &currency, sender.as_ref(), agent.as_ref())
+    }
+}
@@ -0,0 +1,156 @@
+//! Sender loyalty points.
+//!
+//! Each completed remittance credits its sender with points proportional to
+//! the amount sent, at a rate set by the admin. Senders redeem points into a
+//! fee credit, one point per token unit, which is drawn down to discount the
+//! platform fee of their next remittances. The discount taken by a remittance
+//! is returned to the sender's credit if the remittance is refunded.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::{validate_amount, ContractError};
+
+#[contracttype]
+#[derive(Clone)]
+enum LoyaltyKey {
+    /// Points earned per 10000 units of completed volume
+    EarnBps,
+    /// Unredeemed points of a sender (persistent storage)
+    Points(Address),
+    /// Redeemed fee credit of a sender (persistent storage)
+    FeeCredit(Address),
+    /// Fee credit drawn by an open remittance (persistent storage)
+    Discount(u64),
+}
+
+pub fn set_loyalty_earn_bps(env: &Env, earn_bps: u32) {
+    env.storage().instance().set(&LoyaltyKey::EarnBps, &earn_bps);
+}
+
+pub fn get_loyalty_earn_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&LoyaltyKey::EarnBps)
+        .unwrap_or(0)
+}
+
+pub fn get_points(env: &Env, user: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&LoyaltyKey::Points(user.clone()))
+        .unwrap_or(0)
+}
+
+fn set_points(env: &Env, user: &Address, points: i128) {
+    env.storage()
+        .persistent()
+        .set(&LoyaltyKey::Points(user.clone()), &points);
+}
+
+pub fn get_fee_credit(env: &Env, user: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&LoyaltyKey::FeeCredit(user.clone()))
+        .unwrap_or(0)
+}
+
+fn set_fee_credit(env: &Env, user: &Address, credit: i128) {
+    let key = LoyaltyKey::FeeCredit(user.clone());
+    if credit > 0 {
+        env.storage().persistent().set(&key, &credit);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Credits `user` with points for `volume` of completed remittances.
+///
+/// # Returns
+///
+/// * `Ok(i128)` - Points earned, 0 when the earn rate is unset
+pub fn accrue_points(env: &Env, user: &Address, volume: i128) -> Result<i128, ContractError> {
+    let earned = volume
+        .checked_mul(get_loyalty_earn_bps(env) as i128)
+        .ok_or(ContractError::Overflow)?
+        / 10000;
+    if earned > 0 {
+        let points = get_points(env, user)
+            .checked_add(earned)
+            .ok_or(ContractError::Overflow)?;
+        set_points(env, user, points);
+    }
+    Ok(earned)
+}
+
+/// Converts `points` of `user` into fee credit.
+///
+/// # Returns
+///
+/// * `Ok(i128)` - Fee credit available after the redemption
+/// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - `points` is zero or negative
+/// * `Err(ContractError::InsufficientPoints)` - `user` has fewer than `points` points
+pub fn redeem_points(env: &Env, user: &Address, points: i128) -> Result<i128, ContractError> {
+    validate_amount(points)?;
+    let balance = get_points(env, user);
+    if points > balance {
+        return Err(ContractError::InsufficientPoints);
+    }
+    set_points(env, user, balance - points);
+
+    let credit = get_fee_credit(env, user)
+        .checked_add(points)
+        .ok_or(ContractError::Overflow)?;
+    set_fee_credit(env, user, credit);
+    Ok(credit)
+}
+
+/// Draws fee credit of `user` to discount `fee` on a new remittance.
+///
+/// # Returns
+///
+/// * `i128` - Discount taken, at most `fee`
+pub fn apply_fee_credit(env: &Env, user: &Address, remittance_id: u64, fee: i128) -> i128 {
+    let credit = get_fee_credit(env, user);
+    let discount = credit.min(fee).max(0);
+    if discount > 0 {
+        set_fee_credit(env, user, credit - discount);
+        env.storage()
+            .persistent()
+            .set(&LoyaltyKey::Discount(remittance_id), &discount);
+    }
+    discount
+}
+
+/// Returns to `user` the share of a remittance's discount covering the
+/// refunded `unreleased` part of `amount`, and forgets the discount.
+pub fn restore_fee_credit(
+    env: &Env,
+    user: &Address,
+    remittance_id: u64,
+    unreleased: i128,
+    amount: i128,
+) -> Result<(), ContractError> {
+    let key = LoyaltyKey::Discount(remittance_id);
+    let discount: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+    if discount == 0 || amount <= 0 {
+        return Ok(());
+    }
+    env.storage().persistent().remove(&key);
+
+    let restored = discount
+        .checked_mul(unreleased)
+        .ok_or(ContractError::Overflow)?
+        / amount;
+    let credit = get_fee_credit(env, user)
+        .checked_add(restored)
+        .ok_or(ContractError::Overflow)?;
+    set_fee_credit(env, user, credit);
+    Ok(())
+}
+
+/// Forgets the discount of a paid-out remittance; it can no longer be refunded.
+pub fn settle_fee_discount(env: &Env, remittance_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&LoyaltyKey::Discount(remittance_id));
+}
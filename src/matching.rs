@@ -0,0 +1,230 @@
+//! Weighted agent auto-matching for the SwiftRemit contract.
+//!
+//! Agents in the matching pool carry a profile of normalized scores (stake
+//! coverage, reputation, remaining capacity), each expressed in basis points.
+//! The admin tunes how much each score contributes to an agent's weight, and
+//! assignments follow a smooth weighted round-robin so that, over time, each
+//! agent receives work in proportion to its weight while selection stays
+//! deterministic and reproducible from chain state.
+
+use soroban_sdk::{contracttype, Address, Env, Map, Vec};
+
+use crate::{is_agent_available, is_agent_registered, is_agent_suspended, ContractError};
+
+/// Relative importance of each profile score when computing agent weights.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchingWeights {
+    /// Multiplier applied to the stake coverage score
+    pub stake_weight: u32,
+    /// Multiplier applied to the reputation score
+    pub reputation_weight: u32,
+    /// Multiplier applied to the remaining capacity score
+    pub capacity_weight: u32,
+}
+
+/// Matching profile of an agent. All scores are in basis points (0-10000).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AgentProfile {
+    /// How well the agent's stake covers its outstanding exposure
+    pub stake_coverage_bps: u32,
+    /// Reputation score
+    pub reputation_bps: u32,
+    /// Remaining payout capacity; agents at 0 are never selected
+    pub capacity_bps: u32,
+}
+
+/// Outcome of a matching round.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AgentMatch {
+    /// Selected agent
+    pub agent: Address,
+    /// Weight of the selected agent this round
+    pub weight: i128,
+    /// Sum of the weights of all eligible agents this round
+    pub total_weight: i128,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum MatchingKey {
+    /// Admin-tuned score multipliers
+    Weights,
+    /// Agents eligible for auto-matching
+    Pool,
+    /// Matching profile per agent (persistent storage)
+    Profile(Address),
+    /// Smooth weighted round-robin running weights
+    Current,
+}
+
+pub fn get_matching_weights(env: &Env) -> MatchingWeights {
+    env.storage()
+        .instance()
+        .get(&MatchingKey::Weights)
+        .unwrap_or(MatchingWeights {
+            stake_weight: 1,
+            reputation_weight: 1,
+            capacity_weight: 1,
+        })
+}
+
+pub fn set_matching_weights(env: &Env, weights: &MatchingWeights) {
+    env.storage().instance().set(&MatchingKey::Weights, weights);
+}
+
+pub fn get_agent_profile(env: &Env, agent: &Address) -> Option<AgentProfile> {
+    env.storage()
+        .persistent()
+        .get(&MatchingKey::Profile(agent.clone()))
+}
+
+fn get_matching_pool(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&MatchingKey::Pool)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Stores an agent's profile and adds the agent to the matching pool.
+pub fn set_agent_profile(env: &Env, agent: &Address, profile: &AgentProfile) {
+    env.storage()
+        .persistent()
+        .set(&MatchingKey::Profile(agent.clone()), profile);
+
+    let mut pool = get_matching_pool(env);
+    if !pool.contains(agent) {
+        pool.push_back(agent.clone());
+        env.storage().persistent().set(&MatchingKey::Pool, &pool);
+    }
+}
+
+/// Computes an agent's weight from its profile.
+///
+/// The weight is the weighted average of the profile scores, so it stays in
+/// basis points regardless of how the multipliers are scaled. Agents with no
+/// remaining capacity always weigh zero.
+pub fn compute_agent_weight(weights: &MatchingWeights, profile: &AgentProfile) -> i128 {
+    if profile.capacity_bps == 0 {
+        return 0;
+    }
+
+    let divisor = weights.stake_weight as i128
+        + weights.reputation_weight as i128
+        + weights.capacity_weight as i128;
+    if divisor == 0 {
+        return 0;
+    }
+
+    let score = weights.stake_weight as i128 * profile.stake_coverage_bps as i128
+        + weights.reputation_weight as i128 * profile.reputation_bps as i128
+        + weights.capacity_weight as i128 * profile.capacity_bps as i128;
+
+    score / divisor
+}
+
+/// Selects the next agent using smooth weighted round-robin.
+///
+/// Each round, every eligible agent's running weight grows by its own weight;
+/// the agent with the highest running weight is selected and reduced by the
+/// round's total weight. Ties resolve to the agent registered first in the pool.
+///
+/// # Returns
+///
+/// * `Ok(AgentMatch)` - Selected agent with the weights used
+/// * `Err(ContractError::AgentNotRegistered)` - No registered agent has a positive weight
+pub fn select_agent(env: &Env) -> Result<AgentMatch, ContractError> {
+    let weights = get_matching_weights(env);
+    let pool = get_matching_pool(env);
+    let mut current: Map<Address, i128> = env
+        .storage()
+        .persistent()
+        .get(&MatchingKey::Current)
+        .unwrap_or(Map::new(env));
+
+    let mut total_weight: i128 = 0;
+    let mut best: Option<(Address, i128, i128)> = None;
+
+    for agent in pool.iter() {
+        if !is_agent_registered(env, &agent)
+            || is_agent_suspended(env, &agent)
+            || !is_agent_available(env, &agent)
+        {
+            continue;
+        }
+        let weight = match get_agent_profile(env, &agent) {
+            Some(profile) => compute_agent_weight(&weights, &profile),
+            None => 0,
+        };
+        if weight <= 0 {
+            continue;
+        }
+
+        total_weight += weight;
+        let running = current.get(agent.clone()).unwrap_or(0) + weight;
+        current.set(agent.clone(), running);
+
+        let is_better = match &best {
+            Some((_, best_running, _)) => running > *best_running,
+            None => true,
+        };
+        if is_better {
+            best = Some((agent, running, weight));
+        }
+    }
+
+    let (agent, running, weight) = best.ok_or(ContractError::AgentNotRegistered)?;
+    current.set(agent.clone(), running - total_weight);
+    env.storage().persistent().set(&MatchingKey::Current, &current);
+
+    Ok(AgentMatch {
+        agent,
+        weight,
+        total_weight,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(stake: u32, reputation: u32, capacity: u32) -> AgentProfile {
+        AgentProfile {
+            stake_coverage_bps: stake,
+            reputation_bps: reputation,
+            capacity_bps: capacity,
+        }
+    }
+
+    #[test]
+    fn test_weight_is_weighted_average() {
+        let weights = MatchingWeights {
+            stake_weight: 2,
+            reputation_weight: 1,
+            capacity_weight: 1,
+        };
+        assert_eq!(compute_agent_weight(&weights, &profile(10000, 6000, 2000)), 7000);
+    }
+
+    #[test]
+    fn test_weight_zero_without_capacity() {
+        let weights = MatchingWeights {
+            stake_weight: 1,
+            reputation_weight: 1,
+            capacity_weight: 0,
+        };
+        assert_eq!(compute_agent_weight(&weights, &profile(10000, 10000, 0)), 0);
+    }
+
+    #[test]
+    fn test_weight_zero_when_all_multipliers_zero() {
+        let weights = MatchingWeights {
+            stake_weight: 0,
+            reputation_weight: 0,
+            capacity_weight: 0,
+        };
+        assert_eq!(compute_agent_weight(&weights, &profile(10000, 10000, 10000)), 0);
+    }
+}
@@ -1,6 +1,9 @@
-use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, Map, Vec};
+use soroban_sdk::{contracttype, token, Address, Bytes, BytesN, Env, Map, Vec};
 
-use crate::{ContractError, Remittance, RemittanceStatus};
+use crate::{
+    validate_amount, validate_non_negative, ContractError, Priority, PurposeCode, Remittance,
+    RemittanceStatus,
+};
 
 /// Maximum number of items that can be exported/imported in a single batch
 /// to prevent excessive resource consumption
@@ -110,6 +113,139 @@ pub struct MigrationVerification {
     pub timestamp: u64,
 }
 
+/// Remittance as recorded by the v1 contract.
+///
+/// The v1 contract had no purpose codes, KYC hashes, external references or
+/// priorities; imported remittances get the `Other` purpose, no hashes or
+/// reference, and standard priority.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct LegacyRemittance {
+    pub id: u64,
+    pub sender: Address,
+    pub agent: Address,
+    pub amount: i128,
+    pub fee: i128,
+    /// Pending, Completed, Cancelled or Expired
+    pub status: RemittanceStatus,
+    pub expiry: Option<u64>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum MigrationKey {
+    /// Set once legacy imports are closed
+    ImportFinalized,
+}
+
+pub fn is_import_finalized(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&MigrationKey::ImportFinalized)
+        .unwrap_or(false)
+}
+
+/// Stores remittances exported from the v1 contract under their original IDs.
+///
+/// Pending remittances are booked into escrow and fees and assigned to their
+/// agent as if they had just been created here; finished ones are stored as
+/// history only, and completed ones are marked settled so they cannot be paid
+/// twice. The remittance counter is raised past the highest imported ID.
+///
+/// # Returns
+///
+/// * `Ok(Vec<Remittance>)` - The pending remittances imported, for the caller to index
+/// * `Err(ContractError::MigrationInProgress)` - Imports were finalized
+/// * `Err(ContractError::LimitExceeded)` - More than `MAX_MIGRATION_BATCH_SIZE` remittances
+/// * `Err(ContractError::InvalidMigrationBatch)` - An ID is 0 or already in use
+/// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - An amount is
+///   not positive, or a fee is negative
+/// * `Err(ContractError::InvalidAmount)` - A fee exceeds its amount
+/// * `Err(ContractError::InvalidStatus)` - A remittance is awaiting attestation or quarantined
+pub fn import_legacy_remittances(
+    env: &Env,
+    remittances: &Vec<LegacyRemittance>,
+) -> Result<Vec<Remittance>, ContractError> {
+    if is_import_finalized(env) {
+        return Err(ContractError::MigrationInProgress);
+    }
+    if remittances.len() > MAX_MIGRATION_BATCH_SIZE {
+        return Err(ContractError::LimitExceeded);
+    }
+
+    let mut counter = crate::storage::get_remittance_counter(env)?;
+    let mut pending = Vec::new(env);
+    for legacy in remittances.iter() {
+        if legacy.id == 0 || crate::storage::get_remittance(env, legacy.id).is_ok() {
+            return Err(ContractError::InvalidMigrationBatch);
+        }
+        validate_amount(legacy.amount)?;
+        validate_non_negative(legacy.fee)?;
+        if legacy.fee > legacy.amount {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        let remittance = Remittance {
+            id: legacy.id,
+            sender: legacy.sender,
+            agent: legacy.agent,
+            amount: legacy.amount,
+            fee: legacy.fee,
+            status: legacy.status,
+            expiry: legacy.expiry,
+            purpose: PurposeCode::Other,
+            originator_hash: None,
+            beneficiary_hash: None,
+            external_ref: None,
+            hold_until: None,
+            priority: Priority::Standard,
+            payout_fx: None,
+        };
+        match remittance.status {
+            RemittanceStatus::Pending => {
+                crate::book_remittance(env, remittance.amount, remittance.fee)?;
+                pending.push_back(remittance.clone());
+            }
+            RemittanceStatus::Completed => crate::storage::set_settlement_hash(env, remittance.id),
+            RemittanceStatus::Cancelled | RemittanceStatus::Expired => {}
+            RemittanceStatus::AwaitingAttestation | RemittanceStatus::Quarantined => {
+                return Err(ContractError::InvalidStatus);
+            }
+        }
+        crate::storage::set_remittance(env, remittance.id, &remittance);
+        counter = counter.max(remittance.id);
+    }
+    crate::storage::set_remittance_counter(env, counter);
+
+    Ok(pending)
+}
+
+/// Closes legacy imports for good.
+///
+/// The contract must already hold the settlement tokens its ledger says it
+/// holds, i.e. the escrow and fees of the imported pending remittances must
+/// have been transferred from the v1 contract.
+///
+/// # Returns
+///
+/// * `Ok(())` - Imports closed
+/// * `Err(ContractError::MigrationInProgress)` - Imports were already finalized
+/// * `Err(ContractError::InsufficientEscrow)` - The token balance is below the booked holdings
+pub fn finalize_import(env: &Env) -> Result<(), ContractError> {
+    if is_import_finalized(env) {
+        return Err(ContractError::MigrationInProgress);
+    }
+    let usdc_token = crate::storage::get_usdc_token(env)?;
+    let balance = token::Client::new(env, &usdc_token).balance(&env.current_contract_address());
+    if balance < crate::account_balance(env, crate::AccountClass::Holdings) {
+        return Err(ContractError::InsufficientEscrow);
+    }
+    env.storage()
+        .instance()
+        .set(&MigrationKey::ImportFinalized, &true);
+    Ok(())
+}
+
 /// Export complete contract state for migration
 /// 
 /// This function creates a complete snapshot of all contract data including:
@@ -129,7 +265,7 @@ pub fn export_state(env: &Env) -> Result<MigrationSnapshot, ContractError> {
     let instance_data = InstanceData {
         admin: crate::storage::get_admin(env)?,
         usdc_token: crate::storage::get_usdc_token(env)?,
-        platform_fee_bps: crate::storage::get_platform_fee_bps(env)?,
+        platform_fee_bps: crate::platform_fee::get_effective_fee_bps(env, env.ledger().timestamp())?,
         remittance_counter: crate::storage::get_remittance_counter(env)?,
         accumulated_fees: crate::storage::get_accumulated_fees(env)?,
         paused: crate::storage::is_paused(env),
@@ -242,7 +378,7 @@ pub fn import_state(
     crate::storage::set_usdc_token(env, &snapshot.instance_data.usdc_token);
     crate::storage::set_platform_fee_bps(env, snapshot.instance_data.platform_fee_bps);
     crate::storage::set_remittance_counter(env, snapshot.instance_data.remittance_counter);
-    crate::storage::set_accumulated_fees(env, snapshot.instance_data.accumulated_fees);
+    crate::storage::set_accumulated_fees(env, snapshot.instance_data.accumulated_fees)?;
     crate::storage::set_paused(env, snapshot.instance_data.paused);
     crate::storage::set_admin_count(env, snapshot.instance_data.admin_count);
     
@@ -0,0 +1,24 @@
+//! Native XLM as the settlement token.
+//!
+//! Native XLM is exposed to contracts through its Stellar Asset Contract,
+//! which implements the same token interface as issued assets. A deployment
+//! initialized with the native asset contract address escrows, charges fees
+//! and refunds in XLM through the same code paths as USDC. The native asset
+//! contract address is derived from the network, so wallets can look it up
+//! here instead of hard-coding it per network.
+
+use soroban_sdk::{Address, Bytes, Env};
+
+/// XDR encoding of the Stellar `Asset::Native` variant.
+const NATIVE_ASSET_XDR: [u8; 4] = [0, 0, 0, 0];
+
+/// Returns the address of the native XLM asset contract on the current network.
+pub fn get_native_token(env: &Env) -> Address {
+    env.deployer()
+        .with_stellar_asset(Bytes::from_array(env, &NATIVE_ASSET_XDR))
+        .deployed_address()
+}
+
+pub fn is_native_token(env: &Env, token: &Address) -> bool {
+    *token == get_native_token(env)
+}
@@ -234,6 +234,7 @@ pub fn validate_net_settlement(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{Priority, PurposeCode};
     use soroban_sdk::{testutils::Address as _, Env};
 
     #[test]
@@ -253,6 +254,13 @@ mod tests {
             fee: 2,
             status: RemittanceStatus::Pending,
             expiry: None,
+            purpose: PurposeCode::Other,
+            originator_hash: None,
+            beneficiary_hash: None,
+            external_ref: None,
+            hold_until: None,
+            priority: Priority::Standard,
+            payout_fx: None,
         });
         
         // B -> A: 90
@@ -264,6 +272,13 @@ mod tests {
             fee: 1,
             status: RemittanceStatus::Pending,
             expiry: None,
+            purpose: PurposeCode::Other,
+            originator_hash: None,
+            beneficiary_hash: None,
+            external_ref: None,
+            hold_until: None,
+            priority: Priority::Standard,
+            payout_fx: None,
         });
         
         let net_transfers = compute_net_settlements(&remittances);
@@ -299,6 +314,13 @@ mod tests {
             fee: 2,
             status: RemittanceStatus::Pending,
             expiry: None,
+            purpose: PurposeCode::Other,
+            originator_hash: None,
+            beneficiary_hash: None,
+            external_ref: None,
+            hold_until: None,
+            priority: Priority::Standard,
+            payout_fx: None,
         });
         
         // B -> A: 100
@@ -310,6 +332,13 @@ mod tests {
             fee: 2,
             status: RemittanceStatus::Pending,
             expiry: None,
+            purpose: PurposeCode::Other,
+            originator_hash: None,
+            beneficiary_hash: None,
+            external_ref: None,
+            hold_until: None,
+            priority: Priority::Standard,
+            payout_fx: None,
         });
         
         let net_transfers = compute_net_settlements(&remittances);
@@ -336,6 +365,13 @@ mod tests {
             fee: 2,
             status: RemittanceStatus::Pending,
             expiry: None,
+            purpose: PurposeCode::Other,
+            originator_hash: None,
+            beneficiary_hash: None,
+            external_ref: None,
+            hold_until: None,
+            priority: Priority::Standard,
+            payout_fx: None,
         });
         
         // B -> C: 50
@@ -347,6 +383,13 @@ mod tests {
             fee: 1,
             status: RemittanceStatus::Pending,
             expiry: None,
+            purpose: PurposeCode::Other,
+            originator_hash: None,
+            beneficiary_hash: None,
+            external_ref: None,
+            hold_until: None,
+            priority: Priority::Standard,
+            payout_fx: None,
         });
         
         // C -> A: 30
@@ -358,6 +401,13 @@ mod tests {
             fee: 1,
             status: RemittanceStatus::Pending,
             expiry: None,
+            purpose: PurposeCode::Other,
+            originator_hash: None,
+            beneficiary_hash: None,
+            external_ref: None,
+            hold_until: None,
+            priority: Priority::Standard,
+            payout_fx: None,
         });
         
         let net_transfers = compute_net_settlements(&remittances);
@@ -389,6 +439,13 @@ mod tests {
             fee: 2,
             status: RemittanceStatus::Pending,
             expiry: None,
+            purpose: PurposeCode::Other,
+            originator_hash: None,
+            beneficiary_hash: None,
+            external_ref: None,
+            hold_until: None,
+            priority: Priority::Standard,
+            payout_fx: None,
         });
         
         remittances.push_back(Remittance {
@@ -399,6 +456,13 @@ mod tests {
             fee: 1,
             status: RemittanceStatus::Pending,
             expiry: None,
+            purpose: PurposeCode::Other,
+            originator_hash: None,
+            beneficiary_hash: None,
+            external_ref: None,
+            hold_until: None,
+            priority: Priority::Standard,
+            payout_fx: None,
         });
         
         let net_transfers = compute_net_settlements(&remittances);
@@ -422,6 +486,13 @@ mod tests {
             fee: 2,
             status: RemittanceStatus::Pending,
             expiry: None,
+            purpose: PurposeCode::Other,
+            originator_hash: None,
+            beneficiary_hash: None,
+            external_ref: None,
+            hold_until: None,
+            priority: Priority::Standard,
+            payout_fx: None,
         });
         remittances1.push_back(Remittance {
             id: 2,
@@ -431,6 +502,13 @@ mod tests {
             fee: 1,
             status: RemittanceStatus::Pending,
             expiry: None,
+            purpose: PurposeCode::Other,
+            originator_hash: None,
+            beneficiary_hash: None,
+            external_ref: None,
+            hold_until: None,
+            priority: Priority::Standard,
+            payout_fx: None,
         });
         
         // Second ordering (reversed)
@@ -443,6 +521,13 @@ mod tests {
             fee: 1,
             status: RemittanceStatus::Pending,
             expiry: None,
+            purpose: PurposeCode::Other,
+            originator_hash: None,
+            beneficiary_hash: None,
+            external_ref: None,
+            hold_until: None,
+            priority: Priority::Standard,
+            payout_fx: None,
         });
         remittances2.push_back(Remittance {
             id: 1,
@@ -452,6 +537,13 @@ mod tests {
             fee: 2,
             status: RemittanceStatus::Pending,
             expiry: None,
+            purpose: PurposeCode::Other,
+            originator_hash: None,
+            beneficiary_hash: None,
+            external_ref: None,
+            hold_until: None,
+            priority: Priority::Standard,
+            payout_fx: None,
         });
         
         let net1 = compute_net_settlements(&remittances1);
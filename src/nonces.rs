@@ -0,0 +1,54 @@
+//! Replay protection for operations submitted by relayers.
+//!
+//! A relayer pays the fees of a user's operation and submits it with the
+//! user's signature over the operation's arguments. Each signed operation
+//! carries the user's next nonce and a ledger after which it expires, so a
+//! captured signature can be used at most once and not after it lapses.
+//! Signatures are scoped to this contract by the Soroban auth framework, so
+//! they cannot be replayed against another deployment either.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::ContractError;
+
+#[contracttype]
+#[derive(Clone)]
+enum NonceKey {
+    /// Next nonce expected from a user (persistent storage)
+    Next(Address),
+}
+
+/// Returns the nonce the next signed operation of `user` must carry.
+pub fn get_nonce(env: &Env, user: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&NonceKey::Next(user.clone()))
+        .unwrap_or(0)
+}
+
+/// Consumes `nonce` of `user` for an operation signed to expire after `expiration_ledger`.
+///
+/// # Returns
+///
+/// * `Ok(())` - Nonce consumed; the next operation must carry `nonce + 1`
+/// * `Err(ContractError::SignatureExpired)` - The current ledger is past `expiration_ledger`
+/// * `Err(ContractError::InvalidNonce)` - `nonce` is not the user's next nonce
+pub fn consume_nonce(
+    env: &Env,
+    user: &Address,
+    nonce: u64,
+    expiration_ledger: u32,
+) -> Result<(), ContractError> {
+    if env.ledger().sequence() > expiration_ledger {
+        return Err(ContractError::SignatureExpired);
+    }
+    if nonce != get_nonce(env, user) {
+        return Err(ContractError::InvalidNonce);
+    }
+
+    let next = nonce.checked_add(1).ok_or(ContractError::Overflow)?;
+    env.storage()
+        .persistent()
+        .set(&NonceKey::Next(user.clone()), &next);
+    Ok(())
+}
@@ -0,0 +1,48 @@
+//! Operator role for automation.
+//!
+//! Keeper bots that archive closed remittances or trigger agent settlements
+//! run unattended, so their keys are exposed more than the admin's. The admin
+//! can appoint such bots as operators instead: an operator may call the
+//! automation entry points `archive_remittances` and `settle_agent`, and
+//! nothing else an admin can. `sweep_expired` is open to any keeper and needs
+//! no role.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::{is_admin, ContractError};
+
+#[contracttype]
+#[derive(Clone)]
+enum OperatorKey {
+    /// Address appointed as operator (persistent storage)
+    Operator(Address),
+}
+
+pub fn set_operator(env: &Env, operator: &Address, enabled: bool) {
+    let key = OperatorKey::Operator(operator.clone());
+    if enabled {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub fn is_operator(env: &Env, operator: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&OperatorKey::Operator(operator.clone()))
+}
+
+/// Requires `caller` to authorize and to be an operator or an admin.
+///
+/// # Returns
+///
+/// * `Ok(())` - Caller authorized
+/// * `Err(ContractError::Unauthorized)` - Caller is neither an operator nor an admin
+pub fn require_operator(env: &Env, caller: &Address) -> Result<(), ContractError> {
+    caller.require_auth();
+    if !is_operator(env, caller) && !is_admin(env, caller) {
+        return Err(ContractError::Unauthorized);
+    }
+    Ok(())
+}
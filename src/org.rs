@@ -0,0 +1,143 @@
+//! Organization remittance templates for the SwiftRemit contract.
+//!
+//! An organization (e.g. a diaspora association or an SME) is owned by a single
+//! address that authorizes spenders, typically its treasurers. The owner
+//! publishes templates describing a recurring payout (agent and amount) with a
+//! cap on how many times it can be used; any authorized spender can then send a
+//! remittance from a template, funding it from their own balance.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::ContractError;
+
+/// An organization sharing remittance templates among its spenders.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Organization {
+    /// Organization ID
+    pub id: u64,
+    /// Address that manages spenders and templates
+    pub owner: Address,
+}
+
+/// A shared remittance template published by an organization.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RemittanceTemplate {
+    /// Template ID
+    pub id: u64,
+    /// Organization that owns the template
+    pub org_id: u64,
+    /// Agent that pays out remittances sent from this template
+    pub agent: Address,
+    /// Amount of each remittance
+    pub amount: i128,
+    /// Maximum number of remittances that can be sent from this template
+    pub max_uses: u32,
+    /// Number of remittances sent so far
+    pub uses: u32,
+    /// Whether the template can still be used
+    pub active: bool,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum OrgKey {
+    /// Counter for organization IDs
+    OrgCounter,
+    /// Organization record (persistent storage)
+    Org(u64),
+    /// Spender authorization per organization (persistent storage)
+    Spender(u64, Address),
+    /// Counter for template IDs
+    TemplateCounter,
+    /// Template record (persistent storage)
+    Template(u64),
+}
+
+pub fn get_organization(env: &Env, org_id: u64) -> Result<Organization, ContractError> {
+    env.storage()
+        .persistent()
+        .get(&OrgKey::Org(org_id))
+        .ok_or(ContractError::InvalidStatus)
+}
+
+pub fn create_organization(env: &Env, owner: &Address) -> Result<Organization, ContractError> {
+    let id = env
+        .storage()
+        .instance()
+        .get::<_, u64>(&OrgKey::OrgCounter)
+        .unwrap_or(0)
+        .checked_add(1)
+        .ok_or(ContractError::Overflow)?;
+    env.storage().instance().set(&OrgKey::OrgCounter, &id);
+
+    let org = Organization {
+        id,
+        owner: owner.clone(),
+    };
+    env.storage().persistent().set(&OrgKey::Org(id), &org);
+    Ok(org)
+}
+
+pub fn set_org_spender(env: &Env, org_id: u64, spender: &Address, authorized: bool) {
+    let key = OrgKey::Spender(org_id, spender.clone());
+    if authorized {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Returns whether `spender` may send from the organization's templates.
+/// The owner is always an authorized spender.
+pub fn is_org_spender(env: &Env, org: &Organization, spender: &Address) -> bool {
+    org.owner == *spender
+        || env
+            .storage()
+            .persistent()
+            .get(&OrgKey::Spender(org.id, spender.clone()))
+            .unwrap_or(false)
+}
+
+pub fn get_template(env: &Env, template_id: u64) -> Result<RemittanceTemplate, ContractError> {
+    env.storage()
+        .persistent()
+        .get(&OrgKey::Template(template_id))
+        .ok_or(ContractError::InvalidStatus)
+}
+
+pub fn set_template(env: &Env, template: &RemittanceTemplate) {
+    env.storage()
+        .persistent()
+        .set(&OrgKey::Template(template.id), template);
+}
+
+pub fn next_template_id(env: &Env) -> Result<u64, ContractError> {
+    let id = env
+        .storage()
+        .instance()
+        .get::<_, u64>(&OrgKey::TemplateCounter)
+        .unwrap_or(0)
+        .checked_add(1)
+        .ok_or(ContractError::Overflow)?;
+    env.storage().instance().set(&OrgKey::TemplateCounter, &id);
+    Ok(id)
+}
+
+/// Records one use of a template after checking it is active and under its cap.
+///
+/// # Returns
+///
+/// * `Ok(RemittanceTemplate)` - Template with the use recorded
+/// * `Err(ContractError::InvalidStatus)` - Template is inactive or its usage cap is reached
+pub fn use_template(env: &Env, template_id: u64) -> Result<RemittanceTemplate, ContractError> {
+    let mut template = get_template(env, template_id)?;
+    if !template.active || template.uses >= template.max_uses {
+        return Err(ContractError::InvalidStatus);
+    }
+
+    template.uses += 1;
+    set_template(env, &template);
+    Ok(template)
+}
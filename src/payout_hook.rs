@@ -0,0 +1,61 @@
+//! Programmable payout recipients.
+//!
+//! A sender can direct the payout of a pending remittance to a recipient
+//! contract, such as a savings pot or a bill-payment contract, instead of the
+//! agent's own account. When the agent confirms the payout, the net amount is
+//! transferred to the recipient and its `on_payout` function is invoked in
+//! the same transaction, so the recipient can act on the funds it received.
+//! A recipient that fails aborts the payout.
+
+use soroban_sdk::{contracttype, Address, Env, IntoVal, InvokeError, Symbol, Val, Vec};
+
+use crate::ContractError;
+
+/// Function invoked on the recipient contract after the payout transfer.
+///
+/// Expected signature: `on_payout(remittance_id: u64, amount: i128)`.
+pub const PAYOUT_HOOK_FN: &str = "on_payout";
+
+#[contracttype]
+#[derive(Clone)]
+enum PayoutHookKey {
+    /// Recipient contract of a remittance (persistent storage)
+    Recipient(u64),
+}
+
+pub fn set_payout_recipient(env: &Env, remittance_id: u64, recipient: &Address) {
+    env.storage()
+        .persistent()
+        .set(&PayoutHookKey::Recipient(remittance_id), recipient);
+}
+
+pub fn clear_payout_recipient(env: &Env, remittance_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&PayoutHookKey::Recipient(remittance_id));
+}
+
+pub fn get_payout_recipient(env: &Env, remittance_id: u64) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&PayoutHookKey::Recipient(remittance_id))
+}
+
+/// Notifies `recipient` that `amount` of remittance `remittance_id` was paid to it.
+///
+/// # Returns
+///
+/// * `Ok(())` - The recipient accepted the payout
+/// * `Err(ContractError::PayoutHookFailed)` - The recipient failed or returned an error
+pub fn notify_payout_recipient(
+    env: &Env,
+    recipient: &Address,
+    remittance_id: u64,
+    amount: i128,
+) -> Result<(), ContractError> {
+    let args: Vec<Val> = (remittance_id, amount).into_val(env);
+    match env.try_invoke_contract::<(), InvokeError>(recipient, &Symbol::new(env, PAYOUT_HOOK_FN), args) {
+        Ok(Ok(())) => Ok(()),
+        _ => Err(ContractError::PayoutHookFailed),
+    }
+}
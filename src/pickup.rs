@@ -0,0 +1,115 @@
+//! Pickup codes for cash-out at an agent.
+//!
+//! Every remittance gets a short pickup code the sender passes on to the
+//! recipient, who quotes it at the agent's counter. Codes are 8 characters of
+//! Crockford base32 derived from the remittance ID and a contract-wide random
+//! salt, so they cannot be guessed from the ID, and are indexed back to the
+//! remittance so the agent can look up what the recipient quotes. Lookups
+//! ignore case and accept the characters Crockford base32 treats as
+//! look-alikes (`I`/`L` for `1`, `O` for `0`).
+
+use soroban_sdk::{contracttype, Bytes, BytesN, Env, String};
+
+use crate::ContractError;
+
+/// Number of characters in a pickup code.
+pub const PICKUP_CODE_LEN: usize = 8;
+
+/// Crockford base32 alphabet, without the easily confused I, L, O and U.
+const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+#[contracttype]
+#[derive(Clone)]
+enum PickupKey {
+    /// Random salt mixed into every code
+    Salt,
+    /// Pickup code of a remittance (persistent storage)
+    Code(u64),
+    /// Remittance a pickup code belongs to (persistent storage)
+    Remittance(String),
+}
+
+fn get_or_create_salt(env: &Env) -> BytesN<32> {
+    if let Some(salt) = env.storage().instance().get(&PickupKey::Salt) {
+        return salt;
+    }
+    let salt: BytesN<32> = env.prng().gen();
+    env.storage().instance().set(&PickupKey::Salt, &salt);
+    salt
+}
+
+/// Derives candidate code number `attempt` for a remittance.
+fn derive_code(env: &Env, salt: &BytesN<32>, remittance_id: u64, attempt: u32) -> String {
+    let mut input = Bytes::from_array(env, &salt.to_array());
+    input.extend_from_array(&remittance_id.to_be_bytes());
+    input.extend_from_array(&attempt.to_be_bytes());
+    let digest = env.crypto().sha256(&input).to_array();
+
+    // The first 40 bits of the digest, 5 bits per character
+    let bits = digest[..5]
+        .iter()
+        .fold(0u64, |acc, byte| (acc << 8) | *byte as u64);
+    let mut code = [0u8; PICKUP_CODE_LEN];
+    for (i, c) in code.iter_mut().enumerate() {
+        let shift = 5 * (PICKUP_CODE_LEN - 1 - i);
+        *c = ALPHABET[((bits >> shift) & 0x1f) as usize];
+    }
+    String::from_bytes(env, &code)
+}
+
+/// Assigns a unique pickup code to a new remittance.
+///
+/// A code already in use is skipped by deriving the next candidate.
+pub fn assign_pickup_code(env: &Env, remittance_id: u64) -> String {
+    let salt = get_or_create_salt(env);
+    let mut attempt = 0u32;
+    loop {
+        let code = derive_code(env, &salt, remittance_id, attempt);
+        let key = PickupKey::Remittance(code.clone());
+        if !env.storage().persistent().has(&key) {
+            env.storage().persistent().set(&key, &remittance_id);
+            env.storage()
+                .persistent()
+                .set(&PickupKey::Code(remittance_id), &code);
+            return code;
+        }
+        attempt += 1;
+    }
+}
+
+/// Returns the pickup code of a remittance, if it has one.
+pub fn get_pickup_code(env: &Env, remittance_id: u64) -> Option<String> {
+    env.storage().persistent().get(&PickupKey::Code(remittance_id))
+}
+
+/// Normalizes a quoted code to its canonical form.
+fn normalize_code(env: &Env, code: &String) -> Option<String> {
+    if code.len() as usize != PICKUP_CODE_LEN {
+        return None;
+    }
+    let mut buf = [0u8; PICKUP_CODE_LEN];
+    code.copy_into_slice(&mut buf);
+    for c in buf.iter_mut() {
+        *c = match c.to_ascii_uppercase() {
+            b'I' | b'L' => b'1',
+            b'O' => b'0',
+            upper if ALPHABET.contains(&upper) => upper,
+            _ => return None,
+        };
+    }
+    Some(String::from_bytes(env, &buf))
+}
+
+/// Finds the remittance a pickup code belongs to.
+///
+/// # Returns
+///
+/// * `Ok(u64)` - ID of the remittance
+/// * `Err(ContractError::RemittanceNotFound)` - The code is malformed or unknown
+pub fn find_by_pickup_code(env: &Env, code: &String) -> Result<u64, ContractError> {
+    let code = normalize_code(env, code).ok_or(ContractError::RemittanceNotFound)?;
+    env.storage()
+        .persistent()
+        .get(&PickupKey::Remittance(code))
+        .ok_or(ContractError::RemittanceNotFound)
+}
@@ -0,0 +1,160 @@
+//! Platform fee calculation.
+//!
+//! Shared by every path that charges the platform fee, including remittance
+//! creation and quotes, so a quote for the same agent always matches the
+//! remittance created from it.
+//!
+//! Besides immediate updates, the admin can schedule fee changes for a future
+//! time. A scheduled fee applies automatically from its effective time, so
+//! clients holding signed quotes can see a change coming with
+//! `get_effective_fee(at_time)` instead of being broken by it.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::{
+    div_round, get_agent_corridor, get_corridor_fee, get_fee_rounding_mode, get_platform_fee_bps,
+    get_usdc_token, get_volume_discount_bps, is_fee_exempt, set_platform_fee_bps, ContractError,
+};
+
+/// Maximum number of fee updates scheduled at once.
+pub const MAX_SCHEDULED_FEE_UPDATES: u32 = 10;
+
+/// A platform fee change taking effect at a future time.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduledFee {
+    /// New platform fee in basis points
+    pub fee_bps: u32,
+    /// Ledger timestamp from which the fee applies
+    pub effective_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum PlatformFeeKey {
+    /// Scheduled fee updates, earliest first
+    Scheduled,
+}
+
+/// Returns the fee updates that have not been applied yet, earliest first.
+pub fn get_scheduled_fees(env: &Env) -> Vec<ScheduledFee> {
+    env.storage()
+        .instance()
+        .get(&PlatformFeeKey::Scheduled)
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_scheduled_fees(env: &Env, scheduled: &Vec<ScheduledFee>) {
+    if scheduled.is_empty() {
+        env.storage().instance().remove(&PlatformFeeKey::Scheduled);
+    } else {
+        env.storage().instance().set(&PlatformFeeKey::Scheduled, scheduled);
+    }
+}
+
+/// Schedules the platform fee to change to `fee_bps` at `effective_at`,
+/// replacing an update already scheduled for the same time.
+///
+/// # Returns
+///
+/// * `Ok(())` - Update scheduled
+/// * `Err(ContractError::InvalidAmount)` - `effective_at` is not in the future
+/// * `Err(ContractError::LimitExceeded)` - MAX_SCHEDULED_FEE_UPDATES are already scheduled
+pub fn schedule_fee(env: &Env, fee_bps: u32, effective_at: u64) -> Result<(), ContractError> {
+    if effective_at <= env.ledger().timestamp() {
+        return Err(ContractError::InvalidAmount);
+    }
+    apply_due_fees(env);
+
+    let mut scheduled = get_scheduled_fees(env);
+    let update = ScheduledFee { fee_bps, effective_at };
+    let position = scheduled.iter().position(|s| s.effective_at >= effective_at);
+    match position {
+        Some(i) if scheduled.get_unchecked(i as u32).effective_at == effective_at => {
+            scheduled.set(i as u32, update);
+        }
+        _ => {
+            if scheduled.len() >= MAX_SCHEDULED_FEE_UPDATES {
+                return Err(ContractError::LimitExceeded);
+            }
+            let index = position.map_or(scheduled.len(), |i| i as u32);
+            scheduled.insert(index, update);
+        }
+    }
+    set_scheduled_fees(env, &scheduled);
+    Ok(())
+}
+
+/// Makes the latest scheduled fee that has taken effect the stored platform
+/// fee and drops every update that has taken effect.
+///
+/// Called before the stored fee is read for an update, so an immediate
+/// update is not overridden by an older scheduled one.
+pub fn apply_due_fees(env: &Env) {
+    let now = env.ledger().timestamp();
+    let mut scheduled = get_scheduled_fees(env);
+    let mut applied = None;
+    while let Some(next) = scheduled.first() {
+        if next.effective_at > now {
+            break;
+        }
+        applied = Some(next.fee_bps);
+        scheduled.pop_front();
+    }
+    if let Some(fee_bps) = applied {
+        set_platform_fee_bps(env, fee_bps);
+        set_scheduled_fees(env, &scheduled);
+    }
+}
+
+/// Returns the platform fee in effect at `at_time`, including scheduled updates.
+///
+/// # Returns
+///
+/// * `Ok(u32)` - Fee in basis points
+/// * `Err(ContractError::NotInitialized)` - Contract not initialized
+pub fn get_effective_fee_bps(env: &Env, at_time: u64) -> Result<u32, ContractError> {
+    let mut fee_bps = get_platform_fee_bps(env)?;
+    for scheduled in get_scheduled_fees(env).iter() {
+        if scheduled.effective_at > at_time {
+            break;
+        }
+        fee_bps = scheduled.fee_bps;
+    }
+    Ok(fee_bps)
+}
+
+/// Computes the platform fee charged on `amount` at the fee rate in effect now.
+///
+/// The fee set for the corridor `agent` pays out in takes precedence over
+/// the global fee. The sender's volume discount is taken off the fee before
+/// it is rounded with the settlement token's rounding policy. Returns zero
+/// when `sender` is exempt from fees.
+pub fn calculate_platform_fee(
+    env: &Env,
+    sender: Option<&Address>,
+    agent: Option<&Address>,
+    amount: i128,
+) -> Result<i128, ContractError> {
+    if sender.map_or(false, |sender| is_fee_exempt(env, sender)) {
+        return Ok(0);
+    }
+    let corridor_fee = agent
+        .and_then(|agent| get_agent_corridor(env, agent))
+        .and_then(|corridor| get_corridor_fee(env, &corridor));
+    let fee_bps = match corridor_fee {
+        Some(fee_bps) => fee_bps,
+        None => get_effective_fee_bps(env, env.ledger().timestamp())?,
+    };
+    let mode = get_fee_rounding_mode(env, &get_usdc_token(env)?);
+    let fee = amount.checked_mul(fee_bps as i128).ok_or(ContractError::Overflow)?;
+    let discount_bps = sender.map_or(0, |sender| get_volume_discount_bps(env, sender));
+    if discount_bps == 0 {
+        return div_round(fee, 10000, mode);
+    }
+    div_round(
+        fee.checked_mul((10000 - discount_bps) as i128).ok_or(ContractError::Overflow)?,
+        10000 * 10000,
+        mode,
+    )
+}
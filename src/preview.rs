@@ -0,0 +1,67 @@
+//! Read-only payout previews for agent apps.
+//!
+//! An agent app calls `simulate_payout` before asking the agent to sign a
+//! confirmation, so it can show what the agent will receive and avoid
+//! submitting a transaction that would revert. The preview runs the same
+//! checks as `confirm_payout` without changing any state.
+
+use soroban_sdk::{contracttype, token, Address, Env};
+
+use crate::storage::check_rate_limit;
+use crate::{
+    get_released_totals, get_remittance, get_usdc_token, net_amount, validate_confirm_payout_request,
+    ContractError,
+};
+
+/// Outcome `confirm_payout` would have for a remittance right now.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutPreview {
+    /// Amount the agent would receive, after the fee and any released tranches
+    pub net_to_agent: i128,
+    /// Platform fee that would be charged on the payout
+    pub fee: i128,
+    /// Token the payout is made in
+    pub token: Address,
+    /// `None` if the payout would succeed, otherwise the code of the
+    /// `ContractError` it would fail with
+    pub will_succeed_reason: Option<u32>,
+}
+
+/// Previews the payout of a remittance.
+///
+/// # Returns
+///
+/// * `Ok(PayoutPreview)` - Payout amounts and whether it would succeed
+/// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+/// * `Err(ContractError::NotInitialized)` - Contract not initialized
+pub fn preview_payout(env: &Env, remittance_id: u64) -> Result<PayoutPreview, ContractError> {
+    let remittance = get_remittance(env, remittance_id)?;
+    let token = get_usdc_token(env)?;
+
+    let released = get_released_totals(env, remittance_id);
+    let fee = remittance.fee.checked_sub(released.fee).ok_or(ContractError::Overflow)?;
+    let remaining = remittance
+        .amount
+        .checked_sub(released.amount)
+        .ok_or(ContractError::Overflow)?;
+    let net_to_agent = net_amount(remaining, fee)?;
+
+    let outcome = validate_confirm_payout_request(env, remittance_id)
+        .and_then(|_| check_rate_limit(env, &remittance.sender))
+        .and_then(|_| {
+            let balance = token::Client::new(env, &token).balance(&env.current_contract_address());
+            if balance < net_to_agent {
+                Err(ContractError::InsufficientEscrow)
+            } else {
+                Ok(())
+            }
+        });
+
+    Ok(PayoutPreview {
+        net_to_agent,
+        fee,
+        token,
+        will_succeed_reason: outcome.err().map(|error| error as u32),
+    })
+}
@@ -0,0 +1,41 @@
+//! Express surcharge for priority remittances.
+//!
+//! A sender can expedite a pending remittance by paying a surcharge on top
+//! of its amount. The surcharge is booked as platform fee, so the payout is
+//! unchanged; in exchange the remittance moves ahead of the agent's standard
+//! remittances and is measured against the express SLA.
+
+use soroban_sdk::{contracttype, Env};
+
+use crate::{div_round, get_fee_rounding_mode, get_usdc_token, ContractError};
+
+#[contracttype]
+#[derive(Clone)]
+enum PriorityKey {
+    /// Express surcharge in basis points of the remittance amount
+    ExpressFeeBps,
+}
+
+pub fn set_express_fee_bps(env: &Env, fee_bps: u32) {
+    env.storage()
+        .instance()
+        .set(&PriorityKey::ExpressFeeBps, &fee_bps);
+}
+
+pub fn get_express_fee_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&PriorityKey::ExpressFeeBps)
+        .unwrap_or(0)
+}
+
+/// Returns the express surcharge on `amount`, rounded like the platform fee.
+pub fn express_surcharge(env: &Env, amount: i128) -> Result<i128, ContractError> {
+    let fee_bps = get_express_fee_bps(env);
+    let mode = get_fee_rounding_mode(env, &get_usdc_token(env)?);
+    div_round(
+        amount.checked_mul(fee_bps as i128).ok_or(ContractError::Overflow)?,
+        10000,
+        mode,
+    )
+}
@@ -0,0 +1,61 @@
+//! Quarantine vault for compliance-frozen funds.
+//!
+//! Funds of a remittance frozen by compliance, or cancelled for a sanctions
+//! hit, are neither paid out nor refunded. They leave escrow and are held
+//! here, per remittance, until an investigation decides where they go: back
+//! to the sender, on to the agent, or to an authority. Quarantined funds stay
+//! in the contract's balance and are counted separately by `reconcile`.
+
+use soroban_sdk::{contracttype, Env};
+
+use crate::{account_balance, adjust_liability, AccountClass, ContractError};
+
+#[contracttype]
+#[derive(Clone)]
+enum QuarantineKey {
+    /// Amount held for a quarantined remittance (persistent storage)
+    Held(u64),
+}
+
+pub fn get_total_quarantined(env: &Env) -> i128 {
+    account_balance(env, AccountClass::Quarantine)
+}
+
+/// Returns the amount held in quarantine for a remittance, 0 if none.
+pub fn get_quarantined_amount(env: &Env, remittance_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&QuarantineKey::Held(remittance_id))
+        .unwrap_or(0)
+}
+
+/// Holds `amount` in quarantine for a remittance.
+pub fn quarantine_funds(env: &Env, remittance_id: u64, amount: i128) -> Result<(), ContractError> {
+    let held = get_quarantined_amount(env, remittance_id)
+        .checked_add(amount)
+        .ok_or(ContractError::Overflow)?;
+
+    env.storage()
+        .persistent()
+        .set(&QuarantineKey::Held(remittance_id), &held);
+    adjust_liability(env, AccountClass::Quarantine, amount)
+}
+
+/// Takes the funds held in quarantine for a remittance out of the vault.
+///
+/// # Returns
+///
+/// * `Ok(i128)` - Amount released, to be transferred by the caller
+/// * `Err(ContractError::InvalidStatus)` - Nothing is held for the remittance
+pub fn release_quarantined(env: &Env, remittance_id: u64) -> Result<i128, ContractError> {
+    let held = get_quarantined_amount(env, remittance_id);
+    if held <= 0 {
+        return Err(ContractError::InvalidStatus);
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&QuarantineKey::Held(remittance_id));
+    adjust_liability(env, AccountClass::Quarantine, -held)?;
+    Ok(held)
+}
@@ -0,0 +1,226 @@
+//! M-of-N approval of critical operations.
+//!
+//! Once the admin configures a quorum, fee withdrawals and distributions,
+//! fee split and treasury changes, yield adapter changes and escrow and fee
+//! deposits into it, contract upgrades and token rescues can no longer be performed by a
+//! single admin. A signer
+//! proposes the operation, other signers approve it, and anyone can execute
+//! it once `threshold` signers have approved, as long as the proposal has not
+//! expired. The quorum itself can then only be changed by a proposal.
+
+use soroban_sdk::{contracttype, Address, BytesN, Env, Vec};
+
+use crate::{validate_fee_split, validate_treasury, ContractError, YieldBeneficiary};
+
+/// Signers, approval threshold and proposal lifetime.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QuorumConfig {
+    /// Addresses that can propose and approve operations
+    pub signers: Vec<Address>,
+    /// Number of approvals an operation needs
+    pub threshold: u32,
+    /// Ledgers a proposal stays open for approval and execution
+    pub ttl_ledgers: u32,
+}
+
+/// Operation that requires the quorum's approval.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CriticalOp {
+    /// Withdraw the withdrawable fees in a token to an address
+    WithdrawFees(Address, Address),
+    /// Upgrade the contract to the uploaded WASM with this hash
+    Upgrade(BytesN<32>),
+    /// Send an amount of a token the contract does not owe anyone to an address
+    RescueTokens(Address, Address, i128),
+    /// Replace the quorum configuration
+    SetQuorum(QuorumConfig),
+    /// Replace the fee split with these recipients and shares in basis points
+    SetFeeSplit(Vec<(Address, u32)>),
+    /// Distribute the withdrawable fees across the fee split
+    DistributeFees,
+    /// Register a yield adapter and the beneficiary of its yield
+    SetYieldAdapter(Address, YieldBeneficiary),
+    /// Deposit an amount of idle escrow into the yield adapter
+    DepositIdleEscrow(i128),
+    /// Set or clear the treasury fee withdrawals are restricted to
+    SetTreasury(Option<Address>),
+    /// Wrap an amount of withdrawable fees in the yield adapter
+    WrapFees(i128),
+}
+
+/// A proposed critical operation and its approvals.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OpProposal {
+    pub id: u64,
+    pub op: CriticalOp,
+    /// Signers that approved, the proposer first
+    pub approvals: Vec<Address>,
+    /// Last ledger the proposal can be approved or executed in
+    pub expires_at_ledger: u32,
+    pub executed: bool,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum QuorumKey {
+    /// Quorum configuration
+    Config,
+    /// Next proposal ID
+    NextId,
+    /// Proposal by ID (persistent storage)
+    Proposal(u64),
+}
+
+/// Validates a quorum configuration.
+///
+/// # Returns
+///
+/// * `Ok(())` - The threshold is between 1 and the number of distinct signers
+/// * `Err(ContractError::InvalidAmount)` - Threshold or TTL is zero, or the threshold exceeds the signers
+/// * `Err(ContractError::AdminAlreadyExists)` - A signer is listed twice
+pub fn validate_quorum_config(config: &QuorumConfig) -> Result<(), ContractError> {
+    for (i, signer) in config.signers.iter().enumerate() {
+        if config.signers.first_index_of(&signer) != Some(i as u32) {
+            return Err(ContractError::AdminAlreadyExists);
+        }
+    }
+    if config.threshold == 0 || config.threshold > config.signers.len() || config.ttl_ledgers == 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+    Ok(())
+}
+
+pub fn set_quorum_config(env: &Env, config: &QuorumConfig) {
+    env.storage().instance().set(&QuorumKey::Config, config);
+}
+
+pub fn get_quorum_config(env: &Env) -> Option<QuorumConfig> {
+    env.storage().instance().get(&QuorumKey::Config)
+}
+
+/// Fails when a quorum is configured, so the single-admin path of a critical
+/// operation is closed.
+///
+/// # Returns
+///
+/// * `Ok(())` - No quorum is configured
+/// * `Err(ContractError::Unauthorized)` - The operation must go through `propose_op`
+pub fn require_no_quorum(env: &Env) -> Result<(), ContractError> {
+    if get_quorum_config(env).is_some() {
+        return Err(ContractError::Unauthorized);
+    }
+    Ok(())
+}
+
+pub fn get_op_proposal(env: &Env, proposal_id: u64) -> Option<OpProposal> {
+    env.storage().persistent().get(&QuorumKey::Proposal(proposal_id))
+}
+
+fn set_op_proposal(env: &Env, proposal: &OpProposal) {
+    env.storage()
+        .persistent()
+        .set(&QuorumKey::Proposal(proposal.id), proposal);
+}
+
+fn require_signer(env: &Env, signer: &Address) -> Result<QuorumConfig, ContractError> {
+    let config = get_quorum_config(env).ok_or(ContractError::InvalidStatus)?;
+    if !config.signers.contains(signer) {
+        return Err(ContractError::Unauthorized);
+    }
+    signer.require_auth();
+    Ok(config)
+}
+
+/// Loads a proposal that is still open for approval or execution.
+fn get_open_proposal(env: &Env, proposal_id: u64) -> Result<OpProposal, ContractError> {
+    let proposal = get_op_proposal(env, proposal_id).ok_or(ContractError::InvalidStatus)?;
+    if proposal.executed {
+        return Err(ContractError::InvalidStatus);
+    }
+    if env.ledger().sequence() > proposal.expires_at_ledger {
+        return Err(ContractError::SignatureExpired);
+    }
+    Ok(proposal)
+}
+
+/// Records a new proposal by `proposer`, counting it as the first approval.
+///
+/// # Returns
+///
+/// * `Ok(OpProposal)` - The stored proposal
+/// * `Err(ContractError::InvalidStatus)` - No quorum is configured
+/// * `Err(ContractError::Unauthorized)` - `proposer` is not a signer
+pub fn propose_op(env: &Env, proposer: &Address, op: CriticalOp) -> Result<OpProposal, ContractError> {
+    let config = require_signer(env, proposer)?;
+    match &op {
+        CriticalOp::SetQuorum(new_config) => validate_quorum_config(new_config)?,
+        CriticalOp::SetFeeSplit(splits) => validate_fee_split(splits)?,
+        CriticalOp::SetTreasury(Some(treasury)) => validate_treasury(env, treasury)?,
+        _ => {}
+    }
+
+    let id: u64 = env.storage().instance().get(&QuorumKey::NextId).unwrap_or(1);
+    env.storage().instance().set(&QuorumKey::NextId, &(id + 1));
+
+    let mut approvals = Vec::new(env);
+    approvals.push_back(proposer.clone());
+    let proposal = OpProposal {
+        id,
+        op,
+        approvals,
+        expires_at_ledger: env.ledger().sequence().saturating_add(config.ttl_ledgers),
+        executed: false,
+    };
+    set_op_proposal(env, &proposal);
+    Ok(proposal)
+}
+
+/// Adds `signer`'s approval to an open proposal.
+///
+/// # Returns
+///
+/// * `Ok(u32)` - Number of approvals so far
+/// * `Err(ContractError::Unauthorized)` - `signer` is not a signer
+/// * `Err(ContractError::InvalidStatus)` - Unknown or executed proposal, or already approved by `signer`
+/// * `Err(ContractError::SignatureExpired)` - The proposal has expired
+pub fn approve_op(env: &Env, signer: &Address, proposal_id: u64) -> Result<u32, ContractError> {
+    require_signer(env, signer)?;
+    let mut proposal = get_open_proposal(env, proposal_id)?;
+    if proposal.approvals.contains(signer) {
+        return Err(ContractError::InvalidStatus);
+    }
+    proposal.approvals.push_back(signer.clone());
+    set_op_proposal(env, &proposal);
+    Ok(proposal.approvals.len())
+}
+
+/// Marks an open proposal with enough approvals as executed and returns it.
+///
+/// Approvals of signers removed since they approved do not count.
+///
+/// # Returns
+///
+/// * `Ok(OpProposal)` - The proposal, whose operation the caller must now perform
+/// * `Err(ContractError::InvalidStatus)` - Unknown or executed proposal, or no quorum configured
+/// * `Err(ContractError::SignatureExpired)` - The proposal has expired
+/// * `Err(ContractError::Unauthorized)` - Fewer than `threshold` signers approved
+pub fn take_approved_op(env: &Env, proposal_id: u64) -> Result<OpProposal, ContractError> {
+    let config = get_quorum_config(env).ok_or(ContractError::InvalidStatus)?;
+    let mut proposal = get_open_proposal(env, proposal_id)?;
+
+    let approvals = proposal
+        .approvals
+        .iter()
+        .filter(|signer| config.signers.contains(signer))
+        .count() as u32;
+    if approvals < config.threshold {
+        return Err(ContractError::Unauthorized);
+    }
+
+    proposal.executed = true;
+    set_op_proposal(env, &proposal);
+    Ok(proposal)
+}
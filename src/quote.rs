@@ -0,0 +1,116 @@
+//! Payout quotes for wallets.
+//!
+//! Wallets call `quote` before asking the user to sign so they can show the
+//! exact fee and payout. The fee is computed by the same function
+//! `create_remittance` uses, so a quote for the same agent always matches the
+//! remittance created from it while the fee rate is unchanged. FX rates are published by the
+//! admin per payout currency and a quote is valid for `QUOTE_VALIDITY_SECONDS`.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::{
+    calculate_platform_fee, get_fee_credit, get_volume_discount_bps, is_fee_exempt, validate_amount, ContractError,
+    Currency,
+};
+
+/// Fixed-point scale of FX rates (7 decimals, matching Stellar amounts).
+pub const FX_RATE_SCALE: i128 = 10_000_000;
+
+/// How long a quote stays valid after it is issued.
+pub const QUOTE_VALIDITY_SECONDS: u64 = 300;
+
+/// Maximum age of a published FX rate before quotes against it are refused.
+pub const FX_RATE_MAX_AGE_SECONDS: u64 = 86_400;
+
+/// Quote for sending `amount` to a payout currency.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Quote {
+    /// Platform fee deducted from the amount
+    pub fee: i128,
+    /// Amount paid out to the agent after the fee
+    pub net_amount: i128,
+    /// Units of payout currency per USDC, scaled by FX_RATE_SCALE
+    pub fx_rate: i128,
+    /// Timestamp after which the quote should be refreshed
+    pub expiry: u64,
+    /// Whether the fee was waived because the sender is fee-exempt
+    pub fee_exempt: bool,
+    /// Volume discount taken off the fee, in basis points of the fee
+    pub volume_discount_bps: u32,
+}
+
+/// FX rate published by the admin for a payout currency.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FxRate {
+    /// Units of payout currency per USDC, scaled by FX_RATE_SCALE
+    pub rate: i128,
+    /// Timestamp the rate was published
+    pub updated_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum QuoteKey {
+    /// FX rate per currency (persistent storage)
+    FxRate(Currency),
+}
+
+pub fn set_fx_rate(env: &Env, currency: &Currency, rate: i128) {
+    let fx_rate = FxRate {
+        rate,
+        updated_at: env.ledger().timestamp(),
+    };
+    env.storage()
+        .persistent()
+        .set(&QuoteKey::FxRate(currency.clone()), &fx_rate);
+}
+
+pub fn get_fx_rate(env: &Env, currency: &Currency) -> Option<FxRate> {
+    env.storage()
+        .persistent()
+        .get(&QuoteKey::FxRate(currency.clone()))
+}
+
+/// Builds a quote for `amount` paid out in `currency`, sent by `sender` to
+/// `agent` if known.
+///
+/// # Returns
+///
+/// * `Ok(Quote)` - Fee, payout and FX rate valid until `expiry`
+/// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - Amount is not positive
+/// * `Err(ContractError::CorridorClosed)` - No FX rate is published for the currency
+/// * `Err(ContractError::RateExpired)` - The published rate is older than FX_RATE_MAX_AGE_SECONDS
+pub fn build_quote(
+    env: &Env,
+    amount: i128,
+    currency: &Currency,
+    sender: Option<&Address>,
+    agent: Option<&Address>,
+) -> Result<Quote, ContractError> {
+    validate_amount(amount)?;
+
+    let fx_rate = get_fx_rate(env, currency).ok_or(ContractError::CorridorClosed)?;
+    let now = env.ledger().timestamp();
+    if now.saturating_sub(fx_rate.updated_at) > FX_RATE_MAX_AGE_SECONDS {
+        return Err(ContractError::RateExpired);
+    }
+    let fee_exempt = sender.map_or(false, |sender| is_fee_exempt(env, sender));
+    let volume_discount_bps = sender.map_or(0, |sender| get_volume_discount_bps(env, sender));
+    let mut fee = calculate_platform_fee(env, sender, agent, amount)?;
+    if let Some(sender) = sender {
+        // The sender's loyalty fee credit is drawn first
+        fee -= get_fee_credit(env, sender).min(fee);
+    }
+    let net_amount = amount.checked_sub(fee).ok_or(ContractError::Overflow)?;
+
+    Ok(Quote {
+        fee,
+        net_amount,
+        fx_rate: fx_rate.rate,
+        expiry: now.saturating_add(QUOTE_VALIDITY_SECONDS),
+        fee_exempt,
+        volume_discount_bps,
+    })
+}
@@ -1,6 +1,6 @@
-use soroban_sdk::{contracttype, Address, Env};
+use soroban_sdk::{contracttype, symbol_short, Address, Env};
 
-use crate::ContractError;
+use crate::{is_limit_exempt, log_limit_error, ContractError};
 
 /// Rate limit configuration stored in instance storage
 #[contracttype]
@@ -14,6 +14,30 @@ pub struct RateLimitConfig {
     pub enabled: bool,
 }
 
+/// Limit on remittance creation per sender, measured in ledgers.
+/// A `max_creates` of 0 disables the limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreateRateLimit {
+    /// Maximum number of remittances a sender can create per window
+    pub max_creates: u32,
+    /// Window length in ledgers
+    pub window_ledgers: u32,
+}
+
+/// Cooldown imposed on senders who cancel too many remittances within a
+/// window, measured in seconds. A `max_cancels` of 0 disables it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CancelCooldown {
+    /// Cancellations within the window that start a cooldown
+    pub max_cancels: u32,
+    /// Window length in seconds
+    pub window_seconds: u64,
+    /// How long the sender cannot create remittances, in seconds
+    pub cooldown_seconds: u64,
+}
+
 /// Rate limit tracking per address
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -31,6 +55,16 @@ enum RateLimitKey {
     Config,
     /// Per-address rate limit tracking
     Entry(Address),
+    /// Remittance creation limit configuration
+    CreateConfig,
+    /// Per-sender remittance creation tracking
+    CreateEntry(Address),
+    /// Cancellation cooldown configuration
+    CancelConfig,
+    /// Per-sender cancellation tracking
+    CancelEntry(Address),
+    /// End of a sender's cancellation cooldown
+    Cooldown(Address),
 }
 
 /// Initialize rate limiting with default configuration
@@ -137,3 +171,176 @@ pub fn get_rate_limit_status(env: &Env, address: &Address) -> (u32, u32, u64) {
         (entry.request_count, config.max_requests, config.window_seconds)
     }
 }
+
+/// Get the remittance creation limit (disabled by default)
+pub fn get_create_rate_limit(env: &Env) -> CreateRateLimit {
+    env.storage()
+        .instance()
+        .get(&RateLimitKey::CreateConfig)
+        .unwrap_or(CreateRateLimit {
+            max_creates: 0,
+            window_ledgers: 0,
+        })
+}
+
+/// Update the remittance creation limit (admin only)
+pub fn set_create_rate_limit(env: &Env, limit: &CreateRateLimit) {
+    env.storage()
+        .instance()
+        .set(&RateLimitKey::CreateConfig, limit);
+}
+
+/// Check and update the remittance creation count for a sender
+/// Returns Ok(()) if within limits or the sender is limit-exempt,
+/// Err(ContractError::RateLimited) if exceeded
+pub fn check_create_rate_limit(env: &Env, sender: &Address) -> Result<(), ContractError> {
+    let limit = get_create_rate_limit(env);
+
+    // A zero limit or window disables the check
+    if limit.max_creates == 0 || limit.window_ledgers == 0 || is_limit_exempt(env, sender) {
+        return Ok(());
+    }
+
+    let current_ledger = env.ledger().sequence();
+    let key = RateLimitKey::CreateEntry(sender.clone());
+
+    // Window start is tracked as a ledger sequence rather than a timestamp
+    let mut entry: RateLimitEntry = env
+        .storage()
+        .temporary()
+        .get(&key)
+        .unwrap_or(RateLimitEntry {
+            request_count: 0,
+            window_start: current_ledger as u64,
+        });
+
+    let ledgers_elapsed = (current_ledger as u64).saturating_sub(entry.window_start);
+    if ledgers_elapsed >= limit.window_ledgers as u64 {
+        entry.request_count = 1;
+        entry.window_start = current_ledger as u64;
+    } else {
+        if entry.request_count >= limit.max_creates {
+            return Err(log_limit_error(
+                env,
+                symbol_short!("create"),
+                ContractError::RateLimited,
+                entry.request_count as i128,
+                limit.max_creates as i128,
+            ));
+        }
+        entry.request_count = entry.request_count.saturating_add(1);
+    }
+
+    env.storage()
+        .temporary()
+        .set(&key, &entry);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, limit.window_ledgers, limit.window_ledgers);
+
+    Ok(())
+}
+
+/// Get the cancellation cooldown (disabled by default)
+pub fn get_cancel_cooldown(env: &Env) -> CancelCooldown {
+    env.storage()
+        .instance()
+        .get(&RateLimitKey::CancelConfig)
+        .unwrap_or(CancelCooldown {
+            max_cancels: 0,
+            window_seconds: 0,
+            cooldown_seconds: 0,
+        })
+}
+
+/// Update the cancellation cooldown (admin only)
+pub fn set_cancel_cooldown(env: &Env, config: &CancelCooldown) {
+    env.storage()
+        .instance()
+        .set(&RateLimitKey::CancelConfig, config);
+}
+
+/// Returns when the cancellation cooldown of a sender ends, or `None` when
+/// the sender is not cooling down
+pub fn get_cooldown(env: &Env, sender: &Address) -> Option<u64> {
+    let until: u64 = env
+        .storage()
+        .temporary()
+        .get(&RateLimitKey::Cooldown(sender.clone()))?;
+    if until > env.ledger().timestamp() {
+        Some(until)
+    } else {
+        None
+    }
+}
+
+/// Count a cancellation by a sender, starting its cooldown once it reaches
+/// the configured number within the window
+/// Returns the end of the cooldown if one was started
+pub fn record_cancellation(env: &Env, sender: &Address) -> Option<u64> {
+    let config = get_cancel_cooldown(env);
+
+    // A zero limit or window disables the cooldown
+    if config.max_cancels == 0 || config.window_seconds == 0 {
+        return None;
+    }
+
+    let current_time = env.ledger().timestamp();
+    let key = RateLimitKey::CancelEntry(sender.clone());
+
+    let mut entry: RateLimitEntry = env
+        .storage()
+        .temporary()
+        .get(&key)
+        .unwrap_or(RateLimitEntry {
+            request_count: 0,
+            window_start: current_time,
+        });
+
+    if current_time.saturating_sub(entry.window_start) >= config.window_seconds {
+        entry.request_count = 0;
+        entry.window_start = current_time;
+    }
+    entry.request_count = entry.request_count.saturating_add(1);
+
+    if entry.request_count >= config.max_cancels {
+        // The count starts over once the cooldown is served
+        env.storage().temporary().remove(&key);
+
+        let until = current_time.saturating_add(config.cooldown_seconds);
+        let cooldown_key = RateLimitKey::Cooldown(sender.clone());
+        let ttl = config.cooldown_seconds.saturating_add(3600);
+        env.storage()
+            .temporary()
+            .set(&cooldown_key, &until);
+        env.storage()
+            .temporary()
+            .extend_ttl(&cooldown_key, ttl as u32, ttl as u32);
+        return Some(until);
+    }
+
+    let ttl = config.window_seconds.saturating_add(3600);
+    env.storage()
+        .temporary()
+        .set(&key, &entry);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, ttl as u32, ttl as u32);
+
+    None
+}
+
+/// Check that a sender is not in a cancellation cooldown
+/// Returns Ok(()) if not cooling down, Err(ContractError::RateLimited) otherwise
+pub fn check_cancel_cooldown(env: &Env, sender: &Address) -> Result<(), ContractError> {
+    match get_cooldown(env, sender) {
+        Some(until) => Err(log_limit_error(
+            env,
+            symbol_short!("cooldown"),
+            ContractError::RateLimited,
+            env.ledger().timestamp() as i128,
+            until as i128,
+        )),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,199 @@
+//! Per-sender risk scores and the limits of each score band.
+//!
+//! Risk scorers appointed by the admin, either compliance officers or an
+//! external risk contract calling in with its own address, keep a score for
+//! each sender. The admin splits the score range into bands, each capping the
+//! size of a single remittance and how many remittances a sender in the band
+//! can create per day. A sender without a score has score 0, and no limits
+//! apply until bands are configured.
+//!
+//! Risk scorers can also exempt audited institutional senders from the band
+//! limits and the creation rate limit, instead of raising limits that would
+//! then apply to every sender.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::{validate_non_negative, ContractError};
+
+/// Maximum number of risk bands.
+pub const MAX_RISK_BANDS: u32 = 10;
+
+/// Length of the window remittances are counted over for velocity limits (24 hours).
+pub const RISK_VELOCITY_WINDOW_SECONDS: u64 = 24 * 60 * 60;
+
+/// Limits applying to senders whose score is at least `min_score` and below
+/// the next band's.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RiskBand {
+    /// Lowest score in the band
+    pub min_score: u32,
+    /// Largest single remittance; 0 for no cap
+    pub max_amount: i128,
+    /// Most remittances per 24 hours; 0 for no cap
+    pub max_per_day: u32,
+}
+
+/// Remittances a sender created in the current velocity window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RiskVelocity {
+    pub window_start: u64,
+    pub count: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum RiskKey {
+    /// Score of a sender (persistent storage)
+    Score(Address),
+    /// Whether an address may set scores (persistent storage)
+    Scorer(Address),
+    /// Bands in ascending order of `min_score`
+    Bands,
+    /// Velocity window of a sender (temporary storage)
+    Velocity(Address),
+    /// Whether a sender is exempt from band and creation rate limits (persistent storage)
+    LimitExempt(Address),
+}
+
+pub fn get_risk_score(env: &Env, sender: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&RiskKey::Score(sender.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_risk_score(env: &Env, sender: &Address, score: u32) {
+    env.storage()
+        .persistent()
+        .set(&RiskKey::Score(sender.clone()), &score);
+}
+
+pub fn set_risk_scorer(env: &Env, scorer: &Address, enabled: bool) {
+    let key = RiskKey::Scorer(scorer.clone());
+    if enabled {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub fn is_risk_scorer(env: &Env, scorer: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&RiskKey::Scorer(scorer.clone()))
+        .unwrap_or(false)
+}
+
+pub fn set_limit_exempt(env: &Env, sender: &Address, exempt: bool) {
+    let key = RiskKey::LimitExempt(sender.clone());
+    if exempt {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Returns whether `sender` bypasses the band and creation rate limits.
+pub fn is_limit_exempt(env: &Env, sender: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&RiskKey::LimitExempt(sender.clone()))
+}
+
+pub fn get_risk_bands(env: &Env) -> Vec<RiskBand> {
+    env.storage()
+        .instance()
+        .get(&RiskKey::Bands)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Replaces the risk bands.
+///
+/// # Returns
+///
+/// * `Ok(())` - Bands stored; an empty list removes all limits
+/// * `Err(ContractError::LimitExceeded)` - More than `MAX_RISK_BANDS` bands
+/// * `Err(ContractError::InvalidAmount)` - Bands not in strictly ascending order of `min_score`
+/// * `Err(ContractError::NegativeAmount)` - A band has a negative `max_amount`
+pub fn set_risk_bands(env: &Env, bands: &Vec<RiskBand>) -> Result<(), ContractError> {
+    if bands.len() > MAX_RISK_BANDS {
+        return Err(ContractError::LimitExceeded);
+    }
+    for i in 0..bands.len() {
+        let band = bands.get_unchecked(i);
+        validate_non_negative(band.max_amount)?;
+        if i > 0 && band.min_score <= bands.get_unchecked(i - 1).min_score {
+            return Err(ContractError::InvalidAmount);
+        }
+    }
+    env.storage().instance().set(&RiskKey::Bands, bands);
+    Ok(())
+}
+
+/// Returns the band a sender's score falls in, if any band covers it.
+pub fn get_sender_band(env: &Env, sender: &Address) -> Option<RiskBand> {
+    let score = get_risk_score(env, sender);
+    let mut matched = None;
+    for band in get_risk_bands(env).iter() {
+        if band.min_score > score {
+            break;
+        }
+        matched = Some(band);
+    }
+    matched
+}
+
+fn get_velocity(env: &Env, sender: &Address) -> RiskVelocity {
+    let now = env.ledger().timestamp();
+    match env
+        .storage()
+        .temporary()
+        .get::<_, RiskVelocity>(&RiskKey::Velocity(sender.clone()))
+    {
+        Some(velocity) if now.saturating_sub(velocity.window_start) < RISK_VELOCITY_WINDOW_SECONDS => velocity,
+        _ => RiskVelocity {
+            window_start: now,
+            count: 0,
+        },
+    }
+}
+
+/// Checks a new remittance of `amount` against the limits of the sender's band.
+///
+/// # Returns
+///
+/// * `Ok(())` - Within the band's limits, or the sender is in no band or limit-exempt
+/// * `Err(ContractError::AmountAboveMaximum)` - Amount exceeds the band's cap
+/// * `Err(ContractError::RateLimited)` - The sender reached the band's daily count
+pub fn check_risk_limits(env: &Env, sender: &Address, amount: i128) -> Result<(), ContractError> {
+    if is_limit_exempt(env, sender) {
+        return Ok(());
+    }
+    let band = match get_sender_band(env, sender) {
+        Some(band) => band,
+        None => return Ok(()),
+    };
+    if band.max_amount > 0 && amount > band.max_amount {
+        return Err(ContractError::AmountAboveMaximum);
+    }
+    if band.max_per_day > 0 && get_velocity(env, sender).count >= band.max_per_day {
+        return Err(ContractError::RateLimited);
+    }
+    Ok(())
+}
+
+/// Counts a new remittance towards the sender's velocity window.
+pub fn record_risk_velocity(env: &Env, sender: &Address) {
+    if get_risk_bands(env).is_empty() || is_limit_exempt(env, sender) {
+        return;
+    }
+    let mut velocity = get_velocity(env, sender);
+    velocity.count = velocity.count.saturating_add(1);
+    let key = RiskKey::Velocity(sender.clone());
+    // Ledgers close at least a second apart, so the entry outlives its window
+    let ttl = RISK_VELOCITY_WINDOW_SECONDS as u32;
+    env.storage().temporary().set(&key, &velocity);
+    env.storage().temporary().extend_ttl(&key, ttl, ttl);
+}
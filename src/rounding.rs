@@ -0,0 +1,119 @@
+//! Fee rounding policy per token.
+//!
+//! Fees are computed in basis points and rarely divide evenly. Rounding down
+//! loses at most one unit per remittance, which is negligible for a 7-decimal
+//! token but a full cent for a 2-decimal one. The admin can therefore choose
+//! how fees in each token are rounded; tokens without a policy round down.
+//!
+//! A token is probed when the contract is initialized with it or it is
+//! whitelisted: it must answer `decimals`, `balance` and a zero `transfer`,
+//! and the decimals it reports are stored for the fee math. An address that
+//! is not a token contract is rejected instead of bricking the deployment.
+
+use soroban_sdk::{contracttype, token, Address, Env};
+
+use crate::ContractError;
+
+/// How a fee that does not divide evenly is rounded to whole token units.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RoundingMode {
+    /// Round down, in the sender's favour
+    Floor,
+    /// Round up, in the platform's favour
+    Ceil,
+    /// Round to the nearest unit, halves up
+    HalfUp,
+}
+
+/// Fee rounding policy of a token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeRoundingPolicy {
+    /// Rounding applied to fees in the token
+    pub mode: RoundingMode,
+    /// Decimals of the token, as reported by the token contract
+    pub decimals: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum RoundingKey {
+    /// Fee rounding mode per token (persistent storage)
+    Mode(Address),
+    /// Decimals reported by a token when it was verified (persistent storage)
+    Decimals(Address),
+}
+
+pub fn set_fee_rounding(env: &Env, token: &Address, mode: RoundingMode) {
+    env.storage()
+        .persistent()
+        .set(&RoundingKey::Mode(token.clone()), &mode);
+}
+
+/// Returns the rounding mode of fees in `token`, `Floor` when unset.
+pub fn get_fee_rounding_mode(env: &Env, token: &Address) -> RoundingMode {
+    env.storage()
+        .persistent()
+        .get(&RoundingKey::Mode(token.clone()))
+        .unwrap_or(RoundingMode::Floor)
+}
+
+/// Checks that `token` implements the token interface by calling it, and
+/// stores the decimals it reports.
+///
+/// # Returns
+///
+/// * `Ok(u32)` - Decimals of the token
+/// * `Err(ContractError::UnsupportedToken)` - A `decimals`, `balance` or zero
+///   `transfer` call on the address failed
+pub fn verify_token(env: &Env, token: &Address) -> Result<u32, ContractError> {
+    let client = token::Client::new(env, token);
+    let this = env.current_contract_address();
+    let decimals = match client.try_decimals() {
+        Ok(Ok(decimals)) => decimals,
+        _ => return Err(ContractError::UnsupportedToken),
+    };
+    if !matches!(client.try_balance(&this), Ok(Ok(_))) {
+        return Err(ContractError::UnsupportedToken);
+    }
+    if !matches!(client.try_transfer(&this, &this, &0), Ok(Ok(_))) {
+        return Err(ContractError::UnsupportedToken);
+    }
+    env.storage()
+        .persistent()
+        .set(&RoundingKey::Decimals(token.clone()), &decimals);
+    Ok(decimals)
+}
+
+/// Returns the decimals stored when `token` was verified.
+pub fn get_token_decimals(env: &Env, token: &Address) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&RoundingKey::Decimals(token.clone()))
+}
+
+/// Returns the fee rounding policy of `token` together with its decimals.
+pub fn get_fee_rounding(env: &Env, token: &Address) -> FeeRoundingPolicy {
+    FeeRoundingPolicy {
+        mode: get_fee_rounding_mode(env, token),
+        decimals: get_token_decimals(env, token)
+            .unwrap_or_else(|| token::Client::new(env, token).decimals()),
+    }
+}
+
+/// Divides a non-negative `numerator` by a positive `denominator`, rounding with `mode`.
+pub fn div_round(numerator: i128, denominator: i128, mode: RoundingMode) -> Result<i128, ContractError> {
+    let quotient = numerator.checked_div(denominator).ok_or(ContractError::Overflow)?;
+    let remainder = numerator.checked_rem(denominator).ok_or(ContractError::Overflow)?;
+    let round_up = match mode {
+        RoundingMode::Floor => false,
+        RoundingMode::Ceil => remainder > 0,
+        RoundingMode::HalfUp => remainder * 2 >= denominator,
+    };
+    if round_up {
+        quotient.checked_add(1).ok_or(ContractError::Overflow)
+    } else {
+        Ok(quotient)
+    }
+}
@@ -0,0 +1,78 @@
+//! Multi-hop remittances routed through intermediary agents.
+//!
+//! Some corridors have no agent that can pay out directly and settle through
+//! a hub instead. A routed remittance is an ordinary pending remittance for
+//! the destination agent, plus the list of intermediaries its funds pass
+//! through. Each intermediary confirms its leg in route order and is paid the
+//! hop fee out of escrow; the destination agent can only confirm the payout
+//! once every leg has been confirmed.
+//!
+//! Hop fees are recorded as released amounts without a platform fee, so the
+//! payout and any refund only cover what is left in escrow.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::ContractError;
+
+/// Maximum number of addresses in a route, destination agent included.
+pub const MAX_ROUTE_LENGTH: u32 = 5;
+
+/// Intermediaries of a routed remittance and the progress of its legs.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Route {
+    /// Intermediary agents, in the order their legs are confirmed
+    pub hops: Vec<Address>,
+    /// Fee paid to each intermediary when it confirms its leg
+    pub hop_fee: i128,
+    /// Number of legs confirmed so far
+    pub confirmed: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum RouteKey {
+    /// Fee per hop in basis points of the remittance amount
+    HopFeeBps,
+    /// Route of a remittance (persistent storage)
+    Route(u64),
+}
+
+pub fn set_hop_fee_bps(env: &Env, fee_bps: u32) {
+    env.storage().instance().set(&RouteKey::HopFeeBps, &fee_bps);
+}
+
+pub fn get_hop_fee_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&RouteKey::HopFeeBps)
+        .unwrap_or(0)
+}
+
+pub fn get_route(env: &Env, remittance_id: u64) -> Option<Route> {
+    env.storage().persistent().get(&RouteKey::Route(remittance_id))
+}
+
+pub fn set_route(env: &Env, remittance_id: u64, route: &Route) {
+    env.storage()
+        .persistent()
+        .set(&RouteKey::Route(remittance_id), route);
+}
+
+/// Returns the intermediary whose leg is next to be confirmed, if any is left.
+pub fn next_hop(route: &Route) -> Option<Address> {
+    route.hops.get(route.confirmed)
+}
+
+/// Checks that every leg of a routed remittance has been confirmed.
+///
+/// # Returns
+///
+/// * `Ok(())` - The remittance is not routed, or all its legs are confirmed
+/// * `Err(ContractError::InvalidStatus)` - An intermediary has not confirmed its leg
+pub fn check_route_complete(env: &Env, remittance_id: u64) -> Result<(), ContractError> {
+    match get_route(env, remittance_id) {
+        Some(route) if next_hop(&route).is_some() => Err(ContractError::InvalidStatus),
+        _ => Ok(()),
+    }
+}
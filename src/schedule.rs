@@ -0,0 +1,190 @@
+//! Per-agent settlement schedules for the SwiftRemit contract.
+//!
+//! Agents settle instantly by default: each confirmed payout is transferred as
+//! soon as it is confirmed. Agents may instead opt into daily or weekly netting,
+//! in which case confirmed payouts accumulate in an open settlement batch that
+//! anyone can execute with `run_settlement` once the batch's window has closed.
+//! Windows are aligned to multiples of the schedule period so every agent on the
+//! same schedule settles on the same boundaries.
+//!
+//! High-frequency agents can also choose on-demand netting: confirmed payouts
+//! accrue to the agent's receivable balance without a window, and the agent
+//! collects the net with a single transfer whenever it calls `settle_agent`.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::{account_balance, adjust_liability, AccountClass, ContractError};
+
+/// Seconds in a daily settlement window.
+pub const DAILY_WINDOW_SECONDS: u64 = 86_400;
+
+/// Seconds in a weekly settlement window.
+pub const WEEKLY_WINDOW_SECONDS: u64 = 604_800;
+
+/// How often an agent's confirmed payouts are transferred.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SettlementSchedule {
+    /// Each payout is transferred on confirmation
+    Instant,
+    /// Payouts are batched and settled once per day
+    Daily,
+    /// Payouts are batched and settled once per week
+    Weekly,
+    /// Payouts accrue until the agent settles them
+    OnDemand,
+}
+
+impl SettlementSchedule {
+    /// Length of the batching window, `Some(0)` when a batch is due as soon
+    /// as it opens, or `None` for instant settlement.
+    pub fn window_seconds(&self) -> Option<u64> {
+        match self {
+            SettlementSchedule::Instant => None,
+            SettlementSchedule::Daily => Some(DAILY_WINDOW_SECONDS),
+            SettlementSchedule::Weekly => Some(WEEKLY_WINDOW_SECONDS),
+            SettlementSchedule::OnDemand => Some(0),
+        }
+    }
+}
+
+/// Batch of confirmed payouts awaiting settlement to an agent.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AgentSettlement {
+    /// Agent receiving the settlement
+    pub agent: Address,
+    /// Start of the batching window
+    pub window_start: u64,
+    /// End of the batching window; the batch can be settled from this time on
+    pub window_end: u64,
+    /// Total payout owed to the agent
+    pub amount: i128,
+    /// Remittances included in the batch
+    pub remittance_ids: Vec<u64>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum ScheduleKey {
+    /// Settlement schedule per agent (persistent storage)
+    Schedule(Address),
+    /// Open settlement batch per agent (persistent storage)
+    Open(Address),
+}
+
+pub fn get_settlement_schedule(env: &Env, agent: &Address) -> SettlementSchedule {
+    env.storage()
+        .persistent()
+        .get(&ScheduleKey::Schedule(agent.clone()))
+        .unwrap_or(SettlementSchedule::Instant)
+}
+
+pub fn set_settlement_schedule(env: &Env, agent: &Address, schedule: SettlementSchedule) {
+    env.storage()
+        .persistent()
+        .set(&ScheduleKey::Schedule(agent.clone()), &schedule);
+}
+
+pub fn get_open_settlement(env: &Env, agent: &Address) -> Option<AgentSettlement> {
+    env.storage()
+        .persistent()
+        .get(&ScheduleKey::Open(agent.clone()))
+}
+
+/// Returns the total owed to agents across all open settlement batches.
+pub fn get_pending_settlements(env: &Env) -> i128 {
+    account_balance(env, AccountClass::Settlements)
+}
+
+fn adjust_pending_settlements(env: &Env, delta: i128) -> Result<(), ContractError> {
+    adjust_liability(env, AccountClass::Settlements, delta)
+}
+
+/// Adds a confirmed payout to the agent's open batch.
+///
+/// Returns `false` without recording anything when the agent settles
+/// instantly, in which case the caller must transfer the payout itself.
+pub fn queue_payout(
+    env: &Env,
+    agent: &Address,
+    remittance_id: u64,
+    amount: i128,
+) -> Result<bool, ContractError> {
+    let window = match get_settlement_schedule(env, agent).window_seconds() {
+        Some(window) => window,
+        None => return Ok(false),
+    };
+
+    let mut batch = match get_open_settlement(env, agent) {
+        Some(batch) => batch,
+        None => {
+            let now = env.ledger().timestamp();
+            let window_start = if window == 0 { now } else { now - now % window };
+            AgentSettlement {
+                agent: agent.clone(),
+                window_start,
+                window_end: window_start.saturating_add(window),
+                amount: 0,
+                remittance_ids: Vec::new(env),
+            }
+        }
+    };
+
+    batch.amount = batch.amount.checked_add(amount).ok_or(ContractError::Overflow)?;
+    batch.remittance_ids.push_back(remittance_id);
+    env.storage()
+        .persistent()
+        .set(&ScheduleKey::Open(agent.clone()), &batch);
+    adjust_pending_settlements(env, amount)?;
+
+    Ok(true)
+}
+
+/// Closes the agent's open batch if its window has ended and returns it.
+///
+/// The caller is responsible for transferring `amount` to the agent.
+///
+/// # Returns
+///
+/// * `Ok(AgentSettlement)` - The closed batch
+/// * `Err(ContractError::InvalidStatus)` - No open batch, or its window has not closed
+pub fn take_due_settlement(env: &Env, agent: &Address) -> Result<AgentSettlement, ContractError> {
+    let batch = get_open_settlement(env, agent).ok_or(ContractError::InvalidStatus)?;
+    if env.ledger().timestamp() < batch.window_end {
+        return Err(ContractError::InvalidStatus);
+    }
+    take_open_settlement(env, agent)
+}
+
+/// Closes the agent's open batch regardless of its window and returns it.
+///
+/// The caller is responsible for transferring `amount` to the agent.
+///
+/// # Returns
+///
+/// * `Ok(AgentSettlement)` - The closed batch
+/// * `Err(ContractError::InvalidStatus)` - The agent has no open batch
+pub fn take_open_settlement(env: &Env, agent: &Address) -> Result<AgentSettlement, ContractError> {
+    let batch = get_open_settlement(env, agent).ok_or(ContractError::InvalidStatus)?;
+
+    env.storage()
+        .persistent()
+        .remove(&ScheduleKey::Open(agent.clone()));
+    adjust_pending_settlements(env, -batch.amount)?;
+
+    Ok(batch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_lengths() {
+        assert_eq!(SettlementSchedule::Instant.window_seconds(), None);
+        assert_eq!(SettlementSchedule::Daily.window_seconds(), Some(86_400));
+        assert_eq!(SettlementSchedule::Weekly.window_seconds(), Some(604_800));
+        assert_eq!(SettlementSchedule::OnDemand.window_seconds(), Some(0));
+    }
+}
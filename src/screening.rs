@@ -0,0 +1,69 @@
+//! Screening window between funding and agent visibility.
+//!
+//! When the admin sets a screening period, each new remittance is withheld
+//! from its agent for that many ledgers after it is funded. During the window
+//! the remittance stays out of the agent's pending assignments and cannot be
+//! confirmed, giving compliance time to screen the sender and freeze the
+//! funds before a payout the sender could later charge back. Once the window
+//! has passed the remittance becomes payable as usual.
+
+use soroban_sdk::{contracttype, Env};
+
+use crate::ContractError;
+
+#[contracttype]
+#[derive(Clone)]
+enum ScreeningKey {
+    /// Ledgers a new remittance is withheld from its agent; 0 disables screening
+    Ledgers,
+    /// First ledger at which a screened remittance is payable (persistent storage)
+    VisibleAt(u64),
+}
+
+pub fn set_screening_ledgers(env: &Env, ledgers: u32) {
+    env.storage()
+        .instance()
+        .set(&ScreeningKey::Ledgers, &ledgers);
+}
+
+pub fn get_screening_ledgers(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ScreeningKey::Ledgers)
+        .unwrap_or(0)
+}
+
+/// Starts the screening window of a newly funded remittance, if screening is enabled.
+pub fn begin_screening(env: &Env, remittance_id: u64) {
+    let ledgers = get_screening_ledgers(env);
+    if ledgers == 0 {
+        return;
+    }
+    let visible_at = env.ledger().sequence().saturating_add(ledgers);
+    env.storage()
+        .persistent()
+        .set(&ScreeningKey::VisibleAt(remittance_id), &visible_at);
+}
+
+/// Returns the first ledger at which a screened remittance is payable.
+pub fn get_visible_at(env: &Env, remittance_id: u64) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&ScreeningKey::VisibleAt(remittance_id))
+}
+
+/// Returns whether a remittance has cleared its screening window, if it had one.
+pub fn is_screened(env: &Env, remittance_id: u64) -> bool {
+    match get_visible_at(env, remittance_id) {
+        Some(visible_at) => env.ledger().sequence() >= visible_at,
+        None => true,
+    }
+}
+
+/// Validates that a remittance has cleared its screening window.
+pub fn validate_screened(env: &Env, remittance_id: u64) -> Result<(), ContractError> {
+    if !is_screened(env, remittance_id) {
+        return Err(ContractError::PayoutOnHold);
+    }
+    Ok(())
+}
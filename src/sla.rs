@@ -0,0 +1,146 @@
+//! Payout service level and fee rebates for slow payouts.
+//!
+//! Every remittance records when it was created and when its agent confirmed
+//! the payout. The admin sets an SLA window per priority; when the agent
+//! confirms later than the window of the remittance's priority after
+//! creation, part of the platform fee is rebated to the sender on payout and
+//! a breach is counted against the agent.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::{ContractError, Priority};
+
+/// Admin-set payout service level.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SlaConfig {
+    /// Time from creation within which the agent should confirm, in seconds
+    pub window_seconds: u64,
+    /// Share of the platform fee rebated to the sender on a breach, in basis points
+    pub rebate_bps: u32,
+}
+
+/// Creation and confirmation times of a remittance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RemittanceTiming {
+    /// Timestamp the remittance was created
+    pub created_at: u64,
+    /// Timestamp the agent confirmed the payout, if it has
+    pub confirmed_at: Option<u64>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum SlaKey {
+    /// Service level configuration of a priority
+    Config(Priority),
+    /// Timing of a remittance (persistent storage)
+    Timing(u64),
+    /// Number of SLA breaches of an agent (persistent storage)
+    Breaches(Address),
+    /// Fee rebated to the sender of a late payout (persistent storage)
+    Rebate(u64),
+}
+
+pub fn set_sla_config(env: &Env, priority: Priority, config: &SlaConfig) {
+    env.storage().instance().set(&SlaKey::Config(priority), config);
+}
+
+pub fn clear_sla_config(env: &Env, priority: Priority) {
+    env.storage().instance().remove(&SlaKey::Config(priority));
+}
+
+pub fn get_sla_config(env: &Env, priority: Priority) -> Option<SlaConfig> {
+    env.storage().instance().get(&SlaKey::Config(priority))
+}
+
+pub fn get_remittance_timing(env: &Env, remittance_id: u64) -> Option<RemittanceTiming> {
+    env.storage().persistent().get(&SlaKey::Timing(remittance_id))
+}
+
+fn set_remittance_timing(env: &Env, remittance_id: u64, timing: &RemittanceTiming) {
+    env.storage()
+        .persistent()
+        .set(&SlaKey::Timing(remittance_id), timing);
+}
+
+/// Records that remittance `remittance_id` was created now.
+pub fn record_created(env: &Env, remittance_id: u64) {
+    let timing = RemittanceTiming {
+        created_at: env.ledger().timestamp(),
+        confirmed_at: None,
+    };
+    set_remittance_timing(env, remittance_id, &timing);
+}
+
+/// Records that the agent confirmed remittance `remittance_id` now, unless
+/// it already had.
+pub fn record_confirmed(env: &Env, remittance_id: u64) {
+    if let Some(mut timing) = get_remittance_timing(env, remittance_id) {
+        if timing.confirmed_at.is_none() {
+            timing.confirmed_at = Some(env.ledger().timestamp());
+            set_remittance_timing(env, remittance_id, &timing);
+        }
+    }
+}
+
+pub fn get_sla_breaches(env: &Env, agent: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&SlaKey::Breaches(agent.clone()))
+        .unwrap_or(0)
+}
+
+/// Checks a confirmed remittance against the SLA of its priority and counts
+/// a breach against `agent`.
+///
+/// # Returns
+///
+/// * `Ok(Some((delay, rebate)))` - The agent confirmed `delay` seconds after
+///   creation, past the SLA window; `rebate` of `fee` is owed to the sender
+/// * `Ok(None)` - No SLA is set for the priority, the remittance has no recorded timing, or
+///   the agent confirmed within the window
+pub fn apply_sla(
+    env: &Env,
+    remittance_id: u64,
+    agent: &Address,
+    priority: Priority,
+    fee: i128,
+) -> Result<Option<(u64, i128)>, ContractError> {
+    let config = match get_sla_config(env, priority) {
+        Some(config) => config,
+        None => return Ok(None),
+    };
+    let timing = match get_remittance_timing(env, remittance_id) {
+        Some(timing) => timing,
+        None => return Ok(None),
+    };
+
+    let confirmed_at = timing.confirmed_at.unwrap_or(env.ledger().timestamp());
+    let delay = confirmed_at.saturating_sub(timing.created_at);
+    if delay <= config.window_seconds {
+        return Ok(None);
+    }
+
+    let key = SlaKey::Breaches(agent.clone());
+    let breaches = get_sla_breaches(env, agent).saturating_add(1);
+    env.storage().persistent().set(&key, &breaches);
+
+    let rebate = fee
+        .checked_mul(config.rebate_bps as i128)
+        .ok_or(ContractError::Overflow)?
+        / 10000;
+    if rebate > 0 {
+        env.storage().persistent().set(&SlaKey::Rebate(remittance_id), &rebate);
+    }
+    Ok(Some((delay, rebate)))
+}
+
+/// Returns the fee rebated to the sender of a late payout, 0 when none.
+pub fn get_sla_rebate(env: &Env, remittance_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&SlaKey::Rebate(remittance_id))
+        .unwrap_or(0)
+}
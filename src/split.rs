@@ -0,0 +1,66 @@
+//! Remittances split across several agents.
+//!
+//! A large transfer can be paid out by agents in different cities. The sender
+//! funds the total with a single transfer and each leg is stored as an
+//! ordinary pending remittance for its agent, so legs are confirmed or
+//! cancelled independently with the usual entry points. The split record only
+//! groups the legs together.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::ContractError;
+
+/// Maximum number of legs in a split remittance.
+pub const MAX_SPLIT_LEGS: u32 = 10;
+
+/// A remittance split across several agents.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SplitRemittance {
+    /// Split ID
+    pub id: u64,
+    /// Address that funded every leg
+    pub sender: Address,
+    /// Sum of the leg amounts
+    pub total: i128,
+    /// Remittance IDs of the legs, in the order they were given
+    pub leg_ids: Vec<u64>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum SplitKey {
+    /// Counter for split IDs
+    Counter,
+    /// Split record (persistent storage)
+    Split(u64),
+    /// Split a leg belongs to (persistent storage)
+    Parent(u64),
+}
+
+pub fn next_split_id(env: &Env) -> Result<u64, ContractError> {
+    let id = env
+        .storage()
+        .instance()
+        .get::<_, u64>(&SplitKey::Counter)
+        .unwrap_or(0)
+        .checked_add(1)
+        .ok_or(ContractError::Overflow)?;
+    env.storage().instance().set(&SplitKey::Counter, &id);
+    Ok(id)
+}
+
+pub fn set_split(env: &Env, split: &SplitRemittance) {
+    env.storage().persistent().set(&SplitKey::Split(split.id), split);
+    for leg_id in split.leg_ids.iter() {
+        env.storage().persistent().set(&SplitKey::Parent(leg_id), &split.id);
+    }
+}
+
+pub fn get_split(env: &Env, split_id: u64) -> Option<SplitRemittance> {
+    env.storage().persistent().get(&SplitKey::Split(split_id))
+}
+
+pub fn get_split_of(env: &Env, remittance_id: u64) -> Option<u64> {
+    env.storage().persistent().get(&SplitKey::Parent(remittance_id))
+}
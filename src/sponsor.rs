@@ -0,0 +1,213 @@
+//! Sponsored remittance creation for senders without XLM.
+//!
+//! A sponsor, such as a wallet onboarding new users, deposits settlement
+//! tokens into its pool, sets the relay fee it pays per remittance and
+//! approves the relayers it works with. An approved relayer submits a
+//! first-time sender's signed remittance creation and pays the network fee;
+//! the sponsor's pool pays the relayer the relay fee in tokens. When the
+//! remittance is paid out, up to the relay fee is returned to the pool out of
+//! the remittance's platform fee, so a sponsor's budget only shrinks for
+//! remittances that are never paid out.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::{adjust_liability, post_entry, validate_amount, validate_non_negative, AccountClass, ContractError};
+
+/// Pool and spending of a sponsor.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SponsorBudget {
+    /// Tokens available to pay relayers
+    pub balance: i128,
+    /// Paid to the relayer of each sponsored remittance
+    pub relay_fee: i128,
+    /// Total paid to relayers
+    pub spent: i128,
+    /// Total returned to the pool from platform fees
+    pub reimbursed: i128,
+    /// Number of remittances sponsored
+    pub sponsored: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum SponsorKey {
+    /// Pool of a sponsor (persistent storage)
+    Budget(Address),
+    /// Whether a sponsor approved a relayer, keyed by (sponsor, relayer) (persistent storage)
+    Relayer(Address, Address),
+    /// Sponsor and amount to return to its pool when a sponsored remittance
+    /// is paid out (persistent storage)
+    Claim(u64),
+    /// Amount returned to the sponsor's pool out of a paid-out remittance's
+    /// platform fee (persistent storage)
+    Reimbursed(u64),
+}
+
+/// Returns the pool of `sponsor`; empty if it never funded one.
+pub fn get_sponsor_budget(env: &Env, sponsor: &Address) -> SponsorBudget {
+    env.storage()
+        .persistent()
+        .get(&SponsorKey::Budget(sponsor.clone()))
+        .unwrap_or_default()
+}
+
+fn set_sponsor_budget(env: &Env, sponsor: &Address, budget: &SponsorBudget) {
+    env.storage()
+        .persistent()
+        .set(&SponsorKey::Budget(sponsor.clone()), budget);
+}
+
+/// Books `amount` deposited by `sponsor` into its pool.
+///
+/// # Returns
+///
+/// * `Ok(SponsorBudget)` - The pool afterwards; the caller transfers the tokens in
+/// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - Amount is not positive
+pub fn fund_sponsor_pool(env: &Env, sponsor: &Address, amount: i128) -> Result<SponsorBudget, ContractError> {
+    validate_amount(amount)?;
+    let mut budget = get_sponsor_budget(env, sponsor);
+    budget.balance = budget.balance.checked_add(amount).ok_or(ContractError::Overflow)?;
+    adjust_liability(env, AccountClass::Sponsorship, amount)?;
+    set_sponsor_budget(env, sponsor, &budget);
+    Ok(budget)
+}
+
+/// Books `amount` returned from the pool of `sponsor`.
+///
+/// # Returns
+///
+/// * `Ok(SponsorBudget)` - The pool afterwards; the caller transfers the tokens out
+/// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - Amount is not positive
+/// * `Err(ContractError::InsufficientEscrow)` - Amount exceeds the pool's balance
+pub fn drain_sponsor_pool(env: &Env, sponsor: &Address, amount: i128) -> Result<SponsorBudget, ContractError> {
+    validate_amount(amount)?;
+    let mut budget = get_sponsor_budget(env, sponsor);
+    if amount > budget.balance {
+        return Err(ContractError::InsufficientEscrow);
+    }
+    budget.balance -= amount;
+    adjust_liability(env, AccountClass::Sponsorship, -amount)?;
+    set_sponsor_budget(env, sponsor, &budget);
+    Ok(budget)
+}
+
+/// Sets the relay fee `sponsor` pays per sponsored remittance.
+///
+/// # Returns
+///
+/// * `Ok(())` - Relay fee stored
+/// * `Err(ContractError::NegativeAmount)` - Relay fee is negative
+pub fn set_sponsor_relay_fee(env: &Env, sponsor: &Address, relay_fee: i128) -> Result<(), ContractError> {
+    validate_non_negative(relay_fee)?;
+    let mut budget = get_sponsor_budget(env, sponsor);
+    budget.relay_fee = relay_fee;
+    set_sponsor_budget(env, sponsor, &budget);
+    Ok(())
+}
+
+pub fn set_sponsor_relayer(env: &Env, sponsor: &Address, relayer: &Address, approved: bool) {
+    let key = SponsorKey::Relayer(sponsor.clone(), relayer.clone());
+    if approved {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub fn is_sponsor_relayer(env: &Env, sponsor: &Address, relayer: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&SponsorKey::Relayer(sponsor.clone(), relayer.clone()))
+}
+
+/// Pays the relay fee of sponsored remittance `remittance_id` out of the
+/// pool of `sponsor`, and records up to `fee` of it to be returned to the
+/// pool when the remittance is paid out.
+///
+/// # Returns
+///
+/// * `Ok(i128)` - Relay fee owed to the relayer; the caller transfers it
+/// * `Err(ContractError::Unauthorized)` - The sponsor has not approved `relayer`
+/// * `Err(ContractError::InsufficientEscrow)` - The pool cannot cover the relay fee
+pub fn charge_sponsor(
+    env: &Env,
+    sponsor: &Address,
+    relayer: &Address,
+    remittance_id: u64,
+    fee: i128,
+) -> Result<i128, ContractError> {
+    if !is_sponsor_relayer(env, sponsor, relayer) {
+        return Err(ContractError::Unauthorized);
+    }
+    let mut budget = get_sponsor_budget(env, sponsor);
+    let relay_fee = budget.relay_fee;
+    if relay_fee > budget.balance {
+        return Err(ContractError::InsufficientEscrow);
+    }
+
+    budget.balance -= relay_fee;
+    budget.spent = budget.spent.checked_add(relay_fee).ok_or(ContractError::Overflow)?;
+    budget.sponsored = budget.sponsored.saturating_add(1);
+    adjust_liability(env, AccountClass::Sponsorship, -relay_fee)?;
+    set_sponsor_budget(env, sponsor, &budget);
+
+    let claim = relay_fee.min(fee);
+    if claim > 0 {
+        env.storage()
+            .persistent()
+            .set(&SponsorKey::Claim(remittance_id), &(sponsor.clone(), claim));
+    }
+    Ok(relay_fee)
+}
+
+/// Returns the claim of a paid-out sponsored remittance to its sponsor's
+/// pool, out of the `fee_earned` on it.
+///
+/// # Returns
+///
+/// * `Ok(Some((sponsor, amount)))` - Amount moved from platform fees to the sponsor's pool
+/// * `Ok(None)` - The remittance was not sponsored, or earned no fee
+pub fn reimburse_sponsor(
+    env: &Env,
+    remittance_id: u64,
+    fee_earned: i128,
+) -> Result<Option<(Address, i128)>, ContractError> {
+    let key = SponsorKey::Claim(remittance_id);
+    let (sponsor, claim): (Address, i128) = match env.storage().persistent().get(&key) {
+        Some(claim) => claim,
+        None => return Ok(None),
+    };
+    env.storage().persistent().remove(&key);
+
+    let amount = claim.min(fee_earned);
+    if amount <= 0 {
+        return Ok(None);
+    }
+    post_entry(env, AccountClass::Fees, AccountClass::Sponsorship, amount)?;
+    env.storage()
+        .persistent()
+        .set(&SponsorKey::Reimbursed(remittance_id), &amount);
+    let mut budget = get_sponsor_budget(env, &sponsor);
+    budget.balance = budget.balance.checked_add(amount).ok_or(ContractError::Overflow)?;
+    budget.reimbursed = budget.reimbursed.checked_add(amount).ok_or(ContractError::Overflow)?;
+    set_sponsor_budget(env, &sponsor, &budget);
+    Ok(Some((sponsor, amount)))
+}
+
+/// Returns the part of a paid-out remittance's platform fee returned to its
+/// sponsor's pool, 0 when none.
+pub fn get_sponsor_reimbursement(env: &Env, remittance_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&SponsorKey::Reimbursed(remittance_id))
+        .unwrap_or(0)
+}
+
+/// Forgets the claim of a sponsored remittance closed without a payout; its
+/// relay fee stays spent.
+pub fn drop_sponsor_claim(env: &Env, remittance_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&SponsorKey::Claim(remittance_id));
+}
@@ -0,0 +1,157 @@
+//! Sharing platform fees with agents pro-rata to their collateral.
+//!
+//! To reward agents for posting larger bonds, the admin can set a share of
+//! every paid-out remittance's earned fee to be distributed to agents in
+//! proportion to the collateral they hold at that moment. Distribution is
+//! O(1): each share raises a global reward per unit of collateral, and an
+//! agent's rewards are the growth of that figure over the collateral it held,
+//! settled into its accrued rewards whenever its collateral changes. Agents
+//! withdraw accrued rewards with `claim_staking_rewards`.
+//!
+//! Shared fees move from the `Fees` account to `StakingRewards` in the
+//! ledger, so they stop being withdrawable by the platform.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::{account_balance, adjust_liability, get_collateral, post_entry, AccountClass, ContractError};
+
+/// Fixed-point scale of the reward per unit of collateral.
+pub const REWARD_PER_UNIT_SCALE: i128 = 1_000_000_000_000;
+
+#[contracttype]
+#[derive(Clone)]
+enum StakingKey {
+    /// Share of earned fees distributed to agents, in basis points
+    ShareBps,
+    /// Rewards distributed per unit of collateral, scaled by REWARD_PER_UNIT_SCALE
+    RewardPerUnit,
+    /// Reward per unit an agent's collateral was last settled at (persistent storage)
+    Checkpoint(Address),
+    /// Rewards settled to an agent and not yet claimed (persistent storage)
+    Accrued(Address),
+}
+
+pub fn set_staking_share_bps(env: &Env, share_bps: u32) {
+    env.storage().instance().set(&StakingKey::ShareBps, &share_bps);
+}
+
+pub fn get_staking_share_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&StakingKey::ShareBps)
+        .unwrap_or(0)
+}
+
+fn get_reward_per_unit(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&StakingKey::RewardPerUnit)
+        .unwrap_or(0)
+}
+
+fn get_checkpoint(env: &Env, agent: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&StakingKey::Checkpoint(agent.clone()))
+        .unwrap_or(0)
+}
+
+fn get_accrued(env: &Env, agent: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&StakingKey::Accrued(agent.clone()))
+        .unwrap_or(0)
+}
+
+fn pending_since_checkpoint(env: &Env, agent: &Address, collateral: i128) -> Result<i128, ContractError> {
+    let growth = get_reward_per_unit(env) - get_checkpoint(env, agent);
+    collateral
+        .checked_mul(growth)
+        .map(|scaled| scaled / REWARD_PER_UNIT_SCALE)
+        .ok_or(ContractError::Overflow)
+}
+
+/// Settles the rewards earned by the collateral `agent` held until now.
+///
+/// Must be called before the agent's collateral changes.
+pub fn settle_staking_rewards(env: &Env, agent: &Address) -> Result<(), ContractError> {
+    let pending = pending_since_checkpoint(env, agent, get_collateral(env, agent))?;
+    if pending > 0 {
+        let accrued = get_accrued(env, agent)
+            .checked_add(pending)
+            .ok_or(ContractError::Overflow)?;
+        env.storage()
+            .persistent()
+            .set(&StakingKey::Accrued(agent.clone()), &accrued);
+    }
+    env.storage()
+        .persistent()
+        .set(&StakingKey::Checkpoint(agent.clone()), &get_reward_per_unit(env));
+    Ok(())
+}
+
+/// Returns the rewards `agent` can claim now.
+pub fn get_staking_rewards(env: &Env, agent: &Address) -> Result<i128, ContractError> {
+    let pending = pending_since_checkpoint(env, agent, get_collateral(env, agent))?;
+    get_accrued(env, agent)
+        .checked_add(pending)
+        .ok_or(ContractError::Overflow)
+}
+
+/// Distributes the staking share of `fee_earned` over all agent collateral.
+///
+/// # Returns
+///
+/// * `Ok(i128)` - Amount moved from platform fees to staking rewards; 0 when
+///   no share is set or no collateral is posted
+pub fn share_staking_rewards(env: &Env, fee_earned: i128) -> Result<i128, ContractError> {
+    let share_bps = get_staking_share_bps(env);
+    let total_collateral = account_balance(env, AccountClass::Collateral);
+    if share_bps == 0 || total_collateral <= 0 || fee_earned <= 0 {
+        return Ok(0);
+    }
+    let reward = fee_earned
+        .checked_mul(share_bps as i128)
+        .ok_or(ContractError::Overflow)?
+        / 10000;
+    let increment = reward
+        .checked_mul(REWARD_PER_UNIT_SCALE)
+        .ok_or(ContractError::Overflow)?
+        / total_collateral;
+    if increment == 0 {
+        return Ok(0);
+    }
+
+    // Only what the increment distributes leaves the fees; rounding dust stays
+    let distributed = increment
+        .checked_mul(total_collateral)
+        .ok_or(ContractError::Overflow)?
+        / REWARD_PER_UNIT_SCALE;
+    post_entry(env, AccountClass::Fees, AccountClass::StakingRewards, distributed)?;
+    let reward_per_unit = get_reward_per_unit(env)
+        .checked_add(increment)
+        .ok_or(ContractError::Overflow)?;
+    env.storage()
+        .instance()
+        .set(&StakingKey::RewardPerUnit, &reward_per_unit);
+    Ok(distributed)
+}
+
+/// Books the claim of all rewards accrued to `agent`.
+///
+/// # Returns
+///
+/// * `Ok(i128)` - Amount claimed; the caller transfers it to the agent
+/// * `Err(ContractError::NoFeesToWithdraw)` - The agent has no rewards
+pub fn claim_staking_rewards(env: &Env, agent: &Address) -> Result<i128, ContractError> {
+    settle_staking_rewards(env, agent)?;
+    let amount = get_accrued(env, agent);
+    if amount <= 0 {
+        return Err(ContractError::NoFeesToWithdraw);
+    }
+    env.storage()
+        .persistent()
+        .remove(&StakingKey::Accrued(agent.clone()));
+    adjust_liability(env, AccountClass::StakingRewards, -amount)?;
+    Ok(amount)
+}
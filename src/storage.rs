@@ -5,9 +5,18 @@
 //! Uses both instance storage (contract-level config) and persistent storage
 //! (per-entity data).
 
-use soroban_sdk::{contracttype, Address, Env};
+use soroban_sdk::{contracttype, Address, BytesN, Env, Map, String, Vec};
 
-use crate::{ContractError, Remittance, TransferRecord, DailyLimit};
+use crate::{
+    account_balance, adjust_liability, extend_persistent_ttl, post_entry, AccountClass, ContractError,
+    Currency, DailyLimit, DailyLimitLevel, Remittance, RemittanceSummary, TransferRecord, TtlClass, WorkingHours,
+};
+
+/// Number of consecutive remittance IDs stored together in one index bucket.
+pub const REMITTANCE_BUCKET_SIZE: u64 = 100;
+
+/// Maximum number of IDs a single range query may span.
+pub const MAX_RANGE_QUERY: u64 = 500;
 
 /// Storage keys for the SwiftRemit contract.
 ///
@@ -44,16 +53,47 @@ enum DataKey {
     /// Individual remittance record indexed by ID (persistent storage)
     Remittance(u64),
 
+    /// Sender that reserved a remittance ID not yet filled (persistent storage)
+    Reservation(u64),
+
+    /// Remittance ID created with a sender's idempotency key (persistent storage)
+    IdempotencyKey(Address, BytesN<32>),
+
+    /// Remittance ID tagged with an integrator's external reference (persistent storage)
+    ExternalRef(BytesN<32>),
+
+    /// Summaries of the stored remittances keyed by `id / REMITTANCE_BUCKET_SIZE`
+    /// for range reads (persistent storage)
+    RemittanceBucket(u64),
+
     // === Agent Management ===
     // Keys for tracking registered agents
     /// Agent registration status indexed by agent address (persistent storage)
     AgentRegistered(Address),
 
+    /// Number of currently registered agents
+    AgentCount,
+
+    /// Agent suspension status indexed by agent address (persistent storage)
+    AgentSuspended(Address),
+
+    /// Agent self-reported offline status indexed by agent address (persistent storage)
+    AgentOffline(Address),
+
+    /// Agent daily working hours indexed by agent address (persistent storage)
+    AgentWorkingHours(Address),
+
     // === Fee Tracking ===
     // Keys for managing platform fees
-    /// Total accumulated platform fees awaiting withdrawal
+    /// Total accumulated platform fees awaiting withdrawal (legacy, USDC only)
     AccumulatedFees,
 
+    /// Accumulated platform fees awaiting withdrawal, keyed by token
+    FeeLedger,
+
+    /// Fee recipients and their shares in basis points used by `distribute_fees`
+    FeeSplit,
+
     /// Contract pause status for emergency halts
     Paused,
 
@@ -61,6 +101,9 @@ enum DataKey {
     // Keys for preventing duplicate settlement execution
     /// Settlement hash for duplicate detection (persistent storage)
     SettlementHash(u64),
+
+    /// Hash of the signed off-chain payout receipt (persistent storage)
+    ReceiptHash(u64),
     
     // === Rate Limiting ===
     // Keys for preventing abuse through rate limiting
@@ -69,6 +112,25 @@ enum DataKey {
     
     /// Last settlement timestamp for a sender address (persistent storage)
     LastSettlementTime(Address),
+
+    // === Transfer History ===
+    // Keys for the per-user ring of recent transfers
+    /// Number of transfers ever recorded for a user (persistent storage)
+    UserTransferCount(Address),
+
+    /// Chunk of a user's transfer history keyed by (user, epoch) (persistent storage)
+    UserTransferChunk(Address, u64),
+
+    // === Daily Limits ===
+    // Keys for the daily send limit hierarchy, most specific first
+    /// Daily limit override for a (currency, country) pair (persistent storage)
+    DailyLimit(Currency, String),
+
+    /// Daily limit default for a currency (persistent storage)
+    CurrencyDailyLimit(Currency),
+
+    /// Daily limit applied when no currency default or override is set
+    DefaultDailyLimit,
 }
 
 /// Checks if the contract has an admin configured.
@@ -205,9 +267,32 @@ pub fn get_remittance_counter(env: &Env) -> Result<u64, ContractError> {
 /// * `id` - Remittance ID
 /// * `remittance` - Remittance record to store
 pub fn set_remittance(env: &Env, id: u64, remittance: &Remittance) {
-    env.storage()
+    let key = DataKey::Remittance(id);
+    env.storage().persistent().set(&key, remittance);
+    extend_persistent_ttl(env, TtlClass::Remittances, &key);
+
+    // The bucket is only rewritten when the summary changes, e.g. on a status
+    // transition, not on every update of the record
+    let summary = RemittanceSummary {
+        id,
+        sender: remittance.sender.clone(),
+        agent: remittance.agent.clone(),
+        amount: remittance.amount,
+        fee: remittance.fee,
+        status: remittance.status.clone(),
+        expiry: remittance.expiry,
+    };
+    let bucket_key = DataKey::RemittanceBucket(id / REMITTANCE_BUCKET_SIZE);
+    let mut bucket: Map<u64, RemittanceSummary> = env
+        .storage()
         .persistent()
-        .set(&DataKey::Remittance(id), remittance);
+        .get(&bucket_key)
+        .unwrap_or(Map::new(env));
+    if bucket.get(id) != Some(summary.clone()) {
+        bucket.set(id, summary);
+        env.storage().persistent().set(&bucket_key, &bucket);
+        extend_persistent_ttl(env, TtlClass::Indexes, &bucket_key);
+    }
 }
 
 /// Retrieves a remittance record by ID.
@@ -228,6 +313,71 @@ pub fn get_remittance(env: &Env, id: u64) -> Result<Remittance, ContractError> {
         .ok_or(ContractError::RemittanceNotFound)
 }
 
+/// Deletes a remittance record and its entry in the range-query bucket.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `id` - Remittance ID to delete
+pub fn remove_remittance(env: &Env, id: u64) {
+    env.storage().persistent().remove(&DataKey::Remittance(id));
+
+    let bucket_key = DataKey::RemittanceBucket(id / REMITTANCE_BUCKET_SIZE);
+    let bucket: Option<Map<u64, RemittanceSummary>> = env.storage().persistent().get(&bucket_key);
+    if let Some(mut bucket) = bucket {
+        bucket.remove(id);
+        if bucket.is_empty() {
+            env.storage().persistent().remove(&bucket_key);
+        } else {
+            env.storage().persistent().set(&bucket_key, &bucket);
+            extend_persistent_ttl(env, TtlClass::Indexes, &bucket_key);
+        }
+    }
+}
+
+/// Retrieves summaries of all remittances with IDs in `[from_id, to_id]`.
+///
+/// Reads one bucket per `REMITTANCE_BUCKET_SIZE` IDs instead of one entry per ID,
+/// keeping indexer backfills within read budgets. Full records are available
+/// from `get_remittance`.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `from_id` - First remittance ID in the range (inclusive)
+/// * `to_id` - Last remittance ID in the range (inclusive)
+///
+/// # Returns
+///
+/// * `Ok(Vec<RemittanceSummary>)` - Remittances found in the range, ordered by ID
+/// * `Err(ContractError::InvalidAmount)` - Range is inverted or wider than `MAX_RANGE_QUERY`
+pub fn get_remittances_in_range(
+    env: &Env,
+    from_id: u64,
+    to_id: u64,
+) -> Result<Vec<RemittanceSummary>, ContractError> {
+    if from_id > to_id || to_id - from_id >= MAX_RANGE_QUERY {
+        return Err(ContractError::InvalidAmount);
+    }
+
+    let mut result = Vec::new(env);
+    for bucket_id in (from_id / REMITTANCE_BUCKET_SIZE)..=(to_id / REMITTANCE_BUCKET_SIZE) {
+        let bucket: Option<Map<u64, RemittanceSummary>> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RemittanceBucket(bucket_id));
+        if let Some(bucket) = bucket {
+            for (id, summary) in bucket.iter() {
+                if id >= from_id && id <= to_id {
+                    result.push_back(summary);
+                }
+            }
+        }
+    }
+
+    Ok(result)
+}
+
 /// Sets an agent's registration status.
 ///
 /// # Arguments
@@ -236,11 +386,24 @@ pub fn get_remittance(env: &Env, id: u64) -> Result<Remittance, ContractError> {
 /// * `agent` - Agent address
 /// * `registered` - Registration status (true = registered, false = removed)
 pub fn set_agent_registered(env: &Env, agent: &Address, registered: bool) {
+    if is_agent_registered(env, agent) != registered {
+        let count = get_agent_count(env);
+        let count = if registered { count.saturating_add(1) } else { count.saturating_sub(1) };
+        env.storage().instance().set(&DataKey::AgentCount, &count);
+    }
     env.storage()
         .persistent()
         .set(&DataKey::AgentRegistered(agent.clone()), &registered);
 }
 
+/// Retrieves the number of currently registered agents.
+pub fn get_agent_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::AgentCount)
+        .unwrap_or(0)
+}
+
 /// Checks if an address is registered as an agent.
 ///
 /// # Arguments
@@ -259,19 +422,173 @@ pub fn is_agent_registered(env: &Env, agent: &Address) -> bool {
         .unwrap_or(false)
 }
 
-/// Sets the accumulated platform fees.
+/// Sets an agent's suspension status.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `fees` - Total accumulated fees
-pub fn set_accumulated_fees(env: &Env, fees: i128) {
+/// * `agent` - Agent address
+/// * `suspended` - Suspension status (true = suspended, false = active)
+pub fn set_agent_suspended(env: &Env, agent: &Address, suspended: bool) {
     env.storage()
-        .instance()
-        .set(&DataKey::AccumulatedFees, &fees);
+        .persistent()
+        .set(&DataKey::AgentSuspended(agent.clone()), &suspended);
+}
+
+/// Checks if an agent is suspended.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent address to check
+///
+/// # Returns
+///
+/// * `true` - Agent is suspended and cannot accept new remittances
+/// * `false` - Agent is not suspended
+pub fn is_agent_suspended(env: &Env, agent: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentSuspended(agent.clone()))
+        .unwrap_or(false)
+}
+
+/// Sets whether an agent is available to accept new remittances.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent address
+/// * `available` - Availability flag reported by the agent
+pub fn set_agent_available(env: &Env, agent: &Address, available: bool) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentOffline(agent.clone()), &!available);
+}
+
+/// Sets or clears an agent's daily working hours.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent address
+/// * `hours` - Working hours, or `None` to accept remittances at any time
+pub fn set_agent_working_hours(env: &Env, agent: &Address, hours: &Option<WorkingHours>) {
+    let key = DataKey::AgentWorkingHours(agent.clone());
+    match hours {
+        Some(hours) => env.storage().persistent().set(&key, hours),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Retrieves an agent's daily working hours.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent address
+///
+/// # Returns
+///
+/// * `Some(WorkingHours)` - Configured working hours
+/// * `None` - Agent accepts remittances at any time
+pub fn get_agent_working_hours(env: &Env, agent: &Address) -> Option<WorkingHours> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentWorkingHours(agent.clone()))
+}
+
+/// Checks if an agent is available to accept new remittances right now.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent address to check
+///
+/// # Returns
+///
+/// * `true` - Agent is online and, if configured, within its working hours
+/// * `false` - Agent is offline or outside its working hours
+pub fn is_agent_available(env: &Env, agent: &Address) -> bool {
+    let offline: bool = env
+        .storage()
+        .persistent()
+        .get(&DataKey::AgentOffline(agent.clone()))
+        .unwrap_or(false);
+    if offline {
+        return false;
+    }
+
+    match get_agent_working_hours(env, agent) {
+        Some(hours) => hours.contains(env.ledger().timestamp()),
+        None => true,
+    }
+}
+
+/// Retrieves the per-token fee ledger.
+///
+/// Deployments that predate per-token accounting stored a single USDC total;
+/// it is read back as the USDC entry until the ledger is first written.
+pub fn get_fee_ledger(env: &Env) -> Map<Address, i128> {
+    if let Some(ledger) = env.storage().instance().get(&DataKey::FeeLedger) {
+        return ledger;
+    }
+
+    let mut ledger = Map::new(env);
+    let legacy: Option<i128> = env.storage().instance().get(&DataKey::AccumulatedFees);
+    if let (Some(fees), Ok(token)) = (legacy, get_usdc_token(env)) {
+        ledger.set(token, fees);
+    }
+    ledger
+}
+
+/// Sets the accumulated platform fees for a token.
+///
+/// Fees in the settlement token are kept in the `Fees` account of the
+/// double-entry ledger; the change is posted against holdings.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `token` - Token the fees were collected in
+/// * `fees` - Total accumulated fees in that token
+pub fn set_accumulated_fees_for(env: &Env, token: &Address, fees: i128) -> Result<(), ContractError> {
+    if is_settlement_token(env, token) {
+        let delta = fees
+            .checked_sub(account_balance(env, AccountClass::Fees))
+            .ok_or(ContractError::Overflow)?;
+        return adjust_liability(env, AccountClass::Fees, delta);
+    }
+    let mut ledger = get_fee_ledger(env);
+    ledger.set(token.clone(), fees);
+    env.storage().instance().set(&DataKey::FeeLedger, &ledger);
+    Ok(())
+}
+
+/// Retrieves the accumulated platform fees for a token, zero if none were collected.
+pub fn get_accumulated_fees_for(env: &Env, token: &Address) -> i128 {
+    if is_settlement_token(env, token) {
+        return account_balance(env, AccountClass::Fees);
+    }
+    get_fee_ledger(env).get(token.clone()).unwrap_or(0)
 }
 
-/// Retrieves the accumulated platform fees.
+fn is_settlement_token(env: &Env, token: &Address) -> bool {
+    matches!(get_usdc_token(env), Ok(usdc) if usdc == *token)
+}
+
+/// Sets the accumulated platform fees in the settlement token (USDC).
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `fees` - Total accumulated fees
+pub fn set_accumulated_fees(env: &Env, fees: i128) -> Result<(), ContractError> {
+    let token = get_usdc_token(env)?;
+    set_accumulated_fees_for(env, &token, fees)
+}
+
+/// Retrieves the accumulated platform fees in the settlement token (USDC).
 ///
 /// # Arguments
 ///
@@ -282,10 +599,70 @@ pub fn set_accumulated_fees(env: &Env, fees: i128) {
 /// * `Ok(i128)` - Total accumulated fees
 /// * `Err(ContractError::NotInitialized)` - Contract not initialized
 pub fn get_accumulated_fees(env: &Env) -> Result<i128, ContractError> {
+    let token = get_usdc_token(env)?;
+    Ok(get_accumulated_fees_for(env, &token))
+}
+
+/// Sets the fee recipients and their shares.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `splits` - Recipient addresses paired with their share in basis points
+pub fn set_fee_split(env: &Env, splits: &Vec<(Address, u32)>) {
+    env.storage().instance().set(&DataKey::FeeSplit, splits);
+}
+
+/// Retrieves the fee recipients and their shares.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `Vec<(Address, u32)>` - Configured recipients, empty if no split is set
+pub fn get_fee_split(env: &Env) -> Vec<(Address, u32)> {
     env.storage()
         .instance()
-        .get(&DataKey::AccumulatedFees)
-        .ok_or(ContractError::NotInitialized)
+        .get(&DataKey::FeeSplit)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Retrieves the total net amount currently held in escrow for agents.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `i128` - Escrowed net amount across all pending remittances (0 if none)
+pub fn get_total_escrow(env: &Env) -> i128 {
+    account_balance(env, AccountClass::Escrow)
+}
+
+/// Adds an amount to the escrow total.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `amount` - Amount newly locked in escrow
+pub fn increase_escrow(env: &Env, amount: i128) -> Result<(), ContractError> {
+    post_entry(env, AccountClass::Holdings, AccountClass::Escrow, amount)
+}
+
+/// Removes an amount from the escrow total.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `amount` - Amount released from escrow (paid out or refunded)
+pub fn decrease_escrow(env: &Env, amount: i128) -> Result<(), ContractError> {
+    if amount > get_total_escrow(env) {
+        return Err(ContractError::InsufficientEscrow);
+    }
+    post_entry(env, AccountClass::Escrow, AccountClass::Holdings, amount)
 }
 
 /// Checks if a settlement hash exists for duplicate detection.
@@ -317,6 +694,140 @@ pub fn set_settlement_hash(env: &Env, remittance_id: u64) {
         .set(&DataKey::SettlementHash(remittance_id), &true);
 }
 
+/// Records that a remittance ID has been reserved by a sender.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Reserved remittance ID
+/// * `sender` - Sender that owns the reservation
+pub fn set_reservation(env: &Env, remittance_id: u64, sender: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Reservation(remittance_id), sender);
+}
+
+/// Retrieves the sender that reserved a remittance ID.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Reserved remittance ID
+///
+/// # Returns
+///
+/// * `Some(Address)` - Sender that owns the unfilled reservation
+/// * `None` - The ID is not reserved or has already been filled
+pub fn get_reservation(env: &Env, remittance_id: u64) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Reservation(remittance_id))
+}
+
+/// Removes a reservation once it has been filled.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Reserved remittance ID
+pub fn remove_reservation(env: &Env, remittance_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Reservation(remittance_id));
+}
+
+/// Records the remittance created with a sender's idempotency key.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `sender` - Sender that supplied the key
+/// * `key` - Client-supplied idempotency key
+/// * `remittance_id` - ID of the remittance created with the key
+pub fn set_idempotent_remittance(env: &Env, sender: &Address, key: &BytesN<32>, remittance_id: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::IdempotencyKey(sender.clone(), key.clone()), &remittance_id);
+}
+
+/// Looks up the remittance created with a sender's idempotency key.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `sender` - Sender that supplied the key
+/// * `key` - Client-supplied idempotency key
+///
+/// # Returns
+///
+/// * `Some(u64)` - ID of the remittance previously created with the key
+/// * `None` - The key has not been used by this sender
+pub fn get_idempotent_remittance(env: &Env, sender: &Address, key: &BytesN<32>) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::IdempotencyKey(sender.clone(), key.clone()))
+}
+
+/// Indexes a remittance under the external reference it was tagged with.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `external_ref` - Integrator-supplied reference
+/// * `remittance_id` - ID of the remittance tagged with the reference
+pub fn set_external_ref(env: &Env, external_ref: &BytesN<32>, remittance_id: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ExternalRef(external_ref.clone()), &remittance_id);
+}
+
+/// Looks up the remittance tagged with an external reference.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `external_ref` - Integrator-supplied reference
+///
+/// # Returns
+///
+/// * `Some(u64)` - ID of the remittance tagged with the reference
+/// * `None` - No remittance uses the reference
+pub fn get_external_ref(env: &Env, external_ref: &BytesN<32>) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ExternalRef(external_ref.clone()))
+}
+
+/// Stores the hash of the signed cash receipt for a paid-out remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Remittance ID the receipt belongs to
+/// * `receipt_hash` - SHA-256 hash of the signed receipt document
+pub fn set_receipt_hash(env: &Env, remittance_id: u64, receipt_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ReceiptHash(remittance_id), receipt_hash);
+}
+
+/// Retrieves the hash of the signed cash receipt for a remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Remittance ID to look up
+///
+/// # Returns
+///
+/// * `Some(BytesN<32>)` - Receipt hash recorded at payout
+/// * `None` - No receipt was recorded
+pub fn get_receipt_hash(env: &Env, remittance_id: u64) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ReceiptHash(remittance_id))
+}
+
 pub fn is_paused(env: &Env) -> bool {
     env.storage()
         .instance()
@@ -368,34 +879,154 @@ pub fn check_rate_limit(env: &Env, sender: &Address) -> Result<(), ContractError
         if elapsed < cooldown {
             return Err(ContractError::RateLimitExceeded);
         }
-pub fn set_daily_limit(env: &Env, currency: &String, country: &String, limit: i128) {
-    let daily_limit = DailyLimit {
-        currency: currency.clone(),
-        country: country.clone(),
-        limit,
-    };
+pub fn set_daily_limit(env: &Env, currency: &Currency, country: &String, limit: i128) {
     env.storage()
         .persistent()
-        .set(&DataKey::DailyLimit(currency.clone(), country.clone()), &daily_limit);
+        .set(&DataKey::DailyLimit(currency.clone(), country.clone()), &limit);
 }
 
-pub fn get_daily_limit(env: &Env, currency: &String, country: &String) -> Option<DailyLimit> {
+pub fn remove_daily_limit(env: &Env, currency: &Currency, country: &String) {
     env.storage()
         .persistent()
-        .get(&DataKey::DailyLimit(currency.clone(), country.clone()))
+        .remove(&DataKey::DailyLimit(currency.clone(), country.clone()));
 }
 
-pub fn get_user_transfers(env: &Env, user: &Address) -> Vec<TransferRecord> {
+pub fn set_currency_daily_limit(env: &Env, currency: &Currency, limit: i128) {
     env.storage()
         .persistent()
-        .get(&DataKey::UserTransfers(user.clone()))
-        .unwrap_or(Vec::new(env))
+        .set(&DataKey::CurrencyDailyLimit(currency.clone()), &limit);
+}
+
+pub fn get_currency_daily_limit(env: &Env, currency: &Currency) -> Option<i128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CurrencyDailyLimit(currency.clone()))
+}
+
+pub fn remove_currency_daily_limit(env: &Env, currency: &Currency) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CurrencyDailyLimit(currency.clone()));
+}
+
+pub fn set_default_daily_limit(env: &Env, limit: i128) {
+    env.storage().instance().set(&DataKey::DefaultDailyLimit, &limit);
+}
+
+pub fn get_default_daily_limit(env: &Env) -> Option<i128> {
+    env.storage().instance().get(&DataKey::DefaultDailyLimit)
+}
+
+pub fn remove_default_daily_limit(env: &Env) {
+    env.storage().instance().remove(&DataKey::DefaultDailyLimit);
+}
+
+/// Resolves the daily limit that applies to a currency-country pair.
+///
+/// The (currency, country) override wins over the currency default, which
+/// wins over the global default, so a limit only has to be configured for
+/// the countries that differ from their currency.
+///
+/// # Returns
+///
+/// * `Some(DailyLimit)` - The most specific limit configured, and its level
+/// * `None` - No limit at any level (unlimited)
+pub fn get_daily_limit(env: &Env, currency: &Currency, country: &String) -> Option<DailyLimit> {
+    let (limit, level) = if let Some(limit) = env
+        .storage()
+        .persistent()
+        .get(&DataKey::DailyLimit(currency.clone(), country.clone()))
+    {
+        (limit, DailyLimitLevel::Country)
+    } else if let Some(limit) = get_currency_daily_limit(env, currency) {
+        (limit, DailyLimitLevel::Currency)
+    } else {
+        (get_default_daily_limit(env)?, DailyLimitLevel::Global)
+    };
+
+    Some(DailyLimit {
+        currency: currency.clone(),
+        country: country.clone(),
+        limit,
+        level,
+    })
 }
 
-pub fn set_user_transfers(env: &Env, user: &Address, transfers: &Vec<TransferRecord>) {
+/// Number of transfer records stored per history chunk.
+pub const TRANSFER_CHUNK_SIZE: u64 = 32;
+
+/// Number of history chunks retained per user.
+pub const TRANSFER_HISTORY_CHUNKS: u64 = 8;
+
+fn get_user_transfer_count(env: &Env, user: &Address) -> u64 {
     env.storage()
         .persistent()
-        .set(&DataKey::UserTransfers(user.clone()), transfers);
+        .get(&DataKey::UserTransferCount(user.clone()))
+        .unwrap_or(0)
+}
+
+/// Appends a transfer to a user's history.
+///
+/// Records are stored in fixed-size chunks keyed by (user, epoch), where the
+/// epoch is the record's sequence number divided by `TRANSFER_CHUNK_SIZE`.
+/// Each append rewrites only the current chunk, and starting a new chunk drops
+/// the one `TRANSFER_HISTORY_CHUNKS` epochs older, so heavy senders keep a
+/// bounded ring of recent transfers and writes stay O(1).
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `user` - User the transfer belongs to
+/// * `record` - Transfer to record
+pub fn append_user_transfer(env: &Env, user: &Address, record: &TransferRecord) {
+    let count = get_user_transfer_count(env, user);
+    let epoch = count / TRANSFER_CHUNK_SIZE;
+    let key = DataKey::UserTransferChunk(user.clone(), epoch);
+
+    let mut chunk: Vec<TransferRecord> = if count % TRANSFER_CHUNK_SIZE == 0 {
+        if epoch >= TRANSFER_HISTORY_CHUNKS {
+            env.storage().persistent().remove(&DataKey::UserTransferChunk(
+                user.clone(),
+                epoch - TRANSFER_HISTORY_CHUNKS,
+            ));
+        }
+        Vec::new(env)
+    } else {
+        env.storage().persistent().get(&key).unwrap_or(Vec::new(env))
+    };
+    chunk.push_back(record.clone());
+
+    env.storage().persistent().set(&key, &chunk);
+    extend_persistent_ttl(env, TtlClass::History, &key);
+    let count_key = DataKey::UserTransferCount(user.clone());
+    env.storage().persistent().set(&count_key, &(count + 1));
+    extend_persistent_ttl(env, TtlClass::History, &count_key);
+}
+
+/// Retrieves the retained transfer history of a user, oldest first.
+///
+/// At most `TRANSFER_CHUNK_SIZE * TRANSFER_HISTORY_CHUNKS` records are kept.
+pub fn get_user_transfers(env: &Env, user: &Address) -> Vec<TransferRecord> {
+    let mut transfers = Vec::new(env);
+    let count = get_user_transfer_count(env, user);
+    if count == 0 {
+        return transfers;
+    }
+
+    let last_epoch = (count - 1) / TRANSFER_CHUNK_SIZE;
+    let first_epoch = (last_epoch + 1).saturating_sub(TRANSFER_HISTORY_CHUNKS);
+    for epoch in first_epoch..=last_epoch {
+        let chunk: Option<Vec<TransferRecord>> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::UserTransferChunk(user.clone(), epoch));
+        if let Some(chunk) = chunk {
+            transfers.append(&chunk);
+        }
+    }
+    transfers
+}
+
 // === Admin Role Management ===
 
 pub fn is_admin(env: &Env, address: &Address) -> bool {
@@ -0,0 +1,93 @@
+//! Sub-agent delegation for field staff.
+//!
+//! A registered (master) agent can delegate payout rights to sub-agent
+//! addresses. Sub-agents may confirm payouts on the master's remittances, but
+//! remittances stay assigned to the master: payouts, commission and reputation
+//! all accrue to the master agent, which remains the accountable party.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::ContractError;
+
+/// Maximum number of sub-agents a master agent can delegate to.
+pub const MAX_SUB_AGENTS: u32 = 50;
+
+#[contracttype]
+#[derive(Clone)]
+enum SubAgentKey {
+    /// Master agent of a sub-agent (persistent storage)
+    Master(Address),
+    /// Sub-agents of a master agent (persistent storage)
+    SubAgents(Address),
+}
+
+pub fn get_master_agent(env: &Env, sub_agent: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&SubAgentKey::Master(sub_agent.clone()))
+}
+
+pub fn get_sub_agents(env: &Env, agent: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&SubAgentKey::SubAgents(agent.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns whether `caller` may act on payouts assigned to `agent`.
+pub fn can_confirm_for(env: &Env, agent: &Address, caller: &Address) -> bool {
+    caller == agent || get_master_agent(env, caller).as_ref() == Some(agent)
+}
+
+/// Delegates payout rights from `agent` to `sub_agent`.
+///
+/// # Returns
+///
+/// * `Ok(())` - Sub-agent added
+/// * `Err(ContractError::InvalidAddress)` - Sub-agent is the master itself
+/// * `Err(ContractError::InvalidStatus)` - Sub-agent already has a master
+/// * `Err(ContractError::LimitExceeded)` - Agent already has MAX_SUB_AGENTS sub-agents
+pub fn add_sub_agent(env: &Env, agent: &Address, sub_agent: &Address) -> Result<(), ContractError> {
+    if agent == sub_agent {
+        return Err(ContractError::InvalidAddress);
+    }
+    if get_master_agent(env, sub_agent).is_some() {
+        return Err(ContractError::InvalidStatus);
+    }
+
+    let mut sub_agents = get_sub_agents(env, agent);
+    if sub_agents.len() >= MAX_SUB_AGENTS {
+        return Err(ContractError::LimitExceeded);
+    }
+    sub_agents.push_back(sub_agent.clone());
+
+    env.storage()
+        .persistent()
+        .set(&SubAgentKey::SubAgents(agent.clone()), &sub_agents);
+    env.storage()
+        .persistent()
+        .set(&SubAgentKey::Master(sub_agent.clone()), agent);
+    Ok(())
+}
+
+/// Revokes the payout rights `agent` delegated to `sub_agent`.
+///
+/// # Returns
+///
+/// * `Ok(())` - Sub-agent removed
+/// * `Err(ContractError::InvalidStatus)` - `sub_agent` is not a sub-agent of `agent`
+pub fn remove_sub_agent(env: &Env, agent: &Address, sub_agent: &Address) -> Result<(), ContractError> {
+    let mut sub_agents = get_sub_agents(env, agent);
+    let index = sub_agents
+        .first_index_of(sub_agent.clone())
+        .ok_or(ContractError::InvalidStatus)?;
+    sub_agents.remove(index);
+
+    env.storage()
+        .persistent()
+        .set(&SubAgentKey::SubAgents(agent.clone()), &sub_agents);
+    env.storage()
+        .persistent()
+        .remove(&SubAgentKey::Master(sub_agent.clone()));
+    Ok(())
+}
@@ -0,0 +1,77 @@
+//! Token swap on payout through an AMM router.
+//!
+//! Agents in some corridors only want local stablecoins. The admin registers a
+//! swap router (an adapter over a Soroban AMM such as a Soroswap-style pool
+//! router); `confirm_payout_with_swap` then hands the escrowed payout to the
+//! router, which swaps it and pays the agent in the requested token. The
+//! agent's balance is checked afterwards so a misbehaving router cannot pay out
+//! less than the agent's `min_out`.
+
+use soroban_sdk::{contractclient, contracttype, token, Address, Env};
+
+use crate::ContractError;
+
+/// Interface the registered swap router must implement.
+///
+/// The router receives `amount_in` of `token_in` before `swap` is called and
+/// must transfer at least `min_out` of `token_out` to `to`, returning the
+/// amount sent.
+#[contractclient(name = "SwapRouterClient")]
+pub trait SwapRouter {
+    fn swap(
+        env: Env,
+        token_in: Address,
+        token_out: Address,
+        amount_in: i128,
+        min_out: i128,
+        to: Address,
+    ) -> i128;
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum SwapKey {
+    /// Registered swap router
+    Router,
+}
+
+pub fn set_swap_router(env: &Env, router: &Address) {
+    env.storage().instance().set(&SwapKey::Router, router);
+}
+
+pub fn get_swap_router(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&SwapKey::Router)
+}
+
+/// Swaps `amount_in` of `token_in` held by the contract into `token_out` for `to`.
+///
+/// # Returns
+///
+/// * `Ok(i128)` - Amount of `token_out` received by `to`
+/// * `Err(ContractError::InvalidStatus)` - No swap router is registered
+/// * `Err(ContractError::SlippageExceeded)` - `to` received less than `min_out`
+pub fn swap_payout(
+    env: &Env,
+    token_in: &Address,
+    token_out: &Address,
+    amount_in: i128,
+    min_out: i128,
+    to: &Address,
+) -> Result<i128, ContractError> {
+    let router = get_swap_router(env).ok_or(ContractError::InvalidStatus)?;
+    let out_client = token::Client::new(env, token_out);
+    let balance_before = out_client.balance(to);
+
+    token::Client::new(env, token_in).transfer(&env.current_contract_address(), &router, &amount_in);
+    SwapRouterClient::new(env, &router).swap(token_in, token_out, &amount_in, &min_out, to);
+
+    // Trust the recipient's balance rather than the router's return value
+    let received = out_client
+        .balance(to)
+        .checked_sub(balance_before)
+        .ok_or(ContractError::Overflow)?;
+    if received < min_out {
+        return Err(ContractError::SlippageExceeded);
+    }
+    Ok(received)
+}
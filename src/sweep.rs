@@ -0,0 +1,118 @@
+//! Keeper sweeping of expired remittances.
+//!
+//! Remittances created with an expiry are indexed in hourly windows of their
+//! expiry time, so indexing one rewrites a single bounded window. Once a
+//! remittance's expiry has passed it can no longer be confirmed, and anyone
+//! may call `sweep_expired` to refund the sender and mark it `Expired`. The
+//! caller earns a small per-remittance bounty paid out of accumulated fees, so
+//! keepers have a reason to keep escrow from holding dead funds.
+
+use soroban_sdk::{contracttype, Env, Vec};
+
+use crate::{extend_persistent_ttl, TtlClass};
+
+/// Maximum number of index entries examined by one sweep.
+pub const MAX_SWEEP_BATCH: u32 = 50;
+
+/// Width of one expiry index window, in seconds.
+pub const EXPIRY_WINDOW_SECONDS: u64 = 3_600;
+
+/// Maximum number of expiry windows one sweep walks.
+pub const MAX_SWEEP_WINDOWS: u32 = 24;
+
+#[contracttype]
+#[derive(Clone)]
+enum SweepKey {
+    /// (expiry, remittance ID) of remittances expiring in window
+    /// `expiry / EXPIRY_WINDOW_SECONDS` (persistent storage)
+    Window(u64),
+    /// Lowest window that may hold unswept entries
+    Cursor,
+    /// Bounty paid per expired remittance, in USDC
+    Bounty,
+}
+
+fn get_window(env: &Env, window: u64) -> Vec<(u64, u64)> {
+    env.storage()
+        .persistent()
+        .get(&SweepKey::Window(window))
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_window(env: &Env, window: u64, entries: &Vec<(u64, u64)>) {
+    let key = SweepKey::Window(window);
+    if entries.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, entries);
+        extend_persistent_ttl(env, TtlClass::Indexes, &key);
+    }
+}
+
+fn get_cursor(env: &Env) -> Option<u64> {
+    env.storage().instance().get(&SweepKey::Cursor)
+}
+
+fn set_cursor(env: &Env, window: u64) {
+    env.storage().instance().set(&SweepKey::Cursor, &window);
+}
+
+/// Adds a remittance to the window of its expiry.
+pub fn index_expiry(env: &Env, remittance_id: u64, expiry: u64) {
+    let window = expiry / EXPIRY_WINDOW_SECONDS;
+    let mut entries = get_window(env, window);
+    entries.push_back((expiry, remittance_id));
+    set_window(env, window, &entries);
+    if get_cursor(env).map_or(true, |cursor| window < cursor) {
+        set_cursor(env, window);
+    }
+}
+
+/// Removes and returns up to `limit` remittance IDs whose expiry is before `now`.
+///
+/// Walks at most `MAX_SWEEP_WINDOWS` windows from the cursor, moving the
+/// cursor past each window it empties, so later sweeps resume where this one
+/// stopped. Entries are returned whatever the current status of the
+/// remittance; the caller skips those that are no longer pending.
+pub fn take_expired(env: &Env, now: u64, limit: u32) -> Vec<u64> {
+    let mut expired = Vec::new(env);
+    let start = match get_cursor(env) {
+        Some(cursor) => cursor,
+        None => return expired,
+    };
+    let current = now / EXPIRY_WINDOW_SECONDS;
+    let mut window = start;
+    let mut walked = 0u32;
+    while window <= current && walked < MAX_SWEEP_WINDOWS && expired.len() < limit {
+        let entries = get_window(env, window);
+        let mut kept = Vec::new(env);
+        for (expiry, remittance_id) in entries.iter() {
+            // At `now == expiry` the remittance can still be confirmed
+            if expiry < now && expired.len() < limit {
+                expired.push_back(remittance_id);
+            } else {
+                kept.push_back((expiry, remittance_id));
+            }
+        }
+        if kept.len() != entries.len() {
+            set_window(env, window, &kept);
+        }
+        if !kept.is_empty() {
+            break;
+        }
+        window += 1;
+        walked += 1;
+    }
+    if window != start {
+        set_cursor(env, window);
+    }
+    expired
+}
+
+pub fn set_sweep_bounty(env: &Env, bounty: i128) {
+    env.storage().instance().set(&SweepKey::Bounty, &bounty);
+}
+
+pub fn get_sweep_bounty(env: &Env) -> i128 {
+    env.storage().instance().get(&SweepKey::Bounty).unwrap_or(0)
+}
@@ -1,7 +1,7 @@
 #![cfg(test)]
 extern crate alloc;
 
-use crate::{SwiftRemitContract, SwiftRemitContractClient};
+use crate::{InitConfig, PausableOp, PurposeCode, SwiftRemitContract, SwiftRemitContractClient};
 use soroban_sdk::token::Client as TokenClient;
 use soroban_sdk::token::StellarAssetClient;
 use soroban_sdk::testutils::Ledger;
@@ -19,6 +19,43 @@ fn get_token_balance(token: &token::StellarAssetClient, address: &Address) -> i1
     token::Client::new(&token.env, &token.address).balance(address)
 }
 
+/// Balance entry of the native asset contract, laid out as the host stores it.
+#[soroban_sdk::contracttype]
+#[derive(Clone)]
+struct NativeBalance {
+    amount: i128,
+    authorized: bool,
+    clawback: bool,
+}
+
+#[soroban_sdk::contracttype]
+#[derive(Clone)]
+enum NativeBalanceKey {
+    Balance(Address),
+}
+
+fn create_native_token_contract<'a>(env: &Env) -> token::StellarAssetClient<'a> {
+    let address = env
+        .deployer()
+        .with_stellar_asset(soroban_sdk::Bytes::from_array(env, &[0u8; 4]))
+        .deploy();
+    token::StellarAssetClient::new(env, &address)
+}
+
+/// Credits native XLM to a contract address; the native asset has no admin to mint it.
+fn fund_native(env: &Env, token: &token::StellarAssetClient, to: &Address, amount: i128) {
+    let balance = NativeBalance {
+        amount,
+        authorized: true,
+        clawback: false,
+    };
+    env.as_contract(&token.address, || {
+        env.storage()
+            .persistent()
+            .set(&NativeBalanceKey::Balance(to.clone()), &balance);
+    });
+}
+
 fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
     SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
 }
@@ -42,7 +79,7 @@ fn test_initialize() {
 
     let contract = create_swiftremit_contract(&env);
 
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     assert_eq!(contract.get_platform_fee_bps(), 250);
 }
@@ -59,8 +96,8 @@ fn test_initialize_twice() {
 
     let contract = create_swiftremit_contract(&env);
 
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 }
 
 #[test]
@@ -75,7 +112,7 @@ fn test_initialize_invalid_fee() {
 
     let contract = create_swiftremit_contract(&env);
 
-    contract.initialize(&admin, &token.address, &10001, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 10001));
 }
 
 #[test]
@@ -89,7 +126,7 @@ fn test_register_agent() {
     let agent = Address::generate(&env);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
         contract.register_agent(&agent);
 
@@ -122,7 +159,7 @@ fn test_remove_agent() {
     let agent = Address::generate(&env);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     contract.register_agent(&agent);
     assert!(contract.is_agent_registered(&agent));
@@ -141,7 +178,7 @@ fn test_update_fee() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     contract.update_fee(&500);
     assert_eq!(contract.get_platform_fee_bps(), 500);
@@ -158,7 +195,7 @@ fn test_update_fee_invalid() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     contract.update_fee(&10001);
 }
@@ -178,10 +215,10 @@ fn test_create_remittance() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     assert_eq!(remittance_id, 1);
 
@@ -196,7 +233,7 @@ fn test_create_remittance() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #3)")]
+#[should_panic(expected = "Error(Contract, #47)")]
 fn test_create_remittance_invalid_amount() {
     let env = Env::default();
     env.mock_all_auths();
@@ -208,10 +245,10 @@ fn test_create_remittance_invalid_amount() {
     let agent = Address::generate(&env);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
-    contract.create_remittance(&sender, &agent, &0, &default_currency(&env), &default_country(&env), &None);
+    contract.create_remittance(&sender, &agent, &0, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 }
 
 #[test]
@@ -230,9 +267,9 @@ fn test_create_remittance_unregistered_agent() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
-    contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 }
 
 #[test]
@@ -250,10 +287,10 @@ fn test_confirm_payout() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     contract.authorize_remittance(&admin, &remittance_id);
     contract.confirm_payout(&remittance_id);
@@ -282,10 +319,10 @@ fn test_confirm_payout_twice() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     contract.authorize_remittance(&admin, &remittance_id);
     contract.confirm_payout(&remittance_id);
@@ -307,15 +344,15 @@ fn test_cancel_remittance() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     contract.cancel_remittance(&remittance_id);
 
     let remittance = contract.get_remittance(&remittance_id);
-    assert_eq!(remittance.status, crate::types::RemittanceStatus::Failed);
+    assert_eq!(remittance.status, crate::types::RemittanceStatus::Cancelled);
 
     assert_eq!(get_token_balance(&token, &sender), 10000);
     assert_eq!(get_token_balance(&token, &contract.address), 0);
@@ -337,10 +374,10 @@ fn test_cancel_remittance_already_completed() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
     contract.authorize_remittance(&admin, &remittance_id);
     contract.confirm_payout(&remittance_id);
 
@@ -367,12 +404,12 @@ fn test_cancel_remittance_full_refund() {
     token.mint(&sender, &initial_balance);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250); // 2.5% fee
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250)); // 2.5% fee
     contract.register_agent(&agent);
 
     // Create remittance with 1000 tokens
     let remittance_amount = 1000i128;
-    let remittance_id = contract.create_remittance(&sender, &agent, &remittance_amount, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &remittance_amount, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
 
     let token_client = token::Client::new(&env, &token.address);
     // Verify sender balance decreased by full amount
@@ -391,7 +428,7 @@ fn test_cancel_remittance_full_refund() {
 
     // Verify remittance status is Cancelled
     let remittance = contract.get_remittance(&remittance_id);
-    assert_eq!(remittance.status, crate::types::RemittanceStatus::Failed);
+    assert_eq!(remittance.status, crate::types::RemittanceStatus::Cancelled);
 }
 
 #[test]
@@ -409,10 +446,10 @@ fn test_cancel_remittance_sender_authorization() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     // Cancel and verify sender authorization was required
     contract.cancel_remittance(&remittance_id);
@@ -448,11 +485,11 @@ fn test_cancel_remittance_event_emission() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
     let remittance_amount = 1000i128;
-    let remittance_id = contract.create_remittance(&sender, &agent, &remittance_amount, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &remittance_amount, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
 
     // Cancel the remittance
     contract.cancel_remittance(&remittance_id);
@@ -467,10 +504,10 @@ fn test_cancel_remittance_event_emission() {
 
     let event_data: soroban_sdk::Vec<soroban_sdk::Val> =
         soroban_sdk::FromVal::from_val(&env, &event.2);
-    let event_remittance_id: u64 = soroban_sdk::FromVal::from_val(&env, &event_data.get(3).unwrap());
-    let event_sender: Address = soroban_sdk::FromVal::from_val(&env, &event_data.get(4).unwrap());
-    let event_agent: Address = soroban_sdk::FromVal::from_val(&env, &event_data.get(5).unwrap());
-    let event_amount: i128 = soroban_sdk::FromVal::from_val(&env, &event_data.get(7).unwrap());
+    let event_remittance_id: u64 = soroban_sdk::FromVal::from_val(&env, &event_data.get(4).unwrap());
+    let event_sender: Address = soroban_sdk::FromVal::from_val(&env, &event_data.get(5).unwrap());
+    let event_agent: Address = soroban_sdk::FromVal::from_val(&env, &event_data.get(6).unwrap());
+    let event_amount: i128 = soroban_sdk::FromVal::from_val(&env, &event_data.get(8).unwrap());
 
     assert_eq!(event_remittance_id, remittance_id);
     assert_eq!(event_sender, sender);
@@ -489,7 +526,7 @@ fn test_cancel_remittance_not_found() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     // Try to cancel non-existent remittance
     contract.cancel_remittance(&999);
@@ -511,10 +548,10 @@ fn test_cancel_remittance_already_cancelled() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     // Cancel once
     contract.cancel_remittance(&remittance_id);
@@ -537,13 +574,13 @@ fn test_cancel_remittance_multiple_remittances() {
     token.mint(&sender, &20000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
     // Create multiple remittances
-    let remittance_id1 = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
-    let remittance_id2 = contract.create_remittance(&sender, &agent, &2000, &default_currency(&env), &default_country(&env), &None);
-    let remittance_id3 = contract.create_remittance(&sender, &agent, &3000, &default_currency(&env), &default_country(&env), &None);
+    let remittance_id1 = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
+    let remittance_id2 = contract.create_remittance(&sender, &agent, &2000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
+    let remittance_id3 = contract.create_remittance(&sender, &agent, &3000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     let token_client = token::Client::new(&env, &token.address);
     // Sender should have 14000 left (20000 - 1000 - 2000 - 3000)
@@ -563,9 +600,9 @@ fn test_cancel_remittance_multiple_remittances() {
     let r2 = contract.get_remittance(&remittance_id2);
     let r3 = contract.get_remittance(&remittance_id3);
 
-    assert_eq!(r1.status, crate::types::RemittanceStatus::Failed);
+    assert_eq!(r1.status, crate::types::RemittanceStatus::Cancelled);
     assert_eq!(r2.status, crate::types::RemittanceStatus::Pending);
-    assert_eq!(r3.status, crate::types::RemittanceStatus::Failed);
+    assert_eq!(r3.status, crate::types::RemittanceStatus::Cancelled);
 }
 
 #[test]
@@ -583,14 +620,14 @@ fn test_cancel_remittance_no_fee_accumulation() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
     // Create and cancel remittance
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
     contract.cancel_remittance(&remittance_id);
 
-    // Verify no fees were accumulated (fees only accumulate on successful payout)
+    // Verify the fee accrued at creation was reversed by the cancellation
     assert_eq!(contract.get_accumulated_fees(), 0);
 }
 
@@ -609,11 +646,11 @@ fn test_cancel_remittance_preserves_remittance_data() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
     let remittance_amount = 1000i128;
-    let remittance_id = contract.create_remittance(&sender, &agent, &remittance_amount, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &remittance_amount, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
 
     // Get original remittance data
     let original = contract.get_remittance(&remittance_id);
@@ -631,7 +668,7 @@ fn test_cancel_remittance_preserves_remittance_data() {
     assert_eq!(cancelled.amount, original.amount);
     assert_eq!(cancelled.fee, original.fee);
     assert_eq!(cancelled.expiry, original.expiry);
-    assert_eq!(cancelled.status, crate::types::RemittanceStatus::Failed);
+    assert_eq!(cancelled.status, crate::types::RemittanceStatus::Cancelled);
     assert_eq!(original.status, crate::types::RemittanceStatus::Pending);
 }
 
@@ -651,14 +688,14 @@ fn test_withdraw_fees() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
     contract.authorize_remittance(&admin, &remittance_id);
     contract.confirm_payout(&remittance_id);
 
-    contract.withdraw_fees(&fee_recipient);
+    contract.withdraw_fees(&token.address, &fee_recipient);
 
     assert_eq!(get_token_balance(&token, &fee_recipient), 25);
     assert_eq!(contract.get_accumulated_fees(), 0);
@@ -677,9 +714,9 @@ fn test_withdraw_fees_no_fees() {
     let fee_recipient = Address::generate(&env);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
-    contract.withdraw_fees(&fee_recipient);
+    contract.withdraw_fees(&token.address, &fee_recipient);
 }
 
 #[test]
@@ -696,10 +733,10 @@ fn test_fee_calculation() {
     token.mint(&sender, &100000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &500, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 500));
     contract.register_agent(&agent);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &10000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     let remittance = contract.get_remittance(&remittance_id);
     assert_eq!(remittance.fee, 500);
@@ -726,11 +763,11 @@ fn test_multiple_remittances() {
     token.mint(&sender2, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
-    let remittance_id1 = contract.create_remittance(&sender1, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
-    let remittance_id2 = contract.create_remittance(&sender2, &agent, &2000, &default_currency(&env), &default_country(&env), &None);
+    let remittance_id1 = contract.create_remittance(&sender1, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
+    let remittance_id2 = contract.create_remittance(&sender2, &agent, &2000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     assert_eq!(remittance_id1, 1);
     assert_eq!(remittance_id2, 2);
@@ -760,14 +797,14 @@ fn test_events_emitted() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     let initial_events = env.events().all().len();
 
     contract.register_agent(&agent);
     assert!(env.events().all().len() > initial_events, "Agent registration should emit event");
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     assert!(env.events().all().len() > initial_events + 1, "Remittance creation should emit event");
 
     contract.authorize_remittance(&admin, &remittance_id);
@@ -791,11 +828,11 @@ fn test_authorization_enforcement() {
     let contract = create_swiftremit_contract(&env);
 
     env.mock_all_auths();
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
     env.mock_all_auths();
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     env.mock_all_auths();
     contract.authorize_remittance(&admin, &remittance_id);
@@ -835,15 +872,15 @@ fn test_withdraw_fees_valid_address() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
     contract.authorize_remittance(&admin, &remittance_id);
     contract.confirm_payout(&remittance_id);
 
     // This should succeed with a valid address
-    contract.withdraw_fees(&fee_recipient);
+    contract.withdraw_fees(&token.address, &fee_recipient);
 
     assert_eq!(get_token_balance(&token, &fee_recipient), 25);
     assert_eq!(contract.get_accumulated_fees(), 0);
@@ -864,10 +901,10 @@ fn test_confirm_payout_valid_address() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     // This should succeed with a valid agent address
     contract.authorize_remittance(&admin, &remittance_id);
@@ -893,11 +930,11 @@ fn test_address_validation_in_settlement_flow() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
     // Create remittance with valid addresses
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     // Confirm payout - should validate agent address
     contract.authorize_remittance(&admin, &remittance_id);
@@ -927,13 +964,13 @@ fn test_multiple_settlements_with_address_validation() {
     token.mint(&sender2, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent1);
     contract.register_agent(&agent2);
 
     // Create and confirm multiple remittances
-    let remittance_id1 = contract.create_remittance(&sender1, &agent1, &1000, &default_currency(&env), &default_country(&env), &None);
-    let remittance_id2 = contract.create_remittance(&sender2, &agent2, &2000, &default_currency(&env), &default_country(&env), &None);
+    let remittance_id1 = contract.create_remittance(&sender1, &agent1, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
+    let remittance_id2 = contract.create_remittance(&sender2, &agent2, &2000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     // Both should succeed with valid addresses
     contract.authorize_remittance(&admin, &remittance_id1);
@@ -962,7 +999,7 @@ fn test_settlement_with_future_expiry() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
     // Set expiry to 1 hour in the future
@@ -970,7 +1007,7 @@ fn test_settlement_with_future_expiry() {
     let current_time = env.ledger().timestamp();
     let expiry_time = current_time + 3600;
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &Some(expiry_time));
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &Some(expiry_time), &None, &None, &None, &None);
 
     // Should succeed since expiry is in the future
     contract.authorize_remittance(&admin, &remittance_id);
@@ -997,7 +1034,7 @@ fn test_settlement_with_past_expiry() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
     // Set expiry to 1 hour in the past
@@ -1005,7 +1042,7 @@ fn test_settlement_with_past_expiry() {
     let current_time = env.ledger().timestamp();
     let expiry_time = current_time.saturating_sub(3600);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &Some(expiry_time));
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &Some(expiry_time), &None, &None, &None, &None);
 
     // Should fail with SettlementExpired error
     contract.authorize_remittance(&admin, &remittance_id);
@@ -1027,11 +1064,11 @@ fn test_settlement_without_expiry() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
     // Create remittance without expiry
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     // Should succeed since there's no expiry
     contract.authorize_remittance(&admin, &remittance_id);
@@ -1058,10 +1095,10 @@ fn test_duplicate_settlement_prevention() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     // First settlement should succeed
     contract.authorize_remittance(&admin, &remittance_id);
@@ -1102,12 +1139,12 @@ fn test_different_settlements_allowed() {
     token.mint(&sender, &20000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
     // Create two different remittances
-    let remittance_id1 = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
-    let remittance_id2 = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let remittance_id1 = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
+    let remittance_id2 = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     // Both settlements should succeed as they are different remittances
     contract.authorize_remittance(&admin, &remittance_id1);
@@ -1140,12 +1177,12 @@ fn test_settlement_hash_storage_efficiency() {
     token.mint(&sender, &50000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
     // Create and settle multiple remittances
     for _ in 0..5 {
-        let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+        let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
         contract.authorize_remittance(&admin, &remittance_id);
         contract.confirm_payout(&remittance_id);
     }
@@ -1174,14 +1211,14 @@ fn test_duplicate_prevention_with_expiry() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
     env.ledger().set(soroban_sdk::testutils::LedgerInfo { timestamp: 10000, ..env.ledger().get() });
     let current_time = env.ledger().timestamp();
     let expiry_time = current_time + 3600;
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &Some(expiry_time));
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &Some(expiry_time), &None, &None, &None, &None);
 
     contract.authorize_remittance(&admin, &remittance_id);
 
@@ -1205,7 +1242,7 @@ fn test_pause_unpause() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     assert!(!contract.is_paused());
 
@@ -1232,10 +1269,10 @@ fn test_settlement_blocked_when_paused() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
     contract.authorize_remittance(&admin, &remittance_id);
 
     contract.pause();
@@ -1257,10 +1294,10 @@ fn test_settlement_works_after_unpause() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
 
     contract.pause();
     contract.unpause();
@@ -1285,10 +1322,10 @@ fn test_get_settlement_valid() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     contract.confirm_payout(&remittance_id);
 
     let settlement = contract.get_settlement(&remittance_id);
@@ -1311,7 +1348,7 @@ fn test_get_settlement_invalid_id() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     contract.get_settlement(&999);
 }
@@ -1331,10 +1368,10 @@ fn test_settlement_completed_event_emission() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     
     contract.confirm_payout(&remittance_id);
 
@@ -1358,10 +1395,10 @@ fn test_settlement_completed_event_fields_accuracy() {
     token.mint(&sender, &20000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &500, &0); // 5% fee
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 500)); // 5% fee
     contract.register_agent(&agent);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &10000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &10000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     
     contract.confirm_payout(&remittance_id);
 
@@ -1389,17 +1426,17 @@ fn test_rate_limit_disabled_by_default() {
     token.mint(&sender, &30000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0); // 0 = disabled
+    contract.initialize(&InitConfig { rate_limit_cooldown: 0, ..InitConfig::new(&env, &admin, &token.address, 250) }); // 0 = disabled
     contract.register_agent(&agent);
 
     // Create and settle multiple remittances immediately
-    let id1 = contract.create_remittance(&sender, &agent, &1000, &None);
+    let id1 = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     contract.confirm_payout(&id1);
 
-    let id2 = contract.create_remittance(&sender, &agent, &1000, &None);
+    let id2 = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     contract.confirm_payout(&id2);
 
-    let id3 = contract.create_remittance(&sender, &agent, &1000, &None);
+    let id3 = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     contract.confirm_payout(&id3);
 
     // All should succeed when rate limiting is disabled
@@ -1420,11 +1457,11 @@ fn test_rate_limit_enforced() {
     token.mint(&sender, &30000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &3600); // 1 hour cooldown
+    contract.initialize(&InitConfig { rate_limit_cooldown: 3600, ..InitConfig::new(&env, &admin, &token.address, 250) }); // 1 hour cooldown
     contract.register_agent(&agent);
 
     // First settlement should succeed
-    let id1 = contract.create_remittance(&sender, &agent, &1000, &None);
+    let id1 = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     contract.confirm_payout(&id1);
 
     // Check last settlement time was recorded
@@ -1433,7 +1470,7 @@ fn test_rate_limit_enforced() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #14)")]
+#[should_panic(expected = "Error(Contract, #28)")]
 fn test_rate_limit_blocks_rapid_settlements() {
     let env = Env::default();
     env.mock_all_auths();
@@ -1447,15 +1484,15 @@ fn test_rate_limit_blocks_rapid_settlements() {
     token.mint(&sender, &30000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &3600); // 1 hour cooldown
+    contract.initialize(&InitConfig { rate_limit_cooldown: 3600, ..InitConfig::new(&env, &admin, &token.address, 250) }); // 1 hour cooldown
     contract.register_agent(&agent);
 
     // First settlement succeeds
-    let id1 = contract.create_remittance(&sender, &agent, &1000, &None);
+    let id1 = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     contract.confirm_payout(&id1);
 
     // Second settlement immediately after should fail
-    let id2 = contract.create_remittance(&sender, &agent, &1000, &None);
+    let id2 = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     contract.confirm_payout(&id2); // Should panic with RateLimitExceeded
 }
 
@@ -1473,11 +1510,11 @@ fn test_rate_limit_allows_after_cooldown() {
     token.mint(&sender, &30000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &60); // 60 second cooldown
+    contract.initialize(&InitConfig { rate_limit_cooldown: 60, ..InitConfig::new(&env, &admin, &token.address, 250) }); // 60 second cooldown
     contract.register_agent(&agent);
 
     // First settlement
-    let id1 = contract.create_remittance(&sender, &agent, &1000, &None);
+    let id1 = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     contract.confirm_payout(&id1);
 
     // Advance time by 61 seconds
@@ -1486,7 +1523,7 @@ fn test_rate_limit_allows_after_cooldown() {
     });
 
     // Second settlement should now succeed
-    let id2 = contract.create_remittance(&sender, &agent, &1000, &None);
+    let id2 = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     contract.confirm_payout(&id2);
 
     assert_eq!(contract.get_accumulated_fees(), 50);
@@ -1508,15 +1545,15 @@ fn test_rate_limit_per_sender() {
     token.mint(&sender2, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &3600); // 1 hour cooldown
+    contract.initialize(&InitConfig { rate_limit_cooldown: 3600, ..InitConfig::new(&env, &admin, &token.address, 250) }); // 1 hour cooldown
     contract.register_agent(&agent);
 
     // Sender1 creates and settles
-    let id1 = contract.create_remittance(&sender1, &agent, &1000, &None);
+    let id1 = contract.create_remittance(&sender1, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     contract.confirm_payout(&id1);
 
     // Sender2 should be able to settle immediately (different sender)
-    let id2 = contract.create_remittance(&sender2, &agent, &1000, &None);
+    let id2 = contract.create_remittance(&sender2, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     contract.confirm_payout(&id2);
 
     // Both should succeed
@@ -1533,7 +1570,7 @@ fn test_update_rate_limit() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &3600);
+    contract.initialize(&InitConfig { rate_limit_cooldown: 3600, ..InitConfig::new(&env, &admin, &token.address, 250) });
 
     assert_eq!(contract.get_rate_limit_cooldown(), 3600);
 
@@ -1557,18 +1594,18 @@ fn test_admin_can_disable_rate_limit() {
     token.mint(&sender, &30000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &3600); // Start with cooldown
+    contract.initialize(&InitConfig { rate_limit_cooldown: 3600, ..InitConfig::new(&env, &admin, &token.address, 250) }); // Start with cooldown
     contract.register_agent(&agent);
 
     // First settlement
-    let id1 = contract.create_remittance(&sender, &agent, &1000, &None);
+    let id1 = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     contract.confirm_payout(&id1);
 
     // Admin disables rate limiting
     contract.update_rate_limit(&0);
 
     // Second settlement should now succeed immediately
-    let id2 = contract.create_remittance(&sender, &agent, &1000, &None);
+    let id2 = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     contract.confirm_payout(&id2);
 
     assert_eq!(contract.get_accumulated_fees(), 50);
@@ -1584,7 +1621,7 @@ fn test_rate_limit_event_emission() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &3600);
+    contract.initialize(&InitConfig { rate_limit_cooldown: 3600, ..InitConfig::new(&env, &admin, &token.address, 250) });
 
     contract.update_rate_limit(&7200);
 
@@ -1608,11 +1645,11 @@ fn test_first_settlement_no_rate_limit() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &3600);
+    contract.initialize(&InitConfig { rate_limit_cooldown: 3600, ..InitConfig::new(&env, &admin, &token.address, 250) });
     contract.register_agent(&agent);
 
     // First settlement should always succeed (no previous timestamp)
-    let id1 = contract.create_remittance(&sender, &agent, &1000, &None);
+    let id1 = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     contract.confirm_payout(&id1);
 
     let remittance = contract.get_remittance(&id1);
@@ -1634,7 +1671,7 @@ fn test_add_admin() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin1, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin1, &token.address, 250));
 
     // Initial admin should be registered
     assert!(contract.is_admin(&admin1));
@@ -1661,7 +1698,7 @@ fn test_add_admin_unauthorized() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     // Non-admin trying to add admin should fail
     contract.add_admin(&non_admin, &new_admin);
@@ -1678,7 +1715,7 @@ fn test_add_admin_already_exists() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     // Try to add the same admin again
     contract.add_admin(&admin, &admin);
@@ -1695,7 +1732,7 @@ fn test_remove_admin() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin1, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin1, &token.address, 250));
 
     // Add second admin
     contract.add_admin(&admin1, &admin2);
@@ -1718,7 +1755,7 @@ fn test_cannot_remove_last_admin() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     // Try to remove the only admin
     contract.remove_admin(&admin, &admin);
@@ -1737,7 +1774,7 @@ fn test_remove_admin_unauthorized() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin1, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin1, &token.address, 250));
     contract.add_admin(&admin1, &admin2);
 
     // Non-admin trying to remove admin should fail
@@ -1756,7 +1793,7 @@ fn test_remove_admin_not_found() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     // Try to remove an address that is not an admin
     contract.remove_admin(&admin, &non_admin);
@@ -1774,7 +1811,7 @@ fn test_multiple_admins_can_perform_admin_actions() {
     let agent = Address::generate(&env);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin1, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin1, &token.address, 250));
     contract.add_admin(&admin1, &admin2);
 
     // Both admins should be able to register agents
@@ -1821,15 +1858,15 @@ fn test_multiple_tokens_different_contracts() {
     let contract1 = create_swiftremit_contract(&env);
     let contract2 = create_swiftremit_contract(&env);
     
-    contract1.initialize(&admin, &token1.address, &250);
-    contract2.initialize(&admin, &token2.address, &300);
+    contract1.initialize(&InitConfig::new(&env, &admin, &token1.address, 250));
+    contract2.initialize(&InitConfig::new(&env, &admin, &token2.address, 300));
     
     contract1.register_agent(&agent);
     contract2.register_agent(&agent);
 
     // Create remittances with different tokens
-    let remittance_id1 = contract1.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
-    let remittance_id2 = contract2.create_remittance(&sender, &agent, &2000, &default_currency(&env), &default_country(&env), &None);
+    let remittance_id1 = contract1.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
+    let remittance_id2 = contract2.create_remittance(&sender, &agent, &2000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     // Confirm payouts
     contract1.confirm_payout(&remittance_id1);
@@ -1874,9 +1911,9 @@ fn test_multi_token_balance_isolation() {
     let contract2 = create_swiftremit_contract(&env);
     let contract3 = create_swiftremit_contract(&env);
     
-    contract1.initialize(&admin, &token1.address, &200);
-    contract2.initialize(&admin, &token2.address, &300);
-    contract3.initialize(&admin, &token3.address, &400);
+    contract1.initialize(&InitConfig::new(&env, &admin, &token1.address, 200));
+    contract2.initialize(&InitConfig::new(&env, &admin, &token2.address, 300));
+    contract3.initialize(&InitConfig::new(&env, &admin, &token3.address, 400));
     
     contract1.register_agent(&agent1);
     contract2.register_agent(&agent1);
@@ -1884,10 +1921,10 @@ fn test_multi_token_balance_isolation() {
     contract3.register_agent(&agent2);
 
     // Create multiple remittances across different tokens
-    let rem1 = contract1.create_remittance(&sender1, &agent1, &5000, &default_currency(&env), &default_country(&env), &None);
-    let rem2 = contract2.create_remittance(&sender1, &agent1, &3000, &default_currency(&env), &default_country(&env), &None);
-    let rem3 = contract2.create_remittance(&sender2, &agent2, &4000, &default_currency(&env), &default_country(&env), &None);
-    let rem4 = contract3.create_remittance(&sender2, &agent2, &6000, &default_currency(&env), &default_country(&env), &None);
+    let rem1 = contract1.create_remittance(&sender1, &agent1, &5000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
+    let rem2 = contract2.create_remittance(&sender1, &agent1, &3000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
+    let rem3 = contract2.create_remittance(&sender2, &agent2, &4000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
+    let rem4 = contract3.create_remittance(&sender2, &agent2, &6000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     // Confirm all payouts
     contract1.confirm_payout(&rem1);
@@ -1940,20 +1977,20 @@ fn test_multi_token_fee_withdrawal() {
     let contract1 = create_swiftremit_contract(&env);
     let contract2 = create_swiftremit_contract(&env);
     
-    contract1.initialize(&admin, &token1.address, &500);
-    contract2.initialize(&admin, &token2.address, &250);
+    contract1.initialize(&InitConfig::new(&env, &admin, &token1.address, 500));
+    contract2.initialize(&InitConfig::new(&env, &admin, &token2.address, 250));
     
     contract1.register_agent(&agent);
     contract2.register_agent(&agent);
 
     // Create and complete multiple remittances
     for _ in 0..3 {
-        let rem1 = contract1.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+        let rem1 = contract1.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
         contract1.confirm_payout(&rem1);
     }
     
     for _ in 0..2 {
-        let rem2 = contract2.create_remittance(&sender, &agent, &2000, &default_currency(&env), &default_country(&env), &None);
+        let rem2 = contract2.create_remittance(&sender, &agent, &2000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
         contract2.confirm_payout(&rem2);
     }
 
@@ -1962,8 +1999,8 @@ fn test_multi_token_fee_withdrawal() {
     assert_eq!(contract2.get_accumulated_fees(), 100); // 2 * 50
 
     // Withdraw fees to different recipients
-    contract1.withdraw_fees(&fee_recipient1);
-    contract2.withdraw_fees(&fee_recipient2);
+    contract1.withdraw_fees(&token1.address, &fee_recipient1);
+    contract2.withdraw_fees(&token2.address, &fee_recipient2);
 
     // Verify fee withdrawals
     assert_eq!(token1.balance(&fee_recipient1), 150);
@@ -1996,16 +2033,16 @@ fn test_multi_token_cancellation_refunds() {
     let contract1 = create_swiftremit_contract(&env);
     let contract2 = create_swiftremit_contract(&env);
     
-    contract1.initialize(&admin, &token1.address, &250);
-    contract2.initialize(&admin, &token2.address, &300);
+    contract1.initialize(&InitConfig::new(&env, &admin, &token1.address, 250));
+    contract2.initialize(&InitConfig::new(&env, &admin, &token2.address, 300));
     
     contract1.register_agent(&agent);
     contract2.register_agent(&agent);
 
     // Create remittances
-    let rem1 = contract1.create_remittance(&sender, &agent, &2000, &default_currency(&env), &default_country(&env), &None);
-    let rem2 = contract2.create_remittance(&sender, &agent, &3000, &default_currency(&env), &default_country(&env), &None);
-    let rem3 = contract1.create_remittance(&sender, &agent, &1500, &default_currency(&env), &default_country(&env), &None);
+    let rem1 = contract1.create_remittance(&sender, &agent, &2000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
+    let rem2 = contract2.create_remittance(&sender, &agent, &3000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
+    let rem3 = contract1.create_remittance(&sender, &agent, &1500, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     // Cancel some remittances
     contract1.cancel_remittance(&rem1);
@@ -2047,15 +2084,15 @@ fn test_multi_token_state_transitions() {
     let contract1 = create_swiftremit_contract(&env);
     let contract2 = create_swiftremit_contract(&env);
     
-    contract1.initialize(&admin, &token1.address, &250);
-    contract2.initialize(&admin, &token2.address, &250);
+    contract1.initialize(&InitConfig::new(&env, &admin, &token1.address, 250));
+    contract2.initialize(&InitConfig::new(&env, &admin, &token2.address, 250));
     
     contract1.register_agent(&agent);
     contract2.register_agent(&agent);
 
     // Create remittances in both tokens
-    let rem1 = contract1.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
-    let rem2 = contract2.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let rem1 = contract1.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
+    let rem2 = contract2.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     // Verify initial state
     let remittance1 = contract1.get_remittance(&rem1);
@@ -2104,8 +2141,8 @@ fn test_multi_token_concurrent_operations() {
     let contract1 = create_swiftremit_contract(&env);
     let contract2 = create_swiftremit_contract(&env);
     
-    contract1.initialize(&admin, &token1.address, &250);
-    contract2.initialize(&admin, &token2.address, &250);
+    contract1.initialize(&InitConfig::new(&env, &admin, &token1.address, 250));
+    contract2.initialize(&InitConfig::new(&env, &admin, &token2.address, 250));
     
     contract1.register_agent(&agent1);
     contract1.register_agent(&agent2);
@@ -2113,10 +2150,10 @@ fn test_multi_token_concurrent_operations() {
     contract2.register_agent(&agent2);
 
     // Create multiple concurrent remittances
-    let rem1_1 = contract1.create_remittance(&sender1, &agent1, &1000, &default_currency(&env), &default_country(&env), &None);
-    let rem1_2 = contract1.create_remittance(&sender2, &agent2, &2000, &default_currency(&env), &default_country(&env), &None);
-    let rem2_1 = contract2.create_remittance(&sender1, &agent2, &1500, &None);
-    let rem2_2 = contract2.create_remittance(&sender2, &agent1, &2500, &None);
+    let rem1_1 = contract1.create_remittance(&sender1, &agent1, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
+    let rem1_2 = contract1.create_remittance(&sender2, &agent2, &2000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
+    let rem2_1 = contract2.create_remittance(&sender1, &agent2, &1500, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let rem2_2 = contract2.create_remittance(&sender2, &agent1, &2500, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
 
     // Process in mixed order
     contract1.confirm_payout(&rem1_1);
@@ -2155,14 +2192,14 @@ fn test_multi_token_edge_case_zero_fee() {
     let contract2 = create_swiftremit_contract(&env);
     
     // One with 0% fee, one with normal fee
-    contract1.initialize(&admin, &token1.address, &0);
-    contract2.initialize(&admin, &token2.address, &500);
+    contract1.initialize(&InitConfig::new(&env, &admin, &token1.address, 0));
+    contract2.initialize(&InitConfig::new(&env, &admin, &token2.address, 500));
     
     contract1.register_agent(&agent);
     contract2.register_agent(&agent);
 
-    let rem1 = contract1.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
-    let rem2 = contract2.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let rem1 = contract1.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
+    let rem2 = contract2.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     contract1.confirm_payout(&rem1);
     contract2.confirm_payout(&rem2);
@@ -2197,15 +2234,15 @@ fn test_multi_token_large_amounts() {
     let contract1 = create_swiftremit_contract(&env);
     let contract2 = create_swiftremit_contract(&env);
     
-    contract1.initialize(&admin, &token1.address, &100);
-    contract2.initialize(&admin, &token2.address, &50);
+    contract1.initialize(&InitConfig::new(&env, &admin, &token1.address, 100));
+    contract2.initialize(&InitConfig::new(&env, &admin, &token2.address, 50));
     
     contract1.register_agent(&agent);
     contract2.register_agent(&agent);
 
     // Large remittances
-    let rem1 = contract1.create_remittance(&sender, &agent, &100_000_000, &None);
-    let rem2 = contract2.create_remittance(&sender, &agent, &500_000_000, &None);
+    let rem1 = contract1.create_remittance(&sender, &agent, &100_000_000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let rem2 = contract2.create_remittance(&sender, &agent, &500_000_000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
 
     contract1.confirm_payout(&rem1);
     contract2.confirm_payout(&rem2);
@@ -2239,8 +2276,8 @@ fn test_multi_token_expiry_handling() {
     let contract1 = create_swiftremit_contract(&env);
     let contract2 = create_swiftremit_contract(&env);
     
-    contract1.initialize(&admin, &token1.address, &250);
-    contract2.initialize(&admin, &token2.address, &250);
+    contract1.initialize(&InitConfig::new(&env, &admin, &token1.address, 250));
+    contract2.initialize(&InitConfig::new(&env, &admin, &token2.address, 250));
     
     contract1.register_agent(&agent);
     contract2.register_agent(&agent);
@@ -2249,8 +2286,8 @@ fn test_multi_token_expiry_handling() {
     let future_expiry = current_time + 7200;
 
     // Create remittances with expiry
-    let rem1 = contract1.create_remittance(&sender, &agent, &1000, &Some(future_expiry));
-    let rem2 = contract2.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let rem1 = contract1.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &Some(future_expiry), &None, &None, &None, &None);
+    let rem2 = contract2.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     // Both should succeed
     contract1.confirm_payout(&rem1);
@@ -2285,14 +2322,14 @@ fn test_multi_token_pause_independence() {
     let contract1 = create_swiftremit_contract(&env);
     let contract2 = create_swiftremit_contract(&env);
     
-    contract1.initialize(&admin, &token1.address, &250);
-    contract2.initialize(&admin, &token2.address, &250);
+    contract1.initialize(&InitConfig::new(&env, &admin, &token1.address, 250));
+    contract2.initialize(&InitConfig::new(&env, &admin, &token2.address, 250));
     
     contract1.register_agent(&agent);
     contract2.register_agent(&agent);
 
-    let rem1 = contract1.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
-    let rem2 = contract2.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let rem1 = contract1.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
+    let rem2 = contract2.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     // Pause only contract1
     contract1.pause();
@@ -2338,8 +2375,8 @@ fn test_multi_token_different_agents() {
     let contract1 = create_swiftremit_contract(&env);
     let contract2 = create_swiftremit_contract(&env);
     
-    contract1.initialize(&admin, &token1.address, &200);
-    contract2.initialize(&admin, &token2.address, &300);
+    contract1.initialize(&InitConfig::new(&env, &admin, &token1.address, 200));
+    contract2.initialize(&InitConfig::new(&env, &admin, &token2.address, 300));
     
     // Register different agents for different contracts
     contract1.register_agent(&agent1);
@@ -2348,10 +2385,10 @@ fn test_multi_token_different_agents() {
     contract2.register_agent(&agent3);
 
     // Create remittances to different agents
-    let rem1 = contract1.create_remittance(&sender, &agent1, &5000, &None);
-    let rem2 = contract1.create_remittance(&sender, &agent2, &3000, &None);
-    let rem3 = contract2.create_remittance(&sender, &agent2, &4000, &None);
-    let rem4 = contract2.create_remittance(&sender, &agent3, &6000, &None);
+    let rem1 = contract1.create_remittance(&sender, &agent1, &5000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let rem2 = contract1.create_remittance(&sender, &agent2, &3000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let rem3 = contract2.create_remittance(&sender, &agent2, &4000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let rem4 = contract2.create_remittance(&sender, &agent3, &6000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
 
     // Complete all
     contract1.confirm_payout(&rem1);
@@ -2392,15 +2429,15 @@ fn test_multi_token_mixed_success_failure() {
     let contract1 = create_swiftremit_contract(&env);
     let contract2 = create_swiftremit_contract(&env);
     
-    contract1.initialize(&admin, &token1.address, &250);
-    contract2.initialize(&admin, &token2.address, &250);
+    contract1.initialize(&InitConfig::new(&env, &admin, &token1.address, 250));
+    contract2.initialize(&InitConfig::new(&env, &admin, &token2.address, 250));
     
     contract1.register_agent(&agent);
     contract2.register_agent(&agent);
 
     // Create remittances
-    let rem1 = contract1.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
-    let rem2 = contract2.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let rem1 = contract1.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
+    let rem2 = contract2.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     // Complete first
     contract1.confirm_payout(&rem1);
@@ -2503,7 +2540,7 @@ fn test_remove_token_not_whitelisted() {
 
 #[test]
 #[should_panic(expected = "Error(Contract, #18)")]
-fn test_initialize_with_non_whitelisted_token() {
+fn test_initialize_without_tokens() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -2513,8 +2550,10 @@ fn test_initialize_with_non_whitelisted_token() {
 
     let contract = create_swiftremit_contract(&env);
 
-    // Try to initialize with non-whitelisted token - should fail
-    contract.initialize(&admin, &token.address, &250);
+    // Try to initialize without a settlement token - should fail
+    let mut config = InitConfig::new(&env, &admin, &token.address, 250);
+    config.tokens = soroban_sdk::Vec::new(&env);
+    contract.initialize(&config);
 }
 
 #[test]
@@ -2532,7 +2571,7 @@ fn test_initialize_with_whitelisted_token() {
     contract.whitelist_token(&admin, &token.address);
 
     // Now initialize should succeed
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     assert_eq!(contract.get_platform_fee_bps(), 250);
 }
@@ -2653,7 +2692,7 @@ fn test_multi_admin_whitelist_management() {
     contract.whitelist_token(&admin1, &token1.address);
     
     // Initialize with whitelisted token
-    contract.initialize(&admin1, &token1.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin1, &token1.address, 250));
     
     // Add second admin
     contract.add_admin(&admin1, &admin2);
@@ -2715,13 +2754,13 @@ fn test_whitelist_and_full_workflow() {
     contract.whitelist_token(&admin, &token.address);
 
     // Initialize with whitelisted token
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     // Register agent
     contract.register_agent(&agent);
 
     // Create and complete remittance
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
     contract.confirm_payout(&remittance_id);
 
     // Verify everything worked
@@ -2764,8 +2803,8 @@ fn test_whitelist_token_isolation_across_contracts() {
     assert!(contract2.is_token_whitelisted(&token3.address));
 
     // Initialize both contracts with their whitelisted tokens
-    contract1.initialize(&admin1, &token1.address, &250);
-    contract2.initialize(&admin2, &token3.address, &300);
+    contract1.initialize(&InitConfig::new(&env, &admin1, &token1.address, 250));
+    contract2.initialize(&InitConfig::new(&env, &admin2, &token3.address, 300));
 
     assert_eq!(contract1.get_platform_fee_bps(), 250);
     assert_eq!(contract2.get_platform_fee_bps(), 300);
@@ -2788,7 +2827,7 @@ fn test_cannot_use_removed_token() {
     contract2.whitelist_token(&admin, &token.address);
 
     // Initialize first contract
-    contract1.initialize(&admin, &token.address, &250);
+    contract1.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     // Remove token from whitelist for contract2
     contract2.remove_whitelisted_token(&admin, &token.address);
@@ -2871,7 +2910,7 @@ fn test_simulate_settlement_success() {
 
     let contract = create_swiftremit_contract(&env);
     contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250); // 2.5% fee
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250)); // 2.5% fee
 
     // Register both as agents
     contract.register_agent(&sender_a);
@@ -2883,10 +2922,10 @@ fn test_simulate_settlement_success() {
 
     // Create opposing remittances:
     // A -> B: 100 (fee: 2.5)
-    let id1 = contract.create_remittance(&sender_a, &sender_b, &100, &None);
+    let id1 = contract.create_remittance(&sender_a, &sender_b, &100, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     
     // B -> A: 90 (fee: 2.25)
-    let id2 = contract.create_remittance(&sender_b, &sender_a, &90, &None);
+    let id2 = contract.create_remittance(&sender_b, &sender_a, &90, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
 
     // Create batch settlement entries
     let mut entries = Vec::new(&env);
@@ -2925,7 +2964,7 @@ fn test_net_settlement_complete_offset() {
 
     let contract = create_swiftremit_contract(&env);
     contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     contract.register_agent(&sender_a);
     contract.register_agent(&sender_b);
@@ -2935,10 +2974,10 @@ fn test_net_settlement_complete_offset() {
 
     // Create equal opposing remittances:
     // A -> B: 100
-    let id1 = contract.create_remittance(&sender_a, &sender_b, &100, &None);
+    let id1 = contract.create_remittance(&sender_a, &sender_b, &100, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     
     // B -> A: 100
-    let id2 = contract.create_remittance(&sender_b, &sender_a, &100, &None);
+    let id2 = contract.create_remittance(&sender_b, &sender_a, &100, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
 
     let mut entries = Vec::new(&env);
     entries.push_back(crate::BatchSettlementEntry { remittance_id: id1 });
@@ -2971,12 +3010,12 @@ fn test_net_settlement_multiple_parties() {
 
     // Whitelist token
     contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
     // Mint and create remittance
     token.mint(&sender, &10000);
-    let remittance_id = contract.create_remittance(&sender, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &10000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     // Simulate settlement
     let simulation = contract.simulate_settlement(&remittance_id);
@@ -3004,7 +3043,7 @@ fn test_simulate_settlement_invalid_status() {
 
     let contract = create_swiftremit_contract(&env);
     contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &100); // 1% fee
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 100)); // 1% fee
 
     contract.register_agent(&party_a);
     contract.register_agent(&party_b);
@@ -3016,13 +3055,13 @@ fn test_simulate_settlement_invalid_status() {
 
     // Create a triangle of remittances:
     // A -> B: 100
-    let id1 = contract.create_remittance(&party_a, &party_b, &100, &None);
+    let id1 = contract.create_remittance(&party_a, &party_b, &100, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     
     // B -> C: 50
-    let id2 = contract.create_remittance(&party_b, &party_c, &50, &None);
+    let id2 = contract.create_remittance(&party_b, &party_c, &50, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     
     // C -> A: 30
-    let id3 = contract.create_remittance(&party_c, &party_a, &30, &None);
+    let id3 = contract.create_remittance(&party_c, &party_a, &30, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
 
     let mut entries = Vec::new(&env);
     entries.push_back(crate::BatchSettlementEntry { remittance_id: id1 });
@@ -3053,7 +3092,7 @@ fn test_net_settlement_order_independence() {
 
     let contract = create_swiftremit_contract(&env);
     contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     contract.register_agent(&sender_a);
     contract.register_agent(&sender_b);
@@ -3061,23 +3100,25 @@ fn test_net_settlement_order_independence() {
     token.mint(&sender_a, &2000);
     token.mint(&sender_b, &2000);
 
+    // Fees accrue when the remittances are created
+    let fees_before = contract.get_accumulated_fees();
+
     // First batch: A->B then B->A
-    let id1 = contract.create_remittance(&sender_a, &sender_b, &100, &None);
-    let id2 = contract.create_remittance(&sender_b, &sender_a, &90, &None);
+    let id1 = contract.create_remittance(&sender_a, &sender_b, &100, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let id2 = contract.create_remittance(&sender_b, &sender_a, &90, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
 
     let mut entries1 = Vec::new(&env);
     entries1.push_back(crate::BatchSettlementEntry { remittance_id: id1 });
     entries1.push_back(crate::BatchSettlementEntry { remittance_id: id2 });
 
-    let fees_before = contract.get_accumulated_fees();
     let result1 = contract.batch_settle_with_netting(&entries1);
     assert!(result1.is_ok());
     let fees_after_batch1 = contract.get_accumulated_fees();
     let fees_batch1 = fees_after_batch1 - fees_before;
 
     // Second batch: B->A then A->B (reversed order)
-    let id3 = contract.create_remittance(&sender_b, &sender_a, &90, &None);
-    let id4 = contract.create_remittance(&sender_a, &sender_b, &100, &None);
+    let id3 = contract.create_remittance(&sender_b, &sender_a, &90, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let id4 = contract.create_remittance(&sender_a, &sender_b, &100, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
 
     let mut entries2 = Vec::new(&env);
     entries2.push_back(crate::BatchSettlementEntry { remittance_id: id3 });
@@ -3105,12 +3146,12 @@ fn test_net_settlement_empty_batch() {
 
     // Whitelist token
     contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
     // Mint and create remittance
     token.mint(&sender, &10000);
-    let remittance_id = contract.create_remittance(&sender, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &10000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     // Complete the remittance
     contract.confirm_payout(&remittance_id);
@@ -3135,7 +3176,7 @@ fn test_simulate_settlement_nonexistent() {
     let contract = create_swiftremit_contract(&env);
 
     contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     let entries = Vec::new(&env);
     contract.batch_settle_with_netting(&entries);
@@ -3156,7 +3197,7 @@ fn test_net_settlement_exceeds_max_batch_size() {
 
     let contract = create_swiftremit_contract(&env);
     contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
     token.mint(&sender, &100000);
@@ -3164,7 +3205,7 @@ fn test_net_settlement_exceeds_max_batch_size() {
     // Create more than MAX_BATCH_SIZE remittances
     let mut entries = Vec::new(&env);
     for _ in 0..51 {
-        let id = contract.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
+        let id = contract.create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
         entries.push_back(crate::BatchSettlementEntry { remittance_id: id });
     }
 
@@ -3178,7 +3219,7 @@ fn test_net_settlement_duplicate_ids() {
 
     // Whitelist token
     contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     // Simulate non-existent remittance
     let simulation = contract.simulate_settlement(&999);
@@ -3203,12 +3244,12 @@ fn test_simulate_settlement_when_paused() {
 
     let contract = create_swiftremit_contract(&env);
     contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
     token.mint(&sender, &1000);
 
-    let id = contract.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
+    let id = contract.create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     let mut entries = Vec::new(&env);
     entries.push_back(crate::BatchSettlementEntry { remittance_id: id });
@@ -3242,13 +3283,13 @@ fn test_net_settlement_already_completed() {
     // Whitelist token
 
     contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
 
     token.mint(&sender, &1000);
 
-    let id = contract.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
+    let id = contract.create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     // Complete it first
     contract.confirm_payout(&id);
@@ -3265,7 +3306,7 @@ fn test_net_settlement_already_completed() {
 fn test_net_settlement_when_paused() {
     // Mint and create remittance
     token.mint(&sender, &10000);
-    let remittance_id = contract.create_remittance(&sender, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &10000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     // Pause contract
     contract.pause();
@@ -3303,13 +3344,13 @@ fn test_settlement_id_returned() {
 
 
     contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
 
     token.mint(&sender, &1000);
 
-    let id = contract.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
+    let id = contract.create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     // Pause the contract
     contract.pause(&admin);
@@ -3324,7 +3365,7 @@ fn test_settlement_id_returned() {
 fn test_net_settlement_fee_preservation() {
 
     token.mint(&sender, &10000);
-    let remittance_id = contract.create_remittance(&sender, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &10000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     // Confirm payout should return the settlement ID
     let settlement_id = contract.confirm_payout(&remittance_id);
@@ -3353,7 +3394,7 @@ fn test_settlement_ids_sequential() {
 
     let contract = create_swiftremit_contract(&env);
     contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &500); // 5% fee
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 500)); // 5% fee
 
     contract.register_agent(&sender_a);
     contract.register_agent(&sender_b);
@@ -3361,10 +3402,13 @@ fn test_settlement_ids_sequential() {
     token.mint(&sender_a, &10000);
     token.mint(&sender_b, &10000);
 
+    // Fees accrue when the remittances are created
+    let fees_before = contract.get_accumulated_fees();
+
     // Create multiple remittances with different amounts
-    let id1 = contract.create_remittance(&sender_a, &sender_b, &1000, &None);
-    let id2 = contract.create_remittance(&sender_b, &sender_a, &800, &None);
-    let id3 = contract.create_remittance(&sender_a, &sender_b, &500, &None);
+    let id1 = contract.create_remittance(&sender_a, &sender_b, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let id2 = contract.create_remittance(&sender_b, &sender_a, &800, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let id3 = contract.create_remittance(&sender_a, &sender_b, &500, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
 
     // Calculate expected fees manually
     let fee1 = 1000 * 500 / 10000; // 50
@@ -3377,7 +3421,6 @@ fn test_settlement_ids_sequential() {
     entries.push_back(crate::BatchSettlementEntry { remittance_id: id2 });
     entries.push_back(crate::BatchSettlementEntry { remittance_id: id3 });
 
-    let fees_before = contract.get_accumulated_fees();
     let result = contract.batch_settle_with_netting(&entries);
     assert!(result.is_ok());
 
@@ -3399,15 +3442,15 @@ fn test_net_settlement_large_batch() {
     let contract = create_swiftremit_contract(&env);
 
     contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
     token.mint(&sender, &100000);
 
     // Create multiple remittances and verify IDs are sequential
-    let id1 = contract.create_remittance(&sender, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
-    let id2 = contract.create_remittance(&sender, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
-    let id3 = contract.create_remittance(&sender, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
+    let id1 = contract.create_remittance(&sender, &agent, &10000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
+    let id2 = contract.create_remittance(&sender, &agent, &10000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
+    let id3 = contract.create_remittance(&sender, &agent, &10000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     assert_eq!(id1, 1);
     assert_eq!(id2, 2);
@@ -3449,18 +3492,18 @@ fn test_settlement_id_uniqueness() {
     let agent = Address::generate(&env);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
     // Test zero amount
     let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        contract.create_remittance(&sender, &agent, &0, &None);
+        contract.create_remittance(&sender, &agent, &0, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     }));
     assert!(result.is_err());
 
     // Test negative amount
     let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        contract.create_remittance(&sender, &agent, &-100, &None);
+        contract.create_remittance(&sender, &agent, &-100, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     }));
     assert!(result.is_err());
 }
@@ -3468,7 +3511,7 @@ fn test_settlement_id_uniqueness() {
 #[test]
 fn test_validation_prevents_invalid_fee_bps() {
     contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &100);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 100));
     contract.register_agent(&agent);
 
     token.mint(&sender, &1000000);
@@ -3476,7 +3519,7 @@ fn test_validation_prevents_invalid_fee_bps() {
     // Create maximum allowed batch size
     let mut entries = Vec::new(&env);
     for _ in 0..50 {
-        let id = contract.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
+        let id = contract.create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
         entries.push_back(crate::BatchSettlementEntry { remittance_id: id });
     }
 
@@ -3500,12 +3543,12 @@ fn test_net_settlement_reduces_transfer_count() {
 
     // Test fee > 10000 in initialize
     let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        contract.initialize(&admin, &token.address, &10001);
+        contract.initialize(&InitConfig::new(&env, &admin, &token.address, 10001));
     }));
     assert!(result.is_err());
 
     // Initialize with valid fee
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     // Test fee > 10000 in update_fee
     let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
@@ -3521,7 +3564,7 @@ fn test_validation_prevents_unregistered_agent() {
 
     let contract = create_swiftremit_contract(&env);
     contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     contract.register_agent(&party_a);
     contract.register_agent(&party_b);
@@ -3533,9 +3576,9 @@ fn test_validation_prevents_unregistered_agent() {
     let mut entries = Vec::new(&env);
     for i in 0..10 {
         let id = if i % 2 == 0 {
-            contract.create_remittance(&party_a, &party_b, &100, &None)
+            contract.create_remittance(&party_a, &party_b, &100, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None)
         } else {
-            contract.create_remittance(&party_b, &party_a, &100, &None)
+            contract.create_remittance(&party_b, &party_a, &100, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None)
         };
         entries.push_back(crate::BatchSettlementEntry { remittance_id: id });
     }
@@ -3565,7 +3608,7 @@ fn test_net_settlement_mathematical_correctness() {
 
     let contract = create_swiftremit_contract(&env);
     contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &200); // 2% fee
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 200)); // 2% fee
 
     contract.register_agent(&party_a);
     contract.register_agent(&party_b);
@@ -3575,13 +3618,13 @@ fn test_net_settlement_mathematical_correctness() {
 
     // Create specific amounts to test mathematical correctness
     // A -> B: 1000, 500, 300 = 1800 total
-    let id1 = contract.create_remittance(&party_a, &party_b, &1000, &None);
-    let id2 = contract.create_remittance(&party_a, &party_b, &500, &None);
-    let id3 = contract.create_remittance(&party_a, &party_b, &300, &None);
+    let id1 = contract.create_remittance(&party_a, &party_b, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let id2 = contract.create_remittance(&party_a, &party_b, &500, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let id3 = contract.create_remittance(&party_a, &party_b, &300, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     
     // B -> A: 800, 400 = 1200 total
-    let id4 = contract.create_remittance(&party_b, &party_a, &800, &None);
-    let id5 = contract.create_remittance(&party_b, &party_a, &400, &None);
+    let id4 = contract.create_remittance(&party_b, &party_a, &800, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let id5 = contract.create_remittance(&party_b, &party_a, &400, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
 
     // Net should be: 1800 - 1200 = 600 from A to B
 
@@ -3615,16 +3658,16 @@ fn test_net_settlement_mathematical_correctness() {
     let contract = create_swiftremit_contract(&env);
 
     contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
     token.mint(&sender1, &50000);
     token.mint(&sender2, &50000);
 
     // Create remittances from different senders
-    let id1 = contract.create_remittance(&sender1, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
-    let id2 = contract.create_remittance(&sender2, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
-    let id3 = contract.create_remittance(&sender1, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
+    let id1 = contract.create_remittance(&sender1, &agent, &10000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
+    let id2 = contract.create_remittance(&sender2, &agent, &10000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
+    let id3 = contract.create_remittance(&sender1, &agent, &10000, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     // All IDs should be unique
     assert_ne!(id1, id2);
@@ -3658,7 +3701,7 @@ fn test_export_migration_state() {
 
     let contract = create_swiftremit_contract(&env);
     contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     // Export state
     let snapshot = contract.export_migration_state(&admin);
@@ -3685,11 +3728,11 @@ fn test_export_import_migration_state() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     // Try to create remittance with unregistered agent
     let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        contract.create_remittance(&sender, &unregistered_agent, &1000, &None);
+        contract.create_remittance(&sender, &unregistered_agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     }));
     assert!(result.is_err());
 }
@@ -3700,14 +3743,14 @@ fn test_validation_prevents_operations_on_nonexistent_remittance() {
     // Create and populate first contract
     let contract1 = create_swiftremit_contract(&env);
     contract1.whitelist_token(&admin, &token.address);
-    contract1.initialize(&admin, &token.address, &250);
+    contract1.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     let sender = Address::generate(&env);
     let agent = Address::generate(&env);
     contract1.register_agent(&agent);
 
     token.mint(&sender, &1000);
-    let id = contract1.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
+    let id = contract1.create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
 
     // Export state
     let snapshot = contract1.export_migration_state(&admin).unwrap();
@@ -3719,7 +3762,8 @@ fn test_validation_prevents_operations_on_nonexistent_remittance() {
 
     // Verify state was imported correctly
     assert_eq!(contract2.get_platform_fee_bps(), 250);
-    assert_eq!(contract2.get_accumulated_fees(), 0);
+    // The pending remittance's fee accrued at creation
+    assert_eq!(contract2.get_accumulated_fees(), 2);
 
     let remittance = contract2.get_remittance(&id);
     assert!(remittance.is_ok());
@@ -3737,7 +3781,7 @@ fn test_verify_migration_snapshot() {
 
     let contract = create_swiftremit_contract(&env);
     contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     // Export and verify
     let snapshot = contract.export_migration_state(&admin).unwrap();
@@ -3757,7 +3801,7 @@ fn test_migration_hash_detects_tampering() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     // Try to confirm payout for non-existent remittance
     let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
@@ -3770,7 +3814,7 @@ fn test_migration_hash_detects_tampering() {
         contract.cancel_remittance(&999);
     }));
     contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     // Export snapshot
     let mut snapshot = contract.export_migration_state(&admin).unwrap();
@@ -3802,13 +3846,13 @@ fn test_import_fails_if_already_initialized() {
     // Create and export from first contract
     let contract1 = create_swiftremit_contract(&env);
     contract1.whitelist_token(&admin, &token.address);
-    contract1.initialize(&admin, &token.address, &250);
+    contract1.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     let snapshot = contract1.export_migration_state(&admin).unwrap();
 
     // Create and initialize second contract
     let contract2 = create_swiftremit_contract(&env);
     contract2.whitelist_token(&admin, &token.address);
-    contract2.initialize(&admin, &token.address, &300);
+    contract2.initialize(&InitConfig::new(&env, &admin, &token.address, 300));
 
     // Import should fail because contract2 is already initialized
     contract2.import_migration_state(&admin, snapshot);
@@ -3825,7 +3869,7 @@ fn test_export_migration_batch() {
 
     let contract = create_swiftremit_contract(&env);
     contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     let sender = Address::generate(&env);
     let agent = Address::generate(&env);
@@ -3835,7 +3879,7 @@ fn test_export_migration_batch() {
 
     // Create 10 remittances
     for _ in 0..10 {
-        contract.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
+        contract.create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
     }
 
     // Export in batches of 5
@@ -3867,7 +3911,7 @@ fn test_import_migration_batch() {
     // Create and populate first contract
     let contract1 = create_swiftremit_contract(&env);
     contract1.whitelist_token(&admin, &token.address);
-    contract1.initialize(&admin, &token.address, &250);
+    contract1.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     let sender = Address::generate(&env);
     let agent = Address::generate(&env);
@@ -3877,7 +3921,7 @@ fn test_import_migration_batch() {
 
     // Create 5 remittances
     for _ in 0..5 {
-        contract1.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
+        contract1.create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
     }
 
     // Export batch
@@ -3886,7 +3930,7 @@ fn test_import_migration_batch() {
     // Create new contract and import batch
     let contract2 = create_swiftremit_contract(&env);
     contract2.whitelist_token(&admin, &token.address);
-    contract2.initialize(&admin, &token.address, &250);
+    contract2.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     let result = contract2.import_migration_batch(&admin, batch);
     assert!(result.is_ok());
@@ -3912,10 +3956,10 @@ fn test_migration_batch_hash_verification() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     contract.confirm_payout(&remittance_id);
 
     // Try to cancel already completed remittance
@@ -3930,7 +3974,7 @@ fn test_validation_prevents_withdraw_with_no_fees() {
 
     let contract1 = create_swiftremit_contract(&env);
     contract1.whitelist_token(&admin, &token.address);
-    contract1.initialize(&admin, &token.address, &250);
+    contract1.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     let sender = Address::generate(&env);
     let agent = Address::generate(&env);
@@ -3940,7 +3984,7 @@ fn test_validation_prevents_withdraw_with_no_fees() {
 
     // Create remittances
     for _ in 0..5 {
-        contract1.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
+        contract1.create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
     }
 
     // Export batch
@@ -3956,7 +4000,7 @@ fn test_validation_prevents_withdraw_with_no_fees() {
     // Import should fail due to hash mismatch
     let contract2 = create_swiftremit_contract(&env);
     contract2.whitelist_token(&admin, &token.address);
-    contract2.initialize(&admin, &token.address, &250);
+    contract2.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     let result = contract2.import_migration_batch(&admin, batch);
     assert!(result.is_err());
@@ -3974,7 +4018,7 @@ fn test_migration_preserves_all_data() {
     // Create and populate first contract
     let contract1 = create_swiftremit_contract(&env);
     contract1.whitelist_token(&admin, &token.address);
-    contract1.initialize(&admin, &token.address, &250);
+    contract1.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     let sender = Address::generate(&env);
     let agent = Address::generate(&env);
@@ -3983,7 +4027,7 @@ fn test_migration_preserves_all_data() {
     token.mint(&sender, &1000);
 
     // Create remittance and complete it
-    let id = contract1.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
+    let id = contract1.create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
     contract1.confirm_payout(&id);
 
     // Export state
@@ -4018,7 +4062,7 @@ fn test_migration_deterministic_hash() {
 
     let contract = create_swiftremit_contract(&env);
     contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     // Export twice
     let snapshot1 = contract.export_migration_state(&admin).unwrap();
@@ -4042,11 +4086,11 @@ fn test_export_batch_invalid_size() {
     let recipient = Address::generate(&env);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     // Try to withdraw when no fees accumulated
     let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        contract.withdraw_fees(&recipient);
+        contract.withdraw_fees(&token.address, &recipient);
     }));
     assert!(result.is_err());
 }
@@ -4056,7 +4100,7 @@ fn test_validation_prevents_paused_operations() {
 
     let contract = create_swiftremit_contract(&env);
     contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     // Try to export with batch size > MAX_MIGRATION_BATCH_SIZE
     contract.export_migration_batch(&admin, 0, 101);
@@ -4074,7 +4118,7 @@ fn test_export_batch_zero_size() {
 
     let contract = create_swiftremit_contract(&env);
     contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     // Try to export with zero batch size
     contract.export_migration_batch(&admin, 0, 0);
@@ -4094,10 +4138,10 @@ fn test_migration_with_multiple_remittance_statuses() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
 
     // Pause contract
     contract.pause();
@@ -4114,7 +4158,7 @@ fn test_validation_allows_valid_operations() {
 
     let contract1 = create_swiftremit_contract(&env);
     contract1.whitelist_token(&admin, &token.address);
-    contract1.initialize(&admin, &token.address, &250);
+    contract1.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     let sender = Address::generate(&env);
     let agent = Address::generate(&env);
@@ -4123,10 +4167,10 @@ fn test_validation_allows_valid_operations() {
     token.mint(&sender, &10000);
 
     // Create remittances with different statuses
-    let id1 = contract1.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None); // Pending
-    let id2 = contract1.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
+    let id1 = contract1.create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None); // Pending
+    let id2 = contract1.create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
     contract1.confirm_payout(&id2); // Completed
-    let id3 = contract1.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
+    let id3 = contract1.create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &default_currency(&env), &default_country(&env), &None, &None, &None, &None, &None);
     contract1.cancel_remittance(&id3); // Cancelled
 
     // Export and import
@@ -4159,13 +4203,13 @@ fn test_rate_limit_initialization() {
     let contract = create_swiftremit_contract(&env);
     
     // Valid initialization
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     
     // Valid agent registration
     contract.register_agent(&agent);
     
     // Valid remittance creation
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     assert_eq!(remittance_id, 1);
     
     // Valid payout confirmation
@@ -4180,7 +4224,7 @@ fn test_validation_structured_error_for_expired_settlement() {
     token.mint(&sender, &20000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     // Check default rate limit config
     let (max_requests, window_seconds, enabled) = contract.get_rate_limit_config();
@@ -4204,14 +4248,14 @@ fn test_update_rate_limit() {
     token.mint(&sender, &20000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
     // Create remittance with past expiry
     let current_time = env.ledger().timestamp();
     let past_expiry = current_time.saturating_sub(3600);
     
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &Some(past_expiry));
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &Some(past_expiry), &None, &None, &None, &None);
 
     // Validation should prevent expired settlement
     let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
@@ -4229,10 +4273,10 @@ fn test_validation_prevents_duplicate_settlement() {
     contract.set_daily_limit(&currency, &country, &10000);
 
     // First transfer of 6000 should succeed
-    contract.create_remittance(&sender, &agent, &6000, &currency, &country, &None);
+    contract.create_remittance(&sender, &agent, &6000, &PurposeCode::FamilySupport, &currency, &country, &None, &None, &None, &None, &None);
 
     // Second transfer of 5000 should fail (total 11000 > 10000)
-    contract.create_remittance(&sender, &agent, &5000, &currency, &country, &None);
+    contract.create_remittance(&sender, &agent, &5000, &PurposeCode::FamilySupport, &currency, &country, &None, &None, &None, &None, &None);
 }
 
 #[test]
@@ -4250,10 +4294,10 @@ fn test_daily_limit_rolling_window() {
     token.mint(&sender, &30000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
 
     // First settlement succeeds
     contract.confirm_payout(&remittance_id);
@@ -4301,11 +4345,11 @@ fn test_rate_limit_status() {
     token.mint(&sender, &30000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
     // Test all validation passes for valid request
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     assert_eq!(remittance_id, 1);
 
     let remittance = contract.get_remittance(&remittance_id);
@@ -4326,10 +4370,10 @@ fn test_validation_comprehensive_confirm_payout() {
     contract.set_daily_limit(&eur, &us, &15000);
 
     // Transfer 9000 in USD should succeed
-    contract.create_remittance(&sender, &agent, &9000, &usd, &us, &None);
+    contract.create_remittance(&sender, &agent, &9000, &PurposeCode::FamilySupport, &usd, &us, &None, &None, &None, &None, &None);
 
     // Transfer 14000 in EUR should succeed (different currency limit)
-    contract.create_remittance(&sender, &agent, &14000, &eur, &us, &None);
+    contract.create_remittance(&sender, &agent, &14000, &PurposeCode::FamilySupport, &eur, &us, &None, &None, &None, &None, &None);
 
     assert_eq!(token.balance(&contract.address), 23000);
 }
@@ -4349,13 +4393,13 @@ fn test_daily_limit_different_countries() {
     token.mint(&sender, &30000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
     let current_time = env.ledger().timestamp();
     let future_expiry = current_time + 7200;
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &Some(future_expiry));
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &Some(future_expiry), &None, &None, &None, &None);
 
     // All validations should pass
     contract.confirm_payout(&remittance_id);
@@ -4376,10 +4420,10 @@ fn test_validation_comprehensive_cancel_remittance() {
     contract.set_daily_limit(&usd, &uk, &15000);
 
     // Transfer 9000 to US should succeed
-    contract.create_remittance(&sender, &agent, &9000, &usd, &us, &None);
+    contract.create_remittance(&sender, &agent, &9000, &PurposeCode::FamilySupport, &usd, &us, &None, &None, &None, &None, &None);
 
     // Transfer 14000 to UK should succeed (different country limit)
-    contract.create_remittance(&sender, &agent, &14000, &usd, &uk, &None);
+    contract.create_remittance(&sender, &agent, &14000, &PurposeCode::FamilySupport, &usd, &uk, &None, &None, &None, &None, &None);
 
     assert_eq!(token.balance(&contract.address), 23000);
 }
@@ -4396,10 +4440,10 @@ fn test_daily_limit_no_limit_configured() {
     token.mint(&sender, &100000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
 
     // All validations should pass
     contract.cancel_remittance(&remittance_id);
@@ -4415,7 +4459,7 @@ fn test_validation_comprehensive_withdraw_fees() {
     let country = String::from_str(&env, "US");
 
     // No limit configured, large transfer should succeed
-    let remittance_id = contract.create_remittance(&sender, &agent, &50000, &currency, &country, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &50000, &PurposeCode::FamilySupport, &currency, &country, &None, &None, &None, &None, &None);
     assert_eq!(remittance_id, 1);
     assert_eq!(token.balance(&contract.address), 50000);
 }
@@ -4441,14 +4485,14 @@ fn test_daily_limit_multiple_users() {
     token.mint(&sender2, &20000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     contract.confirm_payout(&remittance_id);
 
     // All validations should pass
-    contract.withdraw_fees(&recipient);
+    contract.withdraw_fees(&token.address, &recipient);
 
     assert_eq!(token.balance(&recipient), 25);
     assert_eq!(contract.get_accumulated_fees(), 0);
@@ -4463,8 +4507,8 @@ fn test_validation_edge_case_boundary_fee() {
     contract.set_daily_limit(&currency, &country, &10000);
 
     // Each user should have their own limit
-    contract.create_remittance(&sender1, &agent, &9000, &currency, &country, &None);
-    contract.create_remittance(&sender2, &agent, &9000, &currency, &country, &None);
+    contract.create_remittance(&sender1, &agent, &9000, &PurposeCode::FamilySupport, &currency, &country, &None, &None, &None, &None, &None);
+    contract.create_remittance(&sender2, &agent, &9000, &PurposeCode::FamilySupport, &currency, &country, &None, &None, &None, &None, &None);
 
     assert_eq!(token.balance(&contract.address), 18000);
 }
@@ -4480,7 +4524,7 @@ fn test_rate_limit_disable() {
     let contract = create_swiftremit_contract(&env);
 
     // Test boundary: 10000 should be valid (100%)
-    contract.initialize(&admin, &token.address, &10000);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 10000));
     assert_eq!(contract.get_platform_fee_bps(), 10000);
 
     // Test boundary: 0 should be valid (0%)
@@ -4490,7 +4534,7 @@ fn test_rate_limit_disable() {
 
 #[test]
 fn test_validation_edge_case_minimum_amount() {
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     let currency = String::from_str(&env, "USD");
     let country = String::from_str(&env, "US");
@@ -4514,10 +4558,10 @@ fn test_daily_limit_exact_limit() {
     token.mint(&sender, &20000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
 
     // Minimum valid amount is 1
-    let remittance_id = contract.create_remittance(&sender, &agent, &1, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     assert_eq!(remittance_id, 1);
 
     let remittance = contract.get_remittance(&remittance_id);
@@ -4742,12 +4786,12 @@ fn test_error_handler_integration_with_contract() {
     let agent = Address::generate(&env);
     
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
     
     // Test that errors are properly handled through the system
     let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        contract.create_remittance(&sender, &agent, &0, &None);
+        contract.create_remittance(&sender, &agent, &0, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
     }));
     
     assert!(result.is_err(), "Should fail with InvalidAmount error");
@@ -4860,4 +4904,5545 @@ fn test_normalize_symbol_already_upper() {
     let input = soroban_sdk::String::from_str(&env, "USD");
     let result = normalize_symbol(&env, &input);
     assert_eq!(result, soroban_sdk::String::from_str(&env, "USD"));
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_insurance_premium_refunded_on_cancel() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.set_insurance_premium(&100);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let policy = contract.purchase_insurance(&remittance_id);
+    assert_eq!(policy.premium, 10);
+    assert_eq!(get_token_balance(&token, &sender), 8990);
+
+    contract.cancel_remittance(&remittance_id);
+
+    let policy = contract.get_insurance_policy(&remittance_id).unwrap();
+    assert_eq!(policy.status, crate::PremiumStatus::Refunded);
+    assert_eq!(policy.refunded, 10);
+    assert_eq!(get_token_balance(&token, &sender), 10000);
+    assert_eq!(contract.get_insurance_fund(), 0);
+}
+
+#[test]
+fn test_insurance_premium_consumed_on_payout() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.set_insurance_premium(&100);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.purchase_insurance(&remittance_id);
+    contract.confirm_payout(&remittance_id);
+
+    let policy = contract.get_insurance_policy(&remittance_id).unwrap();
+    assert_eq!(policy.status, crate::PremiumStatus::Consumed);
+    assert_eq!(contract.get_insurance_fund(), 10);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_purchase_insurance_when_disabled() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.purchase_insurance(&remittance_id);
+}
+
+#[test]
+fn test_escrow_balance_tracks_pending_remittances() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let id1 = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let id2 = contract.create_remittance(&sender, &agent, &2000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    // Only the net amounts are escrowed; the fees have accrued
+    assert_eq!(contract.get_escrow_balance(), 2925);
+
+    contract.confirm_payout(&id1);
+    assert_eq!(contract.get_escrow_balance(), 1950);
+
+    contract.cancel_remittance(&id2);
+    assert_eq!(contract.get_escrow_balance(), 0);
+}
+
+#[test]
+fn test_reconcile_balanced() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.create_remittance(&sender, &agent, &500, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout(&remittance_id);
+
+    let report = contract.reconcile();
+    assert_eq!(report.token_balance, 525);
+    assert_eq!(report.escrow, 500);
+    assert_eq!(report.accumulated_fees, 25);
+    assert_eq!(report.discrepancy, 0);
+}
+
+#[test]
+fn test_reconcile_reports_discrepancy() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+
+    // Tokens sent directly to the contract are not tracked by any ledger
+    token.mint(&contract.address, &7);
+
+    let report = contract.reconcile();
+    assert_eq!(report.discrepancy, 7);
+    assert!(!env.events().all().is_empty());
+}
+
+#[test]
+fn test_get_remittances_in_range() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    for _ in 0..4 {
+        contract.create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    }
+    contract.confirm_payout(&2);
+
+    let all = contract.get_remittances_in_range(&1, &4);
+    assert_eq!(all.len(), 4);
+    assert_eq!(all.get(0).unwrap().id, 1);
+    assert_eq!(all.get(3).unwrap().id, 4);
+
+    // Bucket summaries reflect status updates
+    let middle = contract.get_remittances_in_range(&2, &3);
+    assert_eq!(middle.len(), 2);
+    assert_eq!(middle.get(0).unwrap().status, crate::RemittanceStatus::Completed);
+
+    assert_eq!(contract.get_remittances_in_range(&10, &20).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_get_remittances_in_range_inverted() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+
+    contract.get_remittances_in_range(&5, &1);
+}
+
+#[test]
+fn test_auto_matching_follows_weights() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent_a = Address::generate(&env);
+    let agent_b = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent_a);
+    contract.register_agent(&agent_b);
+
+    contract.set_matching_weights(&crate::MatchingWeights {
+        stake_weight: 1,
+        reputation_weight: 0,
+        capacity_weight: 0,
+    });
+    contract.set_agent_profile(&agent_a, &crate::AgentProfile {
+        stake_coverage_bps: 7500,
+        reputation_bps: 5000,
+        capacity_bps: 10000,
+    });
+    contract.set_agent_profile(&agent_b, &crate::AgentProfile {
+        stake_coverage_bps: 2500,
+        reputation_bps: 5000,
+        capacity_bps: 10000,
+    });
+
+    let mut count_a = 0;
+    let mut count_b = 0;
+    for _ in 0..4 {
+        let id = contract.create_remittance_auto(&sender, &100, &None);
+        if contract.get_remittance(&id).agent == agent_a {
+            count_a += 1;
+        } else {
+            count_b += 1;
+        }
+    }
+
+    assert_eq!(count_a, 3);
+    assert_eq!(count_b, 1);
+}
+
+#[test]
+fn test_auto_matching_skips_agents_without_capacity() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent_a = Address::generate(&env);
+    let agent_b = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent_a);
+    contract.register_agent(&agent_b);
+
+    contract.set_agent_profile(&agent_a, &crate::AgentProfile {
+        stake_coverage_bps: 10000,
+        reputation_bps: 10000,
+        capacity_bps: 0,
+    });
+    contract.set_agent_profile(&agent_b, &crate::AgentProfile {
+        stake_coverage_bps: 1000,
+        reputation_bps: 1000,
+        capacity_bps: 1000,
+    });
+
+    let id = contract.create_remittance_auto(&sender, &100, &None);
+    assert_eq!(contract.get_remittance(&id).agent, agent_b);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5)")]
+fn test_auto_matching_empty_pool() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+
+    contract.create_remittance_auto(&sender, &100, &None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #24)")]
+fn test_suspended_agent_cannot_accept_new_remittances() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    contract.suspend_agent(&agent);
+    assert!(contract.is_agent_suspended(&agent));
+    assert!(contract.is_agent_registered(&agent));
+
+    contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+}
+
+#[test]
+fn test_suspended_agent_can_confirm_existing_payouts() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.suspend_agent(&agent);
+    contract.confirm_payout(&remittance_id);
+
+    assert_eq!(get_token_balance(&token, &agent), 975);
+}
+
+#[test]
+fn test_reinstated_agent_accepts_remittances() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    contract.suspend_agent(&agent);
+    contract.reinstate_agent(&agent);
+    assert!(!contract.is_agent_suspended(&agent));
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(remittance_id, 1);
+}
+
+#[cfg(feature = "kyc")]
+#[test]
+fn test_regular_sender_unaffected_by_cooling_off() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    contract.set_cooling_off_period(&3600);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(remittance_id, 1);
+}
+
+#[cfg(feature = "kyc")]
+#[test]
+#[should_panic(expected = "Error(Contract, #25)")]
+fn test_high_risk_sender_requires_intent() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    contract.set_high_risk_sender(&sender, &true);
+
+    contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+}
+
+#[cfg(feature = "kyc")]
+#[test]
+#[should_panic(expected = "Error(Contract, #25)")]
+fn test_high_risk_sender_blocked_during_cooling_off() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    contract.set_cooling_off_period(&3600);
+    contract.set_high_risk_sender(&sender, &true);
+    contract.declare_intent(&sender, &agent, &1000);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 1800;
+    });
+
+    contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+}
+
+#[cfg(feature = "kyc")]
+#[test]
+fn test_high_risk_sender_can_send_after_cooling_off() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    contract.set_cooling_off_period(&3600);
+    contract.set_high_risk_sender(&sender, &true);
+    let intent = contract.declare_intent(&sender, &agent, &1000);
+    assert_eq!(intent.available_at, intent.declared_at + 3600);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 3600;
+    });
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(remittance_id, 1);
+    assert!(contract.get_intent(&sender).is_none());
+}
+
+#[test]
+fn test_distribute_fees_by_split() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let platform = Address::generate(&env);
+    let operations = Address::generate(&env);
+    let partner = Address::generate(&env);
+    let mut splits = soroban_sdk::Vec::new(&env);
+    splits.push_back((platform.clone(), 6000u32));
+    splits.push_back((operations.clone(), 3000u32));
+    splits.push_back((partner.clone(), 1000u32));
+    contract.set_fee_split(&splits);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout(&remittance_id);
+
+    let distributed = contract.distribute_fees();
+
+    assert_eq!(distributed, 25);
+    assert_eq!(get_token_balance(&token, &platform), 15);
+    assert_eq!(get_token_balance(&token, &operations), 7);
+    assert_eq!(get_token_balance(&token, &partner), 3);
+    assert_eq!(contract.get_accumulated_fees(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")]
+fn test_fee_split_must_total_10000_bps() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+
+    let mut splits = soroban_sdk::Vec::new(&env);
+    splits.push_back((Address::generate(&env), 6000u32));
+    splits.push_back((Address::generate(&env), 3000u32));
+    contract.set_fee_split(&splits);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_distribute_fees_requires_split() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout(&remittance_id);
+
+    contract.distribute_fees();
+}
+
+#[test]
+fn test_daily_schedule_batches_payouts_until_window_closes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    contract.set_settlement_schedule(&agent, &crate::SettlementSchedule::Daily);
+
+    let id1 = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let id2 = contract.create_remittance(&sender, &agent, &2000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout(&id1);
+    contract.confirm_payout(&id2);
+
+    assert_eq!(get_token_balance(&token, &agent), 0);
+    let batch = contract.get_open_settlement(&agent).unwrap();
+    assert_eq!(batch.amount, 2925);
+    assert_eq!(batch.remittance_ids.len(), 2);
+    assert_eq!(contract.reconcile().discrepancy, 0);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = batch.window_end;
+    });
+
+    let settled = contract.run_settlement(&agent);
+    assert_eq!(settled.amount, 2925);
+    assert_eq!(get_token_balance(&token, &agent), 2925);
+    assert!(contract.get_open_settlement(&agent).is_none());
+    assert_eq!(contract.reconcile().discrepancy, 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_run_settlement_before_window_closes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    contract.set_settlement_schedule(&agent, &crate::SettlementSchedule::Weekly);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout(&remittance_id);
+
+    contract.run_settlement(&agent);
+}
+
+#[test]
+fn test_confirm_payout_with_receipt_stores_hash() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert!(contract.get_receipt_hash(&remittance_id).is_none());
+
+    let receipt_hash = soroban_sdk::BytesN::from_array(&env, &[7u8; 32]);
+    contract.confirm_payout_with_receipt(&remittance_id, &receipt_hash);
+
+    assert_eq!(contract.get_receipt_hash(&remittance_id), Some(receipt_hash));
+    assert_eq!(get_token_balance(&token, &agent), 975);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #26)")]
+fn test_create_remittance_rate_limited_per_sender() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    contract.set_create_rate_limit(&2, &10);
+
+    contract.create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+}
+
+#[test]
+fn test_create_rate_limit_resets_after_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let other_sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+    token.mint(&other_sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    contract.set_create_rate_limit(&1, &10);
+
+    contract.create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    // Other senders have their own allowance
+    contract.create_remittance(&other_sender, &agent, &100, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number += 10;
+    });
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(remittance_id, 3);
+}
+
+#[test]
+fn test_org_spenders_share_template() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let owner = Address::generate(&env);
+    let treasurer = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&owner, &10000);
+    token.mint(&treasurer, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let org_id = contract.create_organization(&owner);
+    contract.set_org_spender(&org_id, &treasurer, &true);
+    let template_id = contract.publish_template(&org_id, &agent, &500, &2);
+
+    let id1 = contract.send_from_template(&treasurer, &template_id);
+    let id2 = contract.send_from_template(&owner, &template_id);
+
+    assert_eq!(contract.get_remittance(&id1).sender, treasurer);
+    assert_eq!(contract.get_remittance(&id2).sender, owner);
+    assert_eq!(contract.get_remittance(&id1).amount, 500);
+    assert_eq!(contract.get_template(&template_id).uses, 2);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_template_usage_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let owner = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&owner, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let org_id = contract.create_organization(&owner);
+    let template_id = contract.publish_template(&org_id, &agent, &500, &1);
+
+    contract.send_from_template(&owner, &template_id);
+    contract.send_from_template(&owner, &template_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+fn test_template_requires_authorized_spender() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let owner = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&outsider, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let org_id = contract.create_organization(&owner);
+    let template_id = contract.publish_template(&org_id, &agent, &500, &5);
+
+    contract.send_from_template(&outsider, &template_id);
+}
+
+#[test]
+fn test_idempotency_key_prevents_duplicate_remittance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let key = soroban_sdk::BytesN::from_array(&env, &[1u8; 32]);
+    let first = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &Some(key.clone()), &None, &None, &None);
+    let retry = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &Some(key), &None, &None, &None);
+
+    assert_eq!(first, retry);
+    assert_eq!(get_token_balance(&token, &sender), 9000);
+
+    let other_key = soroban_sdk::BytesN::from_array(&env, &[2u8; 32]);
+    let second = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &Some(other_key), &None, &None, &None);
+    assert_eq!(second, first + 1);
+    assert_eq!(get_token_balance(&token, &sender), 8000);
+}
+
+#[test]
+fn test_idempotency_key_scoped_per_sender() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let other_sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+    token.mint(&other_sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let key = soroban_sdk::BytesN::from_array(&env, &[1u8; 32]);
+    let first = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &Some(key.clone()), &None, &None, &None);
+    let second = contract.create_remittance(&other_sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &Some(key), &None, &None, &None);
+
+    assert_ne!(first, second);
+}
+
+#[test]
+fn test_failed_cancel_logs_error_context() {
+    use soroban_sdk::testutils::Logs;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout(&remittance_id);
+
+    assert!(contract.try_cancel_remittance(&remittance_id).is_err());
+
+    let logs = env.logs().all();
+    assert!(logs.iter().any(|log| log.contains("Error context: op=cancel")));
+}
+
+#[test]
+fn test_reserved_id_filled_later() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let other_sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+    token.mint(&other_sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let reserved_id = contract.reserve_id(&sender);
+    assert_eq!(contract.get_reservation(&reserved_id), Some(sender.clone()));
+
+    // Remittances created in the meantime skip the reserved ID
+    let other_id = contract.create_remittance(&other_sender, &agent, &500, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_ne!(other_id, reserved_id);
+
+    contract.create_reserved(&reserved_id, &agent, &1000, &None);
+
+    let remittance = contract.get_remittance(&reserved_id);
+    assert_eq!(remittance.sender, sender);
+    assert_eq!(remittance.amount, 1000);
+    assert_eq!(get_token_balance(&token, &sender), 9000);
+    assert!(contract.get_reservation(&reserved_id).is_none());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+fn test_reserved_id_cannot_be_filled_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let reserved_id = contract.reserve_id(&sender);
+    contract.create_reserved(&reserved_id, &agent, &1000, &None);
+    contract.create_reserved(&reserved_id, &agent, &1000, &None);
+}
+
+#[test]
+fn test_amend_remittance_adjusts_escrow_and_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let new_agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.register_agent(&new_agent);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+
+    // Fat-fingered: amount increased
+    contract.amend_remittance(&remittance_id, &2000, &agent);
+    assert_eq!(get_token_balance(&token, &sender), 8000);
+    assert_eq!(contract.get_remittance(&remittance_id).fee, 50);
+
+    // Corrected down and moved to another agent
+    contract.amend_remittance(&remittance_id, &400, &new_agent);
+    assert_eq!(get_token_balance(&token, &sender), 9600);
+    assert_eq!(contract.get_escrow_balance(), 390);
+
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.amount, 400);
+    assert_eq!(remittance.fee, 10);
+    assert_eq!(remittance.agent, new_agent);
+
+    contract.confirm_payout(&remittance_id);
+    assert_eq!(get_token_balance(&token, &new_agent), 390);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_amend_remittance_requires_pending() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout(&remittance_id);
+
+    contract.amend_remittance(&remittance_id, &500, &agent);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #27)")]
+fn test_create_remittance_rejects_offline_agent() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    contract.set_availability(&agent, &false);
+    assert!(!contract.is_agent_available(&agent));
+
+    contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+}
+
+#[test]
+fn test_agent_working_hours() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    contract.set_working_hours(&agent, &Some(crate::WorkingHours { start_hour: 8, end_hour: 18 }));
+
+    // 03:00 UTC
+    env.ledger().with_mut(|li| {
+        li.timestamp = 3 * 3600;
+    });
+    assert!(!contract.is_agent_available(&agent));
+
+    // 09:00 UTC
+    env.ledger().with_mut(|li| {
+        li.timestamp = 9 * 3600;
+    });
+    assert!(contract.is_agent_available(&agent));
+    contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+
+    // Overnight window spanning midnight
+    contract.set_working_hours(&agent, &Some(crate::WorkingHours { start_hour: 22, end_hour: 6 }));
+    assert!(!contract.is_agent_available(&agent));
+    env.ledger().with_mut(|li| {
+        li.timestamp = 86_400 + 2 * 3600;
+    });
+    assert!(contract.is_agent_available(&agent));
+}
+
+#[test]
+fn test_offline_agent_can_still_confirm_payout() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.set_availability(&agent, &false);
+    contract.confirm_payout(&remittance_id);
+
+    assert_eq!(get_token_balance(&token, &agent), 975);
+}
+
+#[test]
+fn test_confirm_partial_releases_tranches() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+
+    let first = contract.confirm_partial(&remittance_id, &400);
+    assert_eq!(first.fee, 10);
+    assert_eq!(get_token_balance(&token, &agent), 390);
+    assert_eq!(contract.get_escrow_balance(), 585);
+
+    let last = contract.confirm_partial(&remittance_id, &600);
+    assert_eq!(last.fee, 15);
+    assert_eq!(get_token_balance(&token, &agent), 975);
+    assert_eq!(contract.get_escrow_balance(), 0);
+    assert_eq!(contract.get_accumulated_fees(), 25);
+    assert_eq!(contract.get_tranches(&remittance_id).len(), 2);
+    assert_eq!(contract.reconcile().discrepancy, 0);
+}
+
+#[test]
+fn test_cancel_after_partial_refunds_remainder() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_partial(&remittance_id, &400);
+    contract.cancel_remittance(&remittance_id);
+
+    assert_eq!(get_token_balance(&token, &sender), 9600);
+    assert_eq!(get_token_balance(&token, &agent), 390);
+    assert_eq!(contract.get_escrow_balance(), 0);
+    assert_eq!(contract.reconcile().discrepancy, 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_confirm_partial_cannot_exceed_remaining() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_partial(&remittance_id, &700);
+    contract.confirm_partial(&remittance_id, &400);
+}
+
+#[test]
+fn test_batch_confirm_records_failed_entries() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let other_agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.register_agent(&other_agent);
+
+    let first = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let foreign = contract.create_remittance(&sender, &other_agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let second = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+
+    let mut ids = soroban_sdk::Vec::new(&env);
+    ids.push_back(first);
+    ids.push_back(foreign);
+    ids.push_back(999);
+    ids.push_back(second);
+    ids.push_back(first);
+
+    let settled = contract.batch_confirm_payouts(&agent, &ids);
+    assert_eq!(settled.len(), 2);
+    assert_eq!(settled.get_unchecked(0), first);
+    assert_eq!(settled.get_unchecked(1), second);
+    assert_eq!(get_token_balance(&token, &agent), 1950);
+
+    assert_eq!(contract.get_failed_operation_count(&agent), 3);
+    let failed = contract.get_failed_operations(&agent, &0, &10);
+    assert_eq!(failed.len(), 3);
+    assert_eq!(failed.get_unchecked(0).reference, foreign);
+    assert_eq!(failed.get_unchecked(0).error_code, crate::ContractError::Unauthorized as u32);
+    assert_eq!(failed.get_unchecked(1).reference, 999);
+    assert_eq!(failed.get_unchecked(1).error_code, crate::ContractError::RemittanceNotFound as u32);
+    assert_eq!(failed.get_unchecked(2).reference, first);
+    assert_eq!(failed.get_unchecked(2).error_code, crate::ContractError::InvalidStatus as u32);
+
+    let page = contract.get_failed_operations(&agent, &1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get_unchecked(0).reference, 999);
+}
+
+#[test]
+fn test_batch_create_records_failed_entries() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let unregistered = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let mut entries = soroban_sdk::Vec::new(&env);
+    entries.push_back(crate::BatchCreateEntry { agent: agent.clone(), amount: 1000, expiry: None });
+    entries.push_back(crate::BatchCreateEntry { agent: agent.clone(), amount: 0, expiry: None });
+    entries.push_back(crate::BatchCreateEntry { agent: unregistered, amount: 500, expiry: None });
+    entries.push_back(crate::BatchCreateEntry { agent: agent.clone(), amount: 2000, expiry: None });
+
+    let created = contract.batch_create_remittances(&sender, &entries);
+    assert_eq!(created.len(), 2);
+    assert_eq!(contract.get_remittance(&created.get_unchecked(1)).amount, 2000);
+    assert_eq!(get_token_balance(&token, &sender), 7000);
+
+    let failed = contract.get_failed_operations(&sender, &0, &10);
+    assert_eq!(failed.len(), 2);
+    assert_eq!(failed.get_unchecked(0).reference, 1);
+    assert_eq!(failed.get_unchecked(0).error_code, crate::ContractError::ZeroAmount as u32);
+    assert_eq!(failed.get_unchecked(1).reference, 2);
+    assert_eq!(failed.get_unchecked(1).error_code, crate::ContractError::AgentNotRegistered as u32);
+}
+
+mod analytics_sink {
+    use soroban_sdk::{contract, contractimpl, symbol_short, Env};
+
+    /// Sink that counts the completion records it receives.
+    #[contract]
+    pub struct CountingSink;
+
+    #[contractimpl]
+    impl CountingSink {
+        pub fn on_completion(env: Env, record: crate::CompletionRecord) {
+            let count: u32 = env.storage().instance().get(&symbol_short!("count")).unwrap_or(0);
+            env.storage().instance().set(&symbol_short!("count"), &(count + 1));
+            env.storage().instance().set(&symbol_short!("last"), &record);
+        }
+
+        pub fn count(env: Env) -> u32 {
+            env.storage().instance().get(&symbol_short!("count")).unwrap_or(0)
+        }
+
+        pub fn last(env: Env) -> Option<crate::CompletionRecord> {
+            env.storage().instance().get(&symbol_short!("last"))
+        }
+    }
+
+    /// Sink that rejects every record.
+    #[contract]
+    pub struct FailingSink;
+
+    #[contractimpl]
+    impl FailingSink {
+        pub fn on_completion(_env: Env, _record: crate::CompletionRecord) {
+            panic!("sink unavailable");
+        }
+    }
+}
+
+#[test]
+fn test_completed_remittance_mirrored_to_analytics_sink() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let sink_id = env.register_contract(None, analytics_sink::CountingSink);
+    let sink = analytics_sink::CountingSinkClient::new(&env, &sink_id);
+    contract.set_analytics_sink(&sink_id, &1);
+
+    let first = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let second = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout(&first);
+    // Budget of one record per ledger is already used
+    contract.confirm_payout(&second);
+
+    assert_eq!(sink.count(), 1);
+    let record = sink.last().unwrap();
+    assert_eq!(record.remittance_id, first);
+    assert_eq!(record.agent, agent);
+    assert_eq!(record.amount, 1000);
+    assert_eq!(record.fee, 25);
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number += 1;
+    });
+    let third = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout(&third);
+    assert_eq!(sink.count(), 2);
+
+    contract.set_analytics_enabled(&false);
+    let fourth = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    env.ledger().with_mut(|li| {
+        li.sequence_number += 1;
+    });
+    contract.confirm_payout(&fourth);
+    assert_eq!(sink.count(), 2);
+}
+
+#[test]
+fn test_failing_analytics_sink_does_not_block_payout() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let sink_id = env.register_contract(None, analytics_sink::FailingSink);
+    contract.set_analytics_sink(&sink_id, &10);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout(&remittance_id);
+
+    assert_eq!(get_token_balance(&token, &agent), 975);
+}
+
+#[test]
+fn test_absorbed_failures_emit_diagnostic_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let sink_id = env.register_contract(None, analytics_sink::FailingSink);
+    contract.set_analytics_sink(&sink_id, &10);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout(&remittance_id);
+
+    let diagnostics = || {
+        let mut found: std::vec::Vec<(Symbol, Address, u32, u64)> = std::vec::Vec::new();
+        for event in env.events().all().iter() {
+            if event.0 != contract.address || Symbol::from_val(&env, &event.1.get(0).unwrap()) != symbol_short!("diag") {
+                continue;
+            }
+            let data: soroban_sdk::Vec<soroban_sdk::Val> = soroban_sdk::FromVal::from_val(&env, &event.2);
+            found.push((
+                Symbol::from_val(&env, &event.1.get(1).unwrap()),
+                soroban_sdk::FromVal::from_val(&env, &data.get(4).unwrap()),
+                soroban_sdk::FromVal::from_val(&env, &data.get(5).unwrap()),
+                soroban_sdk::FromVal::from_val(&env, &data.get(6).unwrap()),
+            ));
+        }
+        found
+    };
+    assert_eq!(diagnostics(), std::vec![(symbol_short!("analytics"), sink_id.clone(), 0, remittance_id)]);
+
+    // A reconciliation that finds the contract short of its liabilities
+    let pending = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    TokenClient::new(&env, &token.address).burn(&contract.address, &5);
+    assert_eq!(contract.reconcile().discrepancy, -5);
+    assert_eq!(
+        diagnostics().last().cloned(),
+        Some((
+            symbol_short!("reconcile"),
+            contract.address.clone(),
+            crate::ContractError::InsufficientEscrow as u32,
+            0
+        ))
+    );
+    assert_eq!(contract.get_remittance(&pending).status, crate::RemittanceStatus::Pending);
+}
+
+#[test]
+fn test_fees_tracked_and_withdrawn_per_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let other_token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout(&remittance_id);
+
+    assert_eq!(contract.get_accumulated_fees_for(&token.address), 25);
+    assert_eq!(contract.get_accumulated_fees_for(&other_token.address), 0);
+    assert_eq!(contract.get_accumulated_fees(), 25);
+
+    let result = contract.try_withdraw_fees(&other_token.address, &recipient);
+    assert!(result.is_err());
+
+    contract.withdraw_fees(&token.address, &recipient);
+    assert_eq!(get_token_balance(&token, &recipient), 25);
+    assert_eq!(contract.get_accumulated_fees_for(&token.address), 0);
+}
+
+#[cfg(feature = "oracle")]
+#[test]
+fn test_quote_matches_created_remittance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.set_fx_rate(&soroban_sdk::String::from_str(&env, "KES"), &1_290_000_000);
+
+    let quote = contract.quote(&1234, &soroban_sdk::String::from_str(&env, "kes"), &None, &None);
+    assert_eq!(quote.fee, 30);
+    assert_eq!(quote.net_amount, 1204);
+    assert_eq!(quote.fx_rate, 1_290_000_000);
+    assert_eq!(quote.expiry, env.ledger().timestamp() + crate::QUOTE_VALIDITY_SECONDS);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1234, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(contract.get_remittance(&remittance_id).fee, quote.fee);
+
+    contract.confirm_payout(&remittance_id);
+    assert_eq!(get_token_balance(&token, &agent), quote.net_amount);
+}
+
+#[cfg(feature = "oracle")]
+#[test]
+#[should_panic(expected = "Error(Contract, #30)")]
+fn test_quote_requires_published_rate() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+
+    contract.quote(&1000, &soroban_sdk::String::from_str(&env, "NGN"), &None, &None);
+}
+
+#[test]
+fn test_sub_agent_confirms_payout_for_master() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let field_staff = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.add_sub_agent(&agent, &field_staff);
+
+    assert_eq!(contract.get_master_agent(&field_staff), Some(agent.clone()));
+    assert_eq!(contract.get_sub_agents(&agent).len(), 1);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout_as(&field_staff, &remittance_id);
+
+    // Payout and fees accrue to the master agent, not the sub-agent
+    assert_eq!(get_token_balance(&token, &agent), 975);
+    assert_eq!(get_token_balance(&token, &field_staff), 0);
+    assert_eq!(contract.get_remittance(&remittance_id).agent, agent);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+fn test_removed_sub_agent_cannot_confirm() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let field_staff = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.add_sub_agent(&agent, &field_staff);
+    contract.remove_sub_agent(&agent, &field_staff);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout_as(&field_staff, &remittance_id);
+}
+
+#[test]
+fn test_archive_closed_remittances() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let completed = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let pending = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let cancelled = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout(&completed);
+    contract.cancel_remittance(&cancelled);
+    let closed_ledger = env.ledger().sequence();
+
+    // Nothing was closed before the closing ledger itself
+    assert_eq!(contract.archive_remittances(&admin, &closed_ledger, &10).len(), 0);
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number += 10;
+    });
+    let archived = contract.archive_remittances(&admin, &(closed_ledger + 1), &10);
+    assert_eq!(archived.len(), 2);
+
+    let record = contract.get_archived_remittance(&completed).unwrap();
+    assert_eq!(record.id, completed);
+    assert_eq!(record.status, crate::RemittanceStatus::Completed);
+    assert!(contract.try_get_remittance(&completed).is_err());
+    assert!(contract.get_archived_remittance(&cancelled).is_some());
+
+    // Pending remittances keep their full record
+    assert_eq!(contract.get_remittance(&pending).amount, 1000);
+    assert!(contract.get_archived_remittance(&pending).is_none());
+}
+
+#[cfg(feature = "oracle")]
+#[test]
+#[should_panic(expected = "Error(Contract, #32)")]
+fn test_quote_rejects_stale_rate() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.set_fx_rate(&soroban_sdk::String::from_str(&env, "KES"), &1_290_000_000);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += crate::FX_RATE_MAX_AGE_SECONDS + 1;
+    });
+    contract.quote(&1000, &soroban_sdk::String::from_str(&env, "KES"), &None, &None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #31)")]
+fn test_batch_confirm_rejects_oversized_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let agent = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let mut ids = soroban_sdk::Vec::new(&env);
+    for id in 0..(crate::MAX_BATCH_SIZE as u64 + 1) {
+        ids.push_back(id);
+    }
+    contract.batch_confirm_payouts(&agent, &ids);
+}
+
+mod compliance_provider {
+    use soroban_sdk::{contract, contractimpl, Address, Env, String};
+
+    /// Provider that approves remittances up to 5000.
+    #[contract]
+    pub struct CapProvider;
+
+    #[contractimpl]
+    impl CapProvider {
+        pub fn check(_env: Env, _sender: Address, _agent: Address, amount: i128, _corridor: Option<String>) -> bool {
+            amount <= 5000
+        }
+    }
+}
+
+#[cfg(feature = "kyc")]
+#[test]
+fn test_compliance_hook_gates_remittance_creation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let provider = env.register_contract(None, compliance_provider::CapProvider);
+    contract.set_compliance_hook(&provider);
+    assert_eq!(contract.get_compliance_hook(), Some(provider));
+
+    contract.create_remittance(&sender, &agent, &5000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let rejected = contract.try_create_remittance(&sender, &agent, &5001, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(rejected, Err(Ok(crate::ContractError::ComplianceRejected)));
+    assert_eq!(get_token_balance(&token, &sender), 5000);
+
+    contract.clear_compliance_hook();
+    contract.create_remittance(&sender, &agent, &5000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+}
+
+mod swap_router {
+    use soroban_sdk::{contract, contractimpl, token, Address, Env};
+
+    /// Router that pays out two units of `token_out` per unit of `token_in`
+    /// from its own balance.
+    #[contract]
+    pub struct DoublingRouter;
+
+    #[contractimpl]
+    impl DoublingRouter {
+        pub fn swap(env: Env, _token_in: Address, token_out: Address, amount_in: i128, _min_out: i128, to: Address) -> i128 {
+            let amount_out = amount_in * 2;
+            token::Client::new(&env, &token_out).transfer(&env.current_contract_address(), &to, &amount_out);
+            amount_out
+        }
+    }
+}
+
+#[test]
+fn test_confirm_payout_with_swap() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let local_token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let router = env.register_contract(None, swap_router::DoublingRouter);
+    local_token.mint(&router, &100000);
+    contract.set_swap_router(&router);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout_with_swap(&remittance_id, &local_token.address, &1900);
+
+    assert_eq!(get_token_balance(&local_token, &agent), 1950);
+    assert_eq!(get_token_balance(&token, &agent), 0);
+    assert_eq!(get_token_balance(&token, &router), 975);
+    assert_eq!(contract.get_accumulated_fees(), 25);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #34)")]
+fn test_confirm_payout_with_swap_enforces_min_out() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let local_token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let router = env.register_contract(None, swap_router::DoublingRouter);
+    local_token.mint(&router, &100000);
+    contract.set_swap_router(&router);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout_with_swap(&remittance_id, &local_token.address, &2000);
+}
+
+#[test]
+fn test_split_remittance_legs_confirm_independently() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let nairobi_agent = Address::generate(&env);
+    let mombasa_agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&nairobi_agent);
+    contract.register_agent(&mombasa_agent);
+
+    let mut legs = soroban_sdk::Vec::new(&env);
+    legs.push_back((nairobi_agent.clone(), 3000i128));
+    legs.push_back((mombasa_agent.clone(), 2000i128));
+    let split_id = contract.create_split_remittance(&sender, &legs);
+
+    let split = contract.get_split(&split_id).unwrap();
+    assert_eq!(split.total, 5000);
+    assert_eq!(split.leg_ids.len(), 2);
+    assert_eq!(get_token_balance(&token, &sender), 5000);
+    assert_eq!(contract.get_escrow_balance(), 4875);
+
+    let nairobi_leg = split.leg_ids.get_unchecked(0);
+    let mombasa_leg = split.leg_ids.get_unchecked(1);
+    assert_eq!(contract.get_split_of(&mombasa_leg), Some(split_id));
+
+    contract.confirm_payout(&nairobi_leg);
+    assert_eq!(get_token_balance(&token, &nairobi_agent), 2925);
+
+    let statuses = contract.get_split_legs(&split_id);
+    assert_eq!(statuses.get_unchecked(0).status, crate::RemittanceStatus::Completed);
+    assert_eq!(statuses.get_unchecked(1).status, crate::RemittanceStatus::Pending);
+
+    contract.cancel_remittance(&mombasa_leg);
+    assert_eq!(get_token_balance(&token, &sender), 7000);
+    assert_eq!(contract.get_escrow_balance(), 0);
+}
+
+#[cfg(feature = "kyc")]
+#[test]
+fn test_sender_allowlist_restricts_creation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let corporate = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&corporate, &10000);
+    token.mint(&outsider, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    // Approvals can be prepared before the mode is switched on
+    contract.allow_sender(&corporate);
+    assert!(contract.is_sender_allowed(&outsider));
+
+    contract.set_sender_allowlist_enabled(&true);
+    assert!(contract.is_sender_allowlist_enabled());
+    contract.create_remittance(&corporate, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let rejected = contract.try_create_remittance(&outsider, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(rejected, Err(Ok(crate::ContractError::SenderNotAllowed)));
+
+    contract.disallow_sender(&corporate);
+    let rejected = contract.try_create_remittance(&corporate, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(rejected, Err(Ok(crate::ContractError::SenderNotAllowed)));
+
+    contract.set_sender_allowlist_enabled(&false);
+    contract.create_remittance(&outsider, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+}
+
+#[test]
+fn test_agent_inbox_tracks_pending_assignments() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let other_agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.register_agent(&other_agent);
+
+    let first = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let second = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let third = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(contract.get_pending_assignment_count(&agent), 3);
+    assert_eq!(contract.get_pending_assignments(&agent, &1, &10), soroban_sdk::vec![&env, second, third]);
+
+    contract.confirm_payout(&first);
+    contract.cancel_remittance(&second);
+    contract.amend_remittance(&third, &1000, &other_agent);
+
+    assert_eq!(contract.get_pending_assignment_count(&agent), 0);
+    assert!(contract.get_pending_assignments(&agent, &0, &10).is_empty());
+    assert_eq!(contract.get_pending_assignments(&other_agent, &0, &10), soroban_sdk::vec![&env, third]);
+}
+
+#[cfg(feature = "oracle")]
+#[test]
+fn test_fee_exempt_sender_pays_no_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let ngo = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&ngo, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.set_fx_rate(&soroban_sdk::String::from_str(&env, "KES"), &1_290_000_000);
+
+    contract.exempt_from_fees(&ngo);
+    assert!(contract.is_fee_exempt(&ngo));
+
+    let quote = contract.quote(&1000, &soroban_sdk::String::from_str(&env, "KES"), &Some(ngo.clone()), &None);
+    assert_eq!(quote.fee, 0);
+    assert!(quote.fee_exempt);
+    let anonymous = contract.quote(&1000, &soroban_sdk::String::from_str(&env, "KES"), &None, &None);
+    assert_eq!(anonymous.fee, 25);
+    assert!(!anonymous.fee_exempt);
+
+    let remittance_id = contract.create_remittance(&ngo, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(contract.get_remittance(&remittance_id).fee, 0);
+    contract.confirm_payout(&remittance_id);
+    assert_eq!(get_token_balance(&token, &agent), 1000);
+    assert_eq!(contract.get_accumulated_fees(), 0);
+
+    contract.remove_fee_exemption(&ngo);
+    let remittance_id = contract.create_remittance(&ngo, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(contract.get_remittance(&remittance_id).fee, 25);
+}
+
+#[test]
+fn test_sweep_expired_refunds_and_pays_keeper() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.set_sweep_bounty(&10);
+
+    let now = env.ledger().timestamp();
+    let expiring = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &Some(now + 100), &None, &None, &None, &None);
+    let later = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &Some(now + 1000), &None, &None, &None, &None);
+    let paid = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &Some(now + 100), &None, &None, &None, &None);
+    contract.confirm_payout(&paid);
+    assert_eq!(contract.get_accumulated_fees(), 75);
+
+    // Nothing has expired yet
+    assert_eq!(contract.sweep_expired(&keeper, &10), 0);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 500;
+    });
+
+    assert_eq!(contract.sweep_expired(&keeper, &10), 1);
+    assert_eq!(contract.get_remittance(&expiring).status, crate::RemittanceStatus::Expired);
+    assert_eq!(contract.get_remittance(&later).status, crate::RemittanceStatus::Pending);
+    assert_eq!(get_token_balance(&token, &sender), 8000);
+    assert_eq!(get_token_balance(&token, &keeper), 10);
+    // The expired remittance's fee was refunded and the bounty paid from the rest
+    assert_eq!(contract.get_accumulated_fees(), 40);
+    assert_eq!(contract.get_pending_assignment_count(&agent), 1);
+
+    // Already swept entries are gone from the index
+    assert_eq!(contract.sweep_expired(&keeper, &10), 0);
+
+    // Later windows are picked up once their expiry passes
+    env.ledger().with_mut(|li| {
+        li.timestamp += 2 * crate::EXPIRY_WINDOW_SECONDS;
+    });
+    assert_eq!(contract.sweep_expired(&keeper, &10), 1);
+    assert_eq!(contract.get_remittance(&later).status, crate::RemittanceStatus::Expired);
+}
+
+#[test]
+fn test_sweep_leaves_remittances_confirmable_at_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let now = env.ledger().timestamp();
+    let confirmed = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &Some(now + 100), &None, &None, &None, &None);
+    let swept = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &Some(now + 100), &None, &None, &None, &None);
+
+    // At the expiry second the agent can still confirm and keepers cannot sweep
+    env.ledger().with_mut(|li| li.timestamp = now + 100);
+    assert_eq!(contract.sweep_expired(&keeper, &10), 0);
+    contract.confirm_payout(&confirmed);
+    assert_eq!(contract.get_remittance(&confirmed).status, crate::RemittanceStatus::Completed);
+
+    env.ledger().with_mut(|li| li.timestamp = now + 101);
+    assert_eq!(contract.sweep_expired(&keeper, &10), 1);
+    assert_eq!(contract.get_remittance(&swept).status, crate::RemittanceStatus::Expired);
+}
+
+#[test]
+fn test_attested_payout_waits_for_sender() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.set_attestation_mode(&true, &None, &3600);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout(&remittance_id);
+    assert_eq!(contract.get_remittance(&remittance_id).status, crate::RemittanceStatus::AwaitingAttestation);
+    assert_eq!(get_token_balance(&token, &agent), 0);
+
+    // Neither the agent nor a stranger can attest, and cancelling is no longer possible
+    let stranger = Address::generate(&env);
+    let result = contract.try_attest_received(&agent, &remittance_id);
+    assert_eq!(result, Err(Ok(crate::ContractError::Unauthorized)));
+    let result = contract.try_attest_received(&stranger, &remittance_id);
+    assert_eq!(result, Err(Ok(crate::ContractError::Unauthorized)));
+    assert!(contract.try_cancel_remittance(&remittance_id).is_err());
+
+    contract.attest_received(&sender, &remittance_id);
+    assert_eq!(get_token_balance(&token, &agent), 975);
+    assert_eq!(contract.get_accumulated_fees(), 25);
+}
+
+#[test]
+fn test_attested_payout_released_after_timeout() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.set_attestation_mode(&true, &Some(oracle.clone()), &3600);
+
+    let attested = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let unattested = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout(&attested);
+    contract.confirm_payout(&unattested);
+
+    contract.attest_received(&oracle, &attested);
+    assert_eq!(get_token_balance(&token, &agent), 975);
+
+    let result = contract.try_release_unattested(&unattested);
+    assert_eq!(result, Err(Ok(crate::ContractError::InvalidStatus)));
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 3600;
+    });
+
+    contract.release_unattested(&unattested);
+    assert_eq!(get_token_balance(&token, &agent), 1950);
+    assert_eq!(contract.get_escrow_balance(), 0);
+}
+
+#[test]
+fn test_fee_accrues_at_creation_and_is_refunded_on_cancel() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let paid = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let cancelled = contract.create_remittance(&sender, &agent, &2000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(contract.get_accumulated_fees(), 75);
+    assert_eq!(contract.get_escrow_balance(), 2925);
+
+    // Fees of open remittances can still be refunded, so none are withdrawable yet
+    assert_eq!(contract.get_withdrawable_fees(), 0);
+    assert!(contract.try_withdraw_fees(&token.address, &treasury).is_err());
+
+    contract.confirm_payout(&paid);
+    assert_eq!(contract.get_accumulated_fees(), 75);
+    assert_eq!(contract.get_withdrawable_fees(), 25);
+
+    contract.withdraw_fees(&token.address, &treasury);
+    assert_eq!(get_token_balance(&token, &treasury), 25);
+    assert_eq!(contract.get_accumulated_fees(), 50);
+
+    contract.cancel_remittance(&cancelled);
+    assert_eq!(get_token_balance(&token, &sender), 9000);
+    assert_eq!(contract.get_accumulated_fees(), 0);
+    assert_eq!(contract.get_escrow_balance(), 0);
+    assert_eq!(get_token_balance(&token, &contract.address), 0);
+}
+
+#[test]
+fn test_accounting_invariant_holds_across_lifecycle() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let other_agent = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    token.mint(&sender, &100000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.register_agent(&other_agent);
+    contract.set_sweep_bounty(&5);
+
+    let assert_invariant = |contract: &SwiftRemitContractClient| {
+        let balance = get_token_balance(&token, &contract.address);
+        assert_eq!(balance, contract.get_escrow_balance() + contract.get_accumulated_fees());
+        assert!(contract.get_withdrawable_fees() <= contract.get_accumulated_fees());
+        assert_eq!(contract.reconcile().discrepancy, 0);
+    };
+
+    let now = env.ledger().timestamp();
+    let settled = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let partial = contract.create_remittance(&sender, &agent, &3333, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let amended = contract.create_remittance(&sender, &agent, &2000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let cancelled = contract.create_remittance(&sender, &agent, &777, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let expiring = contract.create_remittance(&sender, &agent, &1500, &PurposeCode::FamilySupport, &Some(now + 10), &None, &None, &None, &None);
+    assert_invariant(&contract);
+
+    contract.confirm_payout(&settled);
+    assert_invariant(&contract);
+
+    contract.confirm_partial(&partial, &1111);
+    assert_invariant(&contract);
+
+    contract.amend_remittance(&amended, &1234, &other_agent);
+    assert_invariant(&contract);
+
+    contract.withdraw_fees(&token.address, &treasury);
+    assert_invariant(&contract);
+
+    contract.cancel_remittance(&cancelled);
+    contract.cancel_remittance(&partial);
+    assert_invariant(&contract);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 100;
+    });
+    contract.sweep_expired(&keeper, &10);
+    assert_invariant(&contract);
+
+    contract.confirm_payout(&amended);
+    assert_invariant(&contract);
+    assert_eq!(contract.get_escrow_balance(), 0);
+    assert_eq!(contract.get_withdrawable_fees(), contract.get_accumulated_fees());
+}
+
+#[test]
+fn test_user_transfer_history_is_a_bounded_ring() {
+    let env = Env::default();
+    let contract = create_swiftremit_contract(&env);
+    let user = Address::generate(&env);
+
+    let retained = crate::storage::TRANSFER_CHUNK_SIZE * crate::storage::TRANSFER_HISTORY_CHUNKS;
+    let total = retained + crate::storage::TRANSFER_CHUNK_SIZE + 5;
+
+    env.as_contract(&contract.address, || {
+        for i in 0..total {
+            let record = crate::TransferRecord { timestamp: i, amount: i as i128 };
+            crate::storage::append_user_transfer(&env, &user, &record);
+        }
+
+        // The oldest chunks were dropped; the newest records are kept in order
+        let transfers = crate::storage::get_user_transfers(&env, &user);
+        let first_kept = (total / crate::storage::TRANSFER_CHUNK_SIZE + 1 - crate::storage::TRANSFER_HISTORY_CHUNKS)
+            * crate::storage::TRANSFER_CHUNK_SIZE;
+        assert_eq!(transfers.len() as u64, total - first_kept);
+        assert_eq!(transfers.first().unwrap().timestamp, first_kept);
+        assert_eq!(transfers.last().unwrap().timestamp, total - 1);
+    });
+}
+
+#[test]
+fn test_create_and_payout_append_to_transfer_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(contract.get_user_transfers(&sender).len(), 1);
+    assert_eq!(contract.get_user_transfers(&sender).get(0).unwrap().amount, 1000);
+    assert_eq!(contract.get_user_transfers(&agent).len(), 0);
+
+    // The agent's history records the payout net of the platform fee
+    contract.confirm_payout(&id);
+    assert_eq!(contract.get_user_transfers(&sender).len(), 1);
+    let received = contract.get_user_transfers(&agent);
+    assert_eq!(received.len(), 1);
+    assert_eq!(received.get(0).unwrap().amount, 975);
+}
+
+#[test]
+fn test_get_contract_state_bundles_dashboard_fields() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let other_agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.register_agent(&other_agent);
+    // Re-registering must not be counted twice
+    contract.register_agent(&agent);
+    contract.remove_agent(&other_agent);
+
+    contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.pause();
+
+    let state = contract.get_contract_state();
+    assert_eq!(state.admin, admin);
+    assert_eq!(state.usdc_token, token.address);
+    assert_eq!(state.platform_fee_bps, 250);
+    assert!(state.paused);
+    assert_eq!(state.total_escrow, 975);
+    assert_eq!(state.accumulated_fees, 25);
+    assert_eq!(state.agent_count, 1);
+    assert_eq!(state.next_remittance_id, 2);
+}
+
+#[test]
+fn test_event_seq_increases_by_one_per_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let before = contract.get_last_event_seq();
+    assert!(before > 0);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout(&remittance_id);
+
+    let after = contract.get_last_event_seq();
+    assert!(after > before);
+
+    // The latest event carries the latest sequence right after the schema version
+    let events = env.events().all();
+    let event_data: soroban_sdk::Vec<soroban_sdk::Val> =
+        soroban_sdk::FromVal::from_val(&env, &events.last().unwrap().2);
+    let event_seq: u64 = soroban_sdk::FromVal::from_val(&env, &event_data.get(1).unwrap());
+    assert_eq!(event_seq, after);
+}
+
+#[cfg(feature = "corridors")]
+#[test]
+fn test_create_remittance_enforces_corridor_purposes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let corridor = soroban_sdk::String::from_str(&env, "US-PH");
+    contract.set_agent_corridor(&agent, &corridor);
+    contract.set_corridor_purposes(
+        &corridor,
+        &soroban_sdk::vec![&env, PurposeCode::FamilySupport, PurposeCode::Education],
+    );
+
+    assert_eq!(
+        contract.try_create_remittance(&sender, &agent, &1000, &PurposeCode::Business, &None, &None, &None, &None, &None),
+        Err(Ok(crate::ContractError::PurposeNotAllowed))
+    );
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::Education, &None, &None, &None, &None, &None);
+    assert_eq!(contract.get_remittance(&remittance_id).purpose, PurposeCode::Education);
+
+    // Clearing the list accepts every purpose again
+    contract.set_corridor_purposes(&corridor, &soroban_sdk::Vec::new(&env));
+    assert!(contract.get_corridor_purposes(&corridor).is_empty());
+    contract.create_remittance(&sender, &agent, &1000, &PurposeCode::Business, &None, &None, &None, &None, &None);
+}
+
+#[cfg(feature = "corridors")]
+#[test]
+fn test_amend_rejects_agent_whose_corridor_disallows_purpose() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let other_agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.register_agent(&other_agent);
+
+    let corridor = soroban_sdk::String::from_str(&env, "US-IN");
+    contract.set_agent_corridor(&other_agent, &corridor);
+    contract.set_corridor_purposes(&corridor, &soroban_sdk::vec![&env, PurposeCode::Medical]);
+    assert_eq!(contract.get_agent_corridor(&other_agent), Some(corridor));
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+
+    assert_eq!(
+        contract.try_amend_remittance(&remittance_id, &1000, &other_agent),
+        Err(Ok(crate::ContractError::PurposeNotAllowed))
+    );
+}
+
+#[test]
+fn test_create_remittance_anchors_travel_rule_hashes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let originator_hash = soroban_sdk::BytesN::from_array(&env, &[1u8; 32]);
+    let beneficiary_hash = soroban_sdk::BytesN::from_array(&env, &[2u8; 32]);
+
+    let anchored = contract.create_remittance(
+        &sender,
+        &agent,
+        &1000,
+        &PurposeCode::FamilySupport,
+        &None,
+        &None,
+        &Some(originator_hash.clone()),
+        &Some(beneficiary_hash.clone()),
+        &None,
+    );
+    let remittance = contract.get_remittance(&anchored);
+    assert_eq!(remittance.originator_hash, Some(originator_hash));
+    assert_eq!(remittance.beneficiary_hash, Some(beneficiary_hash));
+
+    let plain = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let remittance = contract.get_remittance(&plain);
+    assert_eq!(remittance.originator_hash, None);
+    assert_eq!(remittance.beneficiary_hash, None);
+}
+
+#[cfg(feature = "corridors")]
+#[test]
+fn test_create_remittance_enforces_max_transfer_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let token_scope = crate::LimitScope::Token(token.address.clone());
+    contract.set_max_transfer_amount(&token_scope, &2000);
+    assert_eq!(contract.get_max_transfer_amount(&token_scope), Some(2000));
+
+    assert_eq!(
+        contract.try_create_remittance(&sender, &agent, &2001, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None),
+        Err(Ok(crate::ContractError::AmountAboveMaximum))
+    );
+    contract.create_remittance(&sender, &agent, &2000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+
+    // A stricter corridor cap applies on top of the token cap
+    let corridor = soroban_sdk::String::from_str(&env, "US-PH");
+    contract.set_agent_corridor(&agent, &corridor);
+    contract.set_max_transfer_amount(&crate::LimitScope::Corridor(corridor), &500);
+    assert_eq!(
+        contract.try_create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None),
+        Err(Ok(crate::ContractError::AmountAboveMaximum))
+    );
+
+    contract.clear_max_transfer_amount(&token_scope);
+    assert_eq!(contract.get_max_transfer_amount(&token_scope), None);
+    contract.create_remittance(&sender, &agent, &500, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+}
+
+#[test]
+fn test_create_remittance_rejects_dust_below_min_transfer_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    contract.set_min_transfer_amount(&token.address, &100);
+    assert_eq!(contract.get_min_transfer_amount(&token.address), 100);
+
+    // 39 units would carry a zero fee at 2.5%
+    assert_eq!(
+        contract.try_create_remittance(&sender, &agent, &39, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None),
+        Err(Ok(crate::ContractError::AmountBelowMinimum))
+    );
+    contract.create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+
+    assert_eq!(
+        contract.try_set_min_transfer_amount(&token.address, &-1),
+        Err(Ok(crate::ContractError::NegativeAmount))
+    );
+
+    contract.set_min_transfer_amount(&token.address, &0);
+    contract.create_remittance(&sender, &agent, &39, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+}
+
+#[test]
+fn test_find_agents_by_destination() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let manila = Address::generate(&env);
+    let cebu = Address::generate(&env);
+    let mumbai = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&manila);
+    contract.register_agent(&cebu);
+    contract.register_agent(&mumbai);
+
+    let ph = soroban_sdk::String::from_str(&env, "PH");
+    let php = soroban_sdk::String::from_str(&env, "PHP");
+    let area = |country: &str, city: &str, currency: &str| crate::CoverageArea {
+        country: soroban_sdk::String::from_str(&env, country),
+        city: Some(soroban_sdk::String::from_str(&env, city)),
+        currency: soroban_sdk::String::from_str(&env, currency),
+    };
+
+    contract.set_agent_coverage(&manila, &soroban_sdk::vec![&env, area("PH", "MNL", "PHP"), area("PH", "QC", "PHP")]);
+    contract.set_agent_coverage(&cebu, &soroban_sdk::vec![&env, area("PH", "CEB", "PHP")]);
+    contract.set_agent_coverage(&mumbai, &soroban_sdk::vec![&env, area("IN", "BOM", "INR")]);
+    assert_eq!(contract.get_agent_coverage(&manila).len(), 2);
+
+    assert_eq!(contract.find_agents(&ph, &php, &0, &10), soroban_sdk::vec![&env, manila.clone(), cebu.clone()]);
+    assert_eq!(contract.find_agents(&ph, &php, &1, &10), soroban_sdk::vec![&env, cebu.clone()]);
+
+    // Unavailable and removed agents are not discoverable
+    contract.set_availability(&manila, &false);
+    assert_eq!(contract.find_agents(&ph, &php, &0, &10), soroban_sdk::vec![&env, cebu.clone()]);
+    contract.remove_agent(&cebu);
+    assert!(contract.find_agents(&ph, &php, &0, &10).is_empty());
+
+    // Replacing coverage moves the agent to its new destination
+    contract.set_agent_coverage(&mumbai, &soroban_sdk::vec![&env, area("PH", "DVO", "PHP")]);
+    assert_eq!(contract.find_agents(&ph, &php, &0, &10), soroban_sdk::vec![&env, mumbai]);
+    assert!(contract
+        .find_agents(&soroban_sdk::String::from_str(&env, "IN"), &soroban_sdk::String::from_str(&env, "INR"), &0, &10)
+        .is_empty());
+}
+
+#[cfg(feature = "loyalty")]
+#[test]
+fn test_loyalty_points_accrue_and_discount_fees() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.set_loyalty_rate(&100);
+
+    // 1 point per 100 units on completion only
+    let first = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(contract.get_points(&sender), 0);
+    contract.confirm_payout(&first);
+    assert_eq!(contract.get_points(&sender), 10);
+
+    assert_eq!(
+        contract.try_redeem_points(&sender, &11),
+        Err(Ok(crate::ContractError::InsufficientPoints))
+    );
+    assert_eq!(contract.redeem_points(&sender, &10), 10);
+    assert_eq!(contract.get_points(&sender), 0);
+
+    // The credit reduces the next fee from 25 to 15
+    let second = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(contract.get_remittance(&second).fee, 15);
+    assert_eq!(contract.get_fee_credit(&sender), 0);
+
+    // Cancelling returns the credit drawn by the remittance
+    contract.cancel_remittance(&second);
+    assert_eq!(contract.get_fee_credit(&sender), 10);
+}
+
+#[test]
+fn test_admin_cancel_refunds_or_quarantines_by_reason() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let fraud = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let sanctioned = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(get_token_balance(&token, &sender), 8000);
+
+    // A fraud cancellation refunds the sender in full
+    contract.admin_cancel_remittance(&fraud, &crate::CancelReason::Fraud);
+    assert_eq!(get_token_balance(&token, &sender), 9000);
+    assert_eq!(contract.get_remittance(&fraud).status, crate::RemittanceStatus::Cancelled);
+    assert_eq!(contract.get_quarantined_amount(&fraud), 0);
+
+    // A sanctions hit keeps the funds in quarantine
+    contract.admin_cancel_remittance(&sanctioned, &crate::CancelReason::Sanctions);
+    assert_eq!(get_token_balance(&token, &sender), 9000);
+    assert_eq!(contract.get_remittance(&sanctioned).status, crate::RemittanceStatus::Quarantined);
+    assert_eq!(contract.get_quarantined_amount(&sanctioned), 1000);
+    assert_eq!(contract.get_escrow_balance(), 0);
+    assert_eq!(contract.get_accumulated_fees(), 0);
+
+    let report = contract.reconcile();
+    assert_eq!(report.quarantined, 1000);
+    assert_eq!(report.discrepancy, 0);
+
+    assert_eq!(
+        contract.try_admin_cancel_remittance(&sanctioned, &crate::CancelReason::Fraud),
+        Err(Ok(crate::ContractError::InvalidStatus))
+    );
+}
+
+#[test]
+fn test_freeze_and_release_quarantine() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let authority = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.freeze_remittance(&remittance_id);
+
+    assert_eq!(contract.get_remittance(&remittance_id).status, crate::RemittanceStatus::Quarantined);
+    assert_eq!(contract.get_total_quarantined(), 1000);
+    assert_eq!(contract.get_escrow_balance(), 0);
+    assert_eq!(contract.reconcile().discrepancy, 0);
+
+    // Frozen funds can be neither paid out nor cancelled
+    assert_eq!(contract.try_confirm_payout(&remittance_id), Err(Ok(crate::ContractError::InvalidStatus)));
+    assert_eq!(contract.try_cancel_remittance(&remittance_id), Err(Ok(crate::ContractError::InvalidStatus)));
+
+    assert_eq!(contract.release_quarantine(&remittance_id, &authority), 1000);
+    assert_eq!(get_token_balance(&token, &authority), 1000);
+    assert_eq!(contract.get_total_quarantined(), 0);
+    assert_eq!(contract.reconcile().discrepancy, 0);
+
+    assert_eq!(
+        contract.try_release_quarantine(&remittance_id, &sender),
+        Err(Ok(crate::ContractError::InvalidStatus))
+    );
+}
+
+#[test]
+fn test_routed_remittance_pays_hop_fee_before_payout() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let hub = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&hub);
+    contract.register_agent(&agent);
+    contract.set_hop_fee(&100);
+
+    let route = soroban_sdk::vec![&env, hub.clone(), agent.clone()];
+    let paid = contract.create_routed_remittance(&sender, &route, &1000);
+    assert_eq!(contract.get_remittance(&paid).agent, agent);
+    assert_eq!(contract.get_route(&paid).unwrap().hop_fee, 10);
+
+    // The destination agent waits for the hub to confirm its leg
+    assert_eq!(contract.try_confirm_payout(&paid), Err(Ok(crate::ContractError::InvalidStatus)));
+
+    assert_eq!(contract.confirm_hop(&paid), hub);
+    assert_eq!(get_token_balance(&token, &hub), 10);
+    assert_eq!(contract.try_confirm_hop(&paid), Err(Ok(crate::ContractError::InvalidStatus)));
+
+    contract.confirm_payout(&paid);
+    assert_eq!(get_token_balance(&token, &agent), 965);
+    assert_eq!(contract.get_accumulated_fees(), 25);
+
+    // Cancelling after the hub's leg refunds what it did not take
+    let cancelled = contract.create_routed_remittance(&sender, &route, &1000);
+    contract.confirm_hop(&cancelled);
+    contract.cancel_remittance(&cancelled);
+    assert_eq!(get_token_balance(&token, &sender), 8990);
+    assert_eq!(contract.get_escrow_balance(), 0);
+    assert_eq!(contract.reconcile().discrepancy, 0);
+}
+
+#[cfg(all(feature = "corridors", feature = "oracle"))]
+#[test]
+fn test_corridor_fee_overrides_global_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let other_agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.register_agent(&other_agent);
+    contract.set_fx_rate(&soroban_sdk::String::from_str(&env, "PHP"), &560_000_000);
+
+    let corridor = soroban_sdk::String::from_str(&env, "US-PH");
+    contract.set_agent_corridor(&agent, &corridor);
+    contract.set_corridor_fee(&corridor, &100);
+    assert_eq!(contract.get_corridor_fee(&corridor), Some(100));
+
+    let currency = soroban_sdk::String::from_str(&env, "PHP");
+    let quote = contract.quote(&1000, &currency, &None, &Some(agent.clone()));
+    assert_eq!(quote.fee, 10);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(contract.get_remittance(&remittance_id).fee, quote.fee);
+
+    // Agents outside the corridor keep the global fee
+    assert_eq!(contract.quote(&1000, &currency, &None, &Some(other_agent.clone())).fee, 25);
+    let other_id = contract.create_remittance(&sender, &other_agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(contract.get_remittance(&other_id).fee, 25);
+
+    contract.clear_corridor_fee(&corridor);
+    assert_eq!(contract.get_corridor_fee(&corridor), None);
+    assert_eq!(contract.quote(&1000, &currency, &None, &Some(agent)).fee, 25);
+}
+
+#[test]
+fn test_get_remittance_by_external_ref() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let order_id = soroban_sdk::BytesN::from_array(&env, &[7u8; 32]);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &Some(order_id.clone()));
+
+    let remittance = contract.get_remittance_by_external_ref(&order_id);
+    assert_eq!(remittance.id, remittance_id);
+    assert_eq!(remittance.external_ref, Some(order_id.clone()));
+
+    // A reference identifies a single remittance
+    assert_eq!(
+        contract.try_create_remittance(&sender, &agent, &500, &PurposeCode::FamilySupport, &None, &None, &None, &None, &Some(order_id)),
+        Err(Ok(crate::ContractError::DuplicateExternalRef))
+    );
+    assert_eq!(
+        contract.try_get_remittance_by_external_ref(&soroban_sdk::BytesN::from_array(&env, &[8u8; 32])),
+        Err(Ok(crate::ContractError::RemittanceNotFound))
+    );
+}
+
+#[test]
+fn test_native_xlm_remittance_lifecycle() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let xlm = create_native_token_contract(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    fund_native(&env, &xlm, &sender, 10000);
+
+    let contract = create_swiftremit_contract(&env);
+    assert_eq!(contract.get_native_token(), xlm.address);
+    contract.initialize(&InitConfig::new(&env, &admin, &xlm.address, 250));
+    contract.register_agent(&agent);
+    assert!(contract.is_native_settlement());
+
+    let paid = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let cancelled = contract.create_remittance(&sender, &agent, &2000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(get_token_balance(&xlm, &contract.address), 3000);
+
+    contract.confirm_payout(&paid);
+    assert_eq!(get_token_balance(&xlm, &agent), 975);
+
+    contract.cancel_remittance(&cancelled);
+    assert_eq!(get_token_balance(&xlm, &sender), 9000);
+    assert_eq!(contract.get_escrow_balance(), 0);
+    assert_eq!(contract.reconcile().discrepancy, 0);
+
+    contract.withdraw_fees(&xlm.address, &treasury);
+    assert_eq!(get_token_balance(&xlm, &treasury), 25);
+    assert_eq!(get_token_balance(&xlm, &contract.address), 0);
+}
+
+#[test]
+fn test_relayed_operations_consume_nonces() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let expiration = env.ledger().sequence() + 100;
+    assert_eq!(contract.get_nonce(&sender), 0);
+    let remittance_id = contract.relay_create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &0, &expiration);
+    assert_eq!(contract.get_nonce(&sender), 1);
+    assert_eq!(get_token_balance(&token, &sender), 9000);
+
+    // A captured signature cannot be submitted twice
+    assert_eq!(
+        contract.try_relay_create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &0, &expiration),
+        Err(Ok(crate::ContractError::InvalidNonce))
+    );
+
+    // Nor after it lapses
+    env.ledger().with_mut(|li| li.sequence_number = expiration + 1);
+    assert_eq!(
+        contract.try_relay_cancel_remittance(&remittance_id, &1, &expiration),
+        Err(Ok(crate::ContractError::SignatureExpired))
+    );
+
+    contract.relay_cancel_remittance(&remittance_id, &1, &(expiration + 100));
+    assert_eq!(get_token_balance(&token, &sender), 10000);
+    assert_eq!(contract.get_nonce(&sender), 2);
+}
+
+#[test]
+fn test_simulate_payout_reports_outcome() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+
+    let preview = contract.simulate_payout(&remittance_id);
+    assert_eq!(preview.net_to_agent, 975);
+    assert_eq!(preview.fee, 25);
+    assert_eq!(preview.token, token.address);
+    assert_eq!(preview.will_succeed_reason, None);
+
+    contract.confirm_payout(&remittance_id);
+
+    // The preview names the error a second confirmation would fail with
+    let preview = contract.simulate_payout(&remittance_id);
+    assert_eq!(preview.will_succeed_reason, Some(crate::ContractError::InvalidStatus as u32));
+    assert_eq!(
+        contract.try_simulate_payout(&(remittance_id + 1)),
+        Err(Ok(crate::ContractError::RemittanceNotFound))
+    );
+}
+
+#[test]
+fn test_fee_rounding_policy_per_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    // 2.5% of 1234 is 30.85
+    let policy = contract.get_fee_rounding(&token.address);
+    assert_eq!(policy.mode, crate::RoundingMode::Floor);
+    assert_eq!(policy.decimals, 7);
+    let floor = contract.create_remittance(&sender, &agent, &1234, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(contract.get_remittance(&floor).fee, 30);
+
+    contract.set_fee_rounding(&token.address, &crate::RoundingMode::HalfUp);
+    assert_eq!(contract.get_fee_rounding(&token.address).mode, crate::RoundingMode::HalfUp);
+    let half_up = contract.create_remittance(&sender, &agent, &1234, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(contract.get_remittance(&half_up).fee, 31);
+    // 2.5% of 1220 is 30.5
+    let half = contract.create_remittance(&sender, &agent, &1220, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(contract.get_remittance(&half).fee, 31);
+
+    contract.set_fee_rounding(&token.address, &crate::RoundingMode::Ceil);
+    // 2.5% of 1201 is 30.025
+    let ceil = contract.create_remittance(&sender, &agent, &1201, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(contract.get_remittance(&ceil).fee, 31);
+    let exact = contract.create_remittance(&sender, &agent, &1200, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(contract.get_remittance(&exact).fee, 30);
+}
+
+#[test]
+fn test_settle_agent_collects_accrued_payouts() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.set_settlement_schedule(&agent, &crate::SettlementSchedule::OnDemand);
+
+    for amount in [1000, 2000, 3000] {
+        let id = contract.create_remittance(&sender, &agent, &amount, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+        contract.confirm_payout(&id);
+    }
+
+    // Payouts accrue to the agent's receivable balance
+    assert_eq!(get_token_balance(&token, &agent), 0);
+    assert_eq!(contract.get_open_settlement(&agent).unwrap().amount, 5850);
+    assert_eq!(contract.reconcile().discrepancy, 0);
+
+    let settled = contract.settle_agent(&agent, &agent);
+    assert_eq!(settled.amount, 5850);
+    assert_eq!(settled.remittance_ids.len(), 3);
+    assert_eq!(get_token_balance(&token, &agent), 5850);
+    assert!(contract.get_open_settlement(&agent).is_none());
+    assert_eq!(contract.reconcile().discrepancy, 0);
+
+    assert_eq!(contract.try_settle_agent(&agent, &agent), Err(Ok(crate::ContractError::InvalidStatus)));
+}
+
+#[test]
+fn test_testutils_setup_with_agents() {
+    let t = crate::testutils::setup_with_agents(3);
+    assert_eq!(t.agents.len(), 3);
+    assert!(t.contract.is_agent_registered(&t.agent(2)));
+
+    let sender = t.funded_sender(5000);
+    assert_eq!(t.balance(&sender), 5000);
+
+    let id = t.create_remittance(&sender, &t.agent(0), 1000);
+    assert_eq!(t.contract.get_remittance(&id).amount, 1000);
+    assert_eq!(t.balance(&sender), 4000);
+
+    let before = t.env.ledger().timestamp();
+    crate::testutils::advance_time(&t.env, 3600);
+    assert_eq!(t.env.ledger().timestamp(), before + 3600);
+
+    let sequence = t.env.ledger().sequence();
+    crate::testutils::advance_ledgers(&t.env, 10);
+    assert_eq!(t.env.ledger().sequence(), sequence + 10);
+    assert_eq!(t.env.ledger().timestamp(), before + 3650);
+}
+
+mod payout_recipient {
+    use soroban_sdk::{contract, contractimpl, symbol_short, Env};
+
+    /// Recipient that records the last payout it was notified of.
+    #[contract]
+    pub struct SavingsPot;
+
+    #[contractimpl]
+    impl SavingsPot {
+        pub fn on_payout(env: Env, remittance_id: u64, amount: i128) {
+            env.storage().instance().set(&symbol_short!("last"), &(remittance_id, amount));
+        }
+
+        pub fn last_payout(env: Env) -> Option<(u64, i128)> {
+            env.storage().instance().get(&symbol_short!("last"))
+        }
+    }
+
+    /// Recipient that rejects every payout.
+    #[contract]
+    pub struct RejectingPot;
+
+    #[contractimpl]
+    impl RejectingPot {
+        pub fn on_payout(_env: Env, _remittance_id: u64, _amount: i128) {
+            panic!("pot closed");
+        }
+    }
+}
+
+#[test]
+fn test_payout_to_recipient_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let pot = env.register_contract(None, payout_recipient::SavingsPot);
+    let pot_client = payout_recipient::SavingsPotClient::new(&env, &pot);
+    let id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.set_payout_recipient(&id, &pot);
+    assert_eq!(contract.get_payout_recipient(&id), Some(pot.clone()));
+
+    // Recipient contracts are paid in full, not in tranches
+    assert_eq!(contract.try_confirm_partial(&id, &400), Err(Ok(crate::ContractError::InvalidStatus)));
+
+    contract.confirm_payout(&id);
+    assert_eq!(get_token_balance(&token, &pot), 975);
+    assert_eq!(get_token_balance(&token, &agent), 0);
+    assert_eq!(pot_client.last_payout(), Some((id, 975)));
+
+    // A failing recipient rolls the payout back
+    let closed = env.register_contract(None, payout_recipient::RejectingPot);
+    let id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.set_payout_recipient(&id, &closed);
+    assert_eq!(contract.try_confirm_payout(&id), Err(Ok(crate::ContractError::PayoutHookFailed)));
+    assert_eq!(contract.get_remittance(&id).status, crate::RemittanceStatus::Pending);
+    assert_eq!(get_token_balance(&token, &closed), 0);
+
+    contract.clear_payout_recipient(&id);
+    contract.confirm_payout(&id);
+    assert_eq!(get_token_balance(&token, &agent), 975);
+}
+
+#[test]
+fn test_agent_decline_refunds_sender_and_records_reason() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.agent_decline_remittance(&id, &crate::DeclineReason::RecipientUnreachable);
+
+    assert_eq!(get_token_balance(&token, &sender), 10000);
+    assert_eq!(contract.get_remittance(&id).status, crate::RemittanceStatus::Cancelled);
+    assert_eq!(contract.get_decline_reason(&id), Some(crate::DeclineReason::RecipientUnreachable));
+    assert_eq!(contract.get_decline_count(&agent, &crate::DeclineReason::RecipientUnreachable), 1);
+    assert_eq!(contract.get_decline_count(&agent, &crate::DeclineReason::InsufficientLiquidity), 0);
+
+    // Only pending remittances can be declined
+    assert_eq!(
+        contract.try_agent_decline_remittance(&id, &crate::DeclineReason::Other),
+        Err(Ok(crate::ContractError::InvalidStatus))
+    );
+}
+
+#[test]
+fn test_reassign_remittance_keeps_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let new_agent = Address::generate(&env);
+    let suspended = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.register_agent(&new_agent);
+    contract.register_agent(&suspended);
+    contract.suspend_agent(&suspended);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+
+    assert_eq!(
+        contract.try_reassign_remittance(&sender, &id, &Address::generate(&env)),
+        Err(Ok(crate::ContractError::AgentNotRegistered))
+    );
+    assert_eq!(
+        contract.try_reassign_remittance(&sender, &id, &suspended),
+        Err(Ok(crate::ContractError::AgentSuspended))
+    );
+
+    contract.reassign_remittance(&sender, &id, &new_agent);
+    let remittance = contract.get_remittance(&id);
+    assert_eq!(remittance.agent, new_agent);
+    assert_eq!(remittance.fee, 25);
+    assert_eq!(get_token_balance(&token, &sender), 9000);
+
+    contract.confirm_payout(&id);
+    assert_eq!(get_token_balance(&token, &new_agent), 975);
+    assert_eq!(get_token_balance(&token, &agent), 0);
+
+    assert_eq!(
+        contract.try_reassign_remittance(&admin, &id, &agent),
+        Err(Ok(crate::ContractError::InvalidStatus))
+    );
+}
+
+mod yield_vault {
+    use soroban_sdk::{contract, contractimpl, symbol_short, token, Address, Env};
+
+    /// Vault that holds deposits and pays whatever yield the test credits.
+    #[contract]
+    pub struct Vault;
+
+    #[contractimpl]
+    impl Vault {
+        pub fn init(env: Env, token: Address) {
+            env.storage().instance().set(&symbol_short!("token"), &token);
+        }
+
+        pub fn deposit(env: Env, owner: Address, amount: i128) {
+            let balance = Self::balance(env.clone(), owner.clone());
+            env.storage().instance().set(&owner, &(balance + amount));
+        }
+
+        pub fn withdraw(env: Env, owner: Address, to: Address, amount: i128) {
+            owner.require_auth();
+            let balance = Self::balance(env.clone(), owner.clone());
+            env.storage().instance().set(&owner, &(balance - amount));
+            let token: Address = env.storage().instance().get(&symbol_short!("token")).unwrap();
+            token::Client::new(&env, &token).transfer(&env.current_contract_address(), &to, &amount);
+        }
+
+        pub fn balance(env: Env, owner: Address) -> i128 {
+            env.storage().instance().get(&owner).unwrap_or(0)
+        }
+
+        /// Credits `amount` of yield to `owner`; the test mints the tokens backing it.
+        pub fn accrue(env: Env, owner: Address, amount: i128) {
+            let balance = Self::balance(env.clone(), owner.clone());
+            env.storage().instance().set(&owner, &(balance + amount));
+        }
+    }
+}
+
+#[test]
+fn test_idle_escrow_earns_yield_and_is_withdrawn_at_payout() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let vault = env.register_contract(None, yield_vault::Vault);
+    let vault_client = yield_vault::VaultClient::new(&env, &vault);
+    vault_client.init(&token.address);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(
+        contract.try_deposit_idle_escrow(&975),
+        Err(Ok(crate::ContractError::InvalidStatus))
+    );
+
+    contract.set_yield_adapter(&vault, &crate::YieldBeneficiary::InsuranceFund);
+    // Only escrow can be deposited, never fees
+    assert_eq!(
+        contract.try_deposit_idle_escrow(&976),
+        Err(Ok(crate::ContractError::InsufficientEscrow))
+    );
+    contract.deposit_idle_escrow(&975);
+    assert_eq!(contract.get_yield_principal(), 975);
+    assert_eq!(get_token_balance(&token, &contract.address), 25);
+    assert_eq!(contract.reconcile().discrepancy, 0);
+
+    // The vault earns 40 on the deposit
+    token.mint(&vault, &40);
+    vault_client.accrue(&contract.address, &40);
+
+    // Principal is withdrawn just in time to pay the agent
+    contract.confirm_payout(&id);
+    assert_eq!(get_token_balance(&token, &agent), 975);
+    assert_eq!(contract.get_yield_principal(), 0);
+    assert_eq!(contract.reconcile().discrepancy, 0);
+
+    assert_eq!(contract.harvest_yield(), 40);
+    assert_eq!(contract.get_insurance_fund(), 40);
+    assert_eq!(get_token_balance(&token, &contract.address), 65);
+    assert_eq!(contract.reconcile().discrepancy, 0);
+    assert_eq!(contract.harvest_yield(), 0);
+
+    // A refund also brings the principal back
+    let id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.deposit_idle_escrow(&500);
+    contract.cancel_remittance(&id);
+    assert_eq!(contract.get_yield_principal(), 0);
+    assert_eq!(get_token_balance(&token, &sender), 9000);
+
+    contract.clear_yield_adapter();
+    assert_eq!(contract.get_yield_adapter(), None);
+}
+
+#[test]
+fn test_wrapped_fees_earn_yield_until_withdrawn() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let vault = env.register_contract(None, yield_vault::Vault);
+    let vault_client = yield_vault::VaultClient::new(&env, &vault);
+    vault_client.init(&token.address);
+    contract.set_yield_adapter(&vault, &crate::YieldBeneficiary::InsuranceFund);
+
+    for _ in 0..2 {
+        let id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+        contract.confirm_payout(&id);
+    }
+    assert_eq!(contract.get_withdrawable_fees(), 50);
+
+    // Only withdrawable fees can be wrapped
+    assert_eq!(contract.try_wrap_idle_fees(&51), Err(Ok(crate::ContractError::InsufficientEscrow)));
+    contract.wrap_idle_fees(&40);
+    assert_eq!(contract.get_wrapped_fees(), 40);
+    assert_eq!(get_token_balance(&token, &contract.address), 10);
+    assert_eq!(contract.reconcile().discrepancy, 0);
+
+    // Yield on wrapped fees alone is booked as fees
+    token.mint(&vault, &8);
+    vault_client.accrue(&contract.address, &8);
+    assert_eq!(contract.harvest_yield(), 8);
+    assert_eq!(contract.get_fee_yield(), 8);
+    assert_eq!(contract.get_withdrawable_fees(), 58);
+    assert_eq!(contract.get_insurance_fund(), 0);
+
+    // Alongside escrow, the fees earn their pro-rata share: 40 of 1015
+    contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.deposit_idle_escrow(&975);
+    token.mint(&vault, &203);
+    vault_client.accrue(&contract.address, &203);
+    assert_eq!(contract.harvest_yield(), 203);
+    assert_eq!(contract.get_fee_yield(), 16);
+    assert_eq!(contract.get_insurance_fund(), 195);
+    assert_eq!(contract.reconcile().discrepancy, 0);
+
+    // Withdrawing converts the wrapped fees back first
+    contract.withdraw_fees(&token.address, &admin);
+    assert_eq!(get_token_balance(&token, &admin), 66);
+    assert_eq!(contract.get_wrapped_fees(), 0);
+    assert_eq!(contract.get_yield_principal(), 975);
+    assert_eq!(contract.reconcile().discrepancy, 0);
+}
+
+#[cfg(feature = "corridors")]
+#[test]
+fn test_blocked_corridor_rejects_remittances() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let other_agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.register_agent(&other_agent);
+    contract.set_agent_corridor(&agent, &soroban_sdk::String::from_str(&env, "US-IR"));
+    contract.set_agent_corridor(&other_agent, &soroban_sdk::String::from_str(&env, "US-PH"));
+
+    let us = soroban_sdk::String::from_str(&env, "US");
+    let ir = soroban_sdk::String::from_str(&env, "IR");
+    let pending = contract.create_remittance(&sender, &other_agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+
+    contract.block_corridor(&us, &ir);
+    assert!(contract.is_corridor_blocked(&us, &ir));
+    assert!(!contract.is_corridor_blocked(&ir, &us));
+
+    let result = contract.try_create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(result, Err(Ok(crate::ContractError::CorridorBlocked)));
+    assert_eq!(
+        contract.try_reassign_remittance(&sender, &pending, &agent),
+        Err(Ok(crate::ContractError::CorridorBlocked))
+    );
+    // Other corridors are unaffected
+    contract.create_remittance(&sender, &other_agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+
+    contract.unblock_corridor(&us, &ir);
+    contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+}
+
+#[cfg(feature = "corridors")]
+#[test]
+fn test_corridor_hold_delays_payout() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let corridor = soroban_sdk::String::from_str(&env, "US-PH");
+    contract.set_agent_corridor(&agent, &corridor);
+    contract.set_corridor_hold(&corridor, &600);
+    assert_eq!(contract.get_corridor_hold(&corridor), 600);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(contract.get_remittance(&id).hold_until, Some(1_600));
+
+    assert_eq!(contract.try_confirm_payout(&id), Err(Ok(crate::ContractError::PayoutOnHold)));
+
+    // The sender can still cancel during the hold
+    let mistaken = contract.create_remittance(&sender, &agent, &500, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.cancel_remittance(&mistaken);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_600);
+    contract.confirm_payout(&id);
+    assert_eq!(get_token_balance(&token, &agent), 975);
+
+    // Removing the hold only affects new remittances
+    contract.set_corridor_hold(&corridor, &0);
+    let id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(contract.get_remittance(&id).hold_until, None);
+    contract.confirm_payout(&id);
+}
+
+#[test]
+fn test_late_payout_rebates_fee_and_records_breach() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.set_sla(&crate::Priority::Standard, &3600, &4000);
+
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    let on_time = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let late = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+
+    env.ledger().with_mut(|li| li.timestamp = 13_600);
+    contract.confirm_payout(&on_time);
+    assert_eq!(get_token_balance(&token, &sender), 8000);
+    assert_eq!(contract.get_sla_breaches(&agent), 0);
+
+    env.ledger().with_mut(|li| li.timestamp = 13_601);
+    contract.confirm_payout(&late);
+    let timing = contract.get_remittance_timing(&late).unwrap();
+    assert_eq!(timing.created_at, 10_000);
+    assert_eq!(timing.confirmed_at, Some(13_601));
+
+    // 40% of the 25 fee comes back to the sender
+    assert_eq!(get_token_balance(&token, &sender), 8010);
+    assert_eq!(get_token_balance(&token, &agent), 1950);
+    assert_eq!(contract.get_accumulated_fees(), 40);
+    assert_eq!(contract.get_sla_breaches(&agent), 1);
+    assert_eq!(contract.reconcile().discrepancy, 0);
+}
+
+#[test]
+fn test_quorum_gates_fee_withdrawal_and_token_rescue() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let signers = soroban_sdk::vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    contract.set_quorum(&signers, &2, &100);
+    assert_eq!(contract.try_set_quorum(&signers, &1, &100), Err(Ok(crate::ContractError::Unauthorized)));
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout(&id);
+
+    // The single admin can no longer withdraw fees
+    assert_eq!(
+        contract.try_withdraw_fees(&token.address, &treasury),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+
+    let withdraw = contract.propose_op(&signers.get(0).unwrap(), &crate::CriticalOp::WithdrawFees(token.address.clone(), treasury.clone()));
+    assert_eq!(contract.try_execute_op(&withdraw), Err(Ok(crate::ContractError::Unauthorized)));
+    assert_eq!(
+        contract.try_approve_op(&signers.get(0).unwrap(), &withdraw),
+        Err(Ok(crate::ContractError::InvalidStatus))
+    );
+    assert_eq!(contract.approve_op(&signers.get(1).unwrap(), &withdraw), 2);
+    contract.execute_op(&withdraw);
+    assert_eq!(get_token_balance(&token, &treasury), 25);
+    assert!(contract.get_op_proposal(&withdraw).unwrap().executed);
+    assert_eq!(contract.try_execute_op(&withdraw), Err(Ok(crate::ContractError::InvalidStatus)));
+
+    // Only tokens sent to the contract by mistake can be rescued
+    token.mint(&contract.address, &300);
+    let id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let too_much = contract.propose_op(&signers.get(1).unwrap(), &crate::CriticalOp::RescueTokens(token.address.clone(), treasury.clone(), 301));
+    contract.approve_op(&signers.get(2).unwrap(), &too_much);
+    assert_eq!(contract.try_execute_op(&too_much), Err(Ok(crate::ContractError::InvalidAmount)));
+
+    let rescue = contract.propose_op(&signers.get(1).unwrap(), &crate::CriticalOp::RescueTokens(token.address.clone(), treasury.clone(), 300));
+    contract.approve_op(&signers.get(2).unwrap(), &rescue);
+    contract.execute_op(&rescue);
+    assert_eq!(get_token_balance(&token, &treasury), 325);
+    assert_eq!(contract.reconcile().discrepancy, 0);
+    contract.confirm_payout(&id);
+
+    // Proposals lapse after the configured number of ledgers
+    let lapsed = contract.propose_op(&signers.get(0).unwrap(), &crate::CriticalOp::WithdrawFees(token.address.clone(), treasury.clone()));
+    env.ledger().with_mut(|li| li.sequence_number += 101);
+    assert_eq!(
+        contract.try_approve_op(&signers.get(2).unwrap(), &lapsed),
+        Err(Ok(crate::ContractError::SignatureExpired))
+    );
+}
+
+#[test]
+fn test_epoch_stats_roll_over_daily() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    env.ledger().with_mut(|li| li.timestamp = 3 * 86400 + 100);
+    assert_eq!(contract.get_current_epoch(), 3);
+    let first = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let second = contract.create_remittance(&sender, &agent, &2000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout(&first);
+
+    env.ledger().with_mut(|li| li.timestamp = 4 * 86400);
+    contract.confirm_payout(&second);
+
+    let day3 = contract.get_epoch_stats(&3);
+    assert_eq!(day3.created_count, 2);
+    assert_eq!(day3.created_volume, 3000);
+    assert_eq!(day3.completed_count, 1);
+    assert_eq!(day3.completed_volume, 1000);
+    assert_eq!(day3.fees, 25);
+
+    let day4 = contract.get_epoch_stats(&4);
+    assert_eq!(day4.created_count, 0);
+    assert_eq!(day4.completed_volume, 2000);
+    assert_eq!(day4.fees, 50);
+
+    assert_eq!(contract.get_epoch_stats(&5), crate::EpochStats::default());
+}
+
+#[test]
+fn test_lookup_by_pickup_code() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let first = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let second = contract.create_remittance(&sender, &agent, &2000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+
+    let code = contract.get_pickup_code(&first).unwrap();
+    assert_eq!(code.len(), 8);
+    assert_ne!(Some(code.clone()), contract.get_pickup_code(&second));
+    assert_eq!(contract.lookup_by_code(&code).id, first);
+
+    // Quoted codes are read case-insensitively, with look-alike letters
+    let mut quoted = [0u8; 8];
+    code.copy_into_slice(&mut quoted);
+    for c in quoted.iter_mut() {
+        *c = match *c {
+            b'1' => b'l',
+            b'0' => b'O',
+            other => other.to_ascii_lowercase(),
+        };
+    }
+    let quoted = soroban_sdk::String::from_bytes(&env, &quoted);
+    assert_eq!(contract.lookup_by_code(&quoted).id, first);
+
+    assert_eq!(
+        contract.try_lookup_by_code(&soroban_sdk::String::from_str(&env, "UUUUUUUU")),
+        Err(Ok(crate::ContractError::RemittanceNotFound))
+    );
+    assert_eq!(
+        contract.try_lookup_by_code(&soroban_sdk::String::from_str(&env, "ABC")),
+        Err(Ok(crate::ContractError::RemittanceNotFound))
+    );
+}
+
+mod payroll {
+    use soroban_sdk::auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation};
+    use soroban_sdk::{contract, contractimpl, vec, Address, Env, IntoVal, Symbol};
+
+    use crate::{PurposeCode, SwiftRemitContractClient};
+
+    /// Contract that sends remittances from its own balance.
+    #[contract]
+    pub struct Payroll;
+
+    #[contractimpl]
+    impl Payroll {
+        /// Creates a remittance with this contract as the sender. The escrow
+        /// transfer SwiftRemit makes on its behalf is authorized only when
+        /// `authorize_transfer` is set.
+        pub fn pay(
+            env: Env,
+            swiftremit: Address,
+            token: Address,
+            agent: Address,
+            amount: i128,
+            authorize_transfer: bool,
+        ) -> u64 {
+            let this = env.current_contract_address();
+            if authorize_transfer {
+                env.authorize_as_current_contract(vec![
+                    &env,
+                    InvokerContractAuthEntry::Contract(SubContractInvocation {
+                        context: ContractContext {
+                            contract: token,
+                            fn_name: Symbol::new(&env, "transfer"),
+                            args: (this.clone(), swiftremit.clone(), amount).into_val(&env),
+                        },
+                        sub_invocations: vec![&env],
+                    }),
+                ]);
+            }
+            SwiftRemitContractClient::new(&env, &swiftremit).create_remittance(
+                &this,
+                &agent,
+                &amount,
+                &PurposeCode::Business,
+                &None,
+                &None,
+                &None,
+                &None,
+                &None,
+            )
+        }
+
+        pub fn cancel(env: Env, swiftremit: Address, remittance_id: u64) {
+            SwiftRemitContractClient::new(&env, &swiftremit).cancel_remittance(&remittance_id);
+        }
+    }
+}
+
+#[test]
+fn test_contract_sender_uses_invoker_auth() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let agent = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let payroll = env.register_contract(None, payroll::Payroll);
+    let payroll_client = payroll::PayrollClient::new(&env, &payroll);
+    token.mint(&payroll, &5000);
+
+    // From here on no authorization is mocked: the payroll contract
+    // authorizes as the direct invoker, and for the nested token transfer
+    env.set_auths(&[]);
+
+    assert!(payroll_client
+        .try_pay(&contract.address, &token.address, &agent, &1000, &false)
+        .is_err());
+    assert_eq!(get_token_balance(&token, &payroll), 5000);
+
+    let id = payroll_client.pay(&contract.address, &token.address, &agent, &1000, &true);
+    let remittance = contract.get_remittance(&id);
+    assert_eq!(remittance.sender, payroll);
+    assert_eq!(get_token_balance(&token, &payroll), 4000);
+
+    // A stranger cannot cancel in the contract's name, the contract itself can
+    assert!(contract.try_cancel_remittance(&id).is_err());
+    payroll_client.cancel(&contract.address, &id);
+    assert_eq!(get_token_balance(&token, &payroll), 5000);
+}
+
+#[test]
+fn test_trial_balance_tracks_fund_flows() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let hub = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&hub);
+    contract.register_agent(&agent);
+    contract.set_hop_fee(&100);
+    contract.set_insurance_premium(&100);
+
+    let paid = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.purchase_insurance(&paid);
+    contract.confirm_payout(&paid);
+
+    let route = soroban_sdk::vec![&env, hub.clone(), agent.clone()];
+    let routed = contract.create_routed_remittance(&sender, &route, &1000);
+    contract.confirm_hop(&routed);
+
+    let insured = contract.create_remittance(&sender, &agent, &2000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.purchase_insurance(&insured);
+    contract.cancel_remittance(&insured);
+
+    let trial = contract.get_trial_balance();
+    assert_eq!(trial.total_debits, trial.total_credits);
+    assert_eq!(trial.lines.len(), 10);
+
+    // The hop fee passed through commissions on its way to the hub
+    let commissions = contract.get_account_balance(&crate::AccountClass::Commissions);
+    assert_eq!(commissions, 0);
+    let line = trial.lines.iter().find(|line| line.class == crate::AccountClass::Commissions).unwrap();
+    assert_eq!((line.debits, line.credits), (10, 10));
+
+    // Class balances are the figures the contract reports
+    assert_eq!(contract.get_account_balance(&crate::AccountClass::Escrow), contract.get_escrow_balance());
+    assert_eq!(contract.get_account_balance(&crate::AccountClass::Fees), contract.get_accumulated_fees());
+    assert_eq!(contract.get_account_balance(&crate::AccountClass::Insurance), 10);
+    assert_eq!(contract.get_insurance_fund(), 10);
+    assert_eq!(
+        contract.get_account_balance(&crate::AccountClass::Holdings),
+        get_token_balance(&token, &contract.address)
+    );
+    assert_eq!(contract.reconcile().discrepancy, 0);
+}
+
+#[test]
+fn test_slash_pays_sender_from_agent_collateral() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+    token.mint(&agent, &1000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.set_slash_challenge_window(&3600);
+    assert_eq!(contract.deposit_collateral(&agent, &500), 500);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout(&id);
+
+    let evidence = soroban_sdk::BytesN::from_array(&env, &[7u8; 32]);
+    let other = Address::generate(&env);
+    assert_eq!(
+        contract.try_propose_slash(&other, &id, &evidence),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+
+    // The remittance amount is locked, up to the agent's collateral
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let slash = contract.propose_slash(&agent, &id, &evidence);
+    assert_eq!(slash.amount, 500);
+    assert_eq!(slash.executable_at, 4_600);
+    assert_eq!(contract.get_agent_collateral(&agent), (500, 500));
+    assert_eq!(
+        contract.try_withdraw_collateral(&agent, &1),
+        Err(Ok(crate::ContractError::InsufficientEscrow))
+    );
+    assert_eq!(contract.try_propose_slash(&agent, &id, &evidence), Err(Ok(crate::ContractError::InvalidStatus)));
+    assert_eq!(contract.try_execute_slash(&id), Err(Ok(crate::ContractError::InvalidStatus)));
+
+    env.ledger().with_mut(|li| li.timestamp = 4_600);
+    assert_eq!(contract.execute_slash(&id), 500);
+    assert_eq!(get_token_balance(&token, &sender), 9500);
+    assert_eq!(contract.get_agent_collateral(&agent), (0, 0));
+    assert_eq!(contract.get_slash(&id).unwrap().status, crate::SlashStatus::Executed);
+    assert_eq!(contract.reconcile().discrepancy, 0);
+
+    // A challenged slash waits for the admin, who can drop it
+    let id = contract.create_remittance(&sender, &agent, &200, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout(&id);
+    assert_eq!(
+        contract.try_propose_slash(&agent, &id, &evidence),
+        Err(Ok(crate::ContractError::InsufficientEscrow))
+    );
+    contract.deposit_collateral(&agent, &300);
+    contract.propose_slash(&agent, &id, &evidence);
+    contract.challenge_slash(&id, &soroban_sdk::BytesN::from_array(&env, &[9u8; 32]));
+
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    assert_eq!(contract.try_execute_slash(&id), Err(Ok(crate::ContractError::InvalidStatus)));
+    assert_eq!(contract.resolve_slash(&id, &false), 0);
+    assert_eq!(contract.get_slash(&id).unwrap().status, crate::SlashStatus::Cancelled);
+    assert_eq!(contract.withdraw_collateral(&agent, &300), 0);
+    assert_eq!(get_token_balance(&token, &agent), 975 + 195 + 500);
+    assert_eq!(contract.reconcile().discrepancy, 0);
+}
+
+#[test]
+fn test_slash_requires_paid_out_remittance_and_challenge_to_uphold() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+    token.mint(&agent, &1000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.set_slash_challenge_window(&3600);
+    contract.deposit_collateral(&agent, &1000);
+    let evidence = soroban_sdk::BytesN::from_array(&env, &[7u8; 32]);
+
+    // Pending and cancelled remittances cannot be slashed
+    let pending = contract.create_remittance(&sender, &agent, &500, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(contract.try_propose_slash(&agent, &pending, &evidence), Err(Ok(crate::ContractError::InvalidStatus)));
+    contract.cancel_remittance(&pending);
+    assert_eq!(contract.try_propose_slash(&agent, &pending, &evidence), Err(Ok(crate::ContractError::InvalidStatus)));
+
+    // An unchallenged slash cannot be upheld before its window closes
+    let id = contract.create_remittance(&sender, &agent, &500, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout(&id);
+    contract.propose_slash(&agent, &id, &evidence);
+    assert_eq!(contract.try_resolve_slash(&id, &true), Err(Ok(crate::ContractError::InvalidStatus)));
+
+    contract.challenge_slash(&id, &soroban_sdk::BytesN::from_array(&env, &[9u8; 32]));
+    let before = get_token_balance(&token, &sender);
+    assert_eq!(contract.resolve_slash(&id, &true), 500);
+    assert_eq!(get_token_balance(&token, &sender), before + 500);
+    assert_eq!(contract.get_slash(&id).unwrap().status, crate::SlashStatus::Executed);
+    assert_eq!(contract.reconcile().discrepancy, 0);
+}
+
+#[test]
+fn test_agent_max_pending_caps_assignments() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let other_agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.register_agent(&other_agent);
+    contract.set_default_max_pending(&3);
+    contract.set_agent_max_pending(&agent, &2);
+    assert_eq!(contract.get_agent_max_pending(&agent), 2);
+    assert_eq!(contract.get_agent_max_pending(&other_agent), 3);
+
+    let first = contract.create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(
+        contract.try_create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None),
+        Err(Ok(crate::ContractError::AgentAtCapacity))
+    );
+
+    // Reassigning to a full agent is refused as well
+    let moved = contract.create_remittance(&sender, &other_agent, &100, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(
+        contract.try_reassign_remittance(&sender, &moved, &agent),
+        Err(Ok(crate::ContractError::AgentAtCapacity))
+    );
+
+    // A settled payout frees a slot
+    contract.confirm_payout(&first);
+    contract.reassign_remittance(&sender, &moved, &agent);
+    assert_eq!(contract.get_pending_assignment_count(&agent), 2);
+
+    // Removing the agent's own limit falls back to the default
+    contract.set_agent_max_pending(&agent, &0);
+    contract.create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(
+        contract.try_create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None),
+        Err(Ok(crate::ContractError::AgentAtCapacity))
+    );
+}
+
+#[test]
+fn test_express_remittances_jump_the_queue_and_use_their_own_sla() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.set_express_fee(&100);
+    contract.set_sla(&crate::Priority::Standard, &86_400, &4000);
+    contract.set_sla(&crate::Priority::Express, &3600, &4000);
+
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    let first = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let second = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let third = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+
+    // 1% of the amount is charged on top, so the payout is unchanged
+    assert_eq!(contract.expedite_remittance(&third), 10);
+    assert_eq!(
+        contract.try_expedite_remittance(&third),
+        Err(Ok(crate::ContractError::InvalidStatus))
+    );
+    contract.expedite_remittance(&second);
+    let expedited = contract.get_remittance(&third);
+    assert_eq!(expedited.priority, crate::Priority::Express);
+    assert_eq!(expedited.amount, 1010);
+    assert_eq!(expedited.fee, 35);
+    assert_eq!(get_token_balance(&token, &sender), 6980);
+    assert_eq!(
+        contract.get_pending_assignments(&agent, &0, &10),
+        soroban_sdk::vec![&env, third, second, first]
+    );
+
+    // Standard payouts are still on time two hours in; express ones are late
+    env.ledger().with_mut(|li| li.timestamp = 17_200);
+    contract.confirm_payout(&first);
+    contract.confirm_payout(&third);
+    assert_eq!(contract.get_sla_breaches(&agent), 1);
+    assert_eq!(get_token_balance(&token, &agent), 1950);
+    assert_eq!(get_token_balance(&token, &sender), 6994);
+    assert_eq!(contract.get_pending_assignments(&agent, &0, &10), soroban_sdk::vec![&env, second]);
+    assert_eq!(contract.reconcile().discrepancy, 0);
+}
+
+#[test]
+fn test_confirm_payout_with_fx_records_local_payout() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(
+        contract.try_confirm_payout_with_fx(&id, &0, &1_290_000_000),
+        Err(Ok(crate::ContractError::ZeroAmount))
+    );
+    assert_eq!(
+        contract.try_confirm_payout_with_fx(&id, &125_000, &-1),
+        Err(Ok(crate::ContractError::NegativeAmount))
+    );
+
+    // 975 USDC net paid out as 125,000 KES at 128.2 KES per USDC
+    contract.confirm_payout_with_fx(&id, &125_000, &1_282_000_000);
+
+    let remittance = contract.get_remittance(&id);
+    assert_eq!(remittance.status, crate::RemittanceStatus::Completed);
+    assert_eq!(
+        remittance.payout_fx,
+        Some(crate::PayoutFx { local_amount: 125_000, rate: 1_282_000_000 })
+    );
+    assert_eq!(get_token_balance(&token, &agent), 975);
+}
+
+#[test]
+fn test_scheduled_fee_update_takes_effect_at_its_time() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    assert_eq!(
+        contract.try_schedule_fee_update(&400, &1_000),
+        Err(Ok(crate::ContractError::InvalidAmount))
+    );
+    contract.schedule_fee_update(&400, &5_000);
+    contract.schedule_fee_update(&300, &2_000);
+    contract.schedule_fee_update(&350, &2_000);
+
+    assert_eq!(contract.get_effective_fee(&1_999), 250);
+    assert_eq!(contract.get_effective_fee(&2_000), 350);
+    assert_eq!(contract.get_effective_fee(&9_000), 400);
+    assert_eq!(contract.get_scheduled_fee_updates().len(), 2);
+
+    let before = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(contract.get_remittance(&before).fee, 25);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    assert_eq!(contract.get_platform_fee_bps(), 350);
+    let after = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(contract.get_remittance(&after).fee, 35);
+    assert_eq!(
+        contract.get_scheduled_fee_updates(),
+        soroban_sdk::vec![&env, crate::ScheduledFee { fee_bps: 400, effective_at: 5_000 }]
+    );
+
+    // An immediate update overrides the fee now, but not the later schedule
+    contract.update_fee(&100);
+    assert_eq!(contract.get_platform_fee_bps(), 100);
+    assert_eq!(contract.get_effective_fee(&5_000), 400);
+}
+
+#[test]
+fn test_stale_remittances_lists_unpaid_transfers_past_the_threshold() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let first = contract.create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let second = contract.create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    env.ledger().with_mut(|li| li.timestamp = 5_000);
+    let third = contract.create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let fourth = contract.create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+
+    env.ledger().with_mut(|li| li.timestamp = 9_000);
+    assert_eq!(contract.get_stale_remittances(&7_200, &0, &10), soroban_sdk::vec![&env, first, second]);
+    assert_eq!(contract.get_stale_remittances(&3_600, &0, &10), soroban_sdk::vec![&env, first, second, third, fourth]);
+    assert_eq!(contract.get_stale_remittances(&3_600, &second, &2), soroban_sdk::vec![&env, second, third]);
+    assert_eq!(contract.get_stale_remittances(&3_600, &(third + 1), &10), soroban_sdk::vec![&env, fourth]);
+    assert!(contract.get_stale_remittances(&10_000, &0, &10).is_empty());
+
+    // Paid out and refunded remittances drop out of the index
+    contract.confirm_payout(&first);
+    contract.cancel_remittance(&third);
+    assert_eq!(contract.get_stale_remittances(&3_600, &0, &10), soroban_sdk::vec![&env, second, fourth]);
+}
+
+#[test]
+fn test_stale_remittances_page_across_index_buckets() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &100000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let mut ids = soroban_sdk::Vec::new(&env);
+    for _ in 0..(crate::REMITTANCE_BUCKET_SIZE + 5) {
+        ids.push_back(contract.create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None));
+    }
+    env.ledger().with_mut(|li| li.timestamp = 9_000);
+
+    // A page can start in one bucket and continue into the next
+    let start = crate::REMITTANCE_BUCKET_SIZE - 2;
+    let page = contract.get_stale_remittances(&3_600, &start, &4);
+    assert_eq!(page, soroban_sdk::vec![&env, start, start + 1, start + 2, start + 3]);
+
+    // Emptied buckets are skipped
+    for id in ids.iter().take(crate::REMITTANCE_BUCKET_SIZE as usize) {
+        contract.confirm_payout(&id);
+    }
+    let rest = contract.get_stale_remittances(&3_600, &0, &10);
+    assert_eq!(rest.len(), 5);
+    assert_eq!(rest.get_unchecked(0), crate::REMITTANCE_BUCKET_SIZE + 1);
+}
+
+#[test]
+fn test_deposit_refs_resolve_to_their_remittance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let first = contract.create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let second = contract.create_remittance(&sender, &agent, &100, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+
+    // Partners derive the same reference off-chain
+    let first_ref = contract.get_deposit_ref(&first);
+    let second_ref = contract.get_deposit_ref(&second);
+    assert_eq!(first_ref, crate::compute_deposit_ref(&env, &contract.address, first));
+    assert_ne!(first_ref, second_ref);
+
+    assert_eq!(contract.resolve_deposit_ref(&first_ref), first);
+    assert_eq!(contract.resolve_deposit_ref(&second_ref), second);
+    assert_eq!(
+        contract.try_resolve_deposit_ref(&(first_ref ^ second_ref)),
+        Err(Ok(crate::ContractError::RemittanceNotFound))
+    );
+    assert_eq!(
+        contract.try_get_deposit_ref(&99),
+        Err(Ok(crate::ContractError::RemittanceNotFound))
+    );
+}
+
+#[test]
+fn test_top_up_remittance_adds_escrow_and_proportional_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &crate::PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+
+    // The 2.5% fee charged at creation applies to the top-up despite the fee change
+    contract.update_fee(&500);
+    assert_eq!(contract.top_up_remittance(&remittance_id, &200), 1200);
+    assert_eq!(get_token_balance(&token, &sender), 8800);
+
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.amount, 1200);
+    assert_eq!(remittance.fee, 30);
+    assert_eq!(contract.get_escrow_balance(), 1170);
+
+    contract.confirm_payout(&remittance_id);
+    assert_eq!(get_token_balance(&token, &agent), 1170);
+
+    let result = contract.try_top_up_remittance(&remittance_id, &100);
+    assert_eq!(result, Err(Ok(crate::ContractError::InvalidStatus)));
+}
+
+#[test]
+fn test_top_up_remittance_rejects_non_positive_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &crate::PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+
+    let result = contract.try_top_up_remittance(&remittance_id, &0);
+    assert_eq!(result, Err(Ok(crate::ContractError::ZeroAmount)));
+    assert_eq!(contract.get_remittance(&remittance_id).amount, 1000);
+}
+
+#[test]
+fn test_screening_window_withholds_remittance_from_agent() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.set_screening_period(&10);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &crate::PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let visible_at = env.ledger().sequence() + 10;
+    assert_eq!(contract.get_visible_at_ledger(&remittance_id), Some(visible_at));
+
+    // Funded but not yet visible to the agent
+    assert_eq!(contract.get_pending_assignments(&agent, &0, &10).len(), 0);
+    let result = contract.try_confirm_payout(&remittance_id);
+    assert_eq!(result, Err(Ok(crate::ContractError::PayoutOnHold)));
+
+    env.ledger().with_mut(|li| li.sequence_number = visible_at);
+
+    assert_eq!(contract.get_pending_assignments(&agent, &0, &10), soroban_sdk::vec![&env, remittance_id]);
+    contract.confirm_payout(&remittance_id);
+    assert_eq!(get_token_balance(&token, &agent), 975);
+}
+
+#[test]
+fn test_screening_window_allows_freeze() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.set_screening_period(&10);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &crate::PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.freeze_remittance(&remittance_id);
+
+    env.ledger().with_mut(|li| li.sequence_number += 10);
+
+    let result = contract.try_confirm_payout(&remittance_id);
+    assert_eq!(result, Err(Ok(crate::ContractError::InvalidStatus)));
+    assert_eq!(get_token_balance(&token, &agent), 0);
+}
+
+#[cfg(feature = "kyc")]
+#[test]
+fn test_risk_band_caps_amount_and_daily_count() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let officer = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.set_risk_scorer(&officer, &true);
+    contract.set_risk_bands(&soroban_sdk::vec![
+        &env,
+        crate::RiskBand { min_score: 0, max_amount: 0, max_per_day: 0 },
+        crate::RiskBand { min_score: 70, max_amount: 500, max_per_day: 1 },
+    ]);
+
+    // Unscored senders fall in the unrestricted band
+    contract.create_remittance(&sender, &agent, &1000, &crate::PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+
+    contract.set_risk_score(&officer, &sender, &75);
+    assert_eq!(contract.get_risk_score(&sender), 75);
+
+    let result = contract.try_create_remittance(&sender, &agent, &1000, &crate::PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(result, Err(Ok(crate::ContractError::AmountAboveMaximum)));
+
+    // The remittance created before the score counts towards today's limit
+    let result = contract.try_create_remittance(&sender, &agent, &500, &crate::PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(result, Err(Ok(crate::ContractError::RateLimited)));
+
+    env.ledger().with_mut(|li| li.timestamp += crate::RISK_VELOCITY_WINDOW_SECONDS);
+    contract.create_remittance(&sender, &agent, &500, &crate::PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+}
+
+#[cfg(feature = "kyc")]
+#[test]
+fn test_set_risk_bands_rejects_unordered_bands() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+
+    let result = contract.try_set_risk_bands(&soroban_sdk::vec![
+        &env,
+        crate::RiskBand { min_score: 50, max_amount: 100, max_per_day: 0 },
+        crate::RiskBand { min_score: 50, max_amount: 50, max_per_day: 0 },
+    ]);
+    assert_eq!(result, Err(Ok(crate::ContractError::InvalidAmount)));
+    assert_eq!(contract.get_risk_bands().len(), 0);
+}
+
+#[test]
+fn test_import_remittances_books_pending_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let legacy = |id: u64, status: crate::RemittanceStatus| crate::LegacyRemittance {
+        id,
+        sender: sender.clone(),
+        agent: agent.clone(),
+        amount: 1000,
+        fee: 25,
+        status,
+        expiry: None,
+    };
+    let imported = contract.import_remittances(&soroban_sdk::vec![
+        &env,
+        legacy(7, crate::RemittanceStatus::Completed),
+        legacy(12, crate::RemittanceStatus::Pending),
+    ]);
+    assert_eq!(imported, 2);
+    assert_eq!(contract.get_escrow_balance(), 975);
+    assert_eq!(contract.get_pending_assignments(&agent, &0, &10), soroban_sdk::vec![&env, 12u64]);
+
+    // Completed v1 remittances cannot be settled again
+    let result = contract.try_confirm_payout(&7);
+    assert_eq!(result, Err(Ok(crate::ContractError::InvalidStatus)));
+
+    // IDs cannot be imported twice
+    let result = contract.try_import_remittances(&soroban_sdk::vec![&env, legacy(12, crate::RemittanceStatus::Pending)]);
+    assert_eq!(result, Err(Ok(crate::ContractError::InvalidMigrationBatch)));
+
+    // The v1 escrow must arrive before imports can be closed
+    let result = contract.try_finalize_import();
+    assert_eq!(result, Err(Ok(crate::ContractError::InsufficientEscrow)));
+    token.mint(&contract.address, &1000);
+    contract.finalize_import();
+    assert!(contract.is_import_finalized());
+
+    let result = contract.try_import_remittances(&soroban_sdk::vec![&env, legacy(20, crate::RemittanceStatus::Cancelled)]);
+    assert_eq!(result, Err(Ok(crate::ContractError::MigrationInProgress)));
+
+    contract.confirm_payout(&12);
+    assert_eq!(get_token_balance(&token, &agent), 975);
+
+    // New remittances are numbered after the imported ones
+    token.mint(&sender, &1000);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &crate::PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(remittance_id, 13);
+}
+
+#[test]
+fn test_commit_snapshot_proves_archived_records() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    for amount in [100i128, 200, 300] {
+        contract.create_remittance(&sender, &agent, &amount, &crate::PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    }
+    contract.confirm_payout(&1);
+
+    let records: soroban_sdk::Vec<crate::Remittance> = soroban_sdk::vec![
+        &env,
+        contract.get_remittance(&1),
+        contract.get_remittance(&2),
+        contract.get_remittance(&3),
+    ];
+    let snapshot_id = contract.commit_snapshot(&1, &3);
+    assert_eq!(snapshot_id, 1);
+
+    let leaves: soroban_sdk::Vec<soroban_sdk::BytesN<32>> = soroban_sdk::vec![
+        &env,
+        crate::compute_record_hash(&env, &records.get(0).unwrap()),
+        crate::compute_record_hash(&env, &records.get(1).unwrap()),
+        crate::compute_record_hash(&env, &records.get(2).unwrap()),
+    ];
+    assert_eq!(contract.get_snapshot(&1).unwrap().root, crate::compute_merkle_root(&env, &leaves));
+
+    // Record 1 is archived on-chain but still provable from the backup
+    env.ledger().with_mut(|li| li.sequence_number += 10);
+    contract.archive_remittances(&admin, &env.ledger().sequence(), &10);
+    assert!(contract.try_get_remittance(&1).is_err());
+
+    let first_pair = crate::compute_merkle_root(&env, &soroban_sdk::vec![&env, leaves.get(0).unwrap(), leaves.get(1).unwrap()]);
+    let proof = crate::RecordProof {
+        snapshot_id,
+        record: records.get(0).unwrap(),
+        siblings: soroban_sdk::vec![&env, leaves.get(1).unwrap(), leaves.get(2).unwrap()],
+    };
+    assert!(contract.verify_record(&proof));
+
+    // The last, unpaired leaf only needs the root of the first pair
+    let proof = crate::RecordProof {
+        snapshot_id,
+        record: records.get(2).unwrap(),
+        siblings: soroban_sdk::vec![&env, first_pair],
+    };
+    assert!(contract.verify_record(&proof));
+
+    // A tampered record does not verify
+    let mut tampered = records.get(0).unwrap();
+    tampered.amount = 1_000_000;
+    let proof = crate::RecordProof {
+        snapshot_id,
+        record: tampered,
+        siblings: soroban_sdk::vec![&env, leaves.get(1).unwrap(), leaves.get(2).unwrap()],
+    };
+    assert!(!contract.verify_record(&proof));
+}
+
+#[test]
+fn test_amount_entrypoints_reject_zero_and_negative() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+    token.mint(&agent, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.deposit_collateral(&agent, &500);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let reserved = contract.reserve_id(&sender);
+    let zero = Err(Ok(crate::ContractError::ZeroAmount));
+    let negative = Err(Ok(crate::ContractError::NegativeAmount));
+
+    for (amount, expected) in [(0i128, &zero), (-1, &negative)] {
+        let purpose = PurposeCode::FamilySupport;
+        assert_eq!(&contract.try_create_remittance(&sender, &agent, &amount, &purpose, &None, &None, &None, &None, &None).map(|_| ()), expected);
+        assert_eq!(&contract.try_create_remittance_auto(&sender, &amount, &None).map(|_| ()), expected);
+        assert_eq!(&contract.try_create_reserved(&reserved, &agent, &amount, &None), expected);
+        assert_eq!(&contract.try_create_routed_remittance(&sender, &soroban_sdk::vec![&env, agent.clone()], &amount).map(|_| ()), expected);
+        assert_eq!(&contract.try_create_split_remittance(&sender, &soroban_sdk::vec![&env, (agent.clone(), amount)]).map(|_| ()), expected);
+        assert_eq!(&contract.try_amend_remittance(&id, &amount, &agent), expected);
+        assert_eq!(&contract.try_top_up_remittance(&id, &amount).map(|_| ()), expected);
+        assert_eq!(&contract.try_confirm_partial(&id, &amount).map(|_| ()), expected);
+        assert_eq!(&contract.try_confirm_payout_with_swap(&id, &token.address, &amount), expected);
+        assert_eq!(&contract.try_confirm_payout_with_fx(&id, &amount, &1_290_000_000), expected);
+        assert_eq!(&contract.try_deposit_collateral(&agent, &amount).map(|_| ()), expected);
+        assert_eq!(&contract.try_withdraw_collateral(&agent, &amount).map(|_| ()), expected);
+        assert_eq!(&contract.try_deposit_idle_escrow(&amount), expected);
+        assert_eq!(&contract.try_withdraw_idle_escrow(&amount), expected);
+        assert_eq!(&contract.try_set_max_transfer_amount(&crate::LimitScope::Token(token.address.clone()), &amount), expected);
+    }
+
+    // Zero is a meaningful minimum, bounty and daily limit; only negatives are rejected
+    assert_eq!(contract.try_set_min_transfer_amount(&token.address, &-1), negative);
+    assert_eq!(contract.try_set_sweep_bounty(&-1), negative);
+    assert_eq!(
+        contract.try_set_daily_limit(&String::from_str(&env, "USD"), &String::from_str(&env, "PH"), &-1),
+        negative
+    );
+
+    // Nothing moved
+    assert_eq!(contract.get_remittance(&id).amount, 1000);
+    assert_eq!(contract.get_agent_collateral(&agent).0, 500);
+    assert_eq!(get_token_balance(&token, &sender), 9000);
+}
+
+#[cfg(feature = "kyc")]
+#[test]
+fn test_declare_intent_rejects_zero_and_negative() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    assert_eq!(contract.try_declare_intent(&sender, &agent, &0).map(|_| ()), Err(Ok(crate::ContractError::ZeroAmount)));
+    assert_eq!(contract.try_declare_intent(&sender, &agent, &-5).map(|_| ()), Err(Ok(crate::ContractError::NegativeAmount)));
+}
+
+#[cfg(feature = "loyalty")]
+#[test]
+fn test_redeem_points_rejects_zero_and_negative() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let user = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+
+    assert_eq!(contract.try_redeem_points(&user, &0), Err(Ok(crate::ContractError::ZeroAmount)));
+    assert_eq!(contract.try_redeem_points(&user, &-5), Err(Ok(crate::ContractError::NegativeAmount)));
+}
+
+#[cfg(feature = "oracle")]
+#[test]
+fn test_quote_and_fx_rate_reject_zero_and_negative() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+
+    let php = String::from_str(&env, "PHP");
+    assert_eq!(contract.try_set_fx_rate(&php, &0), Err(Ok(crate::ContractError::ZeroAmount)));
+    assert_eq!(contract.try_set_fx_rate(&php, &-1), Err(Ok(crate::ContractError::NegativeAmount)));
+
+    contract.set_fx_rate(&php, &560_000_000);
+    assert_eq!(contract.try_quote(&0, &php, &None, &None).map(|_| ()), Err(Ok(crate::ContractError::ZeroAmount)));
+    assert_eq!(contract.try_quote(&-1, &php, &None, &None).map(|_| ()), Err(Ok(crate::ContractError::NegativeAmount)));
+}
+
+#[test]
+fn test_daily_limit_hierarchy() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+
+    let usd = String::from_str(&env, "USD");
+    let eur = String::from_str(&env, "EUR");
+    let us = String::from_str(&env, "US");
+    let ph = String::from_str(&env, "PH");
+
+    assert_eq!(contract.get_daily_limit(&usd, &ph), None);
+
+    contract.set_default_daily_limit(&20000);
+    let limit = contract.get_daily_limit(&eur, &us).unwrap();
+    assert_eq!((limit.limit, limit.level), (20000, crate::DailyLimitLevel::Global));
+
+    // A currency default covers every country of the currency
+    contract.set_currency_daily_limit(&usd, &10000);
+    for country in [&us, &ph] {
+        let limit = contract.get_daily_limit(&usd, country).unwrap();
+        assert_eq!((limit.limit, limit.level), (10000, crate::DailyLimitLevel::Currency));
+    }
+    assert_eq!(contract.get_daily_limit(&eur, &us).unwrap().limit, 20000);
+
+    // A pair override wins for its country only, including case-insensitively
+    contract.set_daily_limit(&String::from_str(&env, "usd"), &String::from_str(&env, "ph"), &3000);
+    let limit = contract.get_daily_limit(&usd, &ph).unwrap();
+    assert_eq!((limit.limit, limit.level), (3000, crate::DailyLimitLevel::Country));
+    assert_eq!(limit.country, ph);
+    assert_eq!(contract.get_daily_limit(&usd, &us).unwrap().limit, 10000);
+
+    // Removing a level falls back to the next one
+    contract.remove_daily_limit(&usd, &ph);
+    assert_eq!(contract.get_daily_limit(&usd, &ph).unwrap().level, crate::DailyLimitLevel::Currency);
+    contract.remove_currency_daily_limit(&usd);
+    assert_eq!(contract.get_currency_daily_limit(&usd), None);
+    assert_eq!(contract.get_daily_limit(&usd, &ph).unwrap().level, crate::DailyLimitLevel::Global);
+    contract.remove_default_daily_limit();
+    assert_eq!(contract.get_default_daily_limit(), None);
+    assert_eq!(contract.get_daily_limit(&usd, &ph), None);
+
+    assert_eq!(contract.try_set_currency_daily_limit(&usd, &-1), Err(Ok(crate::ContractError::NegativeAmount)));
+    assert_eq!(contract.try_set_default_daily_limit(&-1), Err(Ok(crate::ContractError::NegativeAmount)));
+}
+
+#[test]
+fn test_register_and_remove_agents_in_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let existing = Address::generate(&env);
+    let new_agent = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&existing);
+
+    let outcomes = contract.register_agents(&soroban_sdk::vec![&env, new_agent.clone(), existing.clone(), new_agent.clone()]);
+    assert_eq!(outcomes.len(), 3);
+    assert_eq!(outcomes.get(0).unwrap(), crate::AgentBatchOutcome { agent: new_agent.clone(), error: None });
+    assert_eq!(outcomes.get(1).unwrap().error, Some(crate::ContractError::InvalidStatus as u32));
+    // A duplicate within the batch is already registered by the earlier entry
+    assert_eq!(outcomes.get(2).unwrap().error, Some(crate::ContractError::InvalidStatus as u32));
+    assert!(contract.is_agent_registered(&new_agent));
+    assert_eq!(contract.get_contract_state().agent_count, 2);
+
+    let outcomes = contract.remove_agents(&soroban_sdk::vec![&env, existing.clone(), stranger.clone()]);
+    assert_eq!(outcomes.get(0).unwrap().error, None);
+    assert_eq!(outcomes.get(1).unwrap().error, Some(crate::ContractError::AgentNotRegistered as u32));
+    assert!(!contract.is_agent_registered(&existing));
+    assert_eq!(contract.get_contract_state().agent_count, 1);
+
+    assert_eq!(contract.try_register_agents(&Vec::new(&env)), Err(Ok(crate::ContractError::InvalidAmount)));
+    let mut oversized = Vec::new(&env);
+    for _ in 0..(crate::MAX_BATCH_SIZE + 1) {
+        oversized.push_back(Address::generate(&env));
+    }
+    assert_eq!(contract.try_remove_agents(&oversized), Err(Ok(crate::ContractError::LimitExceeded)));
+}
+
+#[test]
+fn test_sponsored_creation_pays_relayer_and_refills_from_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sponsor = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sponsor, &1000);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    contract.fund_sponsor_pool(&sponsor, &500);
+    contract.set_sponsor_relay_fee(&sponsor, &20);
+    let expiration = env.ledger().sequence() + 100;
+
+    // Relayers must be approved by the sponsor
+    assert_eq!(
+        contract.try_relay_create_sponsored(&sponsor, &relayer, &sender, &agent, &1000, &PurposeCode::FamilySupport, &0, &expiration),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+    contract.set_sponsor_relayer(&sponsor, &relayer, &true);
+    assert!(contract.is_sponsor_relayer(&sponsor, &relayer));
+
+    let remittance_id = contract.relay_create_sponsored(&sponsor, &relayer, &sender, &agent, &1000, &PurposeCode::FamilySupport, &0, &expiration);
+    assert_eq!(get_token_balance(&token, &relayer), 20);
+    let budget = contract.get_sponsor_budget(&sponsor);
+    assert_eq!((budget.balance, budget.spent, budget.sponsored), (480, 20, 1));
+
+    // Only a sender's first relayed operation is sponsored
+    assert_eq!(
+        contract.try_relay_create_sponsored(&sponsor, &relayer, &sender, &agent, &1000, &PurposeCode::FamilySupport, &1, &expiration),
+        Err(Ok(crate::ContractError::InvalidStatus))
+    );
+
+    // The payout returns the relay fee to the pool out of the 25 platform fee
+    contract.confirm_payout(&remittance_id);
+    let budget = contract.get_sponsor_budget(&sponsor);
+    assert_eq!((budget.balance, budget.reimbursed), (500, 20));
+    assert_eq!(contract.get_accumulated_fees(), 5);
+    let report = contract.reconcile();
+    assert_eq!(report.sponsorship, 500);
+    assert_eq!(report.discrepancy, 0);
+
+    assert_eq!(contract.try_withdraw_sponsor_pool(&sponsor, &501), Err(Ok(crate::ContractError::InsufficientEscrow)));
+    contract.withdraw_sponsor_pool(&sponsor, &500);
+    assert_eq!(get_token_balance(&token, &sponsor), 1000);
+}
+
+#[test]
+fn test_every_status_change_emits_status_changed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let paid = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout(&paid);
+    let cancelled = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.cancel_remittance(&cancelled);
+
+    let mut changes: std::vec::Vec<(u64, Option<crate::RemittanceStatus>, crate::RemittanceStatus, Option<Address>)> =
+        std::vec::Vec::new();
+    for event in env.events().all().iter() {
+        if event.0 != contract.address
+            || Symbol::from_val(&env, &event.1.get(0).unwrap()) != symbol_short!("status")
+            || Symbol::from_val(&env, &event.1.get(1).unwrap()) != symbol_short!("changed")
+        {
+            continue;
+        }
+        let data: soroban_sdk::Vec<soroban_sdk::Val> = soroban_sdk::FromVal::from_val(&env, &event.2);
+        changes.push((
+            soroban_sdk::FromVal::from_val(&env, &data.get(4).unwrap()),
+            soroban_sdk::FromVal::from_val(&env, &data.get(5).unwrap()),
+            soroban_sdk::FromVal::from_val(&env, &data.get(6).unwrap()),
+            soroban_sdk::FromVal::from_val(&env, &data.get(7).unwrap()),
+        ));
+    }
+
+    use crate::RemittanceStatus::*;
+    assert_eq!(
+        changes,
+        std::vec![
+            (paid, None, Pending, Some(sender.clone())),
+            (paid, Some(Pending), Settled, Some(agent.clone())),
+            (cancelled, None, Pending, Some(sender.clone())),
+            (cancelled, Some(Pending), Failed, Some(sender.clone())),
+        ]
+    );
+}
+
+#[test]
+fn test_register_and_unregister_watcher() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let owner = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+
+    let filter = soroban_sdk::vec![&env, symbol_short!("status")];
+    let watcher_id = contract.register_watcher(&owner, &filter);
+    let watcher = contract.get_watcher(&watcher_id).unwrap();
+    assert_eq!(watcher.owner, owner);
+    assert_eq!(watcher.topic_filter, filter);
+
+    let mut oversized = soroban_sdk::Vec::new(&env);
+    for _ in 0..(crate::MAX_WATCHER_TOPICS + 1) {
+        oversized.push_back(symbol_short!("remit"));
+    }
+    assert_eq!(contract.try_register_watcher(&owner, &oversized), Err(Ok(crate::ContractError::LimitExceeded)));
+
+    contract.unregister_watcher(&watcher_id);
+    assert_eq!(contract.get_watcher(&watcher_id), None);
+    assert_eq!(contract.try_unregister_watcher(&watcher_id), Err(Ok(crate::ContractError::InvalidStatus)));
+}
+
+#[test]
+fn test_whitelist_rejects_non_token_contracts() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+
+    // Decimals are stored when the token is verified
+    assert_eq!(contract.get_fee_rounding(&token.address).decimals, 7);
+
+    // Neither an address without a contract nor a contract that is not a token is accepted
+    let account = Address::generate(&env);
+    assert_eq!(contract.try_whitelist_token(&admin, &account), Err(Ok(crate::ContractError::UnsupportedToken)));
+    let not_a_token = create_swiftremit_contract(&env);
+    assert_eq!(
+        contract.try_whitelist_token(&admin, &not_a_token.address),
+        Err(Ok(crate::ContractError::UnsupportedToken))
+    );
+    assert!(!contract.is_token_whitelisted(&account));
+
+    let other = create_token_contract(&env, &token_admin);
+    contract.whitelist_token(&admin, &other.address);
+    assert!(contract.is_token_whitelisted(&other.address));
+}
+
+#[test]
+fn test_sender_cannot_pay_out_to_itself() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.register_agent(&sender);
+
+    assert_eq!(
+        contract.try_create_remittance(&sender, &sender, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None),
+        Err(Ok(crate::ContractError::SelfDealing))
+    );
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(contract.try_set_payout_recipient(&id, &sender), Err(Ok(crate::ContractError::SelfDealing)));
+    assert_eq!(
+        contract.try_reassign_remittance(&sender, &id, &sender),
+        Err(Ok(crate::ContractError::SelfDealing))
+    );
+}
+
+#[cfg(feature = "corridors")]
+#[test]
+fn test_corridor_allows_self_payout() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let agent = Address::generate(&env);
+    token.mint(&agent, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let corridor = soroban_sdk::String::from_str(&env, "US-PH");
+    contract.set_agent_corridor(&agent, &corridor);
+    assert!(!contract.is_corridor_self_payout_allowed(&corridor));
+    assert_eq!(
+        contract.try_create_remittance(&agent, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None),
+        Err(Ok(crate::ContractError::SelfDealing))
+    );
+
+    contract.set_corridor_self_payout(&corridor, &true);
+    assert!(contract.is_corridor_self_payout_allowed(&corridor));
+    let id = contract.create_remittance(&agent, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout(&id);
+    assert_eq!(get_token_balance(&token, &agent), 9975);
+
+    contract.set_corridor_self_payout(&corridor, &false);
+    assert_eq!(
+        contract.try_create_remittance(&agent, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None),
+        Err(Ok(crate::ContractError::SelfDealing))
+    );
+}
+
+#[test]
+fn test_volume_tiers_discount_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &20000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let descending = soroban_sdk::vec![
+        &env,
+        crate::VolumeTier { min_volume: 5000, discount_bps: 5000 },
+        crate::VolumeTier { min_volume: 1000, discount_bps: 2000 },
+    ];
+    assert_eq!(contract.try_set_volume_tiers(&descending), Err(Ok(crate::ContractError::InvalidAmount)));
+    let too_generous = soroban_sdk::vec![&env, crate::VolumeTier { min_volume: 1000, discount_bps: 10001 }];
+    assert_eq!(contract.try_set_volume_tiers(&too_generous), Err(Ok(crate::ContractError::InvalidFeeBps)));
+
+    let tiers = soroban_sdk::vec![
+        &env,
+        crate::VolumeTier { min_volume: 1000, discount_bps: 2000 },
+        crate::VolumeTier { min_volume: 5000, discount_bps: 5000 },
+    ];
+    contract.set_volume_tiers(&tiers);
+    assert_eq!(contract.get_volume_tiers(), tiers);
+
+    // Cancelled remittances do not count towards the sender's volume
+    let cancelled = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.cancel_remittance(&cancelled);
+    assert_eq!(contract.get_sender_volume(&sender), 0);
+
+    let first = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(contract.get_remittance(&first).fee, 25);
+    contract.confirm_payout(&first);
+    assert_eq!(contract.get_sender_volume(&sender), 1000);
+    assert_eq!(contract.get_volume_discount_bps(&sender), 2000);
+
+    let second = contract.create_remittance(&sender, &agent, &4000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(contract.get_remittance(&second).fee, 80);
+    contract.confirm_payout(&second);
+    assert_eq!(contract.get_volume_discount_bps(&sender), 5000);
+
+    let third = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(contract.get_remittance(&third).fee, 12);
+
+    // Removing the tiers restores the full fee
+    contract.set_volume_tiers(&soroban_sdk::Vec::new(&env));
+    let fourth = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(contract.get_remittance(&fourth).fee, 25);
+}
+
+#[test]
+fn test_cancellations_start_cooldown() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.set_cancel_cooldown(&2, &3600, &600);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let first = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.cancel_remittance(&first);
+    assert_eq!(contract.get_cooldown(&sender), None);
+
+    let second = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.cancel_remittance(&second);
+    assert_eq!(contract.get_cooldown(&sender), Some(1_600));
+    assert_eq!(
+        contract.try_create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None),
+        Err(Ok(crate::ContractError::RateLimited))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 1_600);
+    assert_eq!(contract.get_cooldown(&sender), None);
+    let third = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+
+    // The count started over, and cancellations outside the window do not add up
+    contract.cancel_remittance(&third);
+    env.ledger().with_mut(|li| li.timestamp = 1_600 + 3_600);
+    let fourth = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.cancel_remittance(&fourth);
+    assert_eq!(contract.get_cooldown(&sender), None);
+}
+
+#[test]
+fn test_relayed_cancellations_start_cooldown() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.set_cancel_cooldown(&2, &3600, &600);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let expiration = env.ledger().sequence() + 100;
+    let first = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.relay_cancel_remittance(&first, &0, &expiration);
+    assert_eq!(contract.get_cooldown(&sender), None);
+
+    let second = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.relay_cancel_remittance(&second, &1, &expiration);
+    assert_eq!(contract.get_cooldown(&sender), Some(1_600));
+    assert_eq!(
+        contract.try_create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None),
+        Err(Ok(crate::ContractError::RateLimited))
+    );
+}
+
+#[test]
+fn test_operator_runs_automation_entrypoints() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let bot = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.set_settlement_schedule(&agent, &crate::SettlementSchedule::OnDemand);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout(&id);
+
+    assert_eq!(contract.try_settle_agent(&bot, &agent), Err(Ok(crate::ContractError::Unauthorized)));
+    assert_eq!(
+        contract.try_archive_remittances(&bot, &(env.ledger().sequence() + 1), &10),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+
+    contract.set_operator(&bot, &true);
+    assert!(contract.is_operator(&bot));
+
+    // The operator triggers the settlement, but the payouts still go to the agent
+    contract.settle_agent(&bot, &agent);
+    assert_eq!(get_token_balance(&token, &agent), 975);
+    assert_eq!(get_token_balance(&token, &bot), 0);
+
+    let archived = contract.archive_remittances(&bot, &(env.ledger().sequence() + 1), &10);
+    assert_eq!(archived, soroban_sdk::vec![&env, id]);
+
+    contract.set_operator(&bot, &false);
+    assert!(!contract.is_operator(&bot));
+    assert_eq!(contract.try_settle_agent(&bot, &agent), Err(Ok(crate::ContractError::Unauthorized)));
+}
+
+#[test]
+fn test_settlement_breakdown() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.set_insurance_premium(&100);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.purchase_insurance(&id);
+    assert_eq!(contract.try_get_settlement_breakdown(&id), Err(Ok(crate::ContractError::InvalidStatus)));
+
+    contract.confirm_payout(&id);
+    assert_eq!(contract.get_remittance(&id).status, crate::RemittanceStatus::Completed);
+
+    let breakdown = contract.get_settlement_breakdown(&id);
+    assert_eq!(breakdown.gross_amount, 1000);
+    assert_eq!(breakdown.platform_fee, 25);
+    assert_eq!(breakdown.agent_commission, 0);
+    assert_eq!(breakdown.sponsor_share, 0);
+    assert_eq!(breakdown.sla_rebate, 0);
+    assert_eq!(breakdown.insurance_premium, 10);
+    assert_eq!(breakdown.net_payout, 975);
+    assert_eq!(get_token_balance(&token, &agent), breakdown.net_payout);
+
+    // Refunded remittances were never paid out
+    let cancelled = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.cancel_remittance(&cancelled);
+    assert_eq!(contract.try_get_settlement_breakdown(&cancelled), Err(Ok(crate::ContractError::InvalidStatus)));
+
+    assert_eq!(contract.try_get_settlement_breakdown(&99), Err(Ok(crate::ContractError::RemittanceNotFound)));
+}
+
+#[test]
+fn test_currency_codes_are_normalized_and_validated() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let usd = crate::Currency::parse(&env, &String::from_str(&env, "usd")).unwrap();
+    assert_eq!(usd, crate::Currency(soroban_sdk::Symbol::new(&env, "USD")));
+    assert_eq!(crate::Currency::parse(&env, &String::from_str(&env, "Usd")), Ok(usd.clone()));
+    for code in ["US", "USDC", "US1", "U$D", ""] {
+        assert_eq!(
+            crate::Currency::parse(&env, &String::from_str(&env, code)),
+            Err(crate::ContractError::CorridorClosed)
+        );
+    }
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let agent = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    // A limit set in one casing is found in another
+    let ph = String::from_str(&env, "PH");
+    contract.set_daily_limit(&String::from_str(&env, "usd"), &ph, &5000);
+    let limit = contract.get_daily_limit(&String::from_str(&env, "USD"), &ph).unwrap();
+    assert_eq!((limit.currency, limit.limit), (usd, 5000));
+
+    let invalid = String::from_str(&env, "US");
+    assert_eq!(contract.try_set_daily_limit(&invalid, &ph, &5000), Err(Ok(crate::ContractError::CorridorClosed)));
+    assert_eq!(contract.try_set_currency_daily_limit(&invalid, &5000), Err(Ok(crate::ContractError::CorridorClosed)));
+    assert_eq!(contract.get_daily_limit(&invalid, &ph), None);
+
+    let area = crate::CoverageArea {
+        country: ph.clone(),
+        city: None,
+        currency: invalid.clone(),
+    };
+    assert_eq!(
+        contract.try_set_agent_coverage(&agent, &soroban_sdk::vec![&env, area]),
+        Err(Ok(crate::ContractError::CorridorClosed))
+    );
+    let area = crate::CoverageArea {
+        country: ph.clone(),
+        city: None,
+        currency: String::from_str(&env, "php"),
+    };
+    contract.set_agent_coverage(&agent, &soroban_sdk::vec![&env, area]);
+    assert_eq!(contract.find_agents(&ph, &String::from_str(&env, "PHP"), &0, &10), soroban_sdk::vec![&env, agent]);
+    assert!(contract.find_agents(&ph, &invalid, &0, &10).is_empty());
+}
+
+#[cfg(feature = "kyc")]
+#[test]
+fn test_limit_exempt_sender_bypasses_band_and_rate_limits() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let institution = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let officer = Address::generate(&env);
+    token.mint(&institution, &100000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.set_risk_scorer(&officer, &true);
+    contract.set_risk_bands(&soroban_sdk::vec![&env, crate::RiskBand { min_score: 0, max_amount: 500, max_per_day: 1 }]);
+    contract.set_create_rate_limit(&1, &100);
+
+    let result = contract.try_create_remittance(&institution, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(result, Err(Ok(crate::ContractError::AmountAboveMaximum)));
+
+    contract.set_limit_exempt(&officer, &institution, &true);
+    assert!(contract.is_limit_exempt(&institution));
+    for _ in 0..3 {
+        contract.create_remittance(&institution, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    }
+
+    let exemptions = env
+        .events()
+        .all()
+        .iter()
+        .filter(|event| {
+            event.0 == contract.address
+                && Symbol::from_val(&env, &event.1.get(0).unwrap()) == symbol_short!("risk")
+                && Symbol::from_val(&env, &event.1.get(1).unwrap()) == symbol_short!("exempt")
+        })
+        .count();
+    assert_eq!(exemptions, 1);
+
+    contract.set_limit_exempt(&officer, &institution, &false);
+    assert!(!contract.is_limit_exempt(&institution));
+    let result = contract.try_create_remittance(&institution, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(result, Err(Ok(crate::ContractError::AmountAboveMaximum)));
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        contract.try_set_limit_exempt(&stranger, &institution, &true),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_ttl_policy_extends_written_entries() {
+    use soroban_sdk::testutils::storage::Persistent as _;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+
+    let defaults = contract.get_ttl_policy();
+    assert_eq!(defaults.remittances.extend_to, 120 * crate::DAY_IN_LEDGERS);
+    assert_eq!(defaults.history.extend_to, 30 * crate::DAY_IN_LEDGERS);
+
+    let policy = crate::TtlPolicy { threshold: 5000, extend_to: 50000 };
+    contract.set_ttl_policy(&crate::TtlClass::History, &policy);
+    assert_eq!(contract.get_ttl_policy().history, policy);
+    assert_eq!(contract.get_ttl_policy().remittances, defaults.remittances);
+
+    env.as_contract(&contract.address, || {
+        let key = symbol_short!("entry");
+        env.storage().persistent().set(&key, &1u32);
+        crate::extend_persistent_ttl(&env, crate::TtlClass::History, &key);
+        assert_eq!(env.storage().persistent().get_ttl(&key), 50000);
+    });
+
+    let inverted = crate::TtlPolicy { threshold: 50000, extend_to: 5000 };
+    assert_eq!(
+        contract.try_set_ttl_policy(&crate::TtlClass::Indexes, &inverted),
+        Err(Ok(crate::ContractError::InvalidAmount))
+    );
+    let too_long = crate::TtlPolicy { threshold: 0, extend_to: u32::MAX };
+    assert_eq!(
+        contract.try_set_ttl_policy(&crate::TtlClass::Indexes, &too_long),
+        Err(Ok(crate::ContractError::LimitExceeded))
+    );
+}
+
+#[test]
+fn test_direct_remittance_pays_agent_without_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    assert_eq!(
+        contract.try_record_direct_remittance(&sender, &agent, &1000),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+
+    contract.set_direct_pair(&sender, &agent, &true);
+    assert!(contract.is_direct_pair(&sender, &agent));
+    let id = contract.record_direct_remittance(&sender, &agent, &1000);
+
+    assert_eq!(get_token_balance(&token, &agent), 975);
+    assert_eq!(get_token_balance(&token, &contract.address), 25);
+    assert_eq!(get_token_balance(&token, &sender), 9000);
+    assert_eq!(contract.get_accumulated_fees(), 25);
+
+    let remittance = contract.get_remittance(&id);
+    assert_eq!(remittance.status, crate::RemittanceStatus::Completed);
+    assert_eq!(remittance.purpose, PurposeCode::Other);
+    assert_eq!(contract.try_confirm_payout(&id), Err(Ok(crate::ContractError::InvalidStatus)));
+    assert_eq!(contract.try_cancel_remittance(&id), Err(Ok(crate::ContractError::InvalidStatus)));
+
+    contract.set_direct_pair(&sender, &agent, &false);
+    assert_eq!(
+        contract.try_record_direct_remittance(&sender, &agent, &1000),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_staking_rewards_shared_pro_rata_to_collateral() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let other_agent = Address::generate(&env);
+    token.mint(&sender, &100000);
+    token.mint(&agent, &10000);
+    token.mint(&other_agent, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.register_agent(&other_agent);
+    contract.set_staking_share(&4000);
+    contract.deposit_collateral(&agent, &3000);
+    contract.deposit_collateral(&other_agent, &1000);
+
+    // 40% of the 250 fee is shared 3:1
+    let id = contract.create_remittance(&sender, &agent, &10000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout(&id);
+    assert_eq!(contract.get_staking_rewards(&agent), 75);
+    assert_eq!(contract.get_staking_rewards(&other_agent), 25);
+    assert_eq!(contract.get_accumulated_fees(), 150);
+
+    // Collateral posted later only earns from later payouts
+    contract.withdraw_collateral(&agent, &2000);
+    let id = contract.create_remittance(&sender, &other_agent, &10000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout(&id);
+    assert_eq!(contract.get_staking_rewards(&agent), 125);
+    assert_eq!(contract.get_staking_rewards(&other_agent), 75);
+
+    let before = get_token_balance(&token, &agent);
+    assert_eq!(contract.claim_staking_rewards(&agent), 125);
+    assert_eq!(get_token_balance(&token, &agent), before + 125);
+    assert_eq!(contract.get_staking_rewards(&agent), 0);
+    assert_eq!(contract.try_claim_staking_rewards(&agent), Err(Ok(crate::ContractError::NoFeesToWithdraw)));
+    assert_eq!(contract.reconcile().discrepancy, 0);
+}
+
+#[test]
+fn test_pause_op_halts_only_that_operation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    let first = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let second = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+
+    let create = PausableOp::CreateRemittance;
+    contract.pause_op(&create);
+    contract.pause_op(&create);
+    assert!(contract.is_op_paused(&create));
+    assert_eq!(contract.get_paused_ops().len(), 1);
+    assert!(!contract.is_paused());
+
+    let result = contract.try_create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(result, Err(Ok(crate::ContractError::ContractPaused)));
+
+    // Payouts and refunds keep flowing
+    contract.confirm_payout(&first);
+    contract.cancel_remittance(&second);
+
+    contract.unpause_op(&create);
+    assert!(!contract.is_op_paused(&create));
+    assert!(contract.get_paused_ops().is_empty());
+    contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+
+    let confirm = PausableOp::ConfirmPayout;
+    contract.pause_op(&confirm);
+    let third = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(contract.try_confirm_payout(&third), Err(Ok(crate::ContractError::ContractPaused)));
+
+    // Fee distribution and staking claims are fee withdrawals too
+    contract.pause_op(&PausableOp::WithdrawFees);
+    assert_eq!(contract.try_distribute_fees(), Err(Ok(crate::ContractError::ContractPaused)));
+    assert_eq!(contract.try_claim_staking_rewards(&agent), Err(Ok(crate::ContractError::ContractPaused)));
+}
+
+#[test]
+fn test_initialize_applies_and_validates_config() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let other_token = create_token_contract(&env, &token_admin);
+    let treasury = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &100000);
+
+    let contract = create_swiftremit_contract(&env);
+    let base = InitConfig::new(&env, &admin, &token.address, 250);
+
+    // Invalid configurations are rejected before anything is stored
+    let mut config = base.clone();
+    config.tokens.push_back(token.address.clone());
+    assert_eq!(contract.try_initialize(&config), Err(Ok(crate::ContractError::TokenAlreadyWhitelisted)));
+    let config = InitConfig { fee_bps: 10001, ..base.clone() };
+    assert_eq!(contract.try_initialize(&config), Err(Ok(crate::ContractError::InvalidFeeBps)));
+    let config = InitConfig { min_transfer_amount: 500, max_transfer_amount: Some(100), ..base.clone() };
+    assert_eq!(contract.try_initialize(&config), Err(Ok(crate::ContractError::InvalidAmount)));
+    let config = InitConfig { treasury: Some(contract.address.clone()), ..base.clone() };
+    assert_eq!(contract.try_initialize(&config), Err(Ok(crate::ContractError::InvalidAddress)));
+    assert!(!contract.is_token_whitelisted(&token.address));
+
+    let mut config = InitConfig {
+        min_transfer_amount: 100,
+        max_transfer_amount: Some(50000),
+        treasury: Some(treasury.clone()),
+        ..base.clone()
+    };
+    config.tokens.push_back(other_token.address.clone());
+    config.paused_ops.push_back(PausableOp::CancelRemittance);
+    contract.initialize(&config);
+    assert_eq!(contract.try_initialize(&base), Err(Ok(crate::ContractError::AlreadyInitialized)));
+
+    assert!(contract.is_token_whitelisted(&token.address));
+    assert!(contract.is_token_whitelisted(&other_token.address));
+    assert_eq!(contract.get_min_transfer_amount(&token.address), 100);
+    assert_eq!(contract.get_treasury(), Some(treasury.clone()));
+    assert!(!contract.is_paused());
+    assert!(contract.is_op_paused(&PausableOp::CancelRemittance));
+
+    contract.register_agent(&agent);
+    let result = contract.try_create_remittance(&sender, &agent, &60000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(result, Err(Ok(crate::ContractError::AmountAboveMaximum)));
+    let id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert_eq!(contract.try_cancel_remittance(&id), Err(Ok(crate::ContractError::ContractPaused)));
+    contract.confirm_payout(&id);
+
+    // Fees only go to the treasury
+    assert_eq!(contract.try_withdraw_fees(&token.address, &admin), Err(Ok(crate::ContractError::Unauthorized)));
+    contract.withdraw_fees(&token.address, &treasury);
+    assert_eq!(get_token_balance(&token, &treasury), 25);
+
+    // The treasury can be moved, then cleared
+    let new_treasury = Address::generate(&env);
+    assert_eq!(contract.try_set_treasury(&Some(contract.address.clone())), Err(Ok(crate::ContractError::InvalidAddress)));
+    contract.set_treasury(&Some(new_treasury.clone()));
+    assert_eq!(contract.get_treasury(), Some(new_treasury.clone()));
+    let id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout(&id);
+    assert_eq!(contract.try_withdraw_fees(&token.address, &treasury), Err(Ok(crate::ContractError::Unauthorized)));
+    contract.withdraw_fees(&token.address, &new_treasury);
+    assert_eq!(get_token_balance(&token, &new_treasury), 25);
+
+    contract.set_treasury(&None);
+    assert_eq!(contract.get_treasury(), None);
+}
+
+#[test]
+fn test_quorum_gates_fee_distribution_and_yield_deposits() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+
+    let platform = Address::generate(&env);
+    let partner = Address::generate(&env);
+    let splits = soroban_sdk::vec![&env, (platform.clone(), 6000u32), (partner.clone(), 4000u32)];
+    contract.set_fee_split(&splits);
+
+    let signers = soroban_sdk::vec![&env, Address::generate(&env), Address::generate(&env)];
+    contract.set_quorum(&signers, &2, &100);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    contract.confirm_payout(&id);
+
+    // The single admin can no longer move fees or escrow
+    assert_eq!(contract.try_distribute_fees(), Err(Ok(crate::ContractError::Unauthorized)));
+    assert_eq!(contract.try_set_fee_split(&splits), Err(Ok(crate::ContractError::Unauthorized)));
+    let adapter = Address::generate(&env);
+    assert_eq!(
+        contract.try_set_yield_adapter(&adapter, &crate::YieldBeneficiary::InsuranceFund),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+    assert_eq!(contract.try_deposit_idle_escrow(&100), Err(Ok(crate::ContractError::Unauthorized)));
+
+    let distribute = contract.propose_op(&signers.get(0).unwrap(), &crate::CriticalOp::DistributeFees);
+    contract.approve_op(&signers.get(1).unwrap(), &distribute);
+    contract.execute_op(&distribute);
+    assert_eq!(get_token_balance(&token, &platform), 15);
+    assert_eq!(get_token_balance(&token, &partner), 10);
+    assert_eq!(contract.reconcile().discrepancy, 0);
+}
+
+#[test]
+fn test_insurance_premium_pro_rated_on_expiry_and_admin_refund() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    token.mint(&sender, &10000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
+    contract.register_agent(&agent);
+    contract.set_insurance_premium(&100);
+
+    // Refunded by the admin halfway through the coverage window
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let cancelled = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &Some(3_000), &None, &None, &None, &None);
+    contract.purchase_insurance(&cancelled);
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    contract.admin_cancel_remittance(&cancelled, &crate::CancelReason::Operational);
+
+    let policy = contract.get_insurance_policy(&cancelled).unwrap();
+    assert_eq!(policy.status, crate::PremiumStatus::PartiallyRefunded);
+    assert_eq!(policy.refunded, 5);
+    assert_eq!(get_token_balance(&token, &sender), 9995);
+    assert_eq!(contract.get_insurance_fund(), 5);
+
+    // Expired with the whole coverage window used
+    let expired = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &Some(4_000), &None, &None, &None, &None);
+    contract.purchase_insurance(&expired);
+    env.ledger().with_mut(|li| li.timestamp = 4_001);
+    assert_eq!(contract.sweep_expired(&keeper, &10), 1);
+
+    let policy = contract.get_insurance_policy(&expired).unwrap();
+    assert_eq!(policy.refunded, 0);
+    assert_eq!(get_token_balance(&token, &sender), 9985);
+    assert_eq!(contract.get_insurance_fund(), 15);
+    assert_eq!(contract.reconcile().discrepancy, 0);
+}
@@ -0,0 +1,876 @@
+#![cfg(test)]
+//! Authorization matrix tests.
+//!
+//! Every state-changing entry point is invoked once per non-privileged caller
+//! with only that caller's authorization mocked, and must fail each time. A
+//! final call with all auths mocked confirms the rejection came from
+//! authorization rather than from invalid arguments.
+
+use crate::{InitConfig, PurposeCode, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    token, Address, BytesN, Env, IntoVal, Val, Vec,
+};
+
+struct AuthContext<'a> {
+    env: Env,
+    contract: SwiftRemitContractClient<'a>,
+    admin: Address,
+    sender: Address,
+    other_sender: Address,
+    agent: Address,
+    other_agent: Address,
+    removed_agent: Address,
+    stranger: Address,
+    remittance_id: u64,
+}
+
+fn setup<'a>() -> AuthContext<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token_address = env.register_stellar_asset_contract_v2(token_admin).address();
+    let token = token::StellarAssetClient::new(&env, &token_address);
+
+    let sender = Address::generate(&env);
+    let other_sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let other_agent = Address::generate(&env);
+    let removed_agent = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    token.mint(&sender, &10000);
+    token.mint(&other_sender, &10000);
+    token.mint(&agent, &10000);
+
+    let contract =
+        SwiftRemitContractClient::new(&env, &env.register_contract(None, SwiftRemitContract {}));
+    contract.initialize(&InitConfig::new(&env, &admin, &token_address, 250));
+    contract.register_agent(&agent);
+    contract.register_agent(&other_agent);
+    contract.register_agent(&removed_agent);
+    contract.remove_agent(&removed_agent);
+    contract.set_insurance_premium(&100);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+
+    AuthContext {
+        env,
+        contract,
+        admin,
+        sender,
+        other_sender,
+        agent,
+        other_agent,
+        removed_agent,
+        stranger,
+        remittance_id,
+    }
+}
+
+/// Replaces all mocked auths with a single authorization from `caller`.
+fn authorize_only(env: &Env, contract: &Address, caller: &Address, fn_name: &str, args: Vec<Val>) {
+    env.mock_auths(&[MockAuth {
+        address: caller,
+        invoke: &MockAuthInvoke {
+            contract,
+            fn_name,
+            args,
+            sub_invokes: &[],
+        },
+    }]);
+}
+
+/// Asserts that `$method` fails for every caller in `$denied` and succeeds
+/// once all auths are mocked.
+macro_rules! assert_auth_matrix {
+    ($ctx:expr, $denied:expr, $fn_name:literal, $method:ident, $try_method:ident $(, $arg:expr)*) => {{
+        let ctx = &$ctx;
+        #[allow(unused_mut)]
+        let mut args: Vec<Val> = Vec::new(&ctx.env);
+        $(args.push_back($arg.clone().into_val(&ctx.env));)*
+
+        for caller in $denied.iter() {
+            authorize_only(&ctx.env, &ctx.contract.address, caller, $fn_name, args.clone());
+            assert!(
+                ctx.contract.$try_method($(&$arg),*).is_err(),
+                "{} accepted an unauthorized caller",
+                $fn_name
+            );
+        }
+
+        ctx.env.mock_all_auths();
+        ctx.contract.$method($(&$arg),*);
+    }};
+}
+
+fn non_admins(ctx: &AuthContext) -> [Address; 4] {
+    [
+        ctx.sender.clone(),
+        ctx.agent.clone(),
+        ctx.removed_agent.clone(),
+        ctx.stranger.clone(),
+    ]
+}
+
+fn non_senders(ctx: &AuthContext) -> [Address; 5] {
+    [
+        ctx.admin.clone(),
+        ctx.agent.clone(),
+        ctx.other_sender.clone(),
+        ctx.removed_agent.clone(),
+        ctx.stranger.clone(),
+    ]
+}
+
+fn non_agents(ctx: &AuthContext) -> [Address; 5] {
+    [
+        ctx.admin.clone(),
+        ctx.sender.clone(),
+        ctx.other_agent.clone(),
+        ctx.removed_agent.clone(),
+        ctx.stranger.clone(),
+    ]
+}
+
+// ── Admin-only entry points ─────────────────────────────────────────
+
+#[test]
+fn test_auth_register_agent() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let new_agent = Address::generate(&ctx.env);
+    assert_auth_matrix!(ctx, denied, "register_agent", register_agent, try_register_agent, new_agent);
+}
+
+#[test]
+fn test_auth_remove_agent() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let agent = ctx.other_agent.clone();
+    assert_auth_matrix!(ctx, denied, "remove_agent", remove_agent, try_remove_agent, agent);
+}
+
+#[test]
+fn test_auth_batch_agents() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let new_agents: Vec<Address> = soroban_sdk::vec![&ctx.env, Address::generate(&ctx.env)];
+    let agents: Vec<Address> = soroban_sdk::vec![&ctx.env, ctx.other_agent.clone()];
+    assert_auth_matrix!(ctx, denied, "register_agents", register_agents, try_register_agents, new_agents);
+    assert_auth_matrix!(ctx, denied, "remove_agents", remove_agents, try_remove_agents, agents);
+}
+
+#[test]
+fn test_auth_suspend_and_reinstate_agent() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let agent = ctx.other_agent.clone();
+    assert_auth_matrix!(ctx, denied, "suspend_agent", suspend_agent, try_suspend_agent, agent);
+    assert_auth_matrix!(ctx, denied, "reinstate_agent", reinstate_agent, try_reinstate_agent, agent);
+}
+
+#[test]
+fn test_auth_update_fee() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    assert_auth_matrix!(ctx, denied, "update_fee", update_fee, try_update_fee, 300u32);
+    assert_auth_matrix!(ctx, denied, "schedule_fee_update", schedule_fee_update, try_schedule_fee_update, 300u32, 86_400u64);
+}
+
+#[test]
+fn test_auth_pause_and_unpause() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    assert_auth_matrix!(ctx, denied, "pause", pause, try_pause);
+    assert_auth_matrix!(ctx, denied, "unpause", unpause, try_unpause);
+
+    let op = crate::PausableOp::CreateRemittance;
+    assert_auth_matrix!(ctx, denied, "pause_op", pause_op, try_pause_op, op);
+    assert_auth_matrix!(ctx, denied, "unpause_op", unpause_op, try_unpause_op, op);
+}
+
+#[test]
+fn test_auth_set_insurance_premium() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    assert_auth_matrix!(ctx, denied, "set_insurance_premium", set_insurance_premium, try_set_insurance_premium, 200u32);
+}
+
+#[cfg(feature = "kyc")]
+#[test]
+fn test_auth_set_cooling_off_and_high_risk() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let sender = ctx.sender.clone();
+    assert_auth_matrix!(ctx, denied, "set_cooling_off_period", set_cooling_off_period, try_set_cooling_off_period, 60u64);
+    assert_auth_matrix!(ctx, denied, "set_high_risk_sender", set_high_risk_sender, try_set_high_risk_sender, sender, true);
+}
+
+#[test]
+fn test_auth_set_fee_split() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let mut splits: Vec<(Address, u32)> = Vec::new(&ctx.env);
+    splits.push_back((ctx.admin.clone(), 10000u32));
+    assert_auth_matrix!(ctx, denied, "set_fee_split", set_fee_split, try_set_fee_split, splits);
+}
+
+#[test]
+fn test_auth_set_treasury() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let treasury = Some(ctx.admin.clone());
+    assert_auth_matrix!(ctx, denied, "set_treasury", set_treasury, try_set_treasury, treasury);
+}
+
+#[test]
+fn test_auth_set_create_rate_limit() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    assert_auth_matrix!(ctx, denied, "set_create_rate_limit", set_create_rate_limit, try_set_create_rate_limit, 5u32, 10u32);
+}
+
+#[test]
+fn test_auth_set_cancel_cooldown() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    assert_auth_matrix!(ctx, denied, "set_cancel_cooldown", set_cancel_cooldown, try_set_cancel_cooldown, 3u32, 3600u64, 600u64);
+}
+
+#[cfg(feature = "kyc")]
+#[test]
+fn test_auth_compliance_hook() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let hook = Address::generate(&ctx.env);
+    assert_auth_matrix!(ctx, denied, "set_compliance_hook", set_compliance_hook, try_set_compliance_hook, hook);
+    assert_auth_matrix!(ctx, denied, "clear_compliance_hook", clear_compliance_hook, try_clear_compliance_hook);
+}
+
+#[cfg(feature = "kyc")]
+#[test]
+fn test_auth_sender_allowlist() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let corporate = Address::generate(&ctx.env);
+    assert_auth_matrix!(ctx, denied, "set_sender_allowlist_enabled", set_sender_allowlist_enabled, try_set_sender_allowlist_enabled, true);
+    assert_auth_matrix!(ctx, denied, "allow_sender", allow_sender, try_allow_sender, corporate.clone());
+    assert_auth_matrix!(ctx, denied, "disallow_sender", disallow_sender, try_disallow_sender, corporate);
+}
+
+#[cfg(feature = "kyc")]
+#[test]
+fn test_auth_risk_scoring() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let scorer = Address::generate(&ctx.env);
+    let bands = soroban_sdk::vec![&ctx.env, crate::RiskBand { min_score: 50, max_amount: 100, max_per_day: 1 }];
+    assert_auth_matrix!(ctx, denied, "set_risk_scorer", set_risk_scorer, try_set_risk_scorer, scorer.clone(), true);
+    assert_auth_matrix!(ctx, denied, "set_risk_bands", set_risk_bands, try_set_risk_bands, bands);
+
+    // Only appointed scorers and admins can score, each with their own signature
+    let sender = ctx.sender.clone();
+    let stranger = ctx.stranger.clone();
+    authorize_only(&ctx.env, &ctx.contract.address, &stranger, "set_risk_score", (stranger.clone(), sender.clone(), 80u32).into_val(&ctx.env));
+    assert!(ctx.contract.try_set_risk_score(&stranger, &sender, &80).is_err());
+    authorize_only(&ctx.env, &ctx.contract.address, &stranger, "set_risk_score", (scorer.clone(), sender.clone(), 80u32).into_val(&ctx.env));
+    assert!(ctx.contract.try_set_risk_score(&scorer, &sender, &80).is_err());
+
+    ctx.env.mock_all_auths();
+    ctx.contract.set_risk_score(&scorer, &sender, &80);
+
+    // Limit exemptions take the same compliance role
+    authorize_only(&ctx.env, &ctx.contract.address, &stranger, "set_limit_exempt", (stranger.clone(), sender.clone(), true).into_val(&ctx.env));
+    assert!(ctx.contract.try_set_limit_exempt(&stranger, &sender, &true).is_err());
+    authorize_only(&ctx.env, &ctx.contract.address, &stranger, "set_limit_exempt", (scorer.clone(), sender.clone(), true).into_val(&ctx.env));
+    assert!(ctx.contract.try_set_limit_exempt(&scorer, &sender, &true).is_err());
+
+    ctx.env.mock_all_auths();
+    ctx.contract.set_limit_exempt(&scorer, &sender, &true);
+}
+
+#[test]
+fn test_auth_fee_exemptions() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let partner = Address::generate(&ctx.env);
+    assert_auth_matrix!(ctx, denied, "exempt_from_fees", exempt_from_fees, try_exempt_from_fees, partner.clone());
+    assert_auth_matrix!(ctx, denied, "remove_fee_exemption", remove_fee_exemption, try_remove_fee_exemption, partner);
+}
+
+#[test]
+fn test_auth_set_volume_tiers() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let tiers = soroban_sdk::vec![&ctx.env, crate::VolumeTier { min_volume: 1000, discount_bps: 2000 }];
+    assert_auth_matrix!(ctx, denied, "set_volume_tiers", set_volume_tiers, try_set_volume_tiers, tiers);
+}
+
+#[test]
+fn test_auth_set_sweep_bounty() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    assert_auth_matrix!(ctx, denied, "set_sweep_bounty", set_sweep_bounty, try_set_sweep_bounty, 10i128);
+}
+
+#[test]
+fn test_auth_set_attestation_mode() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    assert_auth_matrix!(ctx, denied, "set_attestation_mode", set_attestation_mode, try_set_attestation_mode, true, None::<Address>, 3600u64);
+}
+
+#[cfg(feature = "corridors")]
+#[test]
+fn test_auth_corridor_config() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let agent = ctx.agent.clone();
+    let corridor = soroban_sdk::String::from_str(&ctx.env, "US-PH");
+    let purposes: Vec<PurposeCode> = soroban_sdk::vec![&ctx.env, PurposeCode::FamilySupport];
+    assert_auth_matrix!(ctx, denied, "set_agent_corridor", set_agent_corridor, try_set_agent_corridor, agent, corridor.clone());
+    assert_auth_matrix!(ctx, denied, "set_corridor_purposes", set_corridor_purposes, try_set_corridor_purposes, corridor, purposes);
+}
+
+#[test]
+fn test_auth_max_transfer_amount() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let scope = crate::LimitScope::Corridor(soroban_sdk::String::from_str(&ctx.env, "US-PH"));
+    assert_auth_matrix!(ctx, denied, "set_max_transfer_amount", set_max_transfer_amount, try_set_max_transfer_amount, scope.clone(), 5000i128);
+    assert_auth_matrix!(ctx, denied, "clear_max_transfer_amount", clear_max_transfer_amount, try_clear_max_transfer_amount, scope);
+}
+
+#[test]
+fn test_auth_set_min_transfer_amount() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let token = Address::generate(&ctx.env);
+    assert_auth_matrix!(ctx, denied, "set_min_transfer_amount", set_min_transfer_amount, try_set_min_transfer_amount, token, 100i128);
+}
+
+#[test]
+fn test_auth_daily_limits() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let usd = soroban_sdk::String::from_str(&ctx.env, "USD");
+    let ph = soroban_sdk::String::from_str(&ctx.env, "PH");
+    assert_auth_matrix!(ctx, denied, "set_daily_limit", set_daily_limit, try_set_daily_limit, usd.clone(), ph.clone(), 5000i128);
+    assert_auth_matrix!(ctx, denied, "remove_daily_limit", remove_daily_limit, try_remove_daily_limit, usd.clone(), ph);
+    assert_auth_matrix!(ctx, denied, "set_currency_daily_limit", set_currency_daily_limit, try_set_currency_daily_limit, usd.clone(), 5000i128);
+    assert_auth_matrix!(ctx, denied, "remove_currency_daily_limit", remove_currency_daily_limit, try_remove_currency_daily_limit, usd);
+    assert_auth_matrix!(ctx, denied, "set_default_daily_limit", set_default_daily_limit, try_set_default_daily_limit, 5000i128);
+    assert_auth_matrix!(ctx, denied, "remove_default_daily_limit", remove_default_daily_limit, try_remove_default_daily_limit);
+}
+
+#[test]
+fn test_auth_set_agent_coverage() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let agent = ctx.agent.clone();
+    let areas: Vec<crate::CoverageArea> = Vec::new(&ctx.env);
+    assert_auth_matrix!(ctx, denied, "set_agent_coverage", set_agent_coverage, try_set_agent_coverage, agent, areas);
+}
+
+#[cfg(feature = "loyalty")]
+#[test]
+fn test_auth_set_loyalty_rate() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    assert_auth_matrix!(ctx, denied, "set_loyalty_rate", set_loyalty_rate, try_set_loyalty_rate, 100u32);
+}
+
+#[test]
+fn test_auth_admin_cancel_remittance() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let id = ctx.remittance_id;
+    assert_auth_matrix!(ctx, denied, "admin_cancel_remittance", admin_cancel_remittance, try_admin_cancel_remittance, id, crate::CancelReason::Fraud);
+}
+
+#[test]
+fn test_auth_quarantine() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let id = ctx.remittance_id;
+    let authority = Address::generate(&ctx.env);
+    assert_auth_matrix!(ctx, denied, "freeze_remittance", freeze_remittance, try_freeze_remittance, id);
+    assert_auth_matrix!(ctx, denied, "release_quarantine", release_quarantine, try_release_quarantine, id, authority);
+}
+
+#[test]
+fn test_auth_set_hop_fee() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    assert_auth_matrix!(ctx, denied, "set_hop_fee", set_hop_fee, try_set_hop_fee, 50u32);
+}
+
+#[test]
+fn test_auth_set_screening_period() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    assert_auth_matrix!(ctx, denied, "set_screening_period", set_screening_period, try_set_screening_period, 10u32);
+}
+
+#[test]
+fn test_auth_legacy_import() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let legacy = crate::LegacyRemittance {
+        id: 100,
+        sender: ctx.sender.clone(),
+        agent: ctx.agent.clone(),
+        amount: 1000,
+        fee: 25,
+        status: crate::RemittanceStatus::Completed,
+        expiry: None,
+    };
+    let remittances = soroban_sdk::vec![&ctx.env, legacy];
+    assert_auth_matrix!(ctx, denied, "import_remittances", import_remittances, try_import_remittances, remittances);
+    assert_auth_matrix!(ctx, denied, "finalize_import", finalize_import, try_finalize_import);
+}
+
+#[test]
+fn test_auth_commit_snapshot() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let id = ctx.remittance_id;
+    assert_auth_matrix!(ctx, denied, "commit_snapshot", commit_snapshot, try_commit_snapshot, id, id);
+}
+
+#[cfg(feature = "corridors")]
+#[test]
+fn test_auth_corridor_fee() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let corridor = soroban_sdk::String::from_str(&ctx.env, "US-PH");
+    assert_auth_matrix!(ctx, denied, "set_corridor_fee", set_corridor_fee, try_set_corridor_fee, corridor.clone(), 100u32);
+    assert_auth_matrix!(ctx, denied, "clear_corridor_fee", clear_corridor_fee, try_clear_corridor_fee, corridor);
+}
+
+#[cfg(feature = "corridors")]
+#[test]
+fn test_auth_set_corridor_hold() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let corridor = soroban_sdk::String::from_str(&ctx.env, "US-PH");
+    assert_auth_matrix!(ctx, denied, "set_corridor_hold", set_corridor_hold, try_set_corridor_hold, corridor, 600u64);
+}
+
+#[cfg(feature = "corridors")]
+#[test]
+fn test_auth_set_corridor_self_payout() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let corridor = soroban_sdk::String::from_str(&ctx.env, "US-PH");
+    assert_auth_matrix!(ctx, denied, "set_corridor_self_payout", set_corridor_self_payout, try_set_corridor_self_payout, corridor, true);
+}
+
+#[cfg(feature = "corridors")]
+#[test]
+fn test_auth_block_corridor() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let from = soroban_sdk::String::from_str(&ctx.env, "US");
+    let to = soroban_sdk::String::from_str(&ctx.env, "KP");
+    assert_auth_matrix!(ctx, denied, "block_corridor", block_corridor, try_block_corridor, from, to);
+    assert_auth_matrix!(ctx, denied, "unblock_corridor", unblock_corridor, try_unblock_corridor, from, to);
+}
+
+#[test]
+fn test_auth_set_fee_rounding() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let token = Address::generate(&ctx.env);
+    assert_auth_matrix!(ctx, denied, "set_fee_rounding", set_fee_rounding, try_set_fee_rounding, token, crate::RoundingMode::Ceil);
+}
+
+#[test]
+fn test_auth_yield_adapter() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let adapter = Address::generate(&ctx.env);
+    let beneficiary = crate::YieldBeneficiary::InsuranceFund;
+    assert_auth_matrix!(ctx, denied, "set_yield_adapter", set_yield_adapter, try_set_yield_adapter, adapter, beneficiary);
+    assert_auth_matrix!(ctx, denied, "clear_yield_adapter", clear_yield_adapter, try_clear_yield_adapter);
+}
+
+#[test]
+fn test_auth_sla() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    assert_auth_matrix!(ctx, denied, "set_sla", set_sla, try_set_sla, crate::Priority::Standard, 3600u64, 5000u32);
+    assert_auth_matrix!(ctx, denied, "clear_sla", clear_sla, try_clear_sla, crate::Priority::Standard);
+}
+
+#[test]
+fn test_auth_quorum() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let signer = Address::generate(&ctx.env);
+    let signers = soroban_sdk::vec![&ctx.env, signer.clone(), ctx.admin.clone()];
+    assert_auth_matrix!(ctx, denied, "set_quorum", set_quorum, try_set_quorum, signers, 2u32, 100u32);
+
+    // Only signers can propose and approve, each with their own signature
+    let op = crate::CriticalOp::WithdrawFees(ctx.admin.clone(), ctx.admin.clone());
+    let stranger = ctx.stranger.clone();
+    authorize_only(&ctx.env, &ctx.contract.address, &stranger, "propose_op", (stranger.clone(), op.clone()).into_val(&ctx.env));
+    assert!(ctx.contract.try_propose_op(&stranger, &op).is_err());
+    authorize_only(&ctx.env, &ctx.contract.address, &stranger, "propose_op", (signer.clone(), op.clone()).into_val(&ctx.env));
+    assert!(ctx.contract.try_propose_op(&signer, &op).is_err());
+
+    ctx.env.mock_all_auths();
+    let id = ctx.contract.propose_op(&signer, &op);
+    let admin = ctx.admin.clone();
+    authorize_only(&ctx.env, &ctx.contract.address, &stranger, "approve_op", (admin.clone(), id).into_val(&ctx.env));
+    assert!(ctx.contract.try_approve_op(&admin, &id).is_err());
+
+    ctx.env.mock_all_auths();
+    ctx.contract.approve_op(&admin, &id);
+}
+
+#[test]
+fn test_auth_slashing() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let agent = ctx.agent.clone();
+    let id = ctx.remittance_id;
+    let evidence = BytesN::from_array(&ctx.env, &[7u8; 32]);
+    ctx.contract.deposit_collateral(&agent, &100);
+    ctx.contract.confirm_payout(&id);
+    assert_auth_matrix!(ctx, denied, "set_slash_challenge_window", set_slash_challenge_window, try_set_slash_challenge_window, 60u64);
+    assert_auth_matrix!(ctx, denied, "propose_slash", propose_slash, try_propose_slash, agent, id, evidence);
+
+    // Only the slashed agent can challenge
+    let response = BytesN::from_array(&ctx.env, &[9u8; 32]);
+    for caller in [ctx.admin.clone(), ctx.sender.clone(), ctx.other_agent.clone()] {
+        authorize_only(&ctx.env, &ctx.contract.address, &caller, "challenge_slash", (id, response.clone()).into_val(&ctx.env));
+        assert!(ctx.contract.try_challenge_slash(&id, &response).is_err());
+    }
+    ctx.env.mock_all_auths();
+    ctx.contract.challenge_slash(&id, &response);
+
+    assert_auth_matrix!(ctx, denied, "resolve_slash", resolve_slash, try_resolve_slash, id, false);
+}
+
+#[test]
+fn test_auth_agent_max_pending() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let agent = ctx.agent.clone();
+    assert_auth_matrix!(ctx, denied, "set_agent_max_pending", set_agent_max_pending, try_set_agent_max_pending, agent, 5u32);
+    assert_auth_matrix!(ctx, denied, "set_default_max_pending", set_default_max_pending, try_set_default_max_pending, 5u32);
+}
+
+#[test]
+fn test_auth_express_fee() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    assert_auth_matrix!(ctx, denied, "set_express_fee", set_express_fee, try_set_express_fee, 100u32);
+}
+
+// ── Sender-only entry points ────────────────────────────────────────
+
+#[test]
+fn test_auth_create_remittance() {
+    let ctx = setup();
+    let denied = non_senders(&ctx);
+    let sender = ctx.sender.clone();
+    let agent = ctx.agent.clone();
+    let expiry: Option<u64> = None;
+    let key: Option<BytesN<32>> = None;
+    let hash: Option<BytesN<32>> = None;
+    let external_ref: Option<BytesN<32>> = None;
+    assert_auth_matrix!(ctx, denied, "create_remittance", create_remittance, try_create_remittance, sender, agent, 500i128, PurposeCode::FamilySupport, expiry, key, hash, hash, external_ref);
+}
+
+#[test]
+fn test_auth_direct_remittance() {
+    let ctx = setup();
+    let sender = ctx.sender.clone();
+    let agent = ctx.agent.clone();
+    assert_auth_matrix!(ctx, non_admins(&ctx), "set_direct_pair", set_direct_pair, try_set_direct_pair, sender.clone(), agent.clone(), true);
+    assert_auth_matrix!(ctx, non_senders(&ctx), "record_direct_remittance", record_direct_remittance, try_record_direct_remittance, sender, agent, 500i128);
+}
+
+#[test]
+fn test_auth_relayed_operations() {
+    let ctx = setup();
+    let denied = non_senders(&ctx);
+    let sender = ctx.sender.clone();
+    let agent = ctx.agent.clone();
+    let id = ctx.remittance_id;
+    let expiration = ctx.env.ledger().sequence() + 100;
+    assert_auth_matrix!(ctx, denied, "relay_create_remittance", relay_create_remittance, try_relay_create_remittance, sender, agent, 500i128, PurposeCode::FamilySupport, 0u64, expiration);
+    assert_auth_matrix!(ctx, denied, "relay_cancel_remittance", relay_cancel_remittance, try_relay_cancel_remittance, id, 1u64, expiration);
+}
+
+#[test]
+fn test_auth_sponsor_pool() {
+    let ctx = setup();
+    let sponsor = ctx.other_sender.clone();
+    let relayer = ctx.stranger.clone();
+    let denied = [ctx.admin.clone(), ctx.sender.clone(), ctx.agent.clone(), ctx.stranger.clone()];
+    assert_auth_matrix!(ctx, denied, "fund_sponsor_pool", fund_sponsor_pool, try_fund_sponsor_pool, sponsor, 500i128);
+    assert_auth_matrix!(ctx, denied, "withdraw_sponsor_pool", withdraw_sponsor_pool, try_withdraw_sponsor_pool, sponsor, 100i128);
+    assert_auth_matrix!(ctx, denied, "set_sponsor_relay_fee", set_sponsor_relay_fee, try_set_sponsor_relay_fee, sponsor, 10i128);
+    assert_auth_matrix!(ctx, denied, "set_sponsor_relayer", set_sponsor_relayer, try_set_sponsor_relayer, sponsor, relayer, true);
+
+    // The relayer and the sender must both authorize a sponsored creation
+    let sender = ctx.sender.clone();
+    let agent = ctx.agent.clone();
+    let expiration = ctx.env.ledger().sequence() + 100;
+    let args: Vec<Val> = (sponsor.clone(), relayer.clone(), sender.clone(), agent.clone(), 500i128, PurposeCode::FamilySupport, 0u64, expiration).into_val(&ctx.env);
+    for caller in [relayer.clone(), sender.clone()] {
+        authorize_only(&ctx.env, &ctx.contract.address, &caller, "relay_create_sponsored", args.clone());
+        assert!(ctx
+            .contract
+            .try_relay_create_sponsored(&sponsor, &relayer, &sender, &agent, &500, &PurposeCode::FamilySupport, &0, &expiration)
+            .is_err());
+    }
+
+    ctx.env.mock_all_auths();
+    ctx.contract.relay_create_sponsored(&sponsor, &relayer, &sender, &agent, &500, &PurposeCode::FamilySupport, &0, &expiration);
+}
+
+#[test]
+fn test_auth_watchers() {
+    let ctx = setup();
+    let owner = ctx.stranger.clone();
+    let denied = [ctx.admin.clone(), ctx.sender.clone(), ctx.agent.clone()];
+    let filter: Vec<soroban_sdk::Symbol> = soroban_sdk::vec![&ctx.env, soroban_sdk::symbol_short!("status")];
+    assert_auth_matrix!(ctx, denied, "register_watcher", register_watcher, try_register_watcher, owner, filter);
+    assert_auth_matrix!(ctx, denied, "unregister_watcher", unregister_watcher, try_unregister_watcher, 1u32);
+}
+
+#[test]
+fn test_auth_cancel_remittance() {
+    let ctx = setup();
+    let denied = non_senders(&ctx);
+    let id = ctx.remittance_id;
+    assert_auth_matrix!(ctx, denied, "cancel_remittance", cancel_remittance, try_cancel_remittance, id);
+}
+
+#[test]
+fn test_auth_payout_recipient() {
+    let ctx = setup();
+    let denied = non_senders(&ctx);
+    let id = ctx.remittance_id;
+    let recipient = ctx.stranger.clone();
+    assert_auth_matrix!(ctx, denied, "set_payout_recipient", set_payout_recipient, try_set_payout_recipient, id, recipient);
+
+    let denied = non_senders(&ctx);
+    assert_auth_matrix!(ctx, denied, "clear_payout_recipient", clear_payout_recipient, try_clear_payout_recipient, id);
+}
+
+#[test]
+fn test_auth_reassign_remittance() {
+    let ctx = setup();
+    let id = ctx.remittance_id;
+    let new_agent = ctx.other_agent.clone();
+
+    // Neither a stranger nor the agents can reassign, even in their own name
+    for caller in [ctx.stranger.clone(), ctx.agent.clone(), ctx.other_agent.clone()] {
+        authorize_only(&ctx.env, &ctx.contract.address, &caller, "reassign_remittance", (caller.clone(), id, new_agent.clone()).into_val(&ctx.env));
+        assert!(ctx.contract.try_reassign_remittance(&caller, &id, &new_agent).is_err());
+    }
+    // The sender's or admin's name without their signature is rejected
+    for caller in [ctx.sender.clone(), ctx.admin.clone()] {
+        authorize_only(&ctx.env, &ctx.contract.address, &ctx.stranger, "reassign_remittance", (caller.clone(), id, new_agent.clone()).into_val(&ctx.env));
+        assert!(ctx.contract.try_reassign_remittance(&caller, &id, &new_agent).is_err());
+    }
+
+    ctx.env.mock_all_auths();
+    ctx.contract.reassign_remittance(&ctx.sender, &id, &new_agent);
+    ctx.contract.reassign_remittance(&ctx.admin, &id, &ctx.agent);
+}
+
+#[test]
+fn test_auth_amend_remittance() {
+    let ctx = setup();
+    let denied = non_senders(&ctx);
+    let id = ctx.remittance_id;
+    let agent = ctx.agent.clone();
+    assert_auth_matrix!(ctx, denied, "amend_remittance", amend_remittance, try_amend_remittance, id, 800i128, agent);
+}
+
+#[test]
+fn test_auth_expedite_remittance() {
+    let ctx = setup();
+    let denied = non_senders(&ctx);
+    let id = ctx.remittance_id;
+    assert_auth_matrix!(ctx, denied, "expedite_remittance", expedite_remittance, try_expedite_remittance, id);
+}
+
+#[test]
+fn test_auth_top_up_remittance() {
+    let ctx = setup();
+    let denied = non_senders(&ctx);
+    let id = ctx.remittance_id;
+    assert_auth_matrix!(ctx, denied, "top_up_remittance", top_up_remittance, try_top_up_remittance, id, 100i128);
+}
+
+#[cfg(feature = "kyc")]
+#[test]
+fn test_auth_declare_intent() {
+    let ctx = setup();
+    let denied = non_senders(&ctx);
+    let sender = ctx.sender.clone();
+    let agent = ctx.agent.clone();
+    assert_auth_matrix!(ctx, denied, "declare_intent", declare_intent, try_declare_intent, sender, agent, 500i128);
+}
+
+#[test]
+fn test_auth_reserve_id() {
+    let ctx = setup();
+    let denied = non_senders(&ctx);
+    let sender = ctx.sender.clone();
+    assert_auth_matrix!(ctx, denied, "reserve_id", reserve_id, try_reserve_id, sender);
+}
+
+// ── Agent-only entry points ─────────────────────────────────────────
+
+#[test]
+fn test_auth_collateral() {
+    let ctx = setup();
+    let denied = non_agents(&ctx);
+    let agent = ctx.agent.clone();
+    assert_auth_matrix!(ctx, denied, "deposit_collateral", deposit_collateral, try_deposit_collateral, agent, 100i128);
+    assert_auth_matrix!(ctx, denied, "withdraw_collateral", withdraw_collateral, try_withdraw_collateral, agent, 100i128);
+}
+
+#[test]
+fn test_auth_staking_rewards() {
+    let ctx = setup();
+    let agent = ctx.agent.clone();
+    assert_auth_matrix!(ctx, non_admins(&ctx), "set_staking_share", set_staking_share, try_set_staking_share, 5000u32);
+
+    ctx.env.mock_all_auths();
+    ctx.contract.deposit_collateral(&agent, &100);
+    ctx.contract.confirm_payout(&ctx.remittance_id);
+    assert_auth_matrix!(ctx, non_agents(&ctx), "claim_staking_rewards", claim_staking_rewards, try_claim_staking_rewards, agent);
+}
+
+#[test]
+fn test_auth_agent_decline_remittance() {
+    let ctx = setup();
+    let denied = non_agents(&ctx);
+    let id = ctx.remittance_id;
+    assert_auth_matrix!(ctx, denied, "agent_decline_remittance", agent_decline_remittance, try_agent_decline_remittance, id, crate::DeclineReason::InsufficientLiquidity);
+}
+
+#[test]
+fn test_auth_confirm_payout() {
+    let ctx = setup();
+    let denied = non_agents(&ctx);
+    let id = ctx.remittance_id;
+    assert_auth_matrix!(ctx, denied, "confirm_payout", confirm_payout, try_confirm_payout, id);
+}
+
+#[test]
+fn test_auth_confirm_payout_with_fx() {
+    let ctx = setup();
+    let denied = non_agents(&ctx);
+    let id = ctx.remittance_id;
+    assert_auth_matrix!(ctx, denied, "confirm_payout_with_fx", confirm_payout_with_fx, try_confirm_payout_with_fx, id, 64_000i128, 1_290_000_000i128);
+}
+
+#[test]
+fn test_auth_confirm_partial() {
+    let ctx = setup();
+    let denied = non_agents(&ctx);
+    let id = ctx.remittance_id;
+    assert_auth_matrix!(ctx, denied, "confirm_partial", confirm_partial, try_confirm_partial, id, 400i128);
+}
+
+#[test]
+fn test_auth_confirm_payout_with_receipt() {
+    let ctx = setup();
+    let denied = non_agents(&ctx);
+    let id = ctx.remittance_id;
+    let receipt = BytesN::from_array(&ctx.env, &[9u8; 32]);
+    assert_auth_matrix!(ctx, denied, "confirm_payout_with_receipt", confirm_payout_with_receipt, try_confirm_payout_with_receipt, id, receipt);
+}
+
+#[test]
+fn test_auth_agent_settings() {
+    let ctx = setup();
+    let denied = non_agents(&ctx);
+    let agent = ctx.agent.clone();
+    assert_auth_matrix!(ctx, denied, "set_availability", set_availability, try_set_availability, agent, false);
+    assert_auth_matrix!(
+        ctx,
+        denied,
+        "set_settlement_schedule",
+        set_settlement_schedule,
+        try_set_settlement_schedule,
+        agent,
+        crate::SettlementSchedule::Daily
+    );
+}
+
+#[test]
+fn test_auth_settle_agent() {
+    let ctx = setup();
+    ctx.env.mock_all_auths();
+    ctx.contract.set_settlement_schedule(&ctx.agent, &crate::SettlementSchedule::OnDemand);
+    ctx.contract.confirm_payout(&ctx.remittance_id);
+
+    let denied = non_agents(&ctx);
+    let agent = ctx.agent.clone();
+    assert_auth_matrix!(ctx, denied, "settle_agent", settle_agent, try_settle_agent, agent, agent);
+}
+
+#[test]
+fn test_auth_operators() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let operator = Address::generate(&ctx.env);
+    assert_auth_matrix!(ctx, denied, "set_operator", set_operator, try_set_operator, operator, true);
+
+    // Only operators and admins can run archival, each signing for itself
+    let before_ledger = ctx.env.ledger().sequence();
+    let stranger = ctx.stranger.clone();
+    authorize_only(&ctx.env, &ctx.contract.address, &stranger, "archive_remittances", (stranger.clone(), before_ledger, 10u32).into_val(&ctx.env));
+    assert!(ctx.contract.try_archive_remittances(&stranger, &before_ledger, &10).is_err());
+    authorize_only(&ctx.env, &ctx.contract.address, &stranger, "archive_remittances", (operator.clone(), before_ledger, 10u32).into_val(&ctx.env));
+    assert!(ctx.contract.try_archive_remittances(&operator, &before_ledger, &10).is_err());
+    authorize_only(&ctx.env, &ctx.contract.address, &operator, "archive_remittances", (operator.clone(), before_ledger, 10u32).into_val(&ctx.env));
+    ctx.contract.archive_remittances(&operator, &before_ledger, &10);
+}
+
+#[test]
+fn test_auth_sub_agents() {
+    let ctx = setup();
+    let denied = non_agents(&ctx);
+    let agent = ctx.agent.clone();
+    let sub_agent = Address::generate(&ctx.env);
+    assert_auth_matrix!(ctx, denied, "add_sub_agent", add_sub_agent, try_add_sub_agent, agent, sub_agent);
+    assert_auth_matrix!(ctx, denied, "remove_sub_agent", remove_sub_agent, try_remove_sub_agent, agent, sub_agent);
+}
+
+// ── Removed agents ──────────────────────────────────────────────────
+
+#[test]
+fn test_removed_agent_cannot_receive_new_remittances() {
+    let ctx = setup();
+    let sender = ctx.sender.clone();
+    let removed_agent = ctx.removed_agent.clone();
+
+    ctx.env.mock_all_auths();
+    let result = ctx
+        .contract
+        .try_create_remittance(&sender, &removed_agent, &500, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    assert!(result.is_err());
+}
+
+
+#[test]
+fn test_auth_ttl_policy() {
+    let ctx = setup();
+    let denied = non_admins(&ctx);
+    let policy = crate::TtlPolicy { threshold: 100, extend_to: 1000 };
+    assert_auth_matrix!(ctx, denied, "set_ttl_policy", set_ttl_policy, try_set_ttl_policy, crate::TtlClass::History, policy);
+}
@@ -0,0 +1,189 @@
+#![cfg(test)]
+//! Property-based tests for fee and limit arithmetic.
+//!
+//! Amounts, fee rates, rounding modes and limit windows are generated rather
+//! than hand-picked, so the boundaries of the checked arithmetic (zero fees,
+//! full-amount fees, amounts near `i128::MAX`) are exercised on every run.
+
+extern crate std;
+
+use proptest::prelude::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::Address;
+
+use crate::rate_limit::{check_rate_limit, set_rate_limit_config, RateLimitConfig};
+use crate::testutils::{self, TestSetup};
+use crate::{
+    calculate_platform_fee, check_max_transfer_amount, div_round, set_max_transfer_amount,
+    ContractError, LimitScope, RoundingMode,
+};
+
+fn rounding_mode() -> impl Strategy<Value = RoundingMode> {
+    prop_oneof![
+        Just(RoundingMode::Floor),
+        Just(RoundingMode::Ceil),
+        Just(RoundingMode::HalfUp),
+    ]
+}
+
+fn setup_with_fee<'a>(fee_bps: u32, mode: RoundingMode) -> TestSetup<'a> {
+    let t = testutils::setup_with_agents(1);
+    t.contract.update_fee(&fee_bps);
+    t.contract.set_fee_rounding(&t.token.address, &mode);
+    t
+}
+
+proptest! {
+    #[test]
+    fn div_round_stays_within_one_of_floor(
+        numerator in 0i128..=i128::MAX,
+        denominator in 1i128..=1_000_000,
+        mode in rounding_mode(),
+    ) {
+        let floor = numerator / denominator;
+        let rounded = div_round(numerator, denominator, mode).unwrap();
+        prop_assert!(rounded == floor || rounded == floor + 1);
+        match mode {
+            RoundingMode::Floor => prop_assert_eq!(rounded, floor),
+            RoundingMode::Ceil => prop_assert_eq!(rounded > floor, numerator % denominator != 0),
+            RoundingMode::HalfUp => {
+                prop_assert_eq!(rounded > floor, (numerator % denominator) * 2 >= denominator)
+            }
+        }
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn fee_never_exceeds_amount(
+        amount in 1i128..=i128::MAX / 10_000,
+        fee_bps in 0u32..=10_000,
+        mode in rounding_mode(),
+    ) {
+        let t = setup_with_fee(fee_bps, mode);
+        let sender = Address::generate(&t.env);
+        let fee = t.env.as_contract(&t.contract.address, || {
+            calculate_platform_fee(&t.env, Some(&sender), None, amount)
+        }).unwrap();
+        prop_assert!(fee >= 0);
+        prop_assert!(fee <= amount);
+        if fee_bps == 0 {
+            prop_assert_eq!(fee, 0);
+        }
+        if fee_bps == 10_000 {
+            prop_assert_eq!(fee, amount);
+        }
+    }
+
+    #[test]
+    fn fee_overflow_is_reported_not_panicked(
+        amount in i128::MAX / 10_000..=i128::MAX,
+        fee_bps in 1u32..=10_000,
+        mode in rounding_mode(),
+    ) {
+        let t = setup_with_fee(fee_bps, mode);
+        let result = t.env.as_contract(&t.contract.address, || {
+            calculate_platform_fee(&t.env, None, None, amount)
+        });
+        match result {
+            Ok(fee) => prop_assert!(fee >= 0 && fee <= amount),
+            Err(error) => prop_assert_eq!(error, ContractError::Overflow),
+        }
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn payout_and_fee_add_up_to_amount(
+        amount in 1i128..=1_000_000_000_000,
+        fee_bps in 0u32..=10_000,
+        mode in rounding_mode(),
+    ) {
+        let t = setup_with_fee(fee_bps, mode);
+        let agent = t.agent(0);
+        let sender = t.funded_sender(amount);
+
+        let id = t.create_remittance(&sender, &agent, amount);
+        let fee = t.contract.get_remittance(&id).fee;
+        prop_assert_eq!(t.balance(&t.contract.address), amount);
+        prop_assert_eq!(t.contract.get_escrow_balance() + fee, amount);
+
+        t.contract.confirm_payout(&id);
+        prop_assert_eq!(t.balance(&agent) + fee, amount);
+        prop_assert_eq!(t.contract.get_accumulated_fees(), fee);
+        prop_assert_eq!(t.contract.get_escrow_balance(), 0);
+        prop_assert_eq!(t.balance(&t.contract.address), fee);
+    }
+
+    #[test]
+    fn cancellation_conserves_escrow(
+        amount in 1i128..=1_000_000_000_000,
+        fee_bps in 0u32..=10_000,
+        mode in rounding_mode(),
+    ) {
+        let t = setup_with_fee(fee_bps, mode);
+        let sender = t.funded_sender(amount);
+
+        let id = t.create_remittance(&sender, &t.agent(0), amount);
+        t.contract.cancel_remittance(&id);
+        prop_assert_eq!(t.balance(&sender), amount);
+        prop_assert_eq!(t.balance(&t.contract.address), 0);
+        prop_assert_eq!(t.contract.get_escrow_balance(), 0);
+        prop_assert_eq!(t.contract.get_accumulated_fees(), 0);
+    }
+
+    #[test]
+    fn max_transfer_amount_admits_exactly_up_to_cap(
+        cap in 1i128..=i128::MAX,
+        amount in 1i128..=i128::MAX,
+    ) {
+        let t = testutils::setup_with_agents(1);
+        let agent = t.agent(0);
+        let result = t.env.as_contract(&t.contract.address, || {
+            set_max_transfer_amount(&t.env, &LimitScope::Token(t.token.address.clone()), cap);
+            check_max_transfer_amount(&t.env, &agent, amount)
+        });
+        if amount <= cap {
+            prop_assert_eq!(result, Ok(()));
+        } else {
+            prop_assert_eq!(result, Err(ContractError::AmountAboveMaximum));
+        }
+    }
+
+    #[test]
+    fn rate_limit_admits_max_requests_per_window(
+        max_requests in 1u32..=20,
+        window_seconds in 1u64..=86_400,
+        elapsed in 0u64..=172_800,
+    ) {
+        let t = testutils::setup();
+        let caller = Address::generate(&t.env);
+        t.env.as_contract(&t.contract.address, || {
+            set_rate_limit_config(&t.env, RateLimitConfig {
+                max_requests,
+                window_seconds,
+                enabled: true,
+            });
+            for _ in 0..max_requests {
+                prop_assert_eq!(check_rate_limit(&t.env, &caller), Ok(()));
+            }
+            prop_assert_eq!(
+                check_rate_limit(&t.env, &caller),
+                Err(ContractError::RateLimitExceeded)
+            );
+
+            t.env.ledger().with_mut(|li| li.timestamp += elapsed);
+            let expected = if elapsed >= window_seconds {
+                Ok(())
+            } else {
+                Err(ContractError::RateLimitExceeded)
+            };
+            prop_assert_eq!(check_rate_limit(&t.env, &caller), expected);
+            Ok(())
+        })?;
+    }
+}
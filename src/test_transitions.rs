@@ -1,6 +1,6 @@
 #![cfg(test)]
 
-use crate::{SwiftRemitContract, SwiftRemitContractClient, RemittanceStatus};
+use crate::{InitConfig, PurposeCode, SwiftRemitContract, SwiftRemitContractClient, RemittanceStatus};
 use soroban_sdk::{
     testutils::{Address as _, Events},
     token, Address, Env, symbol_short,
@@ -26,7 +26,7 @@ fn setup_contract(env: &Env) -> (SwiftRemitContractClient, token::StellarAssetCl
     
     env.mock_all_auths();
     contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&InitConfig::new(&env, &admin, &token.address, 250));
     contract.register_agent(&agent);
 
     token.mint(&sender, &10000);
@@ -40,7 +40,7 @@ fn test_lifecycle_pending_to_processing() {
     let (contract, _token, _admin, agent, sender) = setup_contract(&env);
 
     env.mock_all_auths();
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
 
     let remittance = contract.get_remittance(&remittance_id);
     assert_eq!(remittance.status, RemittanceStatus::Pending);
@@ -57,7 +57,7 @@ fn test_lifecycle_pending_to_cancelled() {
     let (contract, _token, _admin, agent, sender) = setup_contract(&env);
 
     env.mock_all_auths();
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
 
     let remittance = contract.get_remittance(&remittance_id);
     assert_eq!(remittance.status, RemittanceStatus::Pending);
@@ -74,7 +74,7 @@ fn test_lifecycle_processing_to_completed() {
     let (contract, _token, _admin, agent, sender) = setup_contract(&env);
 
     env.mock_all_auths();
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
 
     contract.start_processing(&remittance_id);
 
@@ -93,7 +93,7 @@ fn test_lifecycle_processing_to_failed() {
     let (contract, _token, _admin, agent, sender) = setup_contract(&env);
 
     env.mock_all_auths();
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
 
     contract.start_processing(&remittance_id);
 
@@ -113,7 +113,7 @@ fn test_invalid_transition_pending_to_completed() {
     let (contract, _token, _admin, agent, sender) = setup_contract(&env);
 
     env.mock_all_auths();
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
 
     // Should fail: cannot go directly from Pending to Completed
     contract.confirm_payout(&remittance_id);
@@ -126,7 +126,7 @@ fn test_invalid_transition_pending_to_failed() {
     let (contract, _token, _admin, agent, sender) = setup_contract(&env);
 
     env.mock_all_auths();
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
 
     // Should fail: cannot go directly from Pending to Failed
     contract.mark_failed(&remittance_id);
@@ -139,7 +139,7 @@ fn test_invalid_transition_processing_to_cancelled() {
     let (contract, _token, _admin, agent, sender) = setup_contract(&env);
 
     env.mock_all_auths();
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
 
     contract.start_processing(&remittance_id);
 
@@ -154,7 +154,7 @@ fn test_terminal_state_completed_cannot_transition() {
     let (contract, _token, _admin, agent, sender) = setup_contract(&env);
 
     env.mock_all_auths();
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
 
     contract.start_processing(&remittance_id);
     contract.confirm_payout(&remittance_id);
@@ -170,7 +170,7 @@ fn test_terminal_state_cancelled_cannot_transition() {
     let (contract, _token, _admin, agent, sender) = setup_contract(&env);
 
     env.mock_all_auths();
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
 
     contract.cancel_remittance(&remittance_id);
 
@@ -185,7 +185,7 @@ fn test_terminal_state_failed_cannot_transition() {
     let (contract, _token, _admin, agent, sender) = setup_contract(&env);
 
     env.mock_all_auths();
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
 
     contract.start_processing(&remittance_id);
     contract.mark_failed(&remittance_id);
@@ -200,7 +200,7 @@ fn test_transition_events_logged() {
     let (contract, _token, _admin, agent, sender) = setup_contract(&env);
 
     env.mock_all_auths();
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
 
     contract.start_processing(&remittance_id);
     contract.confirm_payout(&remittance_id);
@@ -217,7 +217,7 @@ fn test_failed_remittance_refunds_sender() {
 
     env.mock_all_auths();
     
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
 
     contract.start_processing(&remittance_id);
     contract.mark_failed(&remittance_id);
@@ -233,8 +233,8 @@ fn test_multiple_remittances_independent_lifecycles() {
 
     env.mock_all_auths();
     
-    let remittance_id_1 = contract.create_remittance(&sender, &agent, &1000, &None);
-    let remittance_id_2 = contract.create_remittance(&sender, &agent, &2000, &None);
+    let remittance_id_1 = contract.create_remittance(&sender, &agent, &1000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
+    let remittance_id_2 = contract.create_remittance(&sender, &agent, &2000, &PurposeCode::FamilySupport, &None, &None, &None, &None, &None);
 
     // First remittance: Pending -> Processing -> Completed
     contract.start_processing(&remittance_id_1);
@@ -0,0 +1,134 @@
+//! Test harness for contracts and services integrating with SwiftRemit.
+//!
+//! Available with the `testutils` cargo feature:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! swiftremit = { version = "0.1", features = ["testutils"] }
+//! ```
+//!
+//! [`setup`] deploys a fresh contract with a Stellar asset as its settlement
+//! token, initialized with [`DEFAULT_FEE_BPS`] and no rate limit cooldown.
+//! [`setup_with_agents`] additionally registers a number of agents, and
+//! [`TestSetup::funded_sender`] creates senders holding test tokens. All auths
+//! are mocked, so tests only need to care about authorization when they
+//! reset the mocks themselves.
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, Vec,
+};
+
+use crate::{InitConfig, PurposeCode, SwiftRemitContract, SwiftRemitContractClient};
+
+/// Platform fee the contract is initialized with, in basis points.
+pub const DEFAULT_FEE_BPS: u32 = 250;
+
+/// A deployed and initialized SwiftRemit contract.
+pub struct TestSetup<'a> {
+    pub env: Env,
+    pub contract: SwiftRemitContractClient<'a>,
+    pub admin: Address,
+    /// Settlement token admin client, used to mint test balances
+    pub token: token::StellarAssetClient<'a>,
+    /// Registered agents, in registration order
+    pub agents: Vec<Address>,
+}
+
+impl<'a> TestSetup<'a> {
+    /// Returns a token client for the settlement token.
+    pub fn token_client(&self) -> token::Client<'a> {
+        token::Client::new(&self.env, &self.token.address)
+    }
+
+    /// Returns the settlement token balance of `address`.
+    pub fn balance(&self, address: &Address) -> i128 {
+        self.token_client().balance(address)
+    }
+
+    /// Returns the agent registered at `index`.
+    ///
+    /// # Panics
+    ///
+    /// If fewer than `index + 1` agents were registered.
+    pub fn agent(&self, index: u32) -> Address {
+        self.agents.get(index).expect("agent index out of range")
+    }
+
+    /// Creates a new sender holding `amount` settlement tokens.
+    pub fn funded_sender(&self, amount: i128) -> Address {
+        let sender = Address::generate(&self.env);
+        self.token.mint(&sender, &amount);
+        sender
+    }
+
+    /// Registers a new agent and returns its address.
+    pub fn add_agent(&mut self) -> Address {
+        let agent = Address::generate(&self.env);
+        self.contract.register_agent(&agent);
+        self.agents.push_back(agent.clone());
+        agent
+    }
+
+    /// Creates a pending remittance from `sender` to `agent` with no expiry,
+    /// idempotency key, compliance hashes or external reference.
+    pub fn create_remittance(&self, sender: &Address, agent: &Address, amount: i128) -> u64 {
+        self.contract.create_remittance(
+            sender,
+            agent,
+            &amount,
+            &PurposeCode::FamilySupport,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+}
+
+/// Deploys and initializes a contract with no registered agents.
+pub fn setup<'a>() -> TestSetup<'a> {
+    setup_with_agents(0)
+}
+
+/// Deploys and initializes a contract and registers `agents` agents.
+pub fn setup_with_agents<'a>(agents: u32) -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token_address = env.register_stellar_asset_contract_v2(token_admin).address();
+    let token = token::StellarAssetClient::new(&env, &token_address);
+
+    let contract =
+        SwiftRemitContractClient::new(&env, &env.register_contract(None, SwiftRemitContract {}));
+    contract.initialize(&InitConfig::new(&env, &admin, &token_address, DEFAULT_FEE_BPS));
+
+    let mut setup = TestSetup {
+        agents: Vec::new(&env),
+        env,
+        contract,
+        admin,
+        token,
+    };
+    for _ in 0..agents {
+        setup.add_agent();
+    }
+    setup
+}
+
+/// Moves the ledger clock forward by `seconds`.
+pub fn advance_time(env: &Env, seconds: u64) {
+    env.ledger().with_mut(|li| li.timestamp += seconds);
+}
+
+/// Moves the ledger forward by `ledgers` ledgers, advancing the clock by
+/// five seconds per ledger.
+pub fn advance_ledgers(env: &Env, ledgers: u32) {
+    env.ledger().with_mut(|li| {
+        li.sequence_number += ledgers;
+        li.timestamp += u64::from(ledgers) * 5;
+    });
+}
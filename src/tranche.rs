@@ -0,0 +1,126 @@
+//! Partial escrow release for remittances delivered in tranches.
+//!
+//! In some corridors cash is handed over in several visits. The agent confirms
+//! each delivered tranche and the matching share of the escrow (minus a
+//! proportional share of the fee) is released. Whatever has not been released
+//! stays in escrow and can still be confirmed or cancelled as usual.
+
+use soroban_sdk::{contracttype, Env, Vec};
+
+use crate::{validate_amount, ContractError, Remittance};
+
+/// A single confirmed delivery tranche.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutTranche {
+    /// Portion of the remittance amount delivered
+    pub amount: i128,
+    /// Portion of the platform fee charged on this tranche
+    pub fee: i128,
+    /// Timestamp the tranche was confirmed
+    pub confirmed_at: u64,
+}
+
+/// Running totals of the tranches released for a remittance.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReleasedTotals {
+    /// Sum of released tranche amounts
+    pub amount: i128,
+    /// Sum of fees charged on released tranches
+    pub fee: i128,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum TrancheKey {
+    /// Tranches confirmed for a remittance (persistent storage)
+    Tranches(u64),
+    /// Released totals for a remittance (persistent storage)
+    Released(u64),
+}
+
+pub fn get_tranches(env: &Env, remittance_id: u64) -> Vec<PayoutTranche> {
+    env.storage()
+        .persistent()
+        .get(&TrancheKey::Tranches(remittance_id))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn get_released_totals(env: &Env, remittance_id: u64) -> ReleasedTotals {
+    env.storage()
+        .persistent()
+        .get(&TrancheKey::Released(remittance_id))
+        .unwrap_or_default()
+}
+
+/// Records a delivered tranche and returns it.
+///
+/// The tranche fee is the remittance fee pro-rated by amount; the tranche that
+/// completes the remittance takes whatever fee remains so rounding never leaves
+/// fee behind.
+///
+/// # Returns
+///
+/// * `Ok(PayoutTranche)` - The recorded tranche
+/// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - Amount is not positive
+/// * `Err(ContractError::InvalidAmount)` - Amount exceeds the unreleased amount
+pub fn record_tranche(
+    env: &Env,
+    remittance: &Remittance,
+    amount: i128,
+) -> Result<PayoutTranche, ContractError> {
+    let mut released = get_released_totals(env, remittance.id);
+    let remaining = remittance
+        .amount
+        .checked_sub(released.amount)
+        .ok_or(ContractError::Overflow)?;
+    validate_amount(amount)?;
+    if amount > remaining {
+        return Err(ContractError::InvalidAmount);
+    }
+
+    let fee = if amount == remaining {
+        remittance.fee.checked_sub(released.fee).ok_or(ContractError::Overflow)?
+    } else {
+        remittance
+            .fee
+            .checked_mul(amount)
+            .ok_or(ContractError::Overflow)?
+            .checked_div(remittance.amount)
+            .ok_or(ContractError::Overflow)?
+    };
+
+    let tranche = PayoutTranche {
+        amount,
+        fee,
+        confirmed_at: env.ledger().timestamp(),
+    };
+
+    let mut tranches = get_tranches(env, remittance.id);
+    tranches.push_back(tranche.clone());
+    env.storage()
+        .persistent()
+        .set(&TrancheKey::Tranches(remittance.id), &tranches);
+
+    released.amount = released.amount.checked_add(amount).ok_or(ContractError::Overflow)?;
+    released.fee = released.fee.checked_add(fee).ok_or(ContractError::Overflow)?;
+    env.storage()
+        .persistent()
+        .set(&TrancheKey::Released(remittance.id), &released);
+
+    Ok(tranche)
+}
+
+/// Records `amount` paid out of escrow to an intermediary of a routed remittance.
+///
+/// The amount counts as released so later payouts and refunds exclude it, but
+/// carries no platform fee and is not listed among the delivery tranches.
+pub fn record_hop_release(env: &Env, remittance_id: u64, amount: i128) -> Result<(), ContractError> {
+    let mut released = get_released_totals(env, remittance_id);
+    released.amount = released.amount.checked_add(amount).ok_or(ContractError::Overflow)?;
+    env.storage()
+        .persistent()
+        .set(&TrancheKey::Released(remittance_id), &released);
+    Ok(())
+}
@@ -0,0 +1,98 @@
+//! Single-transfer amount limits.
+//!
+//! Compliance caps the size of any one remittance independently of the daily
+//! send limits. A cap can be set for the token being sent or for a payout
+//! corridor; a remittance must stay within every cap that applies to it.
+//!
+//! A minimum per token keeps out dust remittances, whose fee rounds to zero
+//! and whose payout costs more in network fees than it is worth.
+
+use soroban_sdk::{contracttype, Address, Env, String};
+
+use crate::{get_agent_corridor, get_usdc_token, ContractError};
+
+/// What a transfer limit applies to.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LimitScope {
+    /// Remittances sent in a token
+    Token(Address),
+    /// Remittances paid out by agents in a corridor
+    Corridor(String),
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum TransferLimitKey {
+    /// Maximum single-transfer amount per scope (persistent storage)
+    Max(LimitScope),
+    /// Minimum single-transfer amount per token (persistent storage)
+    Min(Address),
+}
+
+pub fn set_max_transfer_amount(env: &Env, scope: &LimitScope, amount: i128) {
+    env.storage()
+        .persistent()
+        .set(&TransferLimitKey::Max(scope.clone()), &amount);
+}
+
+pub fn clear_max_transfer_amount(env: &Env, scope: &LimitScope) {
+    env.storage()
+        .persistent()
+        .remove(&TransferLimitKey::Max(scope.clone()));
+}
+
+pub fn get_max_transfer_amount(env: &Env, scope: &LimitScope) -> Option<i128> {
+    env.storage()
+        .persistent()
+        .get(&TransferLimitKey::Max(scope.clone()))
+}
+
+/// Checks `amount` against the token cap and the cap of `agent`'s corridor.
+///
+/// # Returns
+///
+/// * `Ok(())` - No cap applies, or `amount` is within every applicable cap
+/// * `Err(ContractError::AmountAboveMaximum)` - `amount` exceeds a cap
+pub fn check_max_transfer_amount(env: &Env, agent: &Address, amount: i128) -> Result<(), ContractError> {
+    let token_cap = get_max_transfer_amount(env, &LimitScope::Token(get_usdc_token(env)?));
+    let corridor_cap = get_agent_corridor(env, agent)
+        .and_then(|corridor| get_max_transfer_amount(env, &LimitScope::Corridor(corridor)));
+
+    for cap in [token_cap, corridor_cap].into_iter().flatten() {
+        if amount > cap {
+            return Err(ContractError::AmountAboveMaximum);
+        }
+    }
+    Ok(())
+}
+
+pub fn set_min_transfer_amount(env: &Env, token: &Address, amount: i128) {
+    let key = TransferLimitKey::Min(token.clone());
+    if amount > 0 {
+        env.storage().persistent().set(&key, &amount);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Returns the minimum single-transfer amount in `token`, 0 when unset.
+pub fn get_min_transfer_amount(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&TransferLimitKey::Min(token.clone()))
+        .unwrap_or(0)
+}
+
+/// Checks `amount` against the minimum set for the remittance token.
+///
+/// # Returns
+///
+/// * `Ok(())` - No minimum is set, or `amount` meets it
+/// * `Err(ContractError::AmountBelowMinimum)` - `amount` is below the minimum
+pub fn check_min_transfer_amount(env: &Env, amount: i128) -> Result<(), ContractError> {
+    if amount < get_min_transfer_amount(env, &get_usdc_token(env)?) {
+        return Err(ContractError::AmountBelowMinimum);
+    }
+    Ok(())
+}
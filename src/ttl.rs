@@ -0,0 +1,112 @@
+//! Rent policy for persistent entries.
+//!
+//! Persistent entries are archived once their TTL runs out, and extending
+//! them costs rent. Each class of data gets its own policy, which the admin
+//! can tune: remittance records, which senders and agents need until a
+//! payout is resolved and support needs long after; indexes such as the range
+//! buckets, open index, expiry index and agent inboxes; and per-user transfer
+//! history. Whenever an entry of a class is written, its TTL is extended to
+//! the class's `extend_to` ledgers if it has fallen below `threshold`.
+
+use soroban_sdk::{contracttype, Env, IntoVal, Val};
+
+use crate::ContractError;
+
+/// Ledgers closed per day at a 5-second close time.
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+
+/// Class of persistent data sharing a TTL policy.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TtlClass {
+    /// Remittance records
+    Remittances,
+    /// Range buckets, open and expiry indexes, and agent inboxes
+    Indexes,
+    /// Per-user transfer history
+    History,
+}
+
+/// When and how far the TTL of an entry is extended on write.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TtlPolicy {
+    /// TTL in ledgers below which a write extends the entry
+    pub threshold: u32,
+    /// TTL in ledgers the entry is extended to
+    pub extend_to: u32,
+}
+
+/// TTL policies of all data classes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TtlConfig {
+    pub remittances: TtlPolicy,
+    pub indexes: TtlPolicy,
+    pub history: TtlPolicy,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum TtlKey {
+    /// Policy of a data class
+    Policy(TtlClass),
+}
+
+fn default_ttl_policy(class: TtlClass) -> TtlPolicy {
+    match class {
+        TtlClass::Remittances => TtlPolicy {
+            threshold: 30 * DAY_IN_LEDGERS,
+            extend_to: 120 * DAY_IN_LEDGERS,
+        },
+        TtlClass::Indexes | TtlClass::History => TtlPolicy {
+            threshold: 7 * DAY_IN_LEDGERS,
+            extend_to: 30 * DAY_IN_LEDGERS,
+        },
+    }
+}
+
+/// Returns the policy of `class`, or its default when none was set.
+pub fn get_class_ttl_policy(env: &Env, class: TtlClass) -> TtlPolicy {
+    env.storage()
+        .instance()
+        .get(&TtlKey::Policy(class))
+        .unwrap_or_else(|| default_ttl_policy(class))
+}
+
+/// Replaces the policy of `class`.
+///
+/// # Returns
+///
+/// * `Ok(())` - Policy stored
+/// * `Err(ContractError::InvalidAmount)` - `extend_to` is zero or below `threshold`
+/// * `Err(ContractError::LimitExceeded)` - `extend_to` exceeds the network's maximum TTL
+pub fn set_class_ttl_policy(env: &Env, class: TtlClass, policy: &TtlPolicy) -> Result<(), ContractError> {
+    if policy.extend_to == 0 || policy.threshold > policy.extend_to {
+        return Err(ContractError::InvalidAmount);
+    }
+    if policy.extend_to > env.storage().max_ttl() {
+        return Err(ContractError::LimitExceeded);
+    }
+    env.storage().instance().set(&TtlKey::Policy(class), policy);
+    Ok(())
+}
+
+pub fn get_ttl_config(env: &Env) -> TtlConfig {
+    TtlConfig {
+        remittances: get_class_ttl_policy(env, TtlClass::Remittances),
+        indexes: get_class_ttl_policy(env, TtlClass::Indexes),
+        history: get_class_ttl_policy(env, TtlClass::History),
+    }
+}
+
+/// Extends the TTL of the existing persistent entry `key` of `class` per its policy.
+pub fn extend_persistent_ttl<K>(env: &Env, class: TtlClass, key: &K)
+where
+    K: IntoVal<Env, Val>,
+{
+    let policy = get_class_ttl_policy(env, class);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, policy.threshold, policy.extend_to);
+}
@@ -3,7 +3,9 @@
 //! This module defines the core data structures used throughout the contract,
 //! including remittance records and status enums.
 
-use soroban_sdk::{contracttype, Address};
+use soroban_sdk::{contracttype, Address, BytesN, String};
+
+use crate::Currency;
 
 /// Status of a remittance transaction.
 ///
@@ -11,15 +13,95 @@ use soroban_sdk::{contracttype, Address};
 /// - `Pending`: Initial state after creation, awaiting agent confirmation
 /// - `Completed`: Agent has confirmed payout and received funds
 /// - `Cancelled`: Sender has cancelled and received refund
+/// - `AwaitingAttestation`: Agent confirmed in attested mode; funds held until receipt is attested
+/// - `Expired`: Expiry passed before payout and the sender was refunded by a sweep
+/// - `Quarantined`: Cancelled by the admin on a sanctions hit; funds held in quarantine
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum RemittanceStatus {
     /// Remittance is awaiting agent confirmation
     Pending,
+    /// Agent has confirmed payout; funds are held until receipt is attested
+    AwaitingAttestation,
     /// Remittance has been paid out to the agent
     Completed,
     /// Remittance has been cancelled and refunded to sender
     Cancelled,
+    /// Remittance expired unpaid and was refunded to sender
+    Expired,
+    /// Remittance was frozen by the admin and its funds are held in quarantine
+    Quarantined,
+}
+
+/// Reason the admin gave for cancelling a remittance.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CancelReason {
+    /// Suspected or confirmed fraud
+    Fraud,
+    /// Sanctions screening hit; funds are quarantined instead of refunded
+    Sanctions,
+    /// Other compliance finding
+    Compliance,
+    /// Operational reason, e.g. at the sender's request through support
+    Other,
+}
+
+/// Reason an agent gave for declining a remittance assigned to it.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DeclineReason {
+    /// The agent lacks the cash or float to pay out
+    InsufficientLiquidity,
+    /// The recipient could not be reached
+    RecipientUnreachable,
+    /// The agent does not serve the recipient's location
+    OutsideCoverage,
+    /// Any other reason
+    Other,
+}
+
+/// Declared purpose of a payment.
+///
+/// Several receiving-country regulators require every inbound transfer to
+/// carry a purpose code; corridors may restrict which codes they accept.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PurposeCode {
+    /// Support for family members
+    FamilySupport,
+    /// Tuition and other education costs
+    Education,
+    /// Medical treatment
+    Medical,
+    /// Business payment
+    Business,
+    /// Any other purpose
+    Other,
+}
+
+/// Service tier of a remittance.
+///
+/// Express remittances cost a surcharge, sit ahead of standard ones in the
+/// agent's pending queue and are measured against their own payout SLA.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Priority {
+    /// Regular payout
+    Standard,
+    /// Paid-for faster payout
+    Express,
+}
+
+/// Local-currency payout reported by the agent on confirmation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutFx {
+    /// Amount handed to the recipient, in the smallest unit of the local currency
+    pub local_amount: i128,
+    /// Local currency units per USDC applied by the agent, scaled by 10^7
+    /// like published FX rates
+    pub rate: i128,
 }
 
 /// A remittance transaction record.
@@ -43,8 +125,50 @@ pub struct Remittance {
     pub status: RemittanceStatus,
     /// Optional expiry timestamp (seconds since epoch) for settlement
     pub expiry: Option<u64>,
+    /// Declared purpose of the payment
+    pub purpose: PurposeCode,
+    /// Hash of the off-chain Travel Rule originator payload, if anchored
+    pub originator_hash: Option<BytesN<32>>,
+    /// Hash of the off-chain Travel Rule beneficiary payload, if anchored
+    pub beneficiary_hash: Option<BytesN<32>>,
+    /// Integrator's reference for the remittance, e.g. a PSP order ID
+    pub external_ref: Option<BytesN<32>>,
+    /// Timestamp before which the agent cannot confirm the payout, set from
+    /// the corridor's hold period so the sender can cancel a mistaken transfer
+    pub hold_until: Option<u64>,
+    /// Service tier; remittances start as `Standard` and can be expedited
+    pub priority: Priority,
+    /// Local-currency amount and rate the agent reported paying out, if confirmed with them
+    pub payout_fx: Option<PayoutFx>,
+}
+
+/// Compact view of a remittance returned by range queries.
+///
+/// Range-query buckets hold this instead of the full record: a hundred full
+/// records, with their hashes and payout FX, would come close to the ledger
+/// entry size limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RemittanceSummary {
+    /// Unique identifier of the remittance
+    pub id: u64,
+    /// Address of the sender who initiated the remittance
+    pub sender: Address,
+    /// Address of the agent who will receive the payout
+    pub agent: Address,
+    /// Total amount sent by the sender (in USDC)
+    pub amount: i128,
+    /// Platform fee deducted from the amount (in USDC)
+    pub fee: i128,
+    /// Current status of the remittance
+    pub status: RemittanceStatus,
+    /// Optional expiry timestamp (seconds since epoch) for settlement
+    pub expiry: Option<u64>,
 }
 
+/// Maximum number of entries accepted by a single batch call.
+pub const MAX_BATCH_SIZE: u32 = 50;
+
 /// Entry for batch settlement processing.
 /// Each entry represents a single remittance to be settled.
 #[contracttype]
@@ -63,6 +187,29 @@ pub struct BatchSettlementResult {
     pub settled_ids: Vec<u64>,
 }
 
+/// Entry for batch remittance creation.
+/// Each entry describes one remittance funded by the batch sender.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchCreateEntry {
+    /// Agent that will pay out the remittance
+    pub agent: Address,
+    /// Amount to send (in USDC)
+    pub amount: i128,
+    /// Optional expiry timestamp (seconds since epoch) for settlement
+    pub expiry: Option<u64>,
+}
+
+/// Outcome of one entry of a batch agent registration or removal.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AgentBatchOutcome {
+    /// Agent the entry was for
+    pub agent: Address,
+    /// Error code if the entry was skipped
+    pub error: Option<u32>,
+}
+
 /// Result of a settlement simulation.
 /// Predicts the outcome without executing state changes.
 #[contracttype]
@@ -78,12 +225,46 @@ pub struct SettlementSimulation {
     pub error_message: Option<u32>,
 }
 
+/// Where the money of a paid-out remittance went.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SettlementBreakdown {
+    /// Amount sent by the sender
+    pub gross_amount: i128,
+    /// Platform fee charged, before rebates and sponsor shares
+    pub platform_fee: i128,
+    /// Hop fees paid to intermediary agents of a routed remittance
+    pub agent_commission: i128,
+    /// Part of the platform fee returned to the sponsor that onboarded the sender
+    pub sponsor_share: i128,
+    /// Part of the platform fee rebated to the sender for a late payout
+    pub sla_rebate: i128,
+    /// Insurance premium kept, paid by the sender on top of the amount
+    pub insurance_premium: i128,
+    /// Amount paid out to the agent or recipient contract
+    pub net_payout: i128,
+}
+
+/// Where an effective daily limit was configured.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DailyLimitLevel {
+    /// Global default
+    Global,
+    /// Currency default
+    Currency,
+    /// Override for the (currency, country) pair
+    Country,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DailyLimit {
-    pub currency: String,
+    pub currency: Currency,
     pub country: String,
     pub limit: i128,
+    /// Level of the hierarchy the limit was configured at
+    pub level: DailyLimitLevel,
 }
 
 #[contracttype]
@@ -92,3 +273,79 @@ pub struct TransferRecord {
     pub timestamp: u64,
     pub amount: i128,
 }
+
+/// Result of an on-chain reconciliation of the contract's token balance.
+///
+/// The expected balance is everything the contract owes: escrowed principal,
+/// accumulated platform fees, and insurance premiums held or retained.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReconciliationReport {
+    /// Actual token balance held by the contract
+    pub token_balance: i128,
+    /// Escrow and wrapped fees deposited in the yield adapter, held on the contract's behalf
+    pub yield_principal: i128,
+    /// Principal locked for pending remittances
+    pub escrow: i128,
+    /// Platform fees awaiting withdrawal
+    pub accumulated_fees: i128,
+    /// Insurance premiums held for active policies plus the insurance fund
+    pub insurance: i128,
+    /// Confirmed payouts batched for agents on a netting schedule
+    pub pending_settlements: i128,
+    /// Funds of quarantined remittances awaiting an investigation outcome
+    pub quarantined: i128,
+    /// Collateral posted by agents
+    pub collateral: i128,
+    /// Pools deposited by sponsors to pay relayers
+    pub sponsorship: i128,
+    /// Fees shared with staked agents and not yet claimed
+    pub staking_rewards: i128,
+    /// token_balance minus the expected balance (0 when balanced)
+    pub discrepancy: i128,
+}
+
+/// Snapshot of the contract's configuration and totals for operational dashboards.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractState {
+    /// Contract administrator
+    pub admin: Address,
+    /// Settlement token (USDC)
+    pub usdc_token: Address,
+    /// Platform fee in basis points
+    pub platform_fee_bps: u32,
+    /// Whether the contract is paused
+    pub paused: bool,
+    /// Net amount held in escrow for pending remittances
+    pub total_escrow: i128,
+    /// Accumulated USDC platform fees
+    pub accumulated_fees: i128,
+    /// Number of registered agents
+    pub agent_count: u32,
+    /// ID the next remittance will receive
+    pub next_remittance_id: u64,
+}
+
+/// Daily working hours of an agent, in UTC hours (0-23).
+///
+/// The agent accepts remittances from `start_hour` up to but excluding
+/// `end_hour`. A window with `start_hour > end_hour` spans midnight.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WorkingHours {
+    pub start_hour: u32,
+    pub end_hour: u32,
+}
+
+impl WorkingHours {
+    /// Returns whether the given ledger timestamp falls inside the window.
+    pub fn contains(&self, timestamp: u64) -> bool {
+        let hour = ((timestamp % 86_400) / 3_600) as u32;
+        if self.start_hour <= self.end_hour {
+            hour >= self.start_hour && hour < self.end_hour
+        } else {
+            hour >= self.start_hour || hour < self.end_hour
+        }
+    }
+}
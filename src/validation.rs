@@ -3,9 +3,9 @@
 //! This module provides validation functions for Stellar addresses used in
 //! contract operations.
 
-use soroban_sdk::Address;
+use soroban_sdk::{symbol_short, Address, Env, Vec};
 
-use crate::{ContractError, is_agent_registered, is_paused, get_remittance, RemittanceStatus};
+use crate::{ContractError, InitConfig, check_corridor_not_blocked, check_op_not_paused, get_treasury, PausableOp, verify_token, check_max_transfer_amount, check_risk_limits, check_route_complete, check_min_transfer_amount, log_limit_error, log_remittance_error, is_agent_available, is_agent_registered, is_agent_suspended, is_paused, is_self_payout_allowed, is_sender_allowed, get_remittance, get_visible_at, validate_screened, RemittanceStatus};
 
 /// Centralized validation module for all API requests.
 /// Validates required fields before controller logic to prevent invalid data
@@ -52,9 +52,23 @@ pub fn validate_fee_bps(fee_bps: u32) -> Result<(), ContractError> {
 }
 
 /// Validates that an amount is positive and non-zero.
+///
+/// Every entry point taking an amount, top-up, partial payout, collateral,
+/// escrow deposit or positive limit checks it here first.
 pub fn validate_amount(amount: i128) -> Result<(), ContractError> {
-    if amount <= 0 {
-        return Err(ContractError::InvalidAmount);
+    if amount == 0 {
+        return Err(ContractError::ZeroAmount);
+    }
+    if amount < 0 {
+        return Err(ContractError::NegativeAmount);
+    }
+    Ok(())
+}
+
+/// Validates that an amount or limit where zero is meaningful is not negative.
+pub fn validate_non_negative(amount: i128) -> Result<(), ContractError> {
+    if amount < 0 {
+        return Err(ContractError::NegativeAmount);
     }
     Ok(())
 }
@@ -67,6 +81,30 @@ pub fn validate_agent_registered(env: &Env, agent: &Address) -> Result<(), Contr
     Ok(())
 }
 
+/// Validates that an agent is not suspended.
+pub fn validate_agent_not_suspended(env: &Env, agent: &Address) -> Result<(), ContractError> {
+    if is_agent_suspended(env, agent) {
+        return Err(ContractError::AgentSuspended);
+    }
+    Ok(())
+}
+
+/// Validates that an agent is currently available.
+pub fn validate_agent_available(env: &Env, agent: &Address) -> Result<(), ContractError> {
+    if !is_agent_available(env, agent) {
+        return Err(ContractError::AgentUnavailable);
+    }
+    Ok(())
+}
+
+/// Validates that a sender may create remittances under the allowlist mode.
+pub fn validate_sender_allowed(env: &Env, sender: &Address) -> Result<(), ContractError> {
+    if !is_sender_allowed(env, sender) {
+        return Err(ContractError::SenderNotAllowed);
+    }
+    Ok(())
+}
+
 /// Validates that the contract is not paused.
 pub fn validate_not_paused(env: &Env) -> Result<(), ContractError> {
     if is_paused(env) {
@@ -99,6 +137,16 @@ pub fn validate_settlement_not_expired(env: &Env, expiry: Option<u64>) -> Result
     Ok(())
 }
 
+/// Validates that a remittance's hold period, if any, has elapsed.
+pub fn validate_hold_elapsed(env: &Env, hold_until: Option<u64>) -> Result<(), ContractError> {
+    if let Some(hold_until) = hold_until {
+        if env.ledger().timestamp() < hold_until {
+            return Err(ContractError::PayoutOnHold);
+        }
+    }
+    Ok(())
+}
+
 /// Validates that a settlement has not been executed before (duplicate check).
 pub fn validate_no_duplicate_settlement(env: &Env, remittance_id: u64) -> Result<(), ContractError> {
     if crate::has_settlement_hash(env, remittance_id) {
@@ -116,26 +164,90 @@ pub fn validate_fees_available(fees: i128) -> Result<(), ContractError> {
 }
 
 /// Comprehensive validation for initialize request.
+///
+/// The configuration is validated as a whole, including probing every token,
+/// before `initialize` writes any state.
+///
+/// # Returns
+///
+/// * `Ok(Address)` - The settlement token
+/// * `Err(ContractError::AlreadyInitialized)` - Contract was already initialized
+/// * `Err(ContractError::TokenNotWhitelisted)` - `tokens` is empty
+/// * `Err(ContractError::TokenAlreadyWhitelisted)` - A token is listed twice
+/// * `Err(ContractError::UnsupportedToken)` - A token is not a token contract
+/// * `Err(ContractError::InvalidFeeBps)` - Fee exceeds 10000 bps
+/// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - A limit is out of range
+/// * `Err(ContractError::InvalidAmount)` - The minimum exceeds the maximum
+/// * `Err(ContractError::InvalidAddress)` - The treasury is the contract itself
+///
+/// `paused_ops` needs no check here: it holds `PausableOp` values, so an
+/// unknown operation name is rejected when the configuration is decoded,
+/// exactly as `pause_op` rejects it.
 pub fn validate_initialize_request(
     env: &Env,
-    admin: &Address,
-    token: &Address,
-    fee_bps: u32,
-) -> Result<(), ContractError> {
-    validate_address(admin)?;
-    validate_address(token)?;
-    validate_fee_bps(fee_bps)?;
-    
+    config: &InitConfig,
+) -> Result<Address, ContractError> {
     // Check if already initialized
     if crate::has_admin(env) {
         return Err(ContractError::AlreadyInitialized);
     }
-    
-    // Check if token is whitelisted
-    if !crate::is_token_whitelisted(env, token) {
-        return Err(ContractError::TokenNotWhitelisted);
+
+    validate_address(&config.admin)?;
+    validate_fee_bps(config.fee_bps)?;
+
+    let token = config.tokens.first().ok_or(ContractError::TokenNotWhitelisted)?;
+    for (index, listed) in config.tokens.iter().enumerate() {
+        if config.tokens.first_index_of(&listed) != Some(index as u32) {
+            return Err(ContractError::TokenAlreadyWhitelisted);
+        }
+        verify_token(env, &listed)?;
+    }
+
+    validate_non_negative(config.min_transfer_amount)?;
+    if let Some(max) = config.max_transfer_amount {
+        validate_amount(max)?;
+        if config.min_transfer_amount > max {
+            return Err(ContractError::InvalidAmount);
+        }
+    }
+
+    if let Some(treasury) = &config.treasury {
+        validate_treasury(env, treasury)?;
+    }
+
+    Ok(token)
+}
+
+/// Validates a treasury address fee withdrawals can be restricted to.
+///
+/// # Returns
+///
+/// * `Ok(())` - The treasury is valid
+/// * `Err(ContractError::InvalidAddress)` - The treasury is the contract itself
+pub fn validate_treasury(env: &Env, treasury: &Address) -> Result<(), ContractError> {
+    validate_address(treasury)?;
+    if *treasury == env.current_contract_address() {
+        return Err(ContractError::InvalidAddress);
+    }
+    Ok(())
+}
+
+/// Validates that `sender` is not paying itself through `counterparty`, the
+/// agent or payout recipient of a remittance assigned to `agent`.
+///
+/// # Returns
+///
+/// * `Ok(())` - The addresses differ, or the agent's corridor allows self-payout
+/// * `Err(ContractError::SelfDealing)` - The sender is the counterparty
+pub fn validate_not_self_dealing(
+    env: &Env,
+    sender: &Address,
+    counterparty: &Address,
+    agent: &Address,
+) -> Result<(), ContractError> {
+    if sender == counterparty && !is_self_payout_allowed(env, agent) {
+        return Err(ContractError::SelfDealing);
     }
-    
     Ok(())
 }
 
@@ -146,10 +258,19 @@ pub fn validate_create_remittance_request(
     agent: &Address,
     amount: i128,
 ) -> Result<(), ContractError> {
+    check_op_not_paused(env, PausableOp::CreateRemittance)?;
     validate_address(sender)?;
     validate_address(agent)?;
+    validate_not_self_dealing(env, sender, agent, agent)?;
+    validate_sender_allowed(env, sender)?;
     validate_amount(amount)?;
     validate_agent_registered(env, agent)?;
+    validate_agent_not_suspended(env, agent)?;
+    validate_agent_available(env, agent)?;
+    check_min_transfer_amount(env, amount)?;
+    check_max_transfer_amount(env, agent, amount)?;
+    check_risk_limits(env, sender, amount)?;
+    check_corridor_not_blocked(env, agent)?;
     Ok(())
 }
 
@@ -159,10 +280,45 @@ pub fn validate_confirm_payout_request(
     remittance_id: u64,
 ) -> Result<crate::Remittance, ContractError> {
     validate_not_paused(env)?;
+    check_op_not_paused(env, PausableOp::ConfirmPayout)?;
     let remittance = validate_remittance_exists(env, remittance_id)?;
-    validate_remittance_pending(&remittance)?;
-    validate_no_duplicate_settlement(env, remittance_id)?;
-    validate_settlement_not_expired(env, remittance.expiry)?;
+    validate_remittance_pending(&remittance).map_err(|e| {
+        log_remittance_error(env, symbol_short!("confirm"), e, remittance_id, &remittance.status)
+    })?;
+    validate_no_duplicate_settlement(env, remittance_id).map_err(|e| {
+        log_remittance_error(env, symbol_short!("confirm"), e, remittance_id, &remittance.status)
+    })?;
+    validate_settlement_not_expired(env, remittance.expiry).map_err(|e| {
+        log_limit_error(
+            env,
+            symbol_short!("confirm"),
+            e,
+            env.ledger().timestamp() as i128,
+            remittance.expiry.unwrap_or(0) as i128,
+        )
+    })?;
+    validate_hold_elapsed(env, remittance.hold_until).map_err(|e| {
+        log_limit_error(
+            env,
+            symbol_short!("confirm"),
+            e,
+            env.ledger().timestamp() as i128,
+            remittance.hold_until.unwrap_or(0) as i128,
+        )
+    })?;
+    validate_screened(env, remittance_id).map_err(|e| {
+        log_limit_error(
+            env,
+            symbol_short!("confirm"),
+            e,
+            env.ledger().sequence() as i128,
+            get_visible_at(env, remittance_id).unwrap_or(0) as i128,
+        )
+    })?;
+    // Intermediaries of a routed remittance confirm their legs first
+    check_route_complete(env, remittance_id).map_err(|e| {
+        log_remittance_error(env, symbol_short!("confirm"), e, remittance_id, &remittance.status)
+    })?;
     validate_address(&remittance.agent)?;
     Ok(remittance)
 }
@@ -172,8 +328,11 @@ pub fn validate_cancel_remittance_request(
     env: &Env,
     remittance_id: u64,
 ) -> Result<crate::Remittance, ContractError> {
+    check_op_not_paused(env, PausableOp::CancelRemittance)?;
     let remittance = validate_remittance_exists(env, remittance_id)?;
-    validate_remittance_pending(&remittance)?;
+    validate_remittance_pending(&remittance).map_err(|e| {
+        log_remittance_error(env, symbol_short!("cancel"), e, remittance_id, &remittance.status)
+    })?;
     validate_address(&remittance.sender)?;
     Ok(remittance)
 }
@@ -181,14 +340,65 @@ pub fn validate_cancel_remittance_request(
 /// Comprehensive validation for withdraw_fees request.
 pub fn validate_withdraw_fees_request(
     env: &Env,
+    token: &Address,
     to: &Address,
 ) -> Result<i128, ContractError> {
+    check_op_not_paused(env, PausableOp::WithdrawFees)?;
     validate_address(to)?;
-    let fees = crate::get_accumulated_fees(env)?;
+    // With a treasury configured, fees can only be withdrawn to it
+    if let Some(treasury) = get_treasury(env) {
+        if treasury != *to {
+            return Err(ContractError::Unauthorized);
+        }
+    }
+    let fees = crate::get_withdrawable_fees_for(env, token);
     validate_fees_available(fees)?;
     Ok(fees)
 }
 
+/// Validates a fee split: at least one recipient, no duplicates, shares summing to 10000 bps.
+pub fn validate_fee_split(splits: &Vec<(Address, u32)>) -> Result<(), ContractError> {
+    if splits.is_empty() {
+        return Err(ContractError::InvalidFeeBps);
+    }
+
+    let mut total: u32 = 0;
+    for (i, (recipient, share_bps)) in splits.iter().enumerate() {
+        validate_address(&recipient)?;
+        if share_bps == 0 {
+            return Err(ContractError::InvalidFeeBps);
+        }
+        for (other, _) in splits.iter().skip(i + 1) {
+            if other == recipient {
+                return Err(ContractError::InvalidAddress);
+            }
+        }
+        total = total.checked_add(share_bps).ok_or(ContractError::InvalidFeeBps)?;
+    }
+
+    if total != 10000 {
+        return Err(ContractError::InvalidFeeBps);
+    }
+    Ok(())
+}
+
+/// Validates the size of a batch of agents to register or remove.
+pub fn validate_agent_batch(env: &Env, agents: &Vec<Address>) -> Result<(), ContractError> {
+    if agents.is_empty() {
+        return Err(ContractError::InvalidAmount);
+    }
+    if agents.len() > crate::MAX_BATCH_SIZE {
+        return Err(log_limit_error(
+            env,
+            symbol_short!("agents"),
+            ContractError::LimitExceeded,
+            agents.len() as i128,
+            crate::MAX_BATCH_SIZE as i128,
+        ));
+    }
+    Ok(())
+}
+
 /// Comprehensive validation for update_fee request.
 pub fn validate_update_fee_request(fee_bps: u32) -> Result<(), ContractError> {
     validate_fee_bps(fee_bps)
@@ -253,9 +463,9 @@ mod tests {
 
     #[test]
     fn test_validate_amount_invalid() {
-        assert_eq!(validate_amount(0), Err(ContractError::InvalidAmount));
-        assert_eq!(validate_amount(-1), Err(ContractError::InvalidAmount));
-        assert_eq!(validate_amount(-1000), Err(ContractError::InvalidAmount));
+        assert_eq!(validate_amount(0), Err(ContractError::ZeroAmount));
+        assert_eq!(validate_amount(-1), Err(ContractError::NegativeAmount));
+        assert_eq!(validate_amount(-1000), Err(ContractError::NegativeAmount));
     }
 
     #[test]
@@ -0,0 +1,103 @@
+//! Progressive fee discounts by cumulative sender volume.
+//!
+//! Every paid-out remittance adds its amount to its sender's lifetime
+//! completed volume. The admin configures volume tiers, each taking a share
+//! off the platform fee once a sender's volume reaches the tier's threshold.
+//! The discount is applied by the shared fee calculation, so remittance
+//! creation and quotes for the same sender agree.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::ContractError;
+
+/// Maximum number of volume tiers.
+pub const MAX_VOLUME_TIERS: u32 = 10;
+
+/// A fee discount for senders whose lifetime volume reaches a threshold.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VolumeTier {
+    /// Lifetime completed volume from which the tier applies
+    pub min_volume: i128,
+    /// Share of the platform fee waived, in basis points of the fee
+    pub discount_bps: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum VolumeKey {
+    /// Volume tiers, lowest threshold first
+    Tiers,
+    /// Lifetime completed volume of a sender (persistent storage)
+    Volume(Address),
+}
+
+/// Replaces the volume tiers; an empty list removes volume discounts.
+///
+/// # Returns
+///
+/// * `Ok(())` - Tiers stored
+/// * `Err(ContractError::LimitExceeded)` - More than MAX_VOLUME_TIERS tiers
+/// * `Err(ContractError::InvalidAmount)` - Thresholds are negative or not strictly ascending
+/// * `Err(ContractError::InvalidFeeBps)` - A discount above 10000 basis points
+pub fn set_volume_tiers(env: &Env, tiers: &Vec<VolumeTier>) -> Result<(), ContractError> {
+    if tiers.len() > MAX_VOLUME_TIERS {
+        return Err(ContractError::LimitExceeded);
+    }
+    let mut previous: Option<i128> = None;
+    for tier in tiers.iter() {
+        if tier.min_volume < 0 || previous.map_or(false, |min| tier.min_volume <= min) {
+            return Err(ContractError::InvalidAmount);
+        }
+        if tier.discount_bps > 10000 {
+            return Err(ContractError::InvalidFeeBps);
+        }
+        previous = Some(tier.min_volume);
+    }
+
+    if tiers.is_empty() {
+        env.storage().instance().remove(&VolumeKey::Tiers);
+    } else {
+        env.storage().instance().set(&VolumeKey::Tiers, tiers);
+    }
+    Ok(())
+}
+
+pub fn get_volume_tiers(env: &Env) -> Vec<VolumeTier> {
+    env.storage()
+        .instance()
+        .get(&VolumeKey::Tiers)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns the lifetime completed volume of `sender`.
+pub fn get_sender_volume(env: &Env, sender: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&VolumeKey::Volume(sender.clone()))
+        .unwrap_or(0)
+}
+
+/// Adds the `amount` of a paid-out remittance to the lifetime volume of `sender`.
+pub fn record_sender_volume(env: &Env, sender: &Address, amount: i128) -> Result<i128, ContractError> {
+    let volume = get_sender_volume(env, sender)
+        .checked_add(amount)
+        .ok_or(ContractError::Overflow)?;
+    env.storage()
+        .persistent()
+        .set(&VolumeKey::Volume(sender.clone()), &volume);
+    Ok(volume)
+}
+
+/// Returns the fee discount of the highest tier `sender` has reached, 0 when none.
+pub fn get_volume_discount_bps(env: &Env, sender: &Address) -> u32 {
+    let volume = get_sender_volume(env, sender);
+    let mut discount_bps = 0;
+    for tier in get_volume_tiers(env).iter() {
+        if tier.min_volume > volume {
+            break;
+        }
+        discount_bps = tier.discount_bps;
+    }
+    discount_bps
+}
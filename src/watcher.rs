@@ -0,0 +1,76 @@
+//! Registry of off-chain watchers of contract events.
+//!
+//! Webhook relays and indexers register the event topics they follow, so
+//! operators can see from contract state who consumes which events before
+//! changing them. The registry is metadata only: events are published to
+//! every subscriber either way, and the `status_changed` topic alone covers
+//! every status transition of a remittance.
+
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+
+use crate::ContractError;
+
+/// Maximum number of topics in a watcher's filter.
+pub const MAX_WATCHER_TOPICS: u32 = 10;
+
+/// A registered watcher.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Watcher {
+    /// Address that registered the watcher and may remove it
+    pub owner: Address,
+    /// Event topics the watcher follows; empty for all
+    pub topic_filter: Vec<Symbol>,
+    /// Timestamp of the registration
+    pub registered_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum WatcherKey {
+    /// Number of watchers ever registered
+    Count,
+    /// Registration by ID (persistent storage)
+    Watcher(u32),
+}
+
+/// Stores a watcher for `owner` following `topic_filter`.
+///
+/// # Returns
+///
+/// * `Ok(u32)` - ID of the registration
+/// * `Err(ContractError::LimitExceeded)` - The filter has more than MAX_WATCHER_TOPICS topics
+pub fn register_watcher(env: &Env, owner: &Address, topic_filter: Vec<Symbol>) -> Result<u32, ContractError> {
+    if topic_filter.len() > MAX_WATCHER_TOPICS {
+        return Err(ContractError::LimitExceeded);
+    }
+
+    let watcher_id = get_watcher_count(env)
+        .checked_add(1)
+        .ok_or(ContractError::Overflow)?;
+    env.storage().instance().set(&WatcherKey::Count, &watcher_id);
+    env.storage().persistent().set(
+        &WatcherKey::Watcher(watcher_id),
+        &Watcher {
+            owner: owner.clone(),
+            topic_filter,
+            registered_at: env.ledger().timestamp(),
+        },
+    );
+    Ok(watcher_id)
+}
+
+/// Returns the number of watchers ever registered, including removed ones.
+pub fn get_watcher_count(env: &Env) -> u32 {
+    env.storage().instance().get(&WatcherKey::Count).unwrap_or(0)
+}
+
+pub fn get_watcher(env: &Env, watcher_id: u32) -> Option<Watcher> {
+    env.storage().persistent().get(&WatcherKey::Watcher(watcher_id))
+}
+
+pub fn remove_watcher(env: &Env, watcher_id: u32) {
+    env.storage()
+        .persistent()
+        .remove(&WatcherKey::Watcher(watcher_id));
+}
@@ -0,0 +1,335 @@
+//! Yield on idle escrow.
+//!
+//! The admin can register a yield adapter (a contract wrapping a lending pool
+//! or vault) and deposit part of the escrowed USDC into it. Deposited
+//! principal never exceeds escrow, and whenever escrow is released the
+//! principal is withdrawn back down to what is still escrowed, so every
+//! payout, refund, fee withdrawal and settlement is paid from funds the
+//! contract holds itself. Every withdrawal is checked against the contract's
+//! own balance rather than trusted from the adapter.
+//!
+//! Withdrawable platform fees can be wrapped in the same position, so revenue
+//! earns yield until it is withdrawn. Wrapped fees never exceed the
+//! withdrawable fees and are converted back on `withdraw_fees`, fee
+//! distribution and keeper bounties, just before they are paid out. They are
+//! tracked apart from the escrow principal, and their share of the yield
+//! apart from the fees themselves.
+//!
+//! Anything the adapter holds above the escrow principal and wrapped fees is
+//! yield. Harvesting it books the wrapped fees' pro-rata share as fees and
+//! credits the rest to the insurance fund or pays it to a treasury address.
+
+use soroban_sdk::{contractclient, contracttype, symbol_short, token, Address, Env};
+
+use crate::{
+    add_to_insurance_fund, adjust_liability, emit_operation_failed, get_total_escrow, get_usdc_token,
+    get_withdrawable_fees, validate_amount, AccountClass, ContractError,
+};
+
+/// Interface the registered yield adapter must implement.
+///
+/// The adapter receives `amount` of the settlement token before `deposit` is
+/// called. `withdraw` must transfer `amount` of it to `to`, and `balance`
+/// returns what `owner` could withdraw, principal and yield included. Calls
+/// come from the SwiftRemit contract itself, so the adapter can require the
+/// owner's authorization.
+#[contractclient(name = "YieldAdapterClient")]
+pub trait YieldAdapter {
+    fn deposit(env: Env, owner: Address, amount: i128);
+    fn withdraw(env: Env, owner: Address, to: Address, amount: i128);
+    fn balance(env: Env, owner: Address) -> i128;
+}
+
+/// Where harvested yield goes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum YieldBeneficiary {
+    /// Credited to the insurance fund, held by the contract
+    InsuranceFund,
+    /// Paid to a treasury address
+    Treasury(Address),
+}
+
+/// Registered yield adapter and the beneficiary of its yield.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct YieldConfig {
+    pub adapter: Address,
+    pub beneficiary: YieldBeneficiary,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum YieldKey {
+    /// Registered adapter and beneficiary
+    Config,
+    /// Escrow principal currently deposited in the adapter
+    Principal,
+    /// Withdrawable fees currently deposited in the adapter
+    WrappedFees,
+    /// Yield earned by wrapped fees and booked as fees, in total
+    FeeYield,
+}
+
+pub fn set_yield_config(env: &Env, config: &YieldConfig) {
+    env.storage().instance().set(&YieldKey::Config, config);
+}
+
+pub fn clear_yield_config(env: &Env) {
+    env.storage().instance().remove(&YieldKey::Config);
+}
+
+pub fn get_yield_config(env: &Env) -> Option<YieldConfig> {
+    env.storage().instance().get(&YieldKey::Config)
+}
+
+/// Returns the escrow principal currently deposited in the yield adapter.
+pub fn get_yield_principal(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&YieldKey::Principal)
+        .unwrap_or(0)
+}
+
+fn set_yield_principal(env: &Env, principal: i128) {
+    env.storage().instance().set(&YieldKey::Principal, &principal);
+}
+
+/// Returns the withdrawable fees currently wrapped in the yield adapter.
+pub fn get_wrapped_fees(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&YieldKey::WrappedFees)
+        .unwrap_or(0)
+}
+
+fn set_wrapped_fees(env: &Env, wrapped: i128) {
+    env.storage().instance().set(&YieldKey::WrappedFees, &wrapped);
+}
+
+/// Returns the yield wrapped fees have earned, as booked by harvests.
+pub fn get_fee_yield(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&YieldKey::FeeYield)
+        .unwrap_or(0)
+}
+
+/// Deposits `amount` of idle escrow into the yield adapter.
+///
+/// # Returns
+///
+/// * `Ok(())` - Principal deposited
+/// * `Err(ContractError::InvalidStatus)` - No yield adapter is registered
+/// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - Amount is not positive
+/// * `Err(ContractError::InsufficientEscrow)` - Principal would exceed escrow
+pub fn deposit_to_yield(env: &Env, amount: i128) -> Result<(), ContractError> {
+    validate_amount(amount)?;
+    let config = get_yield_config(env).ok_or(ContractError::InvalidStatus)?;
+    let principal = get_yield_principal(env)
+        .checked_add(amount)
+        .ok_or(ContractError::Overflow)?;
+    if principal > get_total_escrow(env) {
+        return Err(ContractError::InsufficientEscrow);
+    }
+
+    let contract = env.current_contract_address();
+    token::Client::new(env, &get_usdc_token(env)?).transfer(&contract, &config.adapter, &amount);
+    YieldAdapterClient::new(env, &config.adapter).deposit(&contract, &amount);
+    set_yield_principal(env, principal);
+
+    Ok(())
+}
+
+/// Withdraws `amount` from the adapter to `to`, checking that `to` received it.
+fn withdraw_checked(
+    env: &Env,
+    adapter: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<(), ContractError> {
+    let token_client = token::Client::new(env, &get_usdc_token(env)?);
+    let balance_before = token_client.balance(to);
+
+    YieldAdapterClient::new(env, adapter).withdraw(&env.current_contract_address(), to, &amount);
+
+    let received = token_client
+        .balance(to)
+        .checked_sub(balance_before)
+        .ok_or(ContractError::Overflow)?;
+    if received < amount {
+        return Err(ContractError::InsufficientEscrow);
+    }
+    Ok(())
+}
+
+/// Withdraws `amount` of principal from the yield adapter back into the contract.
+///
+/// # Returns
+///
+/// * `Ok(())` - Principal withdrawn
+/// * `Err(ContractError::InvalidStatus)` - No yield adapter is registered
+/// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - Amount is not positive
+/// * `Err(ContractError::InsufficientEscrow)` - Amount exceeds the principal,
+///   or the adapter paid back less than requested
+pub fn withdraw_from_yield(env: &Env, amount: i128) -> Result<(), ContractError> {
+    validate_amount(amount)?;
+    let config = get_yield_config(env).ok_or(ContractError::InvalidStatus)?;
+    let principal = get_yield_principal(env);
+    if amount > principal {
+        return Err(ContractError::InsufficientEscrow);
+    }
+
+    withdraw_checked(env, &config.adapter, &env.current_contract_address(), amount)?;
+    set_yield_principal(env, principal - amount);
+
+    Ok(())
+}
+
+/// Wraps `amount` of withdrawable fees in the yield adapter.
+///
+/// # Returns
+///
+/// * `Ok(())` - Fees wrapped
+/// * `Err(ContractError::InvalidStatus)` - No yield adapter is registered
+/// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - Amount is not positive
+/// * `Err(ContractError::InsufficientEscrow)` - Wrapped fees would exceed the withdrawable fees
+pub fn wrap_fees(env: &Env, amount: i128) -> Result<(), ContractError> {
+    validate_amount(amount)?;
+    let config = get_yield_config(env).ok_or(ContractError::InvalidStatus)?;
+    let wrapped = get_wrapped_fees(env)
+        .checked_add(amount)
+        .ok_or(ContractError::Overflow)?;
+    if wrapped > get_withdrawable_fees(env)? {
+        return Err(ContractError::InsufficientEscrow);
+    }
+
+    let contract = env.current_contract_address();
+    token::Client::new(env, &get_usdc_token(env)?).transfer(&contract, &config.adapter, &amount);
+    YieldAdapterClient::new(env, &config.adapter).deposit(&contract, &amount);
+    set_wrapped_fees(env, wrapped);
+
+    Ok(())
+}
+
+/// Converts `amount` of wrapped fees back into the contract.
+///
+/// # Returns
+///
+/// * `Ok(())` - Fees unwrapped
+/// * `Err(ContractError::InvalidStatus)` - No yield adapter is registered
+/// * `Err(ContractError::ZeroAmount)` / `Err(ContractError::NegativeAmount)` - Amount is not positive
+/// * `Err(ContractError::InsufficientEscrow)` - Amount exceeds the wrapped fees,
+///   or the adapter paid back less than requested
+pub fn unwrap_fees(env: &Env, amount: i128) -> Result<(), ContractError> {
+    validate_amount(amount)?;
+    let config = get_yield_config(env).ok_or(ContractError::InvalidStatus)?;
+    let wrapped = get_wrapped_fees(env);
+    if amount > wrapped {
+        return Err(ContractError::InsufficientEscrow);
+    }
+
+    withdraw_checked(env, &config.adapter, &env.current_contract_address(), amount)?;
+    set_wrapped_fees(env, wrapped - amount);
+
+    Ok(())
+}
+
+/// Unwraps fees until the contract itself holds `amount` of withdrawable fees.
+///
+/// Called before withdrawable fees are paid out. Fees only become
+/// withdrawable on payout and only leave through these payments, so wrapped
+/// fees never exceed what is left withdrawable afterwards.
+pub fn unwrap_fees_for(env: &Env, amount: i128) -> Result<(), ContractError> {
+    let wrapped = get_wrapped_fees(env);
+    if wrapped == 0 {
+        return Ok(());
+    }
+    let held = get_withdrawable_fees(env)?
+        .checked_sub(wrapped)
+        .ok_or(ContractError::Overflow)?;
+    if amount > held {
+        unwrap_fees(env, (amount - held).min(wrapped))?;
+    }
+    Ok(())
+}
+
+/// Withdraws principal until it no longer exceeds escrow.
+///
+/// Called whenever escrow is released, so the released funds, and every
+/// other liability, are held by the contract when they are paid out.
+pub fn rebalance_yield(env: &Env) -> Result<(), ContractError> {
+    let excess = get_yield_principal(env)
+        .checked_sub(get_total_escrow(env))
+        .ok_or(ContractError::Overflow)?;
+    if excess > 0 {
+        withdraw_from_yield(env, excess)?;
+    }
+    Ok(())
+}
+
+/// Collects the yield earned above the escrow principal and wrapped fees.
+///
+/// The wrapped fees' share of the yield, pro rata to their part of the
+/// position, is booked as fees; the rest goes to the beneficiary. An adapter
+/// that fails to report its balance yields nothing and is reported with a
+/// `diag` event.
+///
+/// # Returns
+///
+/// * `Ok((i128, i128))` - Yield sent to the beneficiary and yield booked as
+///   fees, both 0 if there was none
+/// * `Err(ContractError::InvalidStatus)` - No yield adapter is registered
+/// * `Err(ContractError::InsufficientEscrow)` - The adapter paid out less than it reported
+pub fn harvest_yield(env: &Env) -> Result<(i128, i128), ContractError> {
+    let config = get_yield_config(env).ok_or(ContractError::InvalidStatus)?;
+    let contract = env.current_contract_address();
+
+    let balance = match YieldAdapterClient::new(env, &config.adapter).try_balance(&contract) {
+        Ok(Ok(balance)) => balance,
+        result => {
+            let error_code = match result {
+                Err(Ok(error)) => error.get_code(),
+                _ => 0,
+            };
+            emit_operation_failed(env, symbol_short!("harvest"), config.adapter.clone(), error_code, 0);
+            return Ok((0, 0));
+        }
+    };
+    let principal = get_yield_principal(env);
+    let wrapped = get_wrapped_fees(env);
+    let deposited = principal.checked_add(wrapped).ok_or(ContractError::Overflow)?;
+    let earned = balance.checked_sub(deposited).ok_or(ContractError::Overflow)?;
+    if earned <= 0 {
+        return Ok((0, 0));
+    }
+
+    let fee_yield = if wrapped > 0 {
+        earned.checked_mul(wrapped).ok_or(ContractError::Overflow)? / deposited
+    } else {
+        0
+    };
+    if fee_yield > 0 {
+        withdraw_checked(env, &config.adapter, &contract, fee_yield)?;
+        adjust_liability(env, AccountClass::Fees, fee_yield)?;
+        let total = get_fee_yield(env)
+            .checked_add(fee_yield)
+            .ok_or(ContractError::Overflow)?;
+        env.storage().instance().set(&YieldKey::FeeYield, &total);
+    }
+
+    let beneficiary_yield = earned - fee_yield;
+    if beneficiary_yield > 0 {
+        match &config.beneficiary {
+            YieldBeneficiary::InsuranceFund => {
+                withdraw_checked(env, &config.adapter, &contract, beneficiary_yield)?;
+                add_to_insurance_fund(env, beneficiary_yield)?;
+            }
+            YieldBeneficiary::Treasury(treasury) => {
+                withdraw_checked(env, &config.adapter, treasury, beneficiary_yield)?;
+            }
+        }
+    }
+
+    Ok((beneficiary_yield, fee_yield))
+}